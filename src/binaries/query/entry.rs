@@ -37,8 +37,10 @@ use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::tasks::TaskScheduler;
 use databend_query::GlobalServices;
 use log::info;
 
@@ -149,6 +151,28 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // PostgreSQL handler, which shares the TLS config of the MySQL handler.
+    if conf.query.postgres_handler_enabled {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.postgres_handler_tcp_keepalive_timeout_secs;
+        let tls_config = MySQLTlsConfig::new(
+            conf.query.mysql_tls_server_cert.clone(),
+            conf.query.mysql_tls_server_key.clone(),
+        );
+
+        let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs, tls_config)?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service("PostgresHandler", handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -U root -h {} -p {}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -231,6 +255,12 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Built-in task scheduler, tasks are scheduled by cloud control if it is configured.
+    if conf.query.cloud_control_grpc_server_address.is_none() {
+        TaskScheduler::instance().start();
+        info!("Built-in task scheduler started.");
+    }
+
     // Print information to users.
     println!("Databend Query");
     println!();
@@ -297,6 +327,17 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         "    connect via: mysql -u${{USER}} -p${{PASSWORD}} -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    if conf.query.postgres_handler_enabled {
+        println!("PostgreSQL");
+        println!(
+            "    listened at {}:{}",
+            conf.query.postgres_handler_host, conf.query.postgres_handler_port
+        );
+        println!(
+            "    connect via: psql -U ${{USER}} -h {} -p {} -d default",
+            conf.query.postgres_handler_host, conf.query.postgres_handler_port
+        );
+    }
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
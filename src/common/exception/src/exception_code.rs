@@ -149,6 +149,9 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    /// A statement of the explicit transaction failed, the transaction must be rolled back.
+    CurrentTransactionIsAborted(1122),
+    ColumnReferencedByInvertedIndex(1123),
 
     // Data Related Errors
 
@@ -332,6 +335,16 @@ build_exceptions! {
     IllegalStream(2733),
     StreamVersionMismatched(2734),
 
+    // Pipe error codes.
+    UnknownPipe(2740),
+    PipeAlreadyExists(2741),
+    IllegalPipe(2742),
+
+    // Task error codes.
+    UnknownTask(2750),
+    TaskAlreadyExists(2751),
+    IllegalTask(2752),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -0,0 +1,783 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions between the WKB encoding used to store `GEOMETRY` values and
+//! the textual formats accepted and produced by SQL: WKT, hex-encoded WKB and GeoJSON.
+//! Only 2D geometries are supported.
+
+use std::fmt::Write;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use geo_types::Coord;
+use geo_types::Geometry;
+use geo_types::GeometryCollection;
+use geo_types::LineString;
+use geo_types::MultiLineString;
+use geo_types::MultiPoint;
+use geo_types::MultiPolygon;
+use geo_types::Point;
+use geo_types::Polygon;
+use geo_types::Rect;
+use serde_json::json;
+use serde_json::Value;
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+fn geometry_error(msg: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::BadBytes(format!("Invalid geometry value: {msg}"))
+}
+
+/// Parses WKT, hex-encoded WKB, raw WKB or GeoJSON into the WKB stored by `GEOMETRY` columns.
+pub fn parse_to_wkb(buf: &[u8]) -> Result<Vec<u8>> {
+    let geo = parse_geometry(buf)?;
+    Ok(geometry_to_wkb(&geo))
+}
+
+/// Parses WKT, hex-encoded WKB, raw WKB or GeoJSON.
+pub fn parse_geometry(buf: &[u8]) -> Result<Geometry<f64>> {
+    // A WKB value always starts with its byte order marker.
+    if matches!(buf.first(), Some(0) | Some(1)) {
+        return wkb_to_geometry(buf);
+    }
+    let s = std::str::from_utf8(buf)
+        .map_err(|_| geometry_error(format!("{:?}", String::from_utf8_lossy(buf))))?
+        .trim();
+    if s.starts_with('{') {
+        return geojson_to_geometry(s);
+    }
+    if s.len() >= 10 && s.len() % 2 == 0 && s.bytes().all(|c| c.is_ascii_hexdigit()) {
+        let bytes = (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(geometry_error)?;
+        return wkb_to_geometry(&bytes);
+    }
+    wkt_to_geometry(s)
+}
+
+/// Decodes a WKB value. Both byte orders are accepted.
+pub fn wkb_to_geometry(buf: &[u8]) -> Result<Geometry<f64>> {
+    let mut reader = WkbReader { buf, pos: 0 };
+    let geo = reader.read_geometry()?;
+    if reader.pos != buf.len() {
+        return Err(geometry_error("unexpected trailing bytes in WKB"));
+    }
+    Ok(geo)
+}
+
+/// Encodes a geometry as little-endian WKB.
+pub fn geometry_to_wkb(geo: &Geometry<f64>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_wkb(&mut buf, geo);
+    buf
+}
+
+/// Formats a geometry as WKT, e.g. `POINT(1 2)`.
+pub fn geometry_to_wkt(geo: &Geometry<f64>) -> String {
+    let mut s = String::new();
+    write_wkt(&mut s, geo);
+    s
+}
+
+/// Formats a WKB value as WKT.
+pub fn wkb_to_wkt(buf: &[u8]) -> Result<String> {
+    Ok(geometry_to_wkt(&wkb_to_geometry(buf)?))
+}
+
+/// Formats a geometry as a GeoJSON geometry object.
+pub fn geometry_to_geojson(geo: &Geometry<f64>) -> String {
+    geometry_to_json_value(geo).to_string()
+}
+
+/// Returns the bounding box of a geometry, or `None` if it has no coordinates.
+pub fn geometry_bounds(geo: &Geometry<f64>) -> Option<Rect<f64>> {
+    let mut bounds: Option<(Coord<f64>, Coord<f64>)> = None;
+    visit_coords(geo, &mut |c| {
+        bounds = Some(match bounds {
+            None => (c, c),
+            Some((min, max)) => (
+                Coord {
+                    x: min.x.min(c.x),
+                    y: min.y.min(c.y),
+                },
+                Coord {
+                    x: max.x.max(c.x),
+                    y: max.y.max(c.y),
+                },
+            ),
+        });
+    });
+    bounds.map(|(min, max)| Rect::new(min, max))
+}
+
+fn visit_coords(geo: &Geometry<f64>, f: &mut impl FnMut(Coord<f64>)) {
+    match geo {
+        Geometry::Point(p) => f(p.0),
+        Geometry::Line(l) => {
+            f(l.start);
+            f(l.end);
+        }
+        Geometry::LineString(ls) => ls.0.iter().for_each(|c| f(*c)),
+        Geometry::Polygon(p) => {
+            p.exterior().0.iter().for_each(|c| f(*c));
+            for ring in p.interiors() {
+                ring.0.iter().for_each(|c| f(*c));
+            }
+        }
+        Geometry::MultiPoint(mp) => mp.0.iter().for_each(|p| f(p.0)),
+        Geometry::MultiLineString(mls) => {
+            for ls in &mls.0 {
+                ls.0.iter().for_each(|c| f(*c));
+            }
+        }
+        Geometry::MultiPolygon(mp) => {
+            for p in &mp.0 {
+                visit_coords(&Geometry::Polygon(p.clone()), f);
+            }
+        }
+        Geometry::GeometryCollection(gc) => {
+            for g in &gc.0 {
+                visit_coords(g, f);
+            }
+        }
+        Geometry::Rect(r) => {
+            f(r.min());
+            f(r.max());
+        }
+        Geometry::Triangle(t) => t.to_array().into_iter().for_each(f),
+    }
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WkbReader<'a> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| geometry_error("unexpected end of WKB"))?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self, le: bool) -> Result<u32> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(if le {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self, le: bool) -> Result<f64> {
+        let bytes = self.read_bytes::<8>()?;
+        Ok(if le {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_len(&mut self, le: bool, item_size: usize) -> Result<usize> {
+        let len = self.read_u32(le)? as usize;
+        // Reject lengths that cannot fit in the remaining bytes before allocating.
+        if len.saturating_mul(item_size) > self.buf.len() - self.pos {
+            return Err(geometry_error("unexpected end of WKB"));
+        }
+        Ok(len)
+    }
+
+    fn read_coord(&mut self, le: bool) -> Result<Coord<f64>> {
+        let x = self.read_f64(le)?;
+        let y = self.read_f64(le)?;
+        Ok(Coord { x, y })
+    }
+
+    fn read_line_string(&mut self, le: bool) -> Result<LineString<f64>> {
+        let len = self.read_len(le, 16)?;
+        let coords = (0..len)
+            .map(|_| self.read_coord(le))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(LineString(coords))
+    }
+
+    fn read_polygon(&mut self, le: bool) -> Result<Polygon<f64>> {
+        let len = self.read_len(le, 4)?;
+        let mut rings = (0..len)
+            .map(|_| self.read_line_string(le))
+            .collect::<Result<Vec<_>>>()?;
+        if rings.is_empty() {
+            return Ok(Polygon::new(LineString(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn read_geometry(&mut self) -> Result<Geometry<f64>> {
+        let le = match self.read_bytes::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => return Err(geometry_error(format!("invalid WKB byte order {b}"))),
+        };
+        let ty = self.read_u32(le)?;
+        let geo = match ty {
+            WKB_POINT => {
+                let coord = self.read_coord(le)?;
+                if coord.x.is_nan() && coord.y.is_nan() {
+                    return Err(geometry_error("empty point is not supported"));
+                }
+                Geometry::Point(Point(coord))
+            }
+            WKB_LINESTRING => Geometry::LineString(self.read_line_string(le)?),
+            WKB_POLYGON => Geometry::Polygon(self.read_polygon(le)?),
+            WKB_MULTIPOINT => {
+                let len = self.read_len(le, 21)?;
+                let points = (0..len)
+                    .map(|_| match self.read_geometry()? {
+                        Geometry::Point(p) => Ok(p),
+                        _ => Err(geometry_error("MULTIPOINT must only contain points")),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            WKB_MULTILINESTRING => {
+                let len = self.read_len(le, 9)?;
+                let lines = (0..len)
+                    .map(|_| match self.read_geometry()? {
+                        Geometry::LineString(ls) => Ok(ls),
+                        _ => Err(geometry_error(
+                            "MULTILINESTRING must only contain linestrings",
+                        )),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            WKB_MULTIPOLYGON => {
+                let len = self.read_len(le, 9)?;
+                let polygons = (0..len)
+                    .map(|_| match self.read_geometry()? {
+                        Geometry::Polygon(p) => Ok(p),
+                        _ => Err(geometry_error("MULTIPOLYGON must only contain polygons")),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            WKB_GEOMETRYCOLLECTION => {
+                let len = self.read_len(le, 9)?;
+                let geos = (0..len)
+                    .map(|_| self.read_geometry())
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::GeometryCollection(GeometryCollection(geos))
+            }
+            _ => {
+                return Err(geometry_error(format!(
+                    "unsupported WKB geometry type {ty}, only 2D geometries are supported"
+                )));
+            }
+        };
+        Ok(geo)
+    }
+}
+
+fn write_wkb_header(buf: &mut Vec<u8>, ty: u32) {
+    buf.push(1);
+    buf.extend_from_slice(&ty.to_le_bytes());
+}
+
+fn write_wkb_coords(buf: &mut Vec<u8>, coords: &[Coord<f64>]) {
+    buf.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+    for c in coords {
+        buf.extend_from_slice(&c.x.to_le_bytes());
+        buf.extend_from_slice(&c.y.to_le_bytes());
+    }
+}
+
+fn write_wkb_polygon(buf: &mut Vec<u8>, p: &Polygon<f64>) {
+    write_wkb_header(buf, WKB_POLYGON);
+    if p.exterior().0.is_empty() {
+        buf.extend_from_slice(&0u32.to_le_bytes());
+        return;
+    }
+    buf.extend_from_slice(&(p.interiors().len() as u32 + 1).to_le_bytes());
+    write_wkb_coords(buf, &p.exterior().0);
+    for ring in p.interiors() {
+        write_wkb_coords(buf, &ring.0);
+    }
+}
+
+fn write_wkb(buf: &mut Vec<u8>, geo: &Geometry<f64>) {
+    match geo {
+        Geometry::Point(p) => {
+            write_wkb_header(buf, WKB_POINT);
+            buf.extend_from_slice(&p.x().to_le_bytes());
+            buf.extend_from_slice(&p.y().to_le_bytes());
+        }
+        Geometry::Line(l) => {
+            write_wkb_header(buf, WKB_LINESTRING);
+            write_wkb_coords(buf, &[l.start, l.end]);
+        }
+        Geometry::LineString(ls) => {
+            write_wkb_header(buf, WKB_LINESTRING);
+            write_wkb_coords(buf, &ls.0);
+        }
+        Geometry::Polygon(p) => write_wkb_polygon(buf, p),
+        Geometry::MultiPoint(mp) => {
+            write_wkb_header(buf, WKB_MULTIPOINT);
+            buf.extend_from_slice(&(mp.0.len() as u32).to_le_bytes());
+            for p in &mp.0 {
+                write_wkb(buf, &Geometry::Point(*p));
+            }
+        }
+        Geometry::MultiLineString(mls) => {
+            write_wkb_header(buf, WKB_MULTILINESTRING);
+            buf.extend_from_slice(&(mls.0.len() as u32).to_le_bytes());
+            for ls in &mls.0 {
+                write_wkb_header(buf, WKB_LINESTRING);
+                write_wkb_coords(buf, &ls.0);
+            }
+        }
+        Geometry::MultiPolygon(mp) => {
+            write_wkb_header(buf, WKB_MULTIPOLYGON);
+            buf.extend_from_slice(&(mp.0.len() as u32).to_le_bytes());
+            for p in &mp.0 {
+                write_wkb_polygon(buf, p);
+            }
+        }
+        Geometry::GeometryCollection(gc) => {
+            write_wkb_header(buf, WKB_GEOMETRYCOLLECTION);
+            buf.extend_from_slice(&(gc.0.len() as u32).to_le_bytes());
+            for g in &gc.0 {
+                write_wkb(buf, g);
+            }
+        }
+        Geometry::Rect(r) => write_wkb_polygon(buf, &r.to_polygon()),
+        Geometry::Triangle(t) => write_wkb_polygon(buf, &t.to_polygon()),
+    }
+}
+
+fn write_wkt_coords(s: &mut String, coords: &[Coord<f64>]) {
+    s.push('(');
+    for (i, c) in coords.iter().enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+        write!(s, "{} {}", c.x, c.y).unwrap();
+    }
+    s.push(')');
+}
+
+fn write_wkt_polygon_body(s: &mut String, p: &Polygon<f64>) {
+    s.push('(');
+    write_wkt_coords(s, &p.exterior().0);
+    for ring in p.interiors() {
+        s.push(',');
+        write_wkt_coords(s, &ring.0);
+    }
+    s.push(')');
+}
+
+fn write_wkt(s: &mut String, geo: &Geometry<f64>) {
+    match geo {
+        Geometry::Point(p) => write!(s, "POINT({} {})", p.x(), p.y()).unwrap(),
+        Geometry::Line(l) => {
+            s.push_str("LINESTRING");
+            write_wkt_coords(s, &[l.start, l.end]);
+        }
+        Geometry::LineString(ls) if ls.0.is_empty() => s.push_str("LINESTRING EMPTY"),
+        Geometry::LineString(ls) => {
+            s.push_str("LINESTRING");
+            write_wkt_coords(s, &ls.0);
+        }
+        Geometry::Polygon(p) if p.exterior().0.is_empty() => s.push_str("POLYGON EMPTY"),
+        Geometry::Polygon(p) => {
+            s.push_str("POLYGON");
+            write_wkt_polygon_body(s, p);
+        }
+        Geometry::MultiPoint(mp) if mp.0.is_empty() => s.push_str("MULTIPOINT EMPTY"),
+        Geometry::MultiPoint(mp) => {
+            s.push_str("MULTIPOINT");
+            write_wkt_coords(s, &mp.0.iter().map(|p| p.0).collect::<Vec<_>>());
+        }
+        Geometry::MultiLineString(mls) if mls.0.is_empty() => s.push_str("MULTILINESTRING EMPTY"),
+        Geometry::MultiLineString(mls) => {
+            s.push_str("MULTILINESTRING(");
+            for (i, ls) in mls.0.iter().enumerate() {
+                if i > 0 {
+                    s.push(',');
+                }
+                write_wkt_coords(s, &ls.0);
+            }
+            s.push(')');
+        }
+        Geometry::MultiPolygon(mp) if mp.0.is_empty() => s.push_str("MULTIPOLYGON EMPTY"),
+        Geometry::MultiPolygon(mp) => {
+            s.push_str("MULTIPOLYGON(");
+            for (i, p) in mp.0.iter().enumerate() {
+                if i > 0 {
+                    s.push(',');
+                }
+                write_wkt_polygon_body(s, p);
+            }
+            s.push(')');
+        }
+        Geometry::GeometryCollection(gc) if gc.0.is_empty() => {
+            s.push_str("GEOMETRYCOLLECTION EMPTY")
+        }
+        Geometry::GeometryCollection(gc) => {
+            s.push_str("GEOMETRYCOLLECTION(");
+            for (i, g) in gc.0.iter().enumerate() {
+                if i > 0 {
+                    s.push(',');
+                }
+                write_wkt(s, g);
+            }
+            s.push(')');
+        }
+        Geometry::Rect(r) => write_wkt(s, &Geometry::Polygon(r.to_polygon())),
+        Geometry::Triangle(t) => write_wkt(s, &Geometry::Polygon(t.to_polygon())),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WktToken<'a> {
+    Word(&'a str),
+    Number(f64),
+    LParen,
+    RParen,
+    Comma,
+}
+
+struct WktParser<'a> {
+    tokens: Vec<WktToken<'a>>,
+    pos: usize,
+}
+
+/// Parses a WKT string such as `POINT(1 2)` or `POLYGON((0 0,1 0,1 1,0 0))`.
+pub fn wkt_to_geometry(s: &str) -> Result<Geometry<f64>> {
+    let mut parser = WktParser {
+        tokens: tokenize_wkt(s)?,
+        pos: 0,
+    };
+    let geo = parser.parse_geometry()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(geometry_error(format!(
+            "unexpected trailing input in {s:?}"
+        )));
+    }
+    Ok(geo)
+}
+
+fn tokenize_wkt(s: &str) -> Result<Vec<WktToken<'_>>> {
+    let bytes = s.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b'(' => tokens.push(WktToken::LParen),
+            b')' => tokens.push(WktToken::RParen),
+            b',' => tokens.push(WktToken::Comma),
+            c if c.is_ascii_whitespace() => {}
+            c if c.is_ascii_alphabetic() => {
+                let start = i;
+                while i + 1 < bytes.len() && bytes[i + 1].is_ascii_alphabetic() {
+                    i += 1;
+                }
+                tokens.push(WktToken::Word(&s[start..=i]));
+            }
+            c if c.is_ascii_digit() || c == b'-' || c == b'+' || c == b'.' => {
+                let start = i;
+                while i + 1 < bytes.len()
+                    && matches!(bytes[i + 1], b'0'..=b'9' | b'.' | b'e' | b'E' | b'-' | b'+')
+                {
+                    i += 1;
+                }
+                let n = s[start..=i]
+                    .parse::<f64>()
+                    .map_err(|_| geometry_error(format!("invalid number in {s:?}")))?;
+                tokens.push(WktToken::Number(n));
+            }
+            _ => {
+                return Err(geometry_error(format!(
+                    "unexpected character {:?} in {s:?}",
+                    c as char
+                )));
+            }
+        }
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+impl<'a> WktParser<'a> {
+    fn next(&mut self) -> Result<WktToken<'a>> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| geometry_error("unexpected end of WKT"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn peek(&self) -> Option<WktToken<'a>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, expected: WktToken<'a>) -> Result<()> {
+        let token = self.next()?;
+        if token != expected {
+            return Err(geometry_error(format!(
+                "expected {expected:?} in WKT, got {token:?}"
+            )));
+        }
+        Ok(())
+    }
+
+    fn number(&mut self) -> Result<f64> {
+        match self.next()? {
+            WktToken::Number(n) => Ok(n),
+            token => Err(geometry_error(format!(
+                "expected a number in WKT, got {token:?}"
+            ))),
+        }
+    }
+
+    fn consume_empty(&mut self) -> bool {
+        if matches!(self.peek(), Some(WktToken::Word(w)) if w.eq_ignore_ascii_case("EMPTY")) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    /// Parses a list of items enclosed in parentheses and separated by commas.
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        self.expect(WktToken::LParen)?;
+        let mut items = vec![item(self)?];
+        while self.peek() == Some(WktToken::Comma) {
+            self.pos += 1;
+            items.push(item(self)?);
+        }
+        self.expect(WktToken::RParen)?;
+        Ok(items)
+    }
+
+    fn coord(&mut self) -> Result<Coord<f64>> {
+        let x = self.number()?;
+        let y = self.number()?;
+        if matches!(self.peek(), Some(WktToken::Number(_))) {
+            return Err(geometry_error("only 2D geometries are supported"));
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn line_string(&mut self) -> Result<LineString<f64>> {
+        Ok(LineString(self.list(Self::coord)?))
+    }
+
+    fn polygon(&mut self) -> Result<Polygon<f64>> {
+        let mut rings = self.list(Self::line_string)?;
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn point(&mut self) -> Result<Point<f64>> {
+        // Both `MULTIPOINT((1 2),(3 4))` and `MULTIPOINT(1 2,3 4)` are valid.
+        if self.peek() == Some(WktToken::LParen) {
+            Ok(Point(self.list(Self::coord)?.remove(0)))
+        } else {
+            Ok(Point(self.coord()?))
+        }
+    }
+
+    fn parse_geometry(&mut self) -> Result<Geometry<f64>> {
+        let tag = match self.next()? {
+            WktToken::Word(w) => w.to_ascii_uppercase(),
+            token => {
+                return Err(geometry_error(format!(
+                    "expected a geometry type in WKT, got {token:?}"
+                )));
+            }
+        };
+        let empty = self.consume_empty();
+        let geo = match tag.as_str() {
+            "POINT" if empty => return Err(geometry_error("empty point is not supported")),
+            "POINT" => {
+                self.expect(WktToken::LParen)?;
+                let coord = self.coord()?;
+                self.expect(WktToken::RParen)?;
+                Geometry::Point(Point(coord))
+            }
+            "LINESTRING" if empty => Geometry::LineString(LineString(vec![])),
+            "LINESTRING" => Geometry::LineString(self.line_string()?),
+            "POLYGON" if empty => Geometry::Polygon(Polygon::new(LineString(vec![]), vec![])),
+            "POLYGON" => Geometry::Polygon(self.polygon()?),
+            "MULTIPOINT" if empty => Geometry::MultiPoint(MultiPoint(vec![])),
+            "MULTIPOINT" => Geometry::MultiPoint(MultiPoint(self.list(Self::point)?)),
+            "MULTILINESTRING" if empty => Geometry::MultiLineString(MultiLineString(vec![])),
+            "MULTILINESTRING" => {
+                Geometry::MultiLineString(MultiLineString(self.list(Self::line_string)?))
+            }
+            "MULTIPOLYGON" if empty => Geometry::MultiPolygon(MultiPolygon(vec![])),
+            "MULTIPOLYGON" => Geometry::MultiPolygon(MultiPolygon(self.list(Self::polygon)?)),
+            "GEOMETRYCOLLECTION" if empty => {
+                Geometry::GeometryCollection(GeometryCollection(vec![]))
+            }
+            "GEOMETRYCOLLECTION" => {
+                Geometry::GeometryCollection(GeometryCollection(self.list(Self::parse_geometry)?))
+            }
+            _ => {
+                return Err(geometry_error(format!("unsupported geometry type {tag:?}")));
+            }
+        };
+        Ok(geo)
+    }
+}
+
+/// Parses a GeoJSON geometry object, or a `Feature` wrapping one.
+pub fn geojson_to_geometry(s: &str) -> Result<Geometry<f64>> {
+    let value: Value = serde_json::from_str(s).map_err(geometry_error)?;
+    json_value_to_geometry(&value)
+}
+
+fn json_coord(value: &Value) -> Result<Coord<f64>> {
+    match value.as_array().map(|v| v.as_slice()) {
+        Some([x, y]) => match (x.as_f64(), y.as_f64()) {
+            (Some(x), Some(y)) => Ok(Coord { x, y }),
+            _ => Err(geometry_error(format!("invalid GeoJSON position {value}"))),
+        },
+        Some(_) => Err(geometry_error("only 2D geometries are supported")),
+        None => Err(geometry_error(format!("invalid GeoJSON position {value}"))),
+    }
+}
+
+fn json_array<T>(value: &Value, item: impl Fn(&Value) -> Result<T>) -> Result<Vec<T>> {
+    value
+        .as_array()
+        .ok_or_else(|| geometry_error(format!("expected a GeoJSON array, got {value}")))?
+        .iter()
+        .map(item)
+        .collect()
+}
+
+fn json_line_string(value: &Value) -> Result<LineString<f64>> {
+    Ok(LineString(json_array(value, json_coord)?))
+}
+
+fn json_polygon(value: &Value) -> Result<Polygon<f64>> {
+    let mut rings = json_array(value, json_line_string)?;
+    if rings.is_empty() {
+        return Ok(Polygon::new(LineString(vec![]), vec![]));
+    }
+    let exterior = rings.remove(0);
+    Ok(Polygon::new(exterior, rings))
+}
+
+fn json_value_to_geometry(value: &Value) -> Result<Geometry<f64>> {
+    let ty = value
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| geometry_error("GeoJSON object has no type"))?;
+    if ty == "GeometryCollection" {
+        let geometries = value
+            .get("geometries")
+            .ok_or_else(|| geometry_error("GeoJSON GeometryCollection has no geometries"))?;
+        return Ok(Geometry::GeometryCollection(GeometryCollection(
+            json_array(geometries, json_value_to_geometry)?,
+        )));
+    }
+    if ty == "Feature" {
+        let geometry = value
+            .get("geometry")
+            .ok_or_else(|| geometry_error("GeoJSON Feature has no geometry"))?;
+        return json_value_to_geometry(geometry);
+    }
+    let coords = value
+        .get("coordinates")
+        .ok_or_else(|| geometry_error(format!("GeoJSON {ty} has no coordinates")))?;
+    let geo = match ty {
+        "Point" => Geometry::Point(Point(json_coord(coords)?)),
+        "LineString" => Geometry::LineString(json_line_string(coords)?),
+        "Polygon" => Geometry::Polygon(json_polygon(coords)?),
+        "MultiPoint" => Geometry::MultiPoint(MultiPoint(json_array(coords, |c| {
+            Ok(Point(json_coord(c)?))
+        })?)),
+        "MultiLineString" => {
+            Geometry::MultiLineString(MultiLineString(json_array(coords, json_line_string)?))
+        }
+        "MultiPolygon" => Geometry::MultiPolygon(MultiPolygon(json_array(coords, json_polygon)?)),
+        _ => {
+            return Err(geometry_error(format!(
+                "unsupported GeoJSON geometry type {ty:?}"
+            )));
+        }
+    };
+    Ok(geo)
+}
+
+fn coords_to_json(coords: &[Coord<f64>]) -> Value {
+    Value::Array(coords.iter().map(|c| json!([c.x, c.y])).collect())
+}
+
+fn polygon_to_json(p: &Polygon<f64>) -> Value {
+    if p.exterior().0.is_empty() {
+        return json!([]);
+    }
+    let mut rings = vec![coords_to_json(&p.exterior().0)];
+    rings.extend(p.interiors().iter().map(|ring| coords_to_json(&ring.0)));
+    Value::Array(rings)
+}
+
+fn geometry_to_json_value(geo: &Geometry<f64>) -> Value {
+    match geo {
+        Geometry::Point(p) => json!({"type": "Point", "coordinates": [p.x(), p.y()]}),
+        Geometry::Line(l) => {
+            json!({"type": "LineString", "coordinates": coords_to_json(&[l.start, l.end])})
+        }
+        Geometry::LineString(ls) => {
+            json!({"type": "LineString", "coordinates": coords_to_json(&ls.0)})
+        }
+        Geometry::Polygon(p) => json!({"type": "Polygon", "coordinates": polygon_to_json(p)}),
+        Geometry::MultiPoint(mp) => json!({
+            "type": "MultiPoint",
+            "coordinates": coords_to_json(&mp.0.iter().map(|p| p.0).collect::<Vec<_>>()),
+        }),
+        Geometry::MultiLineString(mls) => json!({
+            "type": "MultiLineString",
+            "coordinates": mls.0.iter().map(|ls| coords_to_json(&ls.0)).collect::<Vec<_>>(),
+        }),
+        Geometry::MultiPolygon(mp) => json!({
+            "type": "MultiPolygon",
+            "coordinates": mp.0.iter().map(polygon_to_json).collect::<Vec<_>>(),
+        }),
+        Geometry::GeometryCollection(gc) => json!({
+            "type": "GeometryCollection",
+            "geometries": gc.0.iter().map(geometry_to_json_value).collect::<Vec<_>>(),
+        }),
+        Geometry::Rect(r) => geometry_to_json_value(&Geometry::Polygon(r.to_polygon())),
+        Geometry::Triangle(t) => geometry_to_json_value(&Geometry::Polygon(t.to_polygon())),
+    }
+}
@@ -37,8 +37,10 @@ pub mod cursor_ext;
 mod decimal;
 mod escape;
 mod format_settings;
+pub mod geometry;
 mod position;
 mod stat_buffer;
+pub mod tokenizer;
 
 pub use bitmap::parse_bitmap;
 pub use decimal::display_decimal_128;
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Text tokenization of the inverted (full-text) index and the `match` function.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// The words that are too common to be searched, Lucene's default English stop words.
+const ENGLISH_STOP_WORDS: [&str; 33] = [
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+/// The tokenizer splits a text into terms, the words of letters and digits in lowercase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tokenizer {
+    /// The English stop words are removed.
+    #[default]
+    English,
+    /// All the words are kept.
+    Simple,
+}
+
+impl FromStr for Tokenizer {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "english" => Ok(Tokenizer::English),
+            "simple" => Ok(Tokenizer::Simple),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Unknown tokenizer '{}', must be 'english' or 'simple'",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Tokenizer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Tokenizer::English => write!(f, "english"),
+            Tokenizer::Simple => write!(f, "simple"),
+        }
+    }
+}
+
+impl Tokenizer {
+    /// Split the text into terms, in the order they appear.
+    pub fn tokenize<'a>(&'a self, text: &'a str) -> impl Iterator<Item = String> + 'a {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .filter(|term| match self {
+                Tokenizer::English => !ENGLISH_STOP_WORDS.contains(&term.as_str()),
+                Tokenizer::Simple => true,
+            })
+    }
+
+    /// Whether the text contains any term of the query.
+    pub fn matches(&self, text: &str, query: &str) -> bool {
+        let query_terms = self.tokenize(query).collect::<Vec<_>>();
+        !query_terms.is_empty() && self.tokenize(text).any(|term| query_terms.contains(&term))
+    }
+
+    /// The relevance of the text to the query, zero if the text contains no term of the query.
+    ///
+    /// Each distinct term of the query scores the square root of its frequency in the text,
+    /// the sum is normalized by the square root of the number of terms in the text, so that
+    /// a term weighs more in a short text.
+    pub fn score(&self, text: &str, query: &str) -> f64 {
+        let mut query_terms = self.tokenize(query).collect::<Vec<_>>();
+        query_terms.sort();
+        query_terms.dedup();
+
+        let mut num_terms = 0;
+        let mut frequencies = HashMap::with_capacity(query_terms.len());
+        for term in self.tokenize(text) {
+            num_terms += 1;
+            if query_terms.contains(&term) {
+                *frequencies.entry(term).or_insert(0) += 1;
+            }
+        }
+        if frequencies.is_empty() {
+            return 0.0;
+        }
+        let score = frequencies
+            .values()
+            .map(|frequency| (*frequency as f64).sqrt())
+            .sum::<f64>();
+        score / (num_terms as f64).sqrt()
+    }
+}
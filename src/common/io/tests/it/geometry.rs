@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_io::geometry::geometry_bounds;
+use databend_common_io::geometry::geometry_to_geojson;
+use databend_common_io::geometry::parse_geometry;
+use databend_common_io::geometry::parse_to_wkb;
+use databend_common_io::geometry::wkb_to_wkt;
+
+#[test]
+fn test_wkt_round_trip() -> Result<()> {
+    let cases = [
+        ("POINT(1 2)", "POINT(1 2)"),
+        ("point ( -1.5  2e2 )", "POINT(-1.5 200)"),
+        ("LINESTRING(0 0, 1 1, 2 0)", "LINESTRING(0 0,1 1,2 0)"),
+        ("LINESTRING EMPTY", "LINESTRING EMPTY"),
+        (
+            "POLYGON((0 0,4 0,4 4,0 4,0 0),(1 1,2 1,2 2,1 1))",
+            "POLYGON((0 0,4 0,4 4,0 4,0 0),(1 1,2 1,2 2,1 1))",
+        ),
+        ("MULTIPOINT((1 2),(3 4))", "MULTIPOINT(1 2,3 4)"),
+        ("MULTIPOINT(1 2,3 4)", "MULTIPOINT(1 2,3 4)"),
+        (
+            "MULTILINESTRING((0 0,1 1),(2 2,3 3))",
+            "MULTILINESTRING((0 0,1 1),(2 2,3 3))",
+        ),
+        (
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((2 2,3 2,3 3,2 2)))",
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((2 2,3 2,3 3,2 2)))",
+        ),
+        (
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))",
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))",
+        ),
+        ("GEOMETRYCOLLECTION EMPTY", "GEOMETRYCOLLECTION EMPTY"),
+    ];
+    for (input, expected) in cases {
+        let wkb = parse_to_wkb(input.as_bytes())?;
+        assert_eq!(wkb_to_wkt(&wkb)?, expected, "{input}");
+        // The WKB value itself is accepted as input.
+        assert_eq!(parse_to_wkb(&wkb)?, wkb, "{input}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parse_wkb_and_geojson() -> Result<()> {
+    // Big-endian hex WKB of POINT(1 2).
+    let wkb = parse_to_wkb(b"00000000013FF00000000000004000000000000000")?;
+    assert_eq!(wkb_to_wkt(&wkb)?, "POINT(1 2)");
+
+    let geo = parse_geometry(br#"{"type":"LineString","coordinates":[[0,0],[1.5,2]]}"#)?;
+    assert_eq!(
+        geometry_to_geojson(&geo),
+        r#"{"type":"LineString","coordinates":[[0.0,0.0],[1.5,2.0]]}"#
+    );
+    let geo = parse_geometry(
+        br#"{"type":"Feature","geometry":{"type":"Point","coordinates":[3,4]},"properties":{}}"#,
+    )?;
+    assert_eq!(
+        geometry_to_geojson(&geo),
+        r#"{"type":"Point","coordinates":[3.0,4.0]}"#
+    );
+    Ok(())
+}
+
+#[test]
+fn test_geometry_bounds() -> Result<()> {
+    let geo = parse_geometry(b"GEOMETRYCOLLECTION(POINT(-1 5),LINESTRING(2 -3,4 0))")?;
+    let bounds = geometry_bounds(&geo).unwrap();
+    assert_eq!((bounds.min().x, bounds.min().y), (-1.0, -3.0));
+    assert_eq!((bounds.max().x, bounds.max().y), (4.0, 5.0));
+
+    let geo = parse_geometry(b"MULTIPOINT EMPTY")?;
+    assert!(geometry_bounds(&geo).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_invalid_geometry() {
+    for input in [
+        "POINT(1)",
+        "POINT(1 2 3)",
+        "POINT EMPTY",
+        "CIRCLE(0 0)",
+        "LINESTRING(0 0,1 1",
+        "POINT(1 2) POINT(3 4)",
+        r#"{"type":"Point"}"#,
+        "0101000000",
+    ] {
+        assert!(parse_to_wkb(input.as_bytes()).is_err(), "{input}");
+    }
+}
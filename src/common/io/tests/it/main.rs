@@ -23,4 +23,6 @@ mod bincode_serialization;
 mod borsh_serialization;
 mod cursor_ext;
 mod escape;
+mod geometry;
 mod serialization_format_compatability;
+mod tokenizer;
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_io::tokenizer::Tokenizer;
+
+#[test]
+fn test_tokenize() -> Result<()> {
+    let text = "The Connection to db-01 was REFUSED: error_code=42, Größe";
+    assert_eq!(Tokenizer::English.tokenize(text).collect::<Vec<_>>(), vec![
+        "connection",
+        "db",
+        "01",
+        "refused",
+        "error",
+        "code",
+        "42",
+        "größe"
+    ]);
+    assert_eq!(Tokenizer::Simple.tokenize(text).collect::<Vec<_>>(), vec![
+        "the",
+        "connection",
+        "to",
+        "db",
+        "01",
+        "was",
+        "refused",
+        "error",
+        "code",
+        "42",
+        "größe"
+    ]);
+
+    assert_eq!("English".parse::<Tokenizer>()?, Tokenizer::English);
+    assert_eq!(" simple ".parse::<Tokenizer>()?, Tokenizer::Simple);
+    assert!("ngram".parse::<Tokenizer>().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_match_and_score() {
+    let tokenizer = Tokenizer::English;
+    assert!(tokenizer.matches("Connection refused", "REFUSED"));
+    assert!(tokenizer.matches("Connection refused", "timeout refused"));
+    assert!(!tokenizer.matches("Connection refused", "timeout"));
+    // The query of stop words only matches nothing.
+    assert!(!tokenizer.matches("the error", "the"));
+    assert!(Tokenizer::Simple.matches("the error", "the"));
+
+    assert_eq!(tokenizer.score("Connection refused", "timeout"), 0.0);
+    assert_eq!(tokenizer.score("error", "error"), 1.0);
+    // A shorter text is more relevant.
+    assert!(tokenizer.score("error", "error") > tokenizer.score("error in disk", "error"));
+    // More terms of the query are more relevant.
+    assert!(tokenizer.score("disk error", "disk error") > tokenizer.score("disk error", "error"));
+    // The repeated terms of the query count once.
+    assert_eq!(
+        tokenizer.score("disk error", "error error"),
+        tokenizer.score("disk error", "error")
+    );
+}
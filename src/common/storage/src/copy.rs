@@ -108,6 +108,8 @@ pub enum FileParseError {
     NumberOfColumnsMismatch { table: usize, file: usize },
     #[error("Invalid JSON row: {message}")]
     InvalidNDJsonRow { message: String },
+    #[error("Invalid Avro row: {message}")]
+    InvalidAvroRow { message: String },
     #[error(
         "Invalid value '{column_data}' for column {column_index} ({column_name} {column_type}): {decode_error}"
     )]
@@ -89,7 +89,7 @@ pub struct StageFilesInfo {
 }
 
 impl StageFilesInfo {
-    fn get_pattern(&self) -> Result<Option<Regex>> {
+    pub fn get_pattern(&self) -> Result<Option<Regex>> {
         match &self.pattern {
             Some(pattern) => match Regex::new(&format!("^{pattern}$")) {
                 Ok(r) => Ok(Some(r)),
@@ -0,0 +1,452 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// The max number of neighbors of a node on the upper layers.
+const MAX_NEIGHBORS: usize = 16;
+/// The max number of neighbors of a node on the bottom layer.
+const MAX_NEIGHBORS_BOTTOM: usize = MAX_NEIGHBORS * 2;
+/// The size of the dynamic candidate list when inserting nodes.
+const EF_CONSTRUCTION: usize = 100;
+/// The min size of the dynamic candidate list when searching.
+const EF_SEARCH: usize = 64;
+
+const FORMAT_VERSION: u32 = 1;
+
+/// The distance function a vector index is built for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VectorDistance {
+    #[default]
+    Cosine,
+    L2,
+}
+
+impl VectorDistance {
+    /// The name of the scalar function computing this distance.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            VectorDistance::Cosine => "cosine_distance",
+            VectorDistance::L2 => "l2_distance",
+        }
+    }
+}
+
+impl FromStr for VectorDistance {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "cosine" => Ok(VectorDistance::Cosine),
+            "l2" => Ok(VectorDistance::L2),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Unknown vector distance '{}', must be 'cosine' or 'l2'",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for VectorDistance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VectorDistance::Cosine => write!(f, "cosine"),
+            VectorDistance::L2 => write!(f, "l2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    distance: f32,
+    node: u32,
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An HNSW (Hierarchical Navigable Small World) graph over the vectors of a block,
+/// used to look up the approximate nearest rows of a vector.
+///
+/// The vectors of the cosine distance are normalized, so that the distance is `1 - dot`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HnswIndex {
+    distance: VectorDistance,
+    dimension: usize,
+    /// The row of each node in the block.
+    rows: Vec<u32>,
+    vectors: Vec<f32>,
+    /// The neighbors of each node on each of its layers, from the bottom layer.
+    neighbors: Vec<Vec<Vec<u32>>>,
+    entry_point: Option<u32>,
+}
+
+impl HnswIndex {
+    /// Build the index with the rows and their vectors, all the vectors must be of `dimension`.
+    /// The zero vectors of the cosine distance are skipped, the distance to them is undefined.
+    pub fn build<'a>(
+        distance: VectorDistance,
+        dimension: usize,
+        vectors: impl IntoIterator<Item = (u32, &'a [f32])>,
+    ) -> Result<Self> {
+        let mut index = HnswIndex {
+            distance,
+            dimension,
+            rows: vec![],
+            vectors: vec![],
+            neighbors: vec![],
+            entry_point: None,
+        };
+        for (row, vector) in vectors {
+            if vector.len() != dimension {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "Vector length not equal: {:} != {:}",
+                    vector.len(),
+                    dimension,
+                )));
+            }
+            if let Some(vector) = index.prepare(vector) {
+                index.insert(row, &vector);
+            }
+        }
+        Ok(index)
+    }
+
+    pub fn distance(&self) -> VectorDistance {
+        self.distance
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Search the approximate `k` nearest rows of the query, ordered by the distance.
+    pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<(u32, f32)>> {
+        if query.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                query.len(),
+                self.dimension,
+            )));
+        }
+        let (Some(entry_point), Some(query)) = (self.entry_point, self.prepare(query)) else {
+            return Ok(vec![]);
+        };
+
+        let mut entry = self.candidate(&query, entry_point);
+        for layer in (1..self.neighbors[entry_point as usize].len()).rev() {
+            entry = self.search_layer(&query, entry, 1, layer)[0];
+        }
+        let mut nearest = self.search_layer(&query, entry, k.max(EF_SEARCH), 0);
+        nearest.truncate(k);
+        Ok(nearest
+            .into_iter()
+            .map(|c| {
+                let distance = match self.distance {
+                    VectorDistance::Cosine => c.distance,
+                    VectorDistance::L2 => c.distance.sqrt(),
+                };
+                (self.rows[c.node as usize], distance)
+            })
+            .collect())
+    }
+
+    /// The vector to index or search, `None` if the distance to it is undefined.
+    fn prepare(&self, vector: &[f32]) -> Option<Vec<f32>> {
+        match self.distance {
+            VectorDistance::Cosine => {
+                let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+                (norm > 0.0 && norm.is_finite()).then(|| vector.iter().map(|v| v / norm).collect())
+            }
+            VectorDistance::L2 => Some(vector.to_vec()),
+        }
+    }
+
+    fn vector(&self, node: u32) -> &[f32] {
+        let start = node as usize * self.dimension;
+        &self.vectors[start..start + self.dimension]
+    }
+
+    /// The cosine distance of the normalized vectors, or the squared l2 distance.
+    fn distance_to(&self, query: &[f32], node: u32) -> f32 {
+        let vector = self.vector(node);
+        match self.distance {
+            VectorDistance::Cosine => {
+                1.0 - query.iter().zip(vector).map(|(a, b)| a * b).sum::<f32>()
+            }
+            VectorDistance::L2 => query
+                .iter()
+                .zip(vector)
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum(),
+        }
+    }
+
+    fn candidate(&self, query: &[f32], node: u32) -> Candidate {
+        Candidate {
+            distance: self.distance_to(query, node),
+            node,
+        }
+    }
+
+    /// The level of a new node, which is exponentially less likely to be higher.
+    fn random_level(&self) -> usize {
+        // SplitMix64 of the node count, the same vectors always build the same graph.
+        let mut x = (self.rows.len() as u64).wrapping_add(0x9E3779B97F4A7C15);
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+        x ^= x >> 31;
+        let uniform = ((x >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+        (-uniform.ln() / (MAX_NEIGHBORS as f64).ln()) as usize
+    }
+
+    fn insert(&mut self, row: u32, vector: &[f32]) {
+        let level = self.random_level();
+        let node = self.rows.len() as u32;
+        self.rows.push(row);
+        self.vectors.extend_from_slice(vector);
+        self.neighbors.push(vec![vec![]; level + 1]);
+
+        let Some(entry_point) = self.entry_point else {
+            self.entry_point = Some(node);
+            return;
+        };
+        let top_level = self.neighbors[entry_point as usize].len() - 1;
+
+        let mut entry = self.candidate(vector, entry_point);
+        for layer in (level + 1..=top_level).rev() {
+            entry = self.search_layer(vector, entry, 1, layer)[0];
+        }
+        for layer in (0..=level.min(top_level)).rev() {
+            let candidates = self.search_layer(vector, entry, EF_CONSTRUCTION, layer);
+            entry = candidates[0];
+
+            let max_neighbors = Self::max_neighbors(layer);
+            let neighbors = candidates
+                .iter()
+                .take(max_neighbors)
+                .map(|c| c.node)
+                .collect::<Vec<_>>();
+            for &neighbor in &neighbors {
+                self.connect(neighbor, node, layer, max_neighbors);
+            }
+            self.neighbors[node as usize][layer] = neighbors;
+        }
+
+        if level > top_level {
+            self.entry_point = Some(node);
+        }
+    }
+
+    fn max_neighbors(layer: usize) -> usize {
+        if layer == 0 {
+            MAX_NEIGHBORS_BOTTOM
+        } else {
+            MAX_NEIGHBORS
+        }
+    }
+
+    /// Add the link from `node` to `neighbor`, and only keep the closest neighbors of `node`.
+    fn connect(&mut self, node: u32, neighbor: u32, layer: usize, max_neighbors: usize) {
+        let mut links = std::mem::take(&mut self.neighbors[node as usize][layer]);
+        links.push(neighbor);
+        if links.len() > max_neighbors {
+            let vector = self.vector(node).to_vec();
+            links.sort_by_cached_key(|&n| self.candidate(&vector, n));
+            links.truncate(max_neighbors);
+        }
+        self.neighbors[node as usize][layer] = links;
+    }
+
+    /// Search the `ef` nearest nodes on the layer greedily, ordered by the distance.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry: Candidate,
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Candidate> {
+        let mut visited = HashSet::new();
+        visited.insert(entry.node);
+        let mut candidates = BinaryHeap::new();
+        candidates.push(Reverse(entry));
+        let mut nearest = BinaryHeap::new();
+        nearest.push(entry);
+
+        while let Some(Reverse(candidate)) = candidates.pop() {
+            let furthest = nearest.peek().unwrap();
+            if candidate.distance > furthest.distance && nearest.len() >= ef {
+                break;
+            }
+            for &neighbor in &self.neighbors[candidate.node as usize][layer] {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+                let neighbor = self.candidate(query, neighbor);
+                let furthest = nearest.peek().unwrap();
+                if nearest.len() < ef || neighbor.distance < furthest.distance {
+                    candidates.push(Reverse(neighbor));
+                    nearest.push(neighbor);
+                    if nearest.len() > ef {
+                        nearest.pop();
+                    }
+                }
+            }
+        }
+        nearest.into_sorted_vec()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(
+            24 + self.rows.len() * 4 + self.vectors.len() * 4 + self.neighbors.len() * 64,
+        );
+        buf.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        let distance: u32 = match self.distance {
+            VectorDistance::Cosine => 0,
+            VectorDistance::L2 => 1,
+        };
+        buf.extend_from_slice(&distance.to_le_bytes());
+        buf.extend_from_slice(&(self.dimension as u32).to_le_bytes());
+        buf.extend_from_slice(&self.entry_point.unwrap_or(u32::MAX).to_le_bytes());
+        buf.extend_from_slice(&(self.rows.len() as u32).to_le_bytes());
+        for row in &self.rows {
+            buf.extend_from_slice(&row.to_le_bytes());
+        }
+        for v in &self.vectors {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for layers in &self.neighbors {
+            buf.extend_from_slice(&(layers.len() as u32).to_le_bytes());
+            for links in layers {
+                buf.extend_from_slice(&(links.len() as u32).to_le_bytes());
+                for link in links {
+                    buf.extend_from_slice(&link.to_le_bytes());
+                }
+            }
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        let version = read_u32(&mut reader)?;
+        if version != FORMAT_VERSION {
+            return Err(ErrorCode::DeprecatedIndexFormat(format!(
+                "Unsupported vector index version {}",
+                version
+            )));
+        }
+        let distance = match read_u32(&mut reader)? {
+            0 => VectorDistance::Cosine,
+            1 => VectorDistance::L2,
+            v => return Err(invalid_index(&format!("unknown distance {}", v))),
+        };
+        let dimension = read_u32(&mut reader)? as usize;
+        let entry_point = Some(read_u32(&mut reader)?).filter(|v| *v != u32::MAX);
+        let len = read_u32(&mut reader)? as usize;
+        if reader.len() < len.saturating_mul(dimension + 1).saturating_mul(4) {
+            return Err(invalid_index("unexpected end"));
+        }
+        let rows = (0..len)
+            .map(|_| read_u32(&mut reader))
+            .collect::<Result<Vec<_>>>()?;
+        let vectors = (0..len * dimension)
+            .map(|_| read_u32(&mut reader).map(f32::from_bits))
+            .collect::<Result<Vec<_>>>()?;
+        let mut neighbors = Vec::with_capacity(len);
+        for _ in 0..len {
+            let num_layers = read_u32(&mut reader)? as usize;
+            let mut layers = Vec::with_capacity(num_layers.min(reader.len()));
+            for _ in 0..num_layers {
+                let num_links = read_u32(&mut reader)? as usize;
+                let links = (0..num_links)
+                    .map(|_| read_u32(&mut reader))
+                    .collect::<Result<Vec<_>>>()?;
+                if links.iter().any(|link| *link as usize >= len) {
+                    return Err(invalid_index("link out of range"));
+                }
+                layers.push(links);
+            }
+            if layers.is_empty() {
+                return Err(invalid_index("node without layers"));
+            }
+            neighbors.push(layers);
+        }
+        if !reader.is_empty() || entry_point.map_or(len != 0, |e| e as usize >= len) {
+            return Err(invalid_index("unexpected length"));
+        }
+        // The links of a node on a layer must point to the nodes on that layer.
+        for layers in &neighbors {
+            for (layer, links) in layers.iter().enumerate() {
+                if links
+                    .iter()
+                    .any(|link| neighbors[*link as usize].len() <= layer)
+                {
+                    return Err(invalid_index("link out of layer"));
+                }
+            }
+        }
+
+        Ok(HnswIndex {
+            distance,
+            dimension,
+            rows,
+            vectors,
+            neighbors,
+            entry_point,
+        })
+    }
+}
+
+fn read_u32(reader: &mut &[u8]) -> Result<u32> {
+    if reader.len() < 4 {
+        return Err(invalid_index("unexpected end"));
+    }
+    let (bytes, rest) = reader.split_at(4);
+    *reader = rest;
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+fn invalid_index(reason: &str) -> ErrorCode {
+    ErrorCode::StorageOther(format!("Invalid vector index: {}", reason))
+}
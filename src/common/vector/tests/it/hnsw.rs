@@ -0,0 +1,110 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_vector::cosine_distance;
+use databend_common_vector::l2_distance;
+use databend_common_vector::HnswIndex;
+use databend_common_vector::VectorDistance;
+
+fn random_vectors(n: usize, dimension: usize) -> Vec<Vec<f32>> {
+    let mut seed = 42u64;
+    (0..n)
+        .map(|_| {
+            (0..dimension)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    (seed % 10000) as f32 / 5000.0 - 1.0
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_hnsw_search() {
+    let vectors = random_vectors(2000, 8);
+    let (queries, vectors) = vectors.split_at(20);
+
+    for distance in [VectorDistance::Cosine, VectorDistance::L2] {
+        let index = HnswIndex::build(
+            distance,
+            8,
+            vectors
+                .iter()
+                .enumerate()
+                .map(|(row, v)| (row as u32, v.as_slice())),
+        )
+        .unwrap();
+        assert_eq!(index.len(), vectors.len());
+
+        let mut hits = 0;
+        for query in queries {
+            let distance_fn = match distance {
+                VectorDistance::Cosine => cosine_distance,
+                VectorDistance::L2 => l2_distance,
+            };
+            let mut expected = vectors
+                .iter()
+                .enumerate()
+                .map(|(row, v)| (row as u32, distance_fn(query, v).unwrap()))
+                .collect::<Vec<_>>();
+            expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+            let nearest = index.search(query, 10).unwrap();
+            assert_eq!(nearest.len(), 10);
+            for (row, d) in nearest {
+                let exact = distance_fn(query, &vectors[row as usize]).unwrap();
+                approx::assert_relative_eq!(d, exact, epsilon = 1e-4);
+                if expected[..10].iter().any(|(r, _)| *r == row) {
+                    hits += 1;
+                }
+            }
+        }
+        // The recall of the approximate search.
+        assert!(hits >= queries.len() * 10 * 9 / 10, "{distance}: {hits}");
+    }
+}
+
+#[test]
+fn test_hnsw_serialization() {
+    let vectors = random_vectors(100, 4);
+    let index = HnswIndex::build(
+        VectorDistance::L2,
+        4,
+        vectors
+            .iter()
+            .enumerate()
+            .map(|(row, v)| (row as u32 * 2, v.as_slice())),
+    )
+    .unwrap();
+    let bytes = index.to_bytes();
+    assert_eq!(HnswIndex::from_bytes(&bytes).unwrap(), index);
+    assert!(HnswIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    let nearest = index.search(&vectors[3], 1).unwrap();
+    assert_eq!(nearest, vec![(6, 0.0)]);
+    assert!(index.search(&[1.0], 1).is_err());
+}
+
+#[test]
+fn test_hnsw_empty() {
+    // The cosine distance to a zero vector is undefined.
+    let index = HnswIndex::build(VectorDistance::Cosine, 2, [(0, [0.0, 0.0].as_slice())]).unwrap();
+    assert!(index.is_empty());
+    assert!(index.search(&[1.0, 0.0], 3).unwrap().is_empty());
+    assert_eq!(HnswIndex::from_bytes(&index.to_bytes()).unwrap(), index);
+    assert!(HnswIndex::build(VectorDistance::L2, 2, [(0, [1.0].as_slice())]).is_err());
+}
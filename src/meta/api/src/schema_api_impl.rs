@@ -157,6 +157,8 @@ use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReply;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -3145,6 +3147,189 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let mut txn_req = TxnRequest {
+            condition: vec![],
+            if_then: vec![],
+            else_then: vec![],
+        };
+        let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+        // The versions of the tables and the streams the transaction conditions are built on.
+        let mut table_seqs = Vec::with_capacity(req.update_table_metas.len());
+        let mut stream_seqs = vec![];
+
+        for req in &req.update_table_metas {
+            let tbid = TableId {
+                table_id: req.table_id,
+            };
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(self, &tbid).await?;
+
+            if tb_meta_seq == 0 || table_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "update_multi_table_meta"),
+                )));
+            }
+            if req.seq.match_seq(tb_meta_seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    TableVersionMismatched::new(
+                        req.table_id,
+                        req.seq,
+                        tb_meta_seq,
+                        "update_multi_table_meta",
+                    ),
+                )));
+            }
+
+            // table is not changed
+            txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+            txn_req
+                .if_then
+                .push(txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?));
+            table_seqs.push(tb_meta_seq);
+
+            if let Some(req) = &req.copied_files {
+                let (conditions, match_operations) =
+                    build_upsert_table_copied_file_info_conditions(
+                        &tbid,
+                        req,
+                        tb_meta_seq,
+                        req.fail_if_duplicated,
+                    )?;
+                txn_req.condition.extend(conditions);
+                txn_req.if_then.extend(match_operations)
+            }
+
+            for req in &req.update_stream_meta {
+                let stream_id = TableId {
+                    table_id: req.stream_id,
+                };
+                let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &stream_id).await?;
+
+                if stream_meta_seq == 0 || stream_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownStreamId(
+                        UnknownStreamId::new(req.stream_id, "update_multi_table_meta"),
+                    )));
+                }
+
+                if req.seq.match_seq(stream_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        StreamVersionMismatched::new(
+                            req.stream_id,
+                            req.seq,
+                            stream_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                let mut new_stream_meta = stream_meta.unwrap();
+                new_stream_meta.options = req.options.clone();
+                new_stream_meta.updated_on = Utc::now();
+
+                txn_req
+                    .condition
+                    .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
+                stream_seqs.push(stream_meta_seq);
+            }
+
+            if let Some(deduplicated_label) = req.deduplicated_label.clone() {
+                txn_req
+                    .if_then
+                    .push(build_upsert_table_deduplicated_label(deduplicated_label))
+            }
+
+            table_metas.push(table_meta.unwrap());
+        }
+
+        let (succ, _responses) = send_txn(self, txn_req).await?;
+
+        debug!(succ = succ; "update_multi_table_meta");
+
+        if !succ {
+            // Unlike `update_table_meta`, the transaction is not retried: the tables or
+            // streams have been changed by others, or the copied files are duplicated.
+            let mut stream_seqs = stream_seqs.into_iter();
+            for (req, read_seq) in req.update_table_metas.iter().zip(table_seqs) {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (tb_meta_seq, _): (_, Option<TableMeta>) = get_pb_value(self, &tbid).await?;
+                if tb_meta_seq != read_seq {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            MatchSeq::Exact(read_seq),
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+                for (req, read_seq) in req.update_stream_meta.iter().zip(stream_seqs.by_ref()) {
+                    let stream_id = TableId {
+                        table_id: req.stream_id,
+                    };
+                    let (stream_meta_seq, _): (_, Option<TableMeta>) =
+                        get_pb_value(self, &stream_id).await?;
+                    if stream_meta_seq != read_seq {
+                        return Err(KVAppError::AppError(AppError::from(
+                            StreamVersionMismatched::new(
+                                req.stream_id,
+                                MatchSeq::Exact(read_seq),
+                                stream_meta_seq,
+                                "update_multi_table_meta",
+                            ),
+                        )));
+                    }
+                }
+            }
+
+            // The versions are unchanged, only the absence of the copied files can fail.
+            let duplicated = req.update_table_metas.iter().find(|req| {
+                req.copied_files
+                    .as_ref()
+                    .is_some_and(|files| files.fail_if_duplicated)
+            });
+            return Err(KVAppError::AppError(match duplicated {
+                Some(req) => AppError::from(DuplicatedUpsertFiles::new(
+                    req.table_id,
+                    "update_multi_table_meta",
+                )),
+                // All the versions are unchanged and no file is required absent, which should not happen.
+                None => {
+                    let table_id = req.update_table_metas.first().map_or(0, |req| req.table_id);
+                    AppError::from(TableVersionMismatched::new(
+                        table_id,
+                        MatchSeq::Any,
+                        0,
+                        "update_multi_table_meta",
+                    ))
+                }
+            }));
+        }
+
+        let mut share_table_info = vec![];
+        for table_meta in &table_metas {
+            if let Some(infos) = get_share_table_info_map(self, table_meta).await? {
+                share_table_info.extend(infos);
+            }
+        }
+        Ok(UpdateMultiTableMetaReply {
+            share_table_info: (!share_table_info.is_empty()).then_some(share_table_info),
+        })
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn set_table_column_mask_policy(
@@ -101,6 +101,7 @@ use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -293,6 +294,7 @@ impl SchemaApiTestSuite {
             .await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite.table_update_multi_meta(&b.build().await).await?;
         suite.table_update_mask_policy(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
@@ -1377,6 +1379,7 @@ impl SchemaApiTestSuite {
                         bucket: "bucket".to_string(),
                         ..Default::default()
                     })),
+                    rest: None,
                 }),
                 created_on: Utc::now(),
             },
@@ -2531,6 +2534,154 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_multi_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let table_meta = |created_on| TableMeta {
+            schema: Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )])),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on,
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(Utc::now()),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_req = |table: &TableInfo, seq: u64, data_bytes: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            }
+        };
+
+        info!("--- update multi table meta, normal case");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![
+                    update_req(tb1.as_ref(), tb1.ident.seq, 1),
+                    update_req(tb2.as_ref(), tb2.ident.seq, 2),
+                ],
+            })
+            .await?;
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(tb1.meta.statistics.data_bytes, 1);
+            assert_eq!(tb2.meta.statistics.data_bytes, 2);
+        }
+
+        info!("--- update multi table meta: version mismatch, no table is updated");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(tb1.as_ref(), tb1.ident.seq, 3),
+                        update_req(tb2.as_ref(), tb2.ident.seq + 1, 4),
+                    ],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(tb1.meta.statistics.data_bytes, 1);
+            assert_eq!(tb2.meta.statistics.data_bytes, 2);
+        }
+
+        info!("--- update multi table meta: duplicated copied files, no table is updated");
+        {
+            let copied_files = || {
+                let mut file_info = BTreeMap::new();
+                file_info.insert("file".to_owned(), TableCopiedFileInfo {
+                    etag: Some("tag".to_string()),
+                    content_length: 1,
+                    last_modified: None,
+                });
+                Some(UpsertTableCopiedFileReq {
+                    file_info,
+                    expire_at: None,
+                    fail_if_duplicated: true,
+                })
+            };
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let mut req = update_req(tb1.as_ref(), tb1.ident.seq, 5);
+            req.copied_files = copied_files();
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![req],
+            })
+            .await?;
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            let mut req = update_req(tb1.as_ref(), tb1.ident.seq, 6);
+            req.copied_files = copied_files();
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![update_req(tb2.as_ref(), tb2.ident.seq, 7), req],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::DuplicatedUpsertFiles("").code(), err.code());
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(tb1.meta.statistics.data_bytes, 5);
+            assert_eq!(tb2.meta.statistics.data_bytes, 2);
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_update_mask_policy<
         MT: SchemaApi + DatamaskApi + kvapi::AsKVApi<Error = MetaError>,
@@ -43,6 +43,12 @@ const MISSING_FIELD_AS: &str = "missing_field_as";
 const NULL_FIELD_AS: &str = "null_field_as";
 const OPT_EMPTY_FIELD_AS: &str = "empty_field_as";
 const OPT_BINARY_FORMAT: &str = "binary_format";
+const OPT_COMPRESSION: &str = "compression";
+const OPT_ROW_GROUP_SIZE: &str = "row_group_size";
+const OPT_ENABLE_DICTIONARY: &str = "enable_dictionary";
+const OPT_ENABLE_PAGE_INDEX: &str = "enable_page_index";
+const OPT_ENABLE_BLOOM_FILTER: &str = "enable_bloom_filter";
+const OPT_IPC_FORMAT: &str = "ipc_format";
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FileFormatOptionsAst {
@@ -117,6 +123,9 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Orc(OrcFileFormatParams),
+    Arrow(ArrowFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -128,6 +137,9 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Arrow(_) => StageFileFormatType::Arrow,
         }
     }
 
@@ -145,6 +157,13 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
+            StageFileFormatType::Arrow => {
+                Ok(FileFormatParams::Arrow(ArrowFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -160,6 +179,9 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Avro(v) => v.compression,
+            FileFormatParams::Orc(_) => StageFileCompression::None,
+            FileFormatParams::Arrow(_) => StageFileCompression::None,
         }
     }
 
@@ -191,9 +213,55 @@ impl FileFormatParams {
                 )?)
             }
             StageFileFormatType::Parquet => {
+                let default = ParquetFileFormatParams::default();
+                let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
+                let compression = ast
+                    .options
+                    .remove(OPT_COMPRESSION)
+                    .map(|s| ParquetCompression::from_str(&s))
+                    .transpose()?
+                    .unwrap_or_default();
+                let row_group_size = ast.take_u64(OPT_ROW_GROUP_SIZE, default.row_group_size)?;
+                let enable_dictionary =
+                    ast.take_bool(OPT_ENABLE_DICTIONARY, default.enable_dictionary)?;
+                let enable_page_index =
+                    ast.take_bool(OPT_ENABLE_PAGE_INDEX, default.enable_page_index)?;
+                let enable_bloom_filter =
+                    ast.take_bool(OPT_ENABLE_BLOOM_FILTER, default.enable_bloom_filter)?;
+                let mut params = ParquetFileFormatParams::try_create(missing_field_as.as_deref())?;
+                params.compression = compression;
+                params.row_group_size = row_group_size;
+                params.enable_dictionary = enable_dictionary;
+                params.enable_page_index = enable_page_index;
+                params.enable_bloom_filter = enable_bloom_filter;
+                FileFormatParams::Parquet(params)
+            }
+            StageFileFormatType::Orc => {
+                let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
+                FileFormatParams::Orc(OrcFileFormatParams::try_create(
+                    missing_field_as.as_deref(),
+                )?)
+            }
+            StageFileFormatType::Arrow => {
+                let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
+                let ipc_format = ast
+                    .options
+                    .remove(OPT_IPC_FORMAT)
+                    .map(|s| ArrowIpcFormat::from_str(&s))
+                    .transpose()?
+                    .unwrap_or_default();
+                let mut params = ArrowFileFormatParams::try_create(missing_field_as.as_deref())?;
+                params.ipc_format = ipc_format;
+                FileFormatParams::Arrow(params)
+            }
+            StageFileFormatType::Avro => {
+                let compression = ast.take_compression()?;
                 let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
-                FileFormatParams::Parquet(ParquetFileFormatParams::try_create(
+                let null_field_as = ast.options.remove(NULL_FIELD_AS);
+                FileFormatParams::Avro(AvroFileFormatParams::try_create(
+                    compression,
                     missing_field_as.as_deref(),
+                    null_field_as.as_deref(),
                 )?)
             }
             StageFileFormatType::Csv => {
@@ -325,9 +393,7 @@ impl FileFormatParams {
 
 impl Default for FileFormatParams {
     fn default() -> Self {
-        FileFormatParams::Parquet(ParquetFileFormatParams {
-            missing_field_as: NullAs::Error,
-        })
+        FileFormatParams::Parquet(ParquetFileFormatParams::default())
     }
 }
 
@@ -559,6 +625,47 @@ impl Display for BinaryFormat {
     }
 }
 
+/// Compression codec of the column chunks when unloading to parquet files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ParquetCompression {
+    None,
+    Snappy,
+    Gzip,
+    Lz4,
+    #[default]
+    Zstd,
+}
+
+impl FromStr for ParquetCompression {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            // `AUTO` is accepted for consistency with other formats.
+            "auto" | "zstd" => Ok(Self::Zstd),
+            "none" => Ok(Self::None),
+            "snappy" => Ok(Self::Snappy),
+            "gzip" => Ok(Self::Gzip),
+            "lz4" => Ok(Self::Lz4),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Invalid option value: COMPRESSION is set to {s}. The valid values for PARQUET are ZSTD | SNAPPY | GZIP | LZ4 | NONE."
+            ))),
+        }
+    }
+}
+
+impl Display for ParquetCompression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "NONE"),
+            Self::Snappy => write!(f, "SNAPPY"),
+            Self::Gzip => write!(f, "GZIP"),
+            Self::Lz4 => write!(f, "LZ4"),
+            Self::Zstd => write!(f, "ZSTD"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct JsonFileFormatParams {
     pub compression: StageFileCompression,
@@ -628,18 +735,163 @@ impl NdJsonFileFormatParams {
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {
     pub missing_field_as: NullAs,
+
+    // options for unloading
+    pub compression: ParquetCompression,
+    /// max number of rows in a row group, 0 means one row group for each data block.
+    pub row_group_size: u64,
+    pub enable_dictionary: bool,
+    /// write column index and offset index (page-level statistics).
+    pub enable_page_index: bool,
+    pub enable_bloom_filter: bool,
 }
 
 impl ParquetFileFormatParams {
+    pub fn try_create(missing_field_as: Option<&str>) -> Result<Self> {
+        let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
+        Ok(Self {
+            missing_field_as,
+            ..Default::default()
+        })
+    }
+
+    pub fn downcast_unchecked(params: &FileFormatParams) -> &ParquetFileFormatParams {
+        match params {
+            FileFormatParams::Parquet(p) => p,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Default for ParquetFileFormatParams {
+    fn default() -> Self {
+        ParquetFileFormatParams {
+            missing_field_as: NullAs::Error,
+            compression: ParquetCompression::Zstd,
+            row_group_size: 0,
+            enable_dictionary: false,
+            enable_page_index: false,
+            enable_bloom_filter: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcFileFormatParams {
+    pub missing_field_as: NullAs,
+}
+
+impl OrcFileFormatParams {
     pub fn try_create(missing_field_as: Option<&str>) -> Result<Self> {
         let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
         Ok(Self { missing_field_as })
     }
 }
 
+/// Encapsulation of the Arrow IPC format, the random access file or the streaming format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ArrowIpcFormat {
+    #[default]
+    File,
+    Stream,
+}
+
+impl FromStr for ArrowIpcFormat {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "file" => Ok(Self::File),
+            "stream" => Ok(Self::Stream),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Invalid option value: IPC_FORMAT is set to {s}. The valid values are FILE | STREAM."
+            ))),
+        }
+    }
+}
+
+impl Display for ArrowIpcFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File => write!(f, "FILE"),
+            Self::Stream => write!(f, "STREAM"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArrowFileFormatParams {
+    pub missing_field_as: NullAs,
+
+    // options for unloading
+    pub ipc_format: ArrowIpcFormat,
+}
+
+impl ArrowFileFormatParams {
+    pub fn try_create(missing_field_as: Option<&str>) -> Result<Self> {
+        let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
+        Ok(Self {
+            missing_field_as,
+            ..Default::default()
+        })
+    }
+
+    pub fn downcast_unchecked(params: &FileFormatParams) -> &ArrowFileFormatParams {
+        match params {
+            FileFormatParams::Arrow(p) => p,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {
+    pub compression: StageFileCompression,
+    pub missing_field_as: NullAs,
+    pub null_field_as: NullAs,
+}
+
+impl AvroFileFormatParams {
+    pub fn try_create(
+        compression: StageFileCompression,
+        missing_field_as: Option<&str>,
+        null_field_as: Option<&str>,
+    ) -> Result<Self> {
+        let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
+        let null_field_as = NullAs::parse(null_field_as, NULL_FIELD_AS, NullAs::Null)?;
+        if matches!(null_field_as, NullAs::Error) {
+            return Err(ErrorCode::InvalidArgument(
+                "Invalid option value: NULL_FIELD_AS is set to ERROR. The valid values are NULL | FIELD_DEFAULT.",
+            ));
+        }
+        Ok(Self {
+            compression,
+            missing_field_as,
+            null_field_as,
+        })
+    }
+
+    pub fn downcast_unchecked(params: &FileFormatParams) -> &AvroFileFormatParams {
+        match params {
+            FileFormatParams::Avro(p) => p,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Default for AvroFileFormatParams {
+    fn default() -> Self {
+        AvroFileFormatParams {
+            compression: StageFileCompression::None,
+            missing_field_as: NullAs::Error,
+            null_field_as: NullAs::Null,
+        }
+    }
+}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -701,10 +953,37 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(params) => {
                 write!(
                     f,
-                    "TYPE = PARQUET MISSING_FIELD_AS = {}",
+                    "TYPE = PARQUET MISSING_FIELD_AS = {} COMPRESSION = {} ROW_GROUP_SIZE = {} \
+                    ENABLE_DICTIONARY = {} ENABLE_PAGE_INDEX = {} ENABLE_BLOOM_FILTER = {}",
+                    params.missing_field_as,
+                    params.compression,
+                    params.row_group_size,
+                    params.enable_dictionary,
+                    params.enable_page_index,
+                    params.enable_bloom_filter
+                )
+            }
+            FileFormatParams::Avro(params) => {
+                write!(
+                    f,
+                    "TYPE = AVRO COMPRESSION = {:?} MISSING_FIELD_AS = {} NULL_FIELD_AS = {}",
+                    params.compression, params.missing_field_as, params.null_field_as
+                )
+            }
+            FileFormatParams::Orc(params) => {
+                write!(
+                    f,
+                    "TYPE = ORC MISSING_FIELD_AS = {}",
                     params.missing_field_as
                 )
             }
+            FileFormatParams::Arrow(params) => {
+                write!(
+                    f,
+                    "TYPE = ARROW MISSING_FIELD_AS = {} IPC_FORMAT = {}",
+                    params.missing_field_as, params.ipc_format
+                )
+            }
         }
     }
 }
@@ -19,8 +19,10 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod pipe;
 mod principal_identity;
 mod role_info;
+mod task;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -37,9 +39,17 @@ pub use file_format::*;
 pub use network_policy::NetworkPolicy;
 pub use ownership_info::OwnershipInfo;
 pub use password_policy::PasswordPolicy;
+pub use pipe::PipeInfo;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use task::ScheduleOptions;
+pub use task::ScheduleType;
+pub use task::Task;
+pub use task::TaskRun;
+pub use task::TaskRunState;
+pub use task::TaskStatus;
+pub use task::WarehouseOptions;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
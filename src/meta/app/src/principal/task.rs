@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use cron::Schedule;
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum ScheduleType {
+    #[default]
+    IntervalType = 0,
+    CronType = 1,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScheduleOptions {
+    pub interval: Option<i32>,
+    pub cron: Option<String>,
+    pub time_zone: Option<String>,
+    pub schedule_type: ScheduleType,
+}
+
+impl ScheduleOptions {
+    /// Returns the first scheduled time strictly after `after`, or None if the
+    /// options can not produce one (e.g. an invalid cron expression).
+    pub fn next_scheduled_at(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.schedule_type {
+            ScheduleType::IntervalType => {
+                let secs = self.interval.filter(|v| *v > 0)?;
+                Some(after + chrono::Duration::seconds(secs as i64))
+            }
+            ScheduleType::CronType => {
+                let schedule = Schedule::from_str(self.cron.as_ref()?).ok()?;
+                let tz = match self.time_zone.as_ref().filter(|v| !v.is_empty()) {
+                    Some(tz) => chrono_tz::Tz::from_str(tz).ok()?,
+                    None => chrono_tz::UTC,
+                };
+                let upcoming = schedule.after(&after.with_timezone(&tz)).next()?;
+                Some(upcoming.with_timezone(&Utc))
+            }
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct WarehouseOptions {
+    pub warehouse: Option<String>,
+    pub using_warehouse_size: Option<String>,
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TaskStatus {
+    #[default]
+    Suspended = 0,
+    Started = 1,
+}
+
+impl Display for TaskStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A task definition persisted by the built-in task scheduler.
+///
+/// The layout follows the task message of the cloud control service, so both
+/// sources can be rendered by `system.tasks` in the same way.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Task {
+    pub task_id: u64,
+    pub task_name: String,
+    pub query_text: String,
+    pub when_condition: Option<String>,
+    pub after: Vec<String>,
+    pub comment: Option<String>,
+    // The role that owns the task, the task is executed under this role.
+    pub owner: String,
+    pub schedule_options: Option<ScheduleOptions>,
+    pub warehouse_options: Option<WarehouseOptions>,
+    pub next_scheduled_at: Option<DateTime<Utc>>,
+    pub suspend_task_after_num_failures: Option<u64>,
+    pub error_integration: Option<String>,
+    pub status: TaskStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_suspended_at: Option<DateTime<Utc>>,
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TaskRunState {
+    #[default]
+    Scheduled = 0,
+    Executing = 1,
+    Succeeded = 2,
+    Failed = 3,
+    Cancelled = 4,
+}
+
+impl Display for TaskRunState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskRunState::Scheduled => write!(f, "SCHEDULED"),
+            TaskRunState::Executing => write!(f, "EXECUTING"),
+            TaskRunState::Succeeded => write!(f, "SUCCEEDED"),
+            TaskRunState::Failed => write!(f, "FAILED"),
+            TaskRunState::Cancelled => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+/// One execution of a task, `task` is the definition at the time it ran.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TaskRun {
+    pub task: Task,
+    pub run_id: String,
+    pub query_id: String,
+    pub attempt_number: i32,
+    pub state: TaskRunState,
+    pub scheduled_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub error_code: i64,
+    pub error_message: Option<String>,
+    // The id of the root task of the DAG this run belongs to.
+    pub root_task_id: u64,
+}
@@ -71,6 +71,22 @@ pub struct HiveCatalogOption {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct IcebergCatalogOption {
     pub storage_params: Box<StorageParams>,
+    /// Connection to an iceberg REST catalog.
+    ///
+    /// If not set, databases and tables are discovered from the directories of `storage_params`.
+    pub rest: Option<IcebergRestCatalogOption>,
+}
+
+/// Option for connecting to a iceberg REST catalog
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct IcebergRestCatalogOption {
+    /// Base uri of the REST catalog service, like `http://127.0.0.1:8181`
+    pub uri: String,
+    pub warehouse: String,
+    /// OAuth2 client credential in `client_id:client_secret` form
+    pub credential: String,
+    /// Bearer token used directly for authentication
+    pub token: String,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
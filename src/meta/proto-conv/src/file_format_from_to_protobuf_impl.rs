@@ -44,6 +44,7 @@ impl FromToProtoEnum for mt::principal::StageFileFormatType {
             pb::StageFileFormatType::Orc => Ok(mt::principal::StageFileFormatType::Orc),
             pb::StageFileFormatType::Parquet => Ok(mt::principal::StageFileFormatType::Parquet),
             pb::StageFileFormatType::Xml => Ok(mt::principal::StageFileFormatType::Xml),
+            pb::StageFileFormatType::Arrow => Ok(mt::principal::StageFileFormatType::Arrow),
         }
     }
 
@@ -57,6 +58,7 @@ impl FromToProtoEnum for mt::principal::StageFileFormatType {
             mt::principal::StageFileFormatType::Orc => Ok(pb::StageFileFormatType::Orc),
             mt::principal::StageFileFormatType::Parquet => Ok(pb::StageFileFormatType::Parquet),
             mt::principal::StageFileFormatType::Xml => Ok(pb::StageFileFormatType::Xml),
+            mt::principal::StageFileFormatType::Arrow => Ok(pb::StageFileFormatType::Arrow),
             mt::principal::StageFileFormatType::None => Err(Incompatible {
                 reason: "StageFileFormatType::None cannot be converted to protobuf".to_string(),
             }),
@@ -244,6 +246,21 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Avro(p)) => {
+                Ok(mt::principal::FileFormatParams::Avro(
+                    mt::principal::AvroFileFormatParams::from_pb(p)?,
+                ))
+            }
+            Some(pb::file_format_params::Format::Orc(p)) => {
+                Ok(mt::principal::FileFormatParams::Orc(
+                    mt::principal::OrcFileFormatParams::from_pb(p)?,
+                ))
+            }
+            Some(pb::file_format_params::Format::Arrow(p)) => {
+                Ok(mt::principal::FileFormatParams::Arrow(
+                    mt::principal::ArrowFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -282,6 +299,21 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Avro(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Avro(
+                    mt::principal::AvroFileFormatParams::to_pb(p)?,
+                )),
+            }),
+            Self::Orc(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Orc(
+                    mt::principal::OrcFileFormatParams::to_pb(p)?,
+                )),
+            }),
+            Self::Arrow(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Arrow(
+                    mt::principal::ArrowFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -295,8 +327,22 @@ impl FromToProto for mt::principal::ParquetFileFormatParams {
     fn from_pb(p: pb::ParquetFileFormatParams) -> Result<Self, Incompatible>
     where Self: Sized {
         reader_check_msg(p.ver, p.min_reader_ver)?;
+        let compression = p
+            .compression
+            .as_deref()
+            .map(mt::principal::ParquetCompression::from_str)
+            .transpose()
+            .map_err(|e| Incompatible {
+                reason: format!("{e}"),
+            })?
+            .unwrap_or_default();
         Ok(mt::principal::ParquetFileFormatParams {
             missing_field_as: NullAs::Error,
+            compression,
+            row_group_size: p.row_group_size,
+            enable_dictionary: p.enable_dictionary,
+            enable_page_index: p.enable_page_index,
+            enable_bloom_filter: p.enable_bloom_filter,
         })
     }
 
@@ -304,6 +350,11 @@ impl FromToProto for mt::principal::ParquetFileFormatParams {
         Ok(pb::ParquetFileFormatParams {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
+            compression: Some(self.compression.to_string()),
+            row_group_size: self.row_group_size,
+            enable_dictionary: self.enable_dictionary,
+            enable_page_index: self.enable_page_index,
+            enable_bloom_filter: self.enable_bloom_filter,
         })
     }
 }
@@ -346,6 +397,102 @@ impl FromToProto for mt::principal::NdJsonFileFormatParams {
     }
 }
 
+impl FromToProto for mt::principal::OrcFileFormatParams {
+    type PB = pb::OrcFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::OrcFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        mt::principal::OrcFileFormatParams::try_create(p.missing_field_as.as_deref()).map_err(|e| {
+            Incompatible {
+                reason: format!("{e}"),
+            }
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::OrcFileFormatParams, Incompatible> {
+        Ok(pb::OrcFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            missing_field_as: Some(self.missing_field_as.to_string()),
+        })
+    }
+}
+
+impl FromToProto for mt::principal::ArrowFileFormatParams {
+    type PB = pb::ArrowFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::ArrowFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        let mut params =
+            mt::principal::ArrowFileFormatParams::try_create(p.missing_field_as.as_deref())
+                .map_err(|e| Incompatible {
+                    reason: format!("{e}"),
+                })?;
+        if let Some(ipc_format) = p.ipc_format {
+            params.ipc_format =
+                mt::principal::ArrowIpcFormat::from_str(&ipc_format).map_err(|e| Incompatible {
+                    reason: format!("{e}"),
+                })?;
+        }
+        Ok(params)
+    }
+
+    fn to_pb(&self) -> Result<pb::ArrowFileFormatParams, Incompatible> {
+        Ok(pb::ArrowFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            missing_field_as: Some(self.missing_field_as.to_string()),
+            ipc_format: Some(self.ipc_format.to_string()),
+        })
+    }
+}
+
+impl FromToProto for mt::principal::AvroFileFormatParams {
+    type PB = pb::AvroFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AvroFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        let compression = mt::principal::StageFileCompression::from_pb_enum(
+            FromPrimitive::from_i32(p.compression).ok_or_else(|| Incompatible {
+                reason: format!("invalid StageFileCompression: {}", p.compression),
+            })?,
+        )?;
+
+        mt::principal::AvroFileFormatParams::try_create(
+            compression,
+            p.missing_field_as.as_deref(),
+            p.null_field_as.as_deref(),
+        )
+        .map_err(|e| Incompatible {
+            reason: format!("{e}"),
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::AvroFileFormatParams, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
+        Ok(pb::AvroFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            compression,
+            missing_field_as: Some(self.missing_field_as.to_string()),
+            null_field_as: Some(self.null_field_as.to_string()),
+        })
+    }
+}
+
 impl FromToProto for mt::principal::JsonFileFormatParams {
     type PB = pb::JsonFileFormatParams;
     fn get_pb_ver(p: &Self::PB) -> u64 {
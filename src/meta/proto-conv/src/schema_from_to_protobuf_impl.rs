@@ -261,6 +261,9 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
+                    Dt24::TimestampTzT(_) => ex::TableDataType::TimestampTz,
+                    Dt24::GeometryT(_) => ex::TableDataType::Geometry,
                 };
                 Ok(x)
             }
@@ -322,6 +325,9 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::TupleT(x))
             }
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
+            TableDataType::TimestampTz => new_pb_dt24(Dt24::TimestampTzT(pb::Empty {})),
+            TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
         };
         Ok(x)
     }
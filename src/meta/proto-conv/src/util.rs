@@ -107,6 +107,16 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (75, "2024-01-15: ADD: user.proto/CsvFileFormatParams add field `binary_format` and `output_header`", ),
     (76, "2024-01-18: ADD: ownership.proto and role.proto", ),
     (77, "2024-01-22: Remove: allow_anonymous in S3 Config", ),
+    (78, "2024-01-29: Add: pipe.proto"),
+    (79, "2024-02-01: Add: task.proto"),
+    (80, "2024-02-05: Add: file_format.proto/AvroFileFormatParams"),
+    (81, "2024-02-07: Add: file_format.proto/OrcFileFormatParams"),
+    (82, "2024-02-09: Add: file_format.proto/ParquetFileFormatParams unload options"),
+    (83, "2024-02-12: Add: catalog.proto/IcebergRestCatalogOption"),
+    (84, "2024-02-14: Add: file_format.proto/ArrowFileFormatParams"),
+    (85, "2024-02-16: Add: datatype.proto Interval type"),
+    (86, "2024-02-18: Add: datatype.proto TimestampTz type"),
+    (87, "2024-02-20: Add: datatype.proto Geometry type"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -81,3 +81,13 @@ mod v074_table_db_meta;
 mod v075_csv_format_params;
 mod v076_role_ownership_info;
 mod v077_s3_remove_allow_anonymous;
+mod v078_pipe;
+mod v079_task;
+mod v080_avro_file_format_params;
+mod v081_orc_file_format_params;
+mod v082_parquet_file_format_params;
+mod v083_iceberg_rest_catalog;
+mod v084_arrow_file_format_params;
+mod v085_interval_type;
+mod v086_timestamp_tz_type;
+mod v087_geometry_type;
@@ -319,6 +319,7 @@ fn new_catalog_meta() -> databend_common_meta_app::schema::CatalogMeta {
                     ..Default::default()
                 },
             )),
+            rest: None,
         }),
         created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
     }
@@ -15,6 +15,7 @@
 use databend_common_meta_app as mt;
 use databend_common_meta_app::principal::CsvFileFormatParams;
 use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::ParquetCompression;
 use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_meta_app::principal::TsvFileFormatParams;
 use minitrace::func_name;
@@ -137,6 +138,11 @@ fn test_decode_v32_parquet_file_format_params() -> anyhow::Result<()> {
     let want = || {
         mt::principal::FileFormatParams::Parquet(ParquetFileFormatParams {
             missing_field_as: Default::default(),
+            compression: ParquetCompression::Zstd,
+            row_group_size: 0,
+            enable_dictionary: false,
+            enable_page_index: false,
+            enable_bloom_filter: false,
         })
     };
     common::test_load_old(func_name!(), file_format_params_v32.as_slice(), 0, want())?;
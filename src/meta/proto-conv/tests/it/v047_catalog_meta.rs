@@ -55,6 +55,7 @@ fn test_decode_v47_catalog() -> anyhow::Result<()> {
                     ..Default::default()
                 },
             )),
+            rest: None,
         }),
         created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
     };
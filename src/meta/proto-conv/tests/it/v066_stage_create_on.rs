@@ -56,6 +56,11 @@ fn test_decode_v66_stage() -> anyhow::Result<()> {
         file_format_params: mt::principal::FileFormatParams::Parquet(
             mt::principal::ParquetFileFormatParams {
                 missing_field_as: Default::default(),
+                compression: mt::principal::ParquetCompression::Zstd,
+                row_group_size: 0,
+                enable_dictionary: false,
+                enable_page_index: false,
+                enable_bloom_filter: false,
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::PipeInfo;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v78_pipe() -> anyhow::Result<()> {
+    let pipe_info_v78 = vec![
+        10, 7, 109, 121, 95, 112, 105, 112, 101, 16, 1, 34, 14, 108, 111, 97, 100, 32, 110, 101,
+        119, 32, 102, 105, 108, 101, 115, 42, 50, 67, 79, 80, 89, 32, 73, 78, 84, 79, 32, 116, 49,
+        32, 70, 82, 79, 77, 32, 64, 115, 49, 32, 70, 73, 76, 69, 95, 70, 79, 82, 77, 65, 84, 32,
+        61, 32, 40, 116, 121, 112, 101, 32, 61, 32, 39, 67, 83, 86, 39, 41, 50, 23, 50, 48, 49, 52,
+        45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 58, 23, 50, 48,
+        49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6,
+        78, 168, 6, 24,
+    ];
+
+    let want = || PipeInfo {
+        name: "my_pipe".to_string(),
+        auto_ingest: true,
+        execution_paused: false,
+        comment: "load new files".to_string(),
+        copy_stmt: "COPY INTO t1 FROM @s1 FILE_FORMAT = (type = 'CSV')".to_string(),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), pipe_info_v78.as_slice(), 78, want())?;
+    Ok(())
+}
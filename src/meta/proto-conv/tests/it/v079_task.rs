@@ -0,0 +1,116 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v79_task() -> anyhow::Result<()> {
+    let task_v79 = vec![
+        8, 11, 18, 7, 109, 121, 95, 116, 97, 115, 107, 26, 23, 73, 78, 83, 69, 82, 84, 32, 73, 78,
+        84, 79, 32, 116, 49, 32, 83, 69, 76, 69, 67, 84, 32, 49, 34, 5, 49, 32, 61, 32, 49, 50, 10,
+        100, 97, 105, 108, 121, 32, 108, 111, 97, 100, 58, 13, 97, 99, 99, 111, 117, 110, 116, 95,
+        97, 100, 109, 105, 110, 66, 30, 18, 11, 48, 32, 48, 32, 57, 32, 42, 32, 42, 32, 42, 26, 13,
+        65, 115, 105, 97, 47, 83, 104, 97, 110, 103, 104, 97, 105, 32, 1, 74, 5, 10, 3, 119, 104,
+        49, 82, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32,
+        85, 84, 67, 88, 3, 98, 10, 110, 111, 116, 105, 102, 121, 95, 111, 112, 115, 104, 1, 114,
+        23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84,
+        67, 122, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57,
+        32, 85, 84, 67, 160, 6, 79, 168, 6, 24,
+    ];
+
+    common::test_pb_from_to(func_name!(), task())?;
+    common::test_load_old(func_name!(), task_v79.as_slice(), 79, task())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v79_task_run() -> anyhow::Result<()> {
+    let task_run_v79 = vec![
+        10, 206, 1, 8, 11, 18, 7, 109, 121, 95, 116, 97, 115, 107, 26, 23, 73, 78, 83, 69, 82, 84,
+        32, 73, 78, 84, 79, 32, 116, 49, 32, 83, 69, 76, 69, 67, 84, 32, 49, 34, 5, 49, 32, 61, 32,
+        49, 50, 10, 100, 97, 105, 108, 121, 32, 108, 111, 97, 100, 58, 13, 97, 99, 99, 111, 117,
+        110, 116, 95, 97, 100, 109, 105, 110, 66, 30, 18, 11, 48, 32, 48, 32, 57, 32, 42, 32, 42,
+        32, 42, 26, 13, 65, 115, 105, 97, 47, 83, 104, 97, 110, 103, 104, 97, 105, 32, 1, 74, 5,
+        10, 3, 119, 104, 49, 82, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48,
+        48, 58, 48, 57, 32, 85, 84, 67, 88, 3, 98, 10, 110, 111, 116, 105, 102, 121, 95, 111, 112,
+        115, 104, 1, 114, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58,
+        48, 57, 32, 85, 84, 67, 122, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58,
+        48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 79, 168, 6, 24, 18, 5, 114, 117, 110, 95, 49,
+        26, 7, 113, 117, 101, 114, 121, 95, 49, 32, 1, 40, 3, 50, 23, 50, 48, 49, 52, 45, 49, 49,
+        45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 58, 23, 50, 48, 49, 52, 45,
+        49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 64, 238, 7, 74, 15,
+        100, 105, 118, 105, 100, 101, 100, 32, 98, 121, 32, 122, 101, 114, 111, 80, 11, 160, 6, 79,
+        168, 6, 24,
+    ];
+
+    let want = || mt::TaskRun {
+        task: task(),
+        run_id: "run_1".to_string(),
+        query_id: "query_1".to_string(),
+        attempt_number: 1,
+        state: mt::TaskRunState::Failed,
+        scheduled_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        completed_at: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        error_code: 1006,
+        error_message: Some("divided by zero".to_string()),
+        root_task_id: 11,
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), task_run_v79.as_slice(), 79, want())?;
+    Ok(())
+}
+
+fn task() -> mt::Task {
+    mt::Task {
+        task_id: 11,
+        task_name: "my_task".to_string(),
+        query_text: "INSERT INTO t1 SELECT 1".to_string(),
+        when_condition: Some("1 = 1".to_string()),
+        after: vec![],
+        comment: Some("daily load".to_string()),
+        owner: "account_admin".to_string(),
+        schedule_options: Some(mt::ScheduleOptions {
+            interval: None,
+            cron: Some("0 0 9 * * *".to_string()),
+            time_zone: Some("Asia/Shanghai".to_string()),
+            schedule_type: mt::ScheduleType::CronType,
+        }),
+        warehouse_options: Some(mt::WarehouseOptions {
+            warehouse: Some("wh1".to_string()),
+            using_warehouse_size: None,
+        }),
+        next_scheduled_at: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        suspend_task_after_num_failures: Some(3),
+        error_integration: Some("notify_ops".to_string()),
+        status: mt::TaskStatus::Started,
+        created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        last_suspended_at: None,
+    }
+}
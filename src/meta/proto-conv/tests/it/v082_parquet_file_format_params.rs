@@ -0,0 +1,52 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::ParquetCompression;
+use databend_common_meta_app::principal::ParquetFileFormatParams;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v82_parquet_file_format_params() -> anyhow::Result<()> {
+    let file_format_params_v82 = vec![
+        10, 23, 10, 6, 83, 78, 65, 80, 80, 89, 16, 232, 7, 24, 1, 32, 1, 40, 1, 160, 6, 82, 168, 6,
+        24,
+    ];
+
+    let want = || {
+        mt::principal::FileFormatParams::Parquet(ParquetFileFormatParams {
+            missing_field_as: NullAs::Error,
+            compression: ParquetCompression::Snappy,
+            row_group_size: 1000,
+            enable_dictionary: true,
+            enable_page_index: true,
+            enable_bloom_filter: true,
+        })
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), file_format_params_v82.as_slice(), 0, want())?;
+    Ok(())
+}
@@ -309,6 +309,8 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    TimestampTz,
+    Interval,
     Binary,
     String,
     Array(Box<TypeName>),
@@ -322,6 +324,7 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Geometry,
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -847,6 +850,12 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::TimestampTz => {
+                write!(f, "TIMESTAMP_TZ")?;
+            }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::Binary => {
                 write!(f, "BINARY")?;
             }
@@ -893,6 +902,9 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -1551,6 +1551,26 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::AddPartition { partition, .. } => {
+                let partition = partition
+                    .iter()
+                    .map(|(key, value)| format!("{key} = {value}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let action_name = format!("Action AddPartition ({})", partition);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropPartition { partition, .. } => {
+                let partition = partition
+                    .iter()
+                    .map(|(key, value)| format!("{key} = {value}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let action_name = format!("Action DropPartition ({})", partition);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1685,6 +1705,38 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_materialized_view(&mut self, stmt: &'ast DropMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
         let mut children = Vec::new();
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
@@ -1779,6 +1831,56 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_refresh_vector_index(&mut self, stmt: &'ast RefreshVectorIndexStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let child = self.children.pop().unwrap();
+        let name = "RefreshVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        let mut children = vec![index_child, table_child];
+        for column in &stmt.columns {
+            self.visit_identifier(column);
+            children.push(self.children.pop().unwrap());
+        }
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_inverted_index(&mut self, stmt: &'ast RefreshInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "RefreshInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2923,6 +3025,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 let mut name = String::new();
                 name.push_str("TableIdentifier ");
@@ -2946,6 +3049,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     name.push_str(&unpivot.to_string());
                 }
 
+                if let Some(sample) = sample {
+                    name.push(' ');
+                    name.push_str(&sample.to_string());
+                }
+
                 let mut children = Vec::new();
                 if let Some(travel_point) = travel_point {
                     self.visit_time_travel_point(travel_point);
@@ -3132,6 +3240,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 children.push(condition_node);
             }
         }
+        if let Some(expr) = &join.match_condition {
+            self.visit_expr(expr);
+            let child = self.children.pop().unwrap();
+            let match_condition_name = "MatchCondition".to_string();
+            let match_condition_format_ctx =
+                AstFormatContext::with_children(match_condition_name, 1);
+            let match_condition_node =
+                FormatTreeNode::with_children(match_condition_format_ctx, vec![child]);
+            children.push(match_condition_node);
+        }
 
         let name = "Join".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
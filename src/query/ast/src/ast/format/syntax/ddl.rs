@@ -28,6 +28,8 @@ use crate::ast::CreateStreamStmt;
 use crate::ast::CreateTableSource;
 use crate::ast::CreateTableStmt;
 use crate::ast::CreateViewStmt;
+use crate::ast::Identifier;
+use crate::ast::Literal;
 use crate::ast::StreamPoint;
 use crate::ast::TimeTravelPoint;
 
@@ -227,9 +229,42 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::AddPartition {
+            if_not_exists,
+            partition,
+        } => RcDoc::line()
+            .append(RcDoc::text("ADD"))
+            .append(if if_not_exists {
+                RcDoc::space().append(RcDoc::text("IF NOT EXISTS"))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(" PARTITION "))
+            .append(parenthesized(pretty_partition_spec(partition))),
+        AlterTableAction::DropPartition {
+            if_exists,
+            partition,
+        } => RcDoc::line()
+            .append(RcDoc::text("DROP"))
+            .append(if if_exists {
+                RcDoc::space().append(RcDoc::text("IF EXISTS"))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(" PARTITION "))
+            .append(parenthesized(pretty_partition_spec(partition))),
     }
 }
 
+fn pretty_partition_spec(partition: Vec<(Identifier, Literal)>) -> RcDoc<'static> {
+    interweave_comma(
+        partition
+            .into_iter()
+            .map(|(key, value)| RcDoc::text(format!("{key} = {value}"))),
+    )
+    .group()
+}
+
 pub(crate) fn pretty_create_view(stmt: CreateViewStmt) -> RcDoc<'static> {
     RcDoc::text("CREATE VIEW")
         .append(if stmt.if_not_exists {
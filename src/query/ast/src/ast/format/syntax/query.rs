@@ -322,6 +322,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             travel_point,
             pivot,
             unpivot,
+            sample,
         } => if let Some(catalog) = catalog {
             RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
         } else {
@@ -354,6 +355,11 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::text(format!(" AS {alias}"))
         } else {
             RcDoc::nil()
+        })
+        .append(if let Some(sample) = sample {
+            RcDoc::text(format!(" {sample}"))
+        } else {
+            RcDoc::nil()
         }),
         TableReference::Subquery {
             span: _,
@@ -422,8 +428,17 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
                 JoinOperator::RightAnti => RcDoc::text("RIGHT ANTI JOIN"),
                 JoinOperator::LeftSemi => RcDoc::text("LEFT SEMI JOIN"),
                 JoinOperator::RightSemi => RcDoc::text("RIGHT SEMI JOIN"),
+                JoinOperator::Asof => RcDoc::text("ASOF JOIN"),
+                JoinOperator::LeftAsof => RcDoc::text("ASOF LEFT JOIN"),
             })
             .append(RcDoc::space().append(pretty_table(*join.right)))
+            .append(match &join.match_condition {
+                Some(expr) => RcDoc::space()
+                    .append(RcDoc::text("MATCH_CONDITION ("))
+                    .append(pretty_expr(*expr.clone()))
+                    .append(RcDoc::text(")")),
+                None => RcDoc::nil(),
+            })
             .append(match &join.condition {
                 JoinCondition::On(expr) => RcDoc::space()
                     .append(RcDoc::text("ON"))
@@ -259,6 +259,28 @@ pub struct Unpivot {
     pub names: Vec<Identifier>,
 }
 
+/// Granularity of a table sample
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleLevel {
+    Row,
+    Block,
+}
+
+/// Size of a table sample, either a percentage or a fixed number of rows
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampleConfig {
+    Probability(f64),
+    RowsNum(f64),
+}
+
+/// `SAMPLE [ROW | BLOCK] (<probability> | <num> ROWS) [SEED (<seed>)]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub sample_level: SampleLevel,
+    pub sample_conf: SampleConfig,
+    pub seed: Option<u64>,
+}
+
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq)]
 pub enum TableReference {
@@ -272,6 +294,7 @@ pub enum TableReference {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<Sample>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
@@ -336,6 +359,8 @@ pub struct TableAlias {
 pub struct Join {
     pub op: JoinOperator,
     pub condition: JoinCondition,
+    // `MATCH_CONDITION (expr)` of ASOF join
+    pub match_condition: Option<Box<Expr>>,
     pub left: Box<TableReference>,
     pub right: Box<TableReference>,
 }
@@ -353,6 +378,9 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // Asof joins must work with a match condition
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -444,6 +472,24 @@ impl Display for Unpivot {
     }
 }
 
+impl Display for Sample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SAMPLE")?;
+        match self.sample_level {
+            SampleLevel::Row => write!(f, " ROW")?,
+            SampleLevel::Block => write!(f, " BLOCK")?,
+        }
+        match self.sample_conf {
+            SampleConfig::Probability(probability) => write!(f, " ({probability})")?,
+            SampleConfig::RowsNum(rows) => write!(f, " ({rows} ROWS)")?,
+        }
+        if let Some(seed) = self.seed {
+            write!(f, " SEED ({seed})")?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for TableReference {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -456,6 +502,7 @@ impl Display for TableReference {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 write_dot_separated_list(
                     f,
@@ -480,6 +527,10 @@ impl Display for TableReference {
                 if let Some(unpivot) = unpivot {
                     write!(f, " {unpivot}")?;
                 }
+
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
             }
             TableReference::TableFunction {
                 span: _,
@@ -555,8 +606,17 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let Some(match_condition) = &join.match_condition {
+                    write!(f, " MATCH_CONDITION ({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
@@ -260,6 +260,7 @@ impl MergeSource {
                 travel_point: None,
                 pivot: None,
                 unpivot: None,
+                sample: None,
             },
         }
     }
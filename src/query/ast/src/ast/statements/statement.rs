@@ -141,6 +141,11 @@ pub enum Statement {
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
 
+    // Materialized views
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    DropMaterializedView(DropMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+
     // Streams
     CreateStream(CreateStreamStmt),
     DropStream(DropStreamStmt),
@@ -151,6 +156,10 @@ pub enum Statement {
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
+    RefreshVectorIndex(RefreshVectorIndexStmt),
+    CreateInvertedIndex(CreateInvertedIndexStmt),
+    DropInvertedIndex(DropInvertedIndexStmt),
+    RefreshInvertedIndex(RefreshInvertedIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -275,6 +284,11 @@ pub enum Statement {
     DescribePipe(DescribePipeStmt),
     DropPipe(DropPipeStmt),
     AlterPipe(AlterPipeStmt),
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -468,6 +482,9 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
             Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
             Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::ShowStreams(stmt) => write!(f, "{stmt}")?,
@@ -475,6 +492,10 @@ impl Display for Statement {
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshVectorIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -623,6 +644,9 @@ impl Display for Statement {
             Statement::DropConnection(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeConnection(stmt) => write!(f, "{stmt}")?,
             Statement::ShowConnections(stmt) => write!(f, "{stmt}")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ROLLBACK")?,
         }
         Ok(())
     }
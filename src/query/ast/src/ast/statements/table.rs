@@ -23,6 +23,7 @@ use crate::ast::write_comma_separated_map;
 use crate::ast::write_dot_separated_list;
 use crate::ast::Expr;
 use crate::ast::Identifier;
+use crate::ast::Literal;
 use crate::ast::Query;
 use crate::ast::TableReference;
 use crate::ast::TimeTravelPoint;
@@ -365,6 +366,14 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    AddPartition {
+        if_not_exists: bool,
+        partition: Vec<(Identifier, Literal)>,
+    },
+    DropPartition {
+        if_exists: bool,
+        partition: Vec<(Identifier, Literal)>,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -419,11 +428,48 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::AddPartition {
+                if_not_exists,
+                partition,
+            } => {
+                write!(f, "ADD")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(f, " PARTITION (")?;
+                write_partition_spec(f, partition)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropPartition {
+                if_exists,
+                partition,
+            } => {
+                write!(f, "DROP")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " PARTITION (")?;
+                write_partition_spec(f, partition)?;
+                write!(f, ")")?;
+            }
         };
         Ok(())
     }
 }
 
+fn write_partition_spec(
+    f: &mut Formatter,
+    partition: &[(Identifier, Literal)],
+) -> std::fmt::Result {
+    for (i, (column, value)) in partition.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{column} = {value}")?;
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AddColumnOption {
     End,
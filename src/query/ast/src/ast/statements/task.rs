@@ -24,6 +24,7 @@ pub struct CreateTaskStmt {
     pub warehouse_opts: WarehouseOptions,
     pub schedule_opts: Option<ScheduleOptions>,
     pub suspend_task_after_num_failures: Option<u64>,
+    pub error_integration: Option<String>,
     pub comments: String,
     pub after: Vec<String>,
     pub when_condition: Option<String>,
@@ -47,6 +48,10 @@ impl Display for CreateTaskStmt {
             write!(f, " SUSPEND TASK AFTER {} FAILURES", num)?;
         }
 
+        if let Some(error_integration) = &self.error_integration {
+            write!(f, " ERROR_INTEGRATION = '{}'", error_integration)?;
+        }
+
         if !self.comments.is_empty() {
             write!(f, " COMMENTS = '{}'", self.comments)?;
         }
@@ -116,6 +121,7 @@ pub enum AlterTaskOptions {
         warehouse: Option<String>,
         schedule: Option<ScheduleOptions>,
         suspend_task_after_num_failures: Option<u64>,
+        error_integration: Option<String>,
         comments: Option<String>,
     },
     Unset {
@@ -137,6 +143,7 @@ impl Display for AlterTaskOptions {
                 warehouse,
                 schedule,
                 suspend_task_after_num_failures,
+                error_integration,
                 comments,
             } => {
                 if let Some(wh) = warehouse {
@@ -148,6 +155,9 @@ impl Display for AlterTaskOptions {
                 if let Some(num) = suspend_task_after_num_failures {
                     write!(f, " SUSPEND TASK AFTER {} FAILURES", num)?;
                 }
+                if let Some(error_integration) = error_integration {
+                    write!(f, " ERROR_INTEGRATION = '{}'", error_integration)?;
+                }
                 if let Some(comments) = comments {
                     write!(f, " COMMENTS = '{}'", comments)?;
                 }
@@ -1166,6 +1166,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.0),
+                lit: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
+
     let is_distinct_from = map(
         rule! {
             IS ~ NOT? ~ DISTINCT ~ FROM
@@ -1203,6 +1216,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+            | #interval_expr: "`INTERVAL <str_literal>`"
             | #pg_cast : "`::<type_name>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK) FROM ...)`"
             | #date_part : "`DATE_PART((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK), ...)`"
@@ -1568,6 +1582,8 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         },
     );
     let ty_date = value(TypeName::Date, rule! { DATE });
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
+    let ty_timestamp_tz = value(TypeName::TimestampTz, rule! { TIMESTAMP_TZ | TIMESTAMPTZ });
     let ty_datetime = map(
         rule! { ( DATETIME | TIMESTAMP ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
         |(_, _)| TypeName::Timestamp,
@@ -1581,6 +1597,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     map_res(
         alt((
             rule! {
@@ -1606,9 +1623,12 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             rule! {
             ( #ty_date
             | #ty_datetime
+            | #ty_timestamp_tz
+            | #ty_interval
             | #ty_binary
             | #ty_string
             | #ty_variant
+            | #ty_geometry
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
@@ -537,6 +537,35 @@ pub fn alias_name(i: Input) -> IResult<Identifier> {
     )(i)
 }
 
+// `SAMPLE [ROW | BLOCK] (<probability> | <num> ROWS) [SEED (<seed>)]`
+pub fn table_sample(i: Input) -> IResult<Sample> {
+    let sample_level = alt((
+        value(SampleLevel::Row, rule! { ROW }),
+        value(SampleLevel::Block, rule! { BLOCK }),
+    ));
+    let sample_number = map_res(
+        rule! {
+            LiteralInteger | LiteralFloat
+        },
+        |token| fast_float::parse::<f64, _>(token.text()).map_err(|e| nom::Err::Failure(e.into())),
+    );
+    map(
+        rule! {
+            ( SAMPLE | TABLESAMPLE ) ~ #sample_level? ~ "(" ~ #sample_number ~ ROWS? ~ ")"
+            ~ ( SEED ~ ^"(" ~ ^#literal_u64 ~ ^")" )?
+        },
+        |(_, level, _, number, rows, _, seed)| Sample {
+            sample_level: level.unwrap_or(SampleLevel::Row),
+            sample_conf: if rows.is_some() {
+                SampleConfig::RowsNum(number)
+            } else {
+                SampleConfig::Probability(number)
+            },
+            seed: seed.map(|(_, _, seed, _)| seed),
+        },
+    )(i)
+}
+
 pub fn table_alias(i: Input) -> IResult<TableAlias> {
     map(
         rule! { #alias_name ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )? },
@@ -568,6 +597,8 @@ pub fn join_operator(i: Input) -> IResult<JoinOperator> {
         value(JoinOperator::RightOuter, rule! { RIGHT ~ OUTER? }),
         value(JoinOperator::FullOuter, rule! { FULL ~ OUTER? }),
         value(JoinOperator::CrossJoin, rule! { CROSS }),
+        value(JoinOperator::LeftAsof, rule! { ASOF ~ LEFT ~ OUTER? }),
+        value(JoinOperator::Asof, rule! { ASOF }),
     ))(i)
 }
 
@@ -629,6 +660,7 @@ pub enum TableReferenceElement {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<Sample>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
@@ -652,6 +684,8 @@ pub enum TableReferenceElement {
     },
     // ON expr | USING (ident, ...)
     JoinCondition(JoinCondition),
+    // MATCH_CONDITION (expr)
+    MatchCondition(Expr),
     Group(TableReference),
     Stage {
         location: FileLocation,
@@ -685,9 +719,17 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_table = map(
         rule! {
-            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)? ~ #table_alias? ~ #pivot? ~ #unpivot?
+            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)? ~ #table_sample? ~ #table_alias? ~ #table_sample? ~ #pivot? ~ #unpivot?
         },
-        |((catalog, database, table), travel_point_opt, alias, pivot, unpivot)| {
+        |(
+            (catalog, database, table),
+            travel_point_opt,
+            sample_before_alias,
+            alias,
+            sample_after_alias,
+            pivot,
+            unpivot,
+        )| {
             TableReferenceElement::Table {
                 catalog,
                 database,
@@ -696,6 +738,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 travel_point: travel_point_opt.map(|p| p.1),
                 pivot: pivot.map(Box::new),
                 unpivot: unpivot.map(Box::new),
+                sample: sample_before_alias.or(sample_after_alias),
             }
         },
     );
@@ -714,6 +757,12 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
         |(_, expr)| TableReferenceElement::JoinCondition(JoinCondition::On(Box::new(expr))),
     );
+    let match_condition = map(
+        rule! {
+            MATCH_CONDITION ~ "(" ~ #expr ~ ")"
+        },
+        |(_, _, expr, _)| TableReferenceElement::MatchCondition(expr),
+    );
     let join_condition_using = map(
         rule! {
             USING ~ "(" ~ #comma_separated_list1(ident) ~ ")"
@@ -773,6 +822,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         | #join
         | #join_condition_on
         | #join_condition_using
+        | #match_condition
     })(i)?;
     Ok((rest, WithSpan { span, elem }))
 }
@@ -790,6 +840,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
         let affix = match &input.elem {
             TableReferenceElement::Join { .. } => Affix::Infix(Precedence(10), Associativity::Left),
             TableReferenceElement::JoinCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::MatchCondition(..) => Affix::Postfix(Precedence(5)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -806,6 +857,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => TableReference::Table {
                 span: transform_span(input.span.0),
                 catalog,
@@ -815,6 +867,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             },
             TableReferenceElement::TableFunction {
                 lateral,
@@ -891,6 +944,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     join: Join {
                         op,
                         condition,
+                        match_condition: None,
                         left: Box::new(lhs),
                         right: Box::new(rhs),
                     },
@@ -929,6 +983,24 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 },
                 _ => Err("join condition must apply to a join"),
             },
+            TableReferenceElement::MatchCondition(expr) => match &mut lhs {
+                TableReference::Join {
+                    join:
+                        Join {
+                            op: JoinOperator::Asof | JoinOperator::LeftAsof,
+                            match_condition,
+                            ..
+                        },
+                    ..
+                } => match match_condition {
+                    None => {
+                        *match_condition = Some(Box::new(expr));
+                        Ok(lhs)
+                    }
+                    Some(_) => Err("match condition already set"),
+                },
+                _ => Err("match condition must apply to an asof join"),
+            },
             _ => unreachable!(),
         }
     }
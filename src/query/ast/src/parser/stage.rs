@@ -72,14 +72,14 @@ pub fn connection_options(i: Input) -> IResult<BTreeMap<String, String>> {
 pub fn format_options(i: Input) -> IResult<BTreeMap<String, String>> {
     let option_type = map(
         rule! {
-            TYPE ~ "=" ~ ( TSV | CSV | NDJSON | PARQUET | JSON | XML )
+            TYPE ~ "=" ~ ( TSV | CSV | NDJSON | PARQUET | ORC | AVRO | ARROW | JSON | XML )
         },
         |(_, _, v)| ("type".to_string(), v.text().to_string()),
     );
 
     let option_compression = map(
         rule! {
-            COMPRESSION ~ "=" ~ ( AUTO | NONE | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAWDEFLATE | XZ )
+            COMPRESSION ~ "=" ~ ( AUTO | NONE | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAWDEFLATE | XZ | SNAPPY | LZ4 )
         },
         |(_, _, v)| ("COMPRESSION".to_string(), v.text().to_string()),
     );
@@ -109,14 +109,18 @@ pub fn format_options(i: Input) -> IResult<BTreeMap<String, String>> {
 
     let int_options = map(
         rule! {
-            SKIP_HEADER ~ ^"=" ~ ^#literal_u64
+            (SKIP_HEADER | ROW_GROUP_SIZE) ~ ^"=" ~ ^#literal_u64
         },
         |(k, _, v)| (k.text().to_string(), v.to_string()),
     );
 
     let bool_options = map(
         rule! {
-            (ERROR_ON_COLUMN_COUNT_MISMATCH | OUTPUT_HEADER) ~ ^"=" ~ ^#literal_bool
+            (ERROR_ON_COLUMN_COUNT_MISMATCH
+                | OUTPUT_HEADER
+                | ENABLE_DICTIONARY
+                | ENABLE_PAGE_INDEX
+                | ENABLE_BLOOM_FILTER) ~ ^"=" ~ ^#literal_bool
         },
         |(k, _, v)| (k.text().to_string(), v.to_string()),
     );
@@ -112,6 +112,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             ~ (AFTER ~ #comma_separated_list0(literal_string))?
             ~ (WHEN ~ #expr )?
             ~ (SUSPEND_TASK_AFTER_NUM_FAILURES ~ "=" ~ #literal_u64)?
+            ~ (ERROR_INTEGRATION ~ "=" ~ #literal_string)?
             ~ ( (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string )?
             ~ AS ~ #statement
         },
@@ -125,6 +126,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             after_tasks,
             when_conditions,
             suspend_opt,
+            error_integration,
             comment_opt,
             _,
             sql,
@@ -137,6 +139,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 warehouse_opts,
                 schedule_opts: schedule_opts.map(|(_, _, opt)| opt),
                 suspend_task_after_num_failures: suspend_opt.map(|(_, _, num)| num),
+                error_integration: error_integration.map(|(_, _, name)| name),
                 comments: comment_opt.map(|v| v.2).unwrap_or_default(),
                 after: match after_tasks {
                     Some((_, tasks)) => tasks,
@@ -921,6 +924,48 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            ALTER ~ MATERIALIZED ~ VIEW ~ #dot_separated_idents_1_to_3 ~ REFRESH
+        },
+        |(_, _, _, (catalog, database, view), _)| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+
     let create_index = map(
         rule! {
             CREATE ~ ASYNC? ~ AGGREGATING ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -1006,6 +1051,80 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let refresh_vector_index = map(
+        rule! {
+            REFRESH ~ VECTOR ~ INDEX ~ FOR ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, _, (catalog, database, table))| {
+            Statement::RefreshVectorIndex(RefreshVectorIndexStmt {
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+            ~ #table_option
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+            index_options,
+        )| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+                index_options,
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
+    let refresh_inverted_index = map(
+        rule! {
+            REFRESH ~ INVERTED ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, index_name, _, (catalog, database, table))| {
+            Statement::RefreshInvertedIndex(RefreshInvertedIndexStmt {
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let refresh_virtual_column = map(
         rule! {
             REFRESH ~ VIRTUAL ~ COLUMN ~ FOR ~ #dot_separated_idents_1_to_3
@@ -1760,6 +1879,13 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let begin = value(
+        Statement::Begin,
+        rule! { ( BEGIN ~ TRANSACTION? ) | ( START ~ TRANSACTION ) },
+    );
+    let commit = value(Statement::Commit, rule! { COMMIT ~ WORK? });
+    let abort = value(Statement::Abort, rule! { ( ROLLBACK | ABORT ) ~ WORK? });
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1833,11 +1959,18 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #stream_table
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+            | #refresh_materialized_view : "`ALTER MATERIALIZED VIEW [<database>.]<view> REFRESH`"
         ),
         rule!(
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #refresh_vector_index: "`REFRESH VECTOR INDEX FOR [<database>.]<table>`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...) [tokenizer = '<tokenizer>']`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1921,6 +2054,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
   [ AFTER <string>, <string>...]
   [ WHEN boolean_expr ]
   [ SUSPEND_TASK_AFTER_NUM_FAILURES = <num> ]
+  [ ERROR_INTEGRATION = '<string_literal>' ]
   [ COMMENT = '<string_literal>' ]
 AS
   <sql>`"
@@ -1946,6 +2080,9 @@ AS
         | #drop_connection: "`DROP CONNECTION [IF EXISTS] <connection_name>`"
         | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
         | #show_connections: "`SHOW CONNECTIONS`"
+        | #begin : "`BEGIN [TRANSACTION]`"
+        | #commit : "`COMMIT [WORK]`"
+        | #abort : "`{ROLLBACK | ABORT} [WORK]`"
         ),
     ));
 
@@ -2722,6 +2859,26 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let add_partition = map(
+        rule! {
+            ADD ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ PARTITION ~ ^"(" ~ ^#comma_separated_list1(partition_value) ~ ^")"
+        },
+        |(_, opt_if_not_exists, _, _, partition, _)| AlterTableAction::AddPartition {
+            if_not_exists: opt_if_not_exists.is_some(),
+            partition,
+        },
+    );
+
+    let drop_partition = map(
+        rule! {
+            DROP ~ ( IF ~ ^EXISTS )? ~ PARTITION ~ ^"(" ~ ^#comma_separated_list1(partition_value) ~ ^")"
+        },
+        |(_, opt_if_exists, _, _, partition, _)| AlterTableAction::DropPartition {
+            if_exists: opt_if_exists.is_some(),
+            partition,
+        },
+    );
+
     rule!(
         #rename_table
         | #rename_column
@@ -2733,9 +2890,18 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #add_partition
+        | #drop_partition
     )(i)
 }
 
+/// A value of partition column, such as `region = 'asia'`.
+pub fn partition_value(i: Input) -> IResult<(Identifier, Literal)> {
+    map(rule! { #ident ~ "=" ~ #literal }, |(column, _, value)| {
+        (column, value)
+    })(i)
+}
+
 pub fn match_clause(i: Input) -> IResult<MergeOption> {
     map(
         rule! {
@@ -2943,13 +3109,17 @@ pub fn alter_task_option(i: Input) -> IResult<AlterTaskOptions> {
              ~ ( WAREHOUSE  ~ "=" ~  #literal_string )?
              ~ ( SCHEDULE ~ "=" ~ #task_schedule_option )?
              ~ ( SUSPEND_TASK_AFTER_NUM_FAILURES ~ "=" ~ #literal_u64 )?
+             ~ ( ERROR_INTEGRATION ~ "=" ~ #literal_string )?
              ~ ( COMMENT ~ "=" ~ #literal_string )?
         },
-        |(_, warehouse_opts, schedule_opts, suspend_opts, comment)| AlterTaskOptions::Set {
-            warehouse: warehouse_opts.map(|(_, _, warehouse)| warehouse),
-            schedule: schedule_opts.map(|(_, _, schedule)| schedule),
-            suspend_task_after_num_failures: suspend_opts.map(|(_, _, num)| num),
-            comments: comment.map(|(_, _, comment)| comment),
+        |(_, warehouse_opts, schedule_opts, suspend_opts, error_integration, comment)| {
+            AlterTaskOptions::Set {
+                warehouse: warehouse_opts.map(|(_, _, warehouse)| warehouse),
+                schedule: schedule_opts.map(|(_, _, schedule)| schedule),
+                suspend_task_after_num_failures: suspend_opts.map(|(_, _, num)| num),
+                error_integration: error_integration.map(|(_, _, name)| name),
+                comments: comment.map(|(_, _, comment)| comment),
+            }
         },
     );
     let unset = map(
@@ -3019,7 +3189,7 @@ pub fn task_schedule_option(i: Input) -> IResult<ScheduleOptions> {
         rule! {
              #literal_u64 ~ MINUTE
         },
-        |(mins, _)| ScheduleOptions::IntervalSecs(mins * 60),
+        |(mins, _)| ScheduleOptions::IntervalSecs(mins.saturating_mul(60)),
     );
     let cron_expr = map(
         rule! {
@@ -3296,6 +3466,7 @@ pub fn table_reference_with_alias(i: Input) -> IResult<TableReference> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
@@ -3314,6 +3485,7 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
@@ -312,6 +312,8 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserved keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ABORT", ignore(ascii_case))]
+    ABORT,
     #[token("ALL", ignore(ascii_case))]
     ALL,
     #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
@@ -342,6 +344,8 @@ pub enum TokenKind {
     AND,
     #[token("ARRAY", ignore(ascii_case))]
     ARRAY,
+    #[token("ARROW", ignore(ascii_case))]
+    ARROW,
     #[token("AS", ignore(ascii_case))]
     AS,
     #[token("AST", ignore(ascii_case))]
@@ -350,14 +354,20 @@ pub enum TokenKind {
     AT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
     #[token("ASYNC", ignore(ascii_case))]
     ASYNC,
     #[token("ATTACH", ignore(ascii_case))]
     ATTACH,
+    #[token("AVRO", ignore(ascii_case))]
+    AVRO,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -376,6 +386,8 @@ pub enum TokenKind {
     BINARY_FORMAT,
     #[token("BITMAP", ignore(ascii_case))]
     BITMAP,
+    #[token("BLOCK", ignore(ascii_case))]
+    BLOCK,
     #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
     BLOCKED_IP_LIST,
     #[token("BOOL", ignore(ascii_case))]
@@ -408,6 +420,8 @@ pub enum TokenKind {
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
@@ -514,6 +528,12 @@ pub enum TokenKind {
     ELSE,
     #[token("EMPTY_FIELD_AS", ignore(ascii_case))]
     EMPTY_FIELD_AS,
+    #[token("ENABLE_BLOOM_FILTER", ignore(ascii_case))]
+    ENABLE_BLOOM_FILTER,
+    #[token("ENABLE_DICTIONARY", ignore(ascii_case))]
+    ENABLE_DICTIONARY,
+    #[token("ENABLE_PAGE_INDEX", ignore(ascii_case))]
+    ENABLE_PAGE_INDEX,
     #[token("ENABLE_VIRTUAL_HOST_STYLE", ignore(ascii_case))]
     ENABLE_VIRTUAL_HOST_STYLE,
     #[token("END", ignore(ascii_case))]
@@ -526,6 +546,8 @@ pub enum TokenKind {
     ENGINES,
     #[token("EPOCH", ignore(ascii_case))]
     EPOCH,
+    #[token("ERROR_INTEGRATION", ignore(ascii_case))]
+    ERROR_INTEGRATION,
     #[token("ERROR_ON_COLUMN_COUNT_MISMATCH", ignore(ascii_case))]
     ERROR_ON_COLUMN_COUNT_MISMATCH,
     #[token("ESCAPE", ignore(ascii_case))]
@@ -594,6 +616,8 @@ pub enum TokenKind {
     FUSE,
     #[token("GENERATED", ignore(ascii_case))]
     GENERATED,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
@@ -644,6 +668,8 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -687,6 +713,8 @@ pub enum TokenKind {
     LIMIT,
     #[token("LIST", ignore(ascii_case))]
     LIST,
+    #[token("LZ4", ignore(ascii_case))]
+    LZ4,
     #[token("LZO", ignore(ascii_case))]
     LZO,
     #[token("MASKING", ignore(ascii_case))]
@@ -751,6 +779,8 @@ pub enum TokenKind {
     OPTIONS,
     #[token("OR", ignore(ascii_case))]
     OR,
+    #[token("ORC", ignore(ascii_case))]
+    ORC,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
@@ -839,6 +869,8 @@ pub enum TokenKind {
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
+    #[token("MATCH_CONDITION", ignore(ascii_case))]
+    MATCH_CONDITION,
     #[token("MISSING_FIELD_AS", ignore(ascii_case))]
     MISSING_FIELD_AS,
     #[token("NULL_FIELD_AS", ignore(ascii_case))]
@@ -849,6 +881,8 @@ pub enum TokenKind {
     ROW,
     #[token("ROWS", ignore(ascii_case))]
     ROWS,
+    #[token("ROW_GROUP_SIZE", ignore(ascii_case))]
+    ROW_GROUP_SIZE,
     #[token("ROW_TAG", ignore(ascii_case))]
     ROW_TAG,
     #[token("GRANT", ignore(ascii_case))]
@@ -885,10 +919,14 @@ pub enum TokenKind {
     RIGHT,
     #[token("RLIKE", ignore(ascii_case))]
     RLIKE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("RAW", ignore(ascii_case))]
     RAW,
     #[token("OPTIMIZED", ignore(ascii_case))]
     OPTIMIZED,
+    #[token("SAMPLE", ignore(ascii_case))]
+    SAMPLE,
     #[token("SCHEMA", ignore(ascii_case))]
     SCHEMA,
     #[token("SCHEMAS", ignore(ascii_case))]
@@ -901,6 +939,8 @@ pub enum TokenKind {
     PIVOT,
     #[token("UNPIVOT", ignore(ascii_case))]
     UNPIVOT,
+    #[token("SEED", ignore(ascii_case))]
+    SEED,
     #[token("SEGMENT", ignore(ascii_case))]
     SEGMENT,
     #[token("SET", ignore(ascii_case))]
@@ -911,6 +951,8 @@ pub enum TokenKind {
     SETTINGS,
     #[token("STAGES", ignore(ascii_case))]
     STAGES,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
     #[token("SHA256_PASSWORD", ignore(ascii_case))]
@@ -977,6 +1019,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TABLESAMPLE", ignore(ascii_case))]
+    TABLESAMPLE,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("LONGTEXT", ignore(ascii_case))]
@@ -995,6 +1039,10 @@ pub enum TokenKind {
     THEN,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
+    #[token("TIMESTAMP_TZ", ignore(ascii_case))]
+    TIMESTAMP_TZ,
+    #[token("TIMESTAMPTZ", ignore(ascii_case))]
+    TIMESTAMPTZ,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
     TIMEZONE_HOUR,
     #[token("TIMEZONE_MINUTE", ignore(ascii_case))]
@@ -1009,6 +1057,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -1063,6 +1113,8 @@ pub enum TokenKind {
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
@@ -1075,6 +1127,8 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WORK", ignore(ascii_case))]
+    WORK,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1362,6 +1416,7 @@ impl TokenKind {
             | TokenKind::LIMIT
             | TokenKind::OFFSET
             | TokenKind::ON
+            | TokenKind::MATCH_CONDITION
             | TokenKind::OF
             | TokenKind::ORDER
             | TokenKind::OVER
@@ -1391,6 +1446,7 @@ impl TokenKind {
             | TokenKind::FUNCTION
             | TokenKind::ASC
             | TokenKind::ANTI
+            | TokenKind::ASOF
             // | TokenKind::ASYMMETRIC
             // | TokenKind::AUTHORIZATION
             // | TokenKind::BINARY
@@ -1454,7 +1510,7 @@ impl TokenKind {
             | TokenKind::SEMI
             // | TokenKind::SYMMETRIC
             // | TokenKind::TABLE
-            // | TokenKind::TABLESAMPLE
+            | TokenKind::TABLESAMPLE
             | TokenKind::THEN
             | TokenKind::TRAILING
             | TokenKind::TRUE
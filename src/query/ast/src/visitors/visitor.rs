@@ -560,6 +560,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &'ast DropMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
     fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
 
     fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
@@ -573,6 +579,11 @@ pub trait Visitor<'ast>: Sized {
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
 
+    fn visit_refresh_vector_index(&mut self, _stmt: &'ast RefreshVectorIndexStmt) {}
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
+    fn visit_refresh_inverted_index(&mut self, _stmt: &'ast RefreshInvertedIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &'ast AlterVirtualColumnStmt) {}
@@ -796,6 +807,7 @@ pub trait Visitor<'ast>: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
@@ -803,6 +815,9 @@ pub trait Visitor<'ast>: Sized {
         walk_table_reference(self, right);
 
         walk_join_condition(self, condition);
+        if let Some(match_condition) = match_condition {
+            self.visit_expr(match_condition);
+        }
     }
     fn visit_window_definition(&mut self, window_definition: &'ast WindowDefinition) {
         walk_window_definition(self, window_definition);
@@ -812,4 +827,15 @@ pub trait Visitor<'ast>: Sized {
     fn visit_drop_connection(&mut self, _stmt: &'ast DropConnectionStmt) {}
     fn visit_describe_connection(&mut self, _stmt: &'ast DescribeConnectionStmt) {}
     fn visit_show_connections(&mut self, _stmt: &'ast ShowConnectionsStmt) {}
+
+    fn visit_create_pipe(&mut self, _stmt: &'ast CreatePipeStmt) {}
+    fn visit_drop_pipe(&mut self, _stmt: &'ast DropPipeStmt) {}
+    fn visit_describe_pipe(&mut self, _stmt: &'ast DescribePipeStmt) {}
+    fn visit_alter_pipe(&mut self, _stmt: &'ast AlterPipeStmt) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
 }
@@ -574,6 +574,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &mut DropMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
     fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
 
     fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
@@ -587,6 +593,11 @@ pub trait VisitorMut: Sized {
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
 
+    fn visit_refresh_vector_index(&mut self, _stmt: &mut RefreshVectorIndexStmt) {}
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+    fn visit_refresh_inverted_index(&mut self, _stmt: &mut RefreshInvertedIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &mut AlterVirtualColumnStmt) {}
@@ -810,6 +821,7 @@ pub trait VisitorMut: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
@@ -817,10 +829,24 @@ pub trait VisitorMut: Sized {
         self.visit_table_reference(right);
 
         walk_join_condition_mut(self, condition);
+        if let Some(match_condition) = match_condition {
+            self.visit_expr(match_condition);
+        }
     }
 
     fn visit_create_connection(&mut self, _stmt: &mut CreateConnectionStmt) {}
     fn visit_drop_connection(&mut self, _stmt: &mut DropConnectionStmt) {}
     fn visit_describe_connection(&mut self, _stmt: &mut DescribeConnectionStmt) {}
     fn visit_show_connections(&mut self, _stmt: &mut ShowConnectionsStmt) {}
+
+    fn visit_create_pipe(&mut self, _stmt: &mut CreatePipeStmt) {}
+    fn visit_drop_pipe(&mut self, _stmt: &mut DropPipeStmt) {}
+    fn visit_describe_pipe(&mut self, _stmt: &mut DescribePipeStmt) {}
+    fn visit_alter_pipe(&mut self, _stmt: &mut AlterPipeStmt) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
 }
@@ -430,6 +430,9 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateView(stmt) => visitor.visit_create_view(stmt),
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
+        Statement::CreateMaterializedView(stmt) => visitor.visit_create_materialized_view(stmt),
+        Statement::DropMaterializedView(stmt) => visitor.visit_drop_materialized_view(stmt),
+        Statement::RefreshMaterializedView(stmt) => visitor.visit_refresh_materialized_view(stmt),
         Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
         Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
         Statement::ShowStreams(stmt) => visitor.visit_show_streams(stmt),
@@ -437,6 +440,10 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateIndex(stmt) => visitor.visit_create_index(stmt),
         Statement::DropIndex(stmt) => visitor.visit_drop_index(stmt),
         Statement::RefreshIndex(stmt) => visitor.visit_refresh_index(stmt),
+        Statement::RefreshVectorIndex(stmt) => visitor.visit_refresh_vector_index(stmt),
+        Statement::CreateInvertedIndex(stmt) => visitor.visit_create_inverted_index(stmt),
+        Statement::DropInvertedIndex(stmt) => visitor.visit_drop_inverted_index(stmt),
+        Statement::RefreshInvertedIndex(stmt) => visitor.visit_refresh_inverted_index(stmt),
         Statement::CreateVirtualColumn(stmt) => visitor.visit_create_virtual_column(stmt),
         Statement::AlterVirtualColumn(stmt) => visitor.visit_alter_virtual_column(stmt),
         Statement::DropVirtualColumn(stmt) => visitor.visit_drop_virtual_column(stmt),
@@ -529,9 +536,12 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::DescribeConnection(stmt) => visitor.visit_describe_connection(stmt),
         Statement::ShowConnections(stmt) => visitor.visit_show_connections(stmt),
 
-        Statement::CreatePipe(_) => todo!(),
-        Statement::AlterPipe(_) => todo!(),
-        Statement::DropPipe(_) => todo!(),
-        Statement::DescribePipe(_) => todo!(),
+        Statement::CreatePipe(stmt) => visitor.visit_create_pipe(stmt),
+        Statement::AlterPipe(stmt) => visitor.visit_alter_pipe(stmt),
+        Statement::DropPipe(stmt) => visitor.visit_drop_pipe(stmt),
+        Statement::DescribePipe(stmt) => visitor.visit_describe_pipe(stmt),
+        Statement::Begin => visitor.visit_begin(),
+        Statement::Commit => visitor.visit_commit(),
+        Statement::Abort => visitor.visit_abort(),
     }
 }
@@ -130,6 +130,9 @@ fn test_statement() {
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create materialized view mv as select * from t;"#,
+        r#"drop materialized view if exists db.mv;"#,
+        r#"alter materialized view mv refresh;"#,
         r#"create stream test2.s1 on table test.t append_only = false;"#,
         r#"create stream if not exists test2.s2 on table test.t at (stream => test1.s1) comment = 'this is a stream';"#,
         r#"show full streams from default.test2 like 's%';"#,
@@ -222,6 +225,8 @@ fn test_statement() {
         r#"ALTER TABLE t MODIFY COLUMN a int;"#,
         r#"ALTER TABLE t MODIFY COLUMN a DROP STORED;"#,
         r#"ALTER TABLE t SET OPTIONS(SNAPSHOT_LOCATION='1/7/_ss/101fd790dbbe4238a31a8f2e2f856179_v4.mpk',block_per_segment = 500);"#,
+        r#"ALTER TABLE t ADD IF NOT EXISTS PARTITION (region = 'asia', dt = 1);"#,
+        r#"ALTER TABLE t DROP PARTITION (region = 'asia');"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
@@ -503,6 +508,10 @@ fn test_statement() {
         r#"ALTER VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMN FOR t"#,
         r#"REFRESH VIRTUAL COLUMN FOR t"#,
+        r#"REFRESH VECTOR INDEX FOR db.t"#,
+        r#"CREATE INVERTED INDEX IF NOT EXISTS idx ON db.t (title, body) tokenizer = 'english'"#,
+        r#"DROP INVERTED INDEX IF EXISTS idx ON t"#,
+        r#"REFRESH INVERTED INDEX idx ON db.t"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
         // tasks
@@ -513,6 +522,7 @@ fn test_statement() {
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 12 * * *' AS copy into streams_test.paper_table from @stream_stage FILE_FORMAT = (TYPE = PARQUET) PURGE=true"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 13 * * *' AS COPY INTO @my_internal_stage FROM canadian_city_population FILE_FORMAT = (TYPE = PARQUET)"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 AFTER 'task2', 'task3' WHEN SYSTEM$GET_PREDECESSOR_RETURN_VALUE('task_name') != 'VALIDATION' AS VACUUM TABLE t"#,
+        r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = 30 SECOND SUSPEND_TASK_AFTER_NUM_FAILURES = 3 ERROR_INTEGRATION = 'notify_ops' AS SELECT 1"#,
         r#"ALTER TASK MyTask1 RESUME"#,
         r#"ALTER TASK MyTask1 SUSPEND"#,
         r#"ALTER TASK MyTask1 ADD AFTER 'task2', 'task3'"#,
@@ -548,6 +558,11 @@ fn test_statement() {
         "GRANT OWNERSHIP ON d20_0014.t TO ROLE 'd20_0015_owner';",
         "GRANT OWNERSHIP ON STAGE s1 TO ROLE 'd20_0015_owner';",
         "GRANT OWNERSHIP ON UDF f1 TO ROLE 'd20_0015_owner';",
+        "BEGIN",
+        "START TRANSACTION",
+        "COMMIT WORK",
+        "ROLLBACK",
+        "ABORT",
     ];
 
     for case in cases {
@@ -785,6 +800,10 @@ fn test_expr() {
         r#"CAST(col1 AS BIGINT UNSIGNED)"#,
         r#"TRY_CAST(col1 AS BIGINT UNSIGNED)"#,
         r#"TRY_CAST(col1 AS TUPLE(BIGINT UNSIGNED NULL, BOOLEAN))"#,
+        r#"INTERVAL '1 day 3 hours'"#,
+        r#"CAST(col1 AS INTERVAL)"#,
+        r#"CAST(col1 AS TIMESTAMP_TZ)"#,
+        r#"CAST(col1 AS GEOMETRY)"#,
         r#"trim(leading 'abc' from 'def')"#,
         r#"extract(year from d)"#,
         r#"date_part(year, d)"#,
@@ -25,6 +25,7 @@ pub mod statistics;
 pub mod table_args;
 pub mod table_context;
 pub mod table_function;
+pub mod txn;
 
 pub mod merge_into_join;
 pub mod runtime_filter_info;
@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::F64;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
@@ -96,6 +97,20 @@ pub struct PushDownInfo {
     pub agg_index: Option<AggIndexInfo>,
     /// Identifies the type of data change we are looking for
     pub change_type: Option<ChangeType>,
+    /// Optional table sample, only a part of the data will be read.
+    pub sample: Option<SampleDesc>,
+}
+
+/// Table sample push down information.
+///
+/// Both probabilities are in range `[0, 1]`, blocks are skipped before reading
+/// by `block_probability`, and the rows of the read blocks are filtered by `row_probability`.
+/// The same `seed` always selects the same blocks and rows of a snapshot.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SampleDesc {
+    pub block_probability: Option<F64>,
+    pub row_probability: Option<F64>,
+    pub seed: u64,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
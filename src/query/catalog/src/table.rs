@@ -378,6 +378,40 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Add a partition of the given values of partition columns.
+    #[async_backtrace::framed]
+    async fn add_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition: Vec<(String, String)>,
+        if_not_exists: bool,
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, partition, if_not_exists);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "The 'add_partition' operation is not supported for the table '{}'. Table engine: '{}'.",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    /// Drop the partition of the given values of partition columns.
+    #[async_backtrace::framed]
+    async fn drop_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition: Vec<(String, String)>,
+        if_exists: bool,
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, partition, if_exists);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "The 'drop_partition' operation is not supported for the table '{}'. Table engine: '{}'.",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     fn is_stage_table(&self) -> bool {
         false
     }
@@ -403,6 +437,11 @@ pub trait TableExt: Table {
         let name = table_info.name.clone();
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        // The table may be mutated by the previous statements of the explicit transaction.
+        let buffered_table_info = ctx.txn_mgr().lock().get_table_from_buffer(tid);
+        if let Some(table_info) = buffered_table_info {
+            return catalog.get_table_by_info(&table_info);
+        }
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
         let table_info = TableInfo {
             ident,
@@ -60,6 +60,7 @@ use crate::query_kind::QueryKind;
 use crate::runtime_filter_info::RuntimeFilterInfo;
 use crate::statistics::data_cache_statistics::DataCacheMetrics;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 pub type MaterializedCtesBlocks = Arc<RwLock<HashMap<(usize, usize), Arc<RwLock<Vec<DataBlock>>>>>>;
 
@@ -257,4 +258,7 @@ pub trait TableContext: Send + Sync {
     fn get_min_max_runtime_filter_with_id(&self, id: usize) -> Vec<Expr<String>>;
 
     fn has_bloom_runtime_filters(&self, id: usize) -> bool;
+
+    /// Get the transaction manager of the session.
+    fn txn_mgr(&self) -> TxnManagerRef;
 }
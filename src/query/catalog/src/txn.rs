@@ -0,0 +1,200 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TxnState {
+    /// Every statement commits its own changes.
+    #[default]
+    AutoCommit,
+    /// In an explicit transaction, the changes are buffered until `COMMIT`.
+    Active,
+    /// A statement of the explicit transaction failed, the transaction can only be rolled back.
+    Fail,
+}
+
+/// The table meta updates of an explicit transaction, which are buffered in the session
+/// and committed to the meta service in one `UpdateMultiTableMetaReq`.
+#[derive(Debug, Default)]
+pub struct TxnManager {
+    state: TxnState,
+    catalog: Option<String>,
+    // table id -> (request, table info with the buffered meta)
+    mutated_tables: BTreeMap<u64, (UpdateTableMetaReq, TableInfo)>,
+    // table id -> table info, the copied files of the tables are purged after the commit
+    truncated_tables: BTreeMap<u64, TableInfo>,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager::default()))
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    /// Begin an explicit transaction, `BEGIN` in a transaction is ignored.
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    /// Discard the buffered changes and go back to the auto commit mode.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.catalog = None;
+        self.mutated_tables.clear();
+        self.truncated_tables.clear();
+    }
+
+    /// Buffer the table meta update of a statement.
+    ///
+    /// The request of a table mutated by several statements keeps the version of the
+    /// table seen by the first statement, so the commit fails if the table is changed
+    /// by others during the transaction.
+    pub fn update_table_meta(
+        &mut self,
+        catalog: &str,
+        req: UpdateTableMetaReq,
+        table_info: &TableInfo,
+    ) -> Result<()> {
+        match &self.catalog {
+            Some(txn_catalog) if txn_catalog != catalog => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Transaction across catalogs is not supported, the transaction has mutated tables in catalog '{}'",
+                    txn_catalog
+                )));
+            }
+            Some(_) => (),
+            None => self.catalog = Some(catalog.to_string()),
+        }
+
+        if req.copied_files.is_some() && self.truncated_tables.contains_key(&req.table_id) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Table {} is truncated in the transaction, copying files into it in the same transaction is not supported",
+                table_info.name
+            )));
+        }
+
+        match self.mutated_tables.entry(req.table_id) {
+            Entry::Occupied(mut entry) => {
+                let (buffered_req, buffered_info) = entry.get_mut();
+                buffered_info.meta = req.new_table_meta.clone();
+                buffered_req.new_table_meta = req.new_table_meta;
+                match (&mut buffered_req.copied_files, req.copied_files) {
+                    (Some(buffered), Some(copied_files)) => {
+                        buffered.file_info.extend(copied_files.file_info);
+                        buffered.expire_at = copied_files.expire_at;
+                        buffered.fail_if_duplicated |= copied_files.fail_if_duplicated;
+                    }
+                    (buffered, copied_files) => {
+                        if copied_files.is_some() {
+                            *buffered = copied_files;
+                        }
+                    }
+                }
+                for stream_req in req.update_stream_meta {
+                    match buffered_req
+                        .update_stream_meta
+                        .iter_mut()
+                        .find(|r| r.stream_id == stream_req.stream_id)
+                    {
+                        Some(buffered) => buffered.options = stream_req.options,
+                        None => buffered_req.update_stream_meta.push(stream_req),
+                    }
+                }
+                if req.deduplicated_label.is_some() {
+                    buffered_req.deduplicated_label = req.deduplicated_label;
+                }
+            }
+            Entry::Vacant(entry) => {
+                let mut table_info = table_info.clone();
+                table_info.meta = req.new_table_meta.clone();
+                entry.insert((req, table_info));
+            }
+        }
+        Ok(())
+    }
+
+    /// Buffer the truncation of a table, its copied files are purged after the commit.
+    ///
+    /// The files copied into the table earlier in the transaction are not recorded,
+    /// as their data is truncated.
+    pub fn truncate_table(
+        &mut self,
+        catalog: &str,
+        req: UpdateTableMetaReq,
+        table_info: &TableInfo,
+    ) -> Result<()> {
+        self.update_table_meta(catalog, req, table_info)?;
+        if let Some((buffered_req, _)) = self.mutated_tables.get_mut(&table_info.ident.table_id) {
+            buffered_req.copied_files = None;
+        }
+        self.truncated_tables
+            .insert(table_info.ident.table_id, table_info.clone());
+        Ok(())
+    }
+
+    /// The tables truncated by the transaction.
+    pub fn truncated_tables(&self) -> Vec<TableInfo> {
+        self.truncated_tables.values().cloned().collect()
+    }
+
+    /// Get the table info with the meta buffered by the transaction, so that
+    /// the statements of the transaction see the changes of the previous ones.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.mutated_tables
+            .get(&table_id)
+            .map(|(_, table_info)| table_info.clone())
+    }
+
+    /// The catalog and the request to commit the transaction, `None` if nothing is mutated.
+    pub fn commit_req(&self) -> Option<(String, UpdateMultiTableMetaReq)> {
+        let catalog = self.catalog.clone()?;
+        let update_table_metas = self
+            .mutated_tables
+            .values()
+            .map(|(req, _)| req.clone())
+            .collect();
+        Some((catalog, UpdateMultiTableMetaReq { update_table_metas }))
+    }
+}
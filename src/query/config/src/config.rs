@@ -1333,6 +1333,19 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub mysql_tls_server_key: String,
 
+    /// Serve the PostgreSQL wire protocol, disabled by default.
+    #[clap(long, value_name = "VALUE", default_value = "false")]
+    pub postgres_handler_enabled: bool,
+
+    #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, value_name = "VALUE", default_value = "15432")]
+    pub postgres_handler_port: u16,
+
+    #[clap(long, value_name = "VALUE", default_value = "120")]
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "256")]
     pub max_active_sessions: u64,
 
@@ -1583,6 +1596,10 @@ pub struct QueryConfig {
 
     #[clap(long)]
     pub cloud_control_grpc_server_address: Option<String>,
+
+    /// The webhooks notified when a task fails, referred by the `ERROR_INTEGRATION` of tasks.
+    #[clap(skip)]
+    pub notification_integrations: Vec<NotificationIntegrationConfig>,
 }
 
 impl Default for QueryConfig {
@@ -1605,6 +1622,11 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: self.mysql_tls_server_cert,
             mysql_tls_server_key: self.mysql_tls_server_key,
+            postgres_handler_enabled: self.postgres_handler_enabled,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: self
+                .postgres_handler_tcp_keepalive_timeout_secs,
             max_active_sessions: self.max_active_sessions,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
@@ -1661,6 +1683,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             enable_udf_server: self.enable_udf_server,
             udf_server_allow_list: self.udf_server_allow_list,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
+            notification_integrations: notification_integrations_to_inner(
+                self.notification_integrations,
+            ),
         })
     }
 }
@@ -1678,6 +1703,11 @@ impl From<InnerQueryConfig> for QueryConfig {
                 .mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: inner.mysql_tls_server_cert,
             mysql_tls_server_key: inner.mysql_tls_server_key,
+            postgres_handler_enabled: inner.postgres_handler_enabled,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: inner
+                .postgres_handler_tcp_keepalive_timeout_secs,
             max_active_sessions: inner.max_active_sessions,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
@@ -1751,6 +1781,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             enable_udf_server: inner.enable_udf_server,
             udf_server_allow_list: inner.udf_server_allow_list,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
+            notification_integrations: notification_integrations_from_inner(
+                inner.notification_integrations,
+            ),
         }
     }
 }
@@ -2449,6 +2482,27 @@ fn users_to_inner(outer: Vec<UserConfig>) -> Result<HashMap<String, AuthInfo>> {
     Ok(inner)
 }
 
+fn notification_integrations_from_inner(
+    inner: HashMap<String, String>,
+) -> Vec<NotificationIntegrationConfig> {
+    inner
+        .into_iter()
+        .map(|(name, webhook_url)| NotificationIntegrationConfig { name, webhook_url })
+        .collect()
+}
+
+fn notification_integrations_to_inner(
+    outer: Vec<NotificationIntegrationConfig>,
+) -> HashMap<String, String> {
+    outer.into_iter().map(|c| (c.name, c.webhook_url)).collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationIntegrationConfig {
+    pub name: String,
+    pub webhook_url: String,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UserConfig {
     pub name: String,
@@ -158,6 +158,10 @@ pub struct QueryConfig {
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
     pub mysql_tls_server_cert: String,
     pub mysql_tls_server_key: String,
+    pub postgres_handler_enabled: bool,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
     pub max_active_sessions: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
@@ -226,6 +230,9 @@ pub struct QueryConfig {
     pub udf_server_allow_list: Vec<String>,
 
     pub cloud_control_grpc_server_address: Option<String>,
+
+    /// The webhook urls of the notification integrations, keyed by the name.
+    pub notification_integrations: HashMap<String, String>,
 }
 
 impl Default for QueryConfig {
@@ -240,6 +247,10 @@ impl Default for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: 120,
             mysql_tls_server_cert: "".to_string(),
             mysql_tls_server_key: "".to_string(),
+            postgres_handler_enabled: false,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 15432,
+            postgres_handler_tcp_keepalive_timeout_secs: 120,
             max_active_sessions: 256,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
@@ -293,6 +304,7 @@ impl Default for QueryConfig {
             enable_udf_server: false,
             udf_server_allow_list: Vec::new(),
             cloud_control_grpc_server_address: None,
+            notification_integrations: HashMap::new(),
             data_retention_time_in_days_max: 90,
         }
     }
@@ -313,6 +325,9 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        for webhook_url in sanitized.notification_integrations.values_mut() {
+            *webhook_url = mask_string(webhook_url, 3);
+        }
         sanitized
     }
 }
@@ -16,7 +16,7 @@ use crate::Config;
 
 // Mask the config value to ******
 impl Config {
-    pub const fn mask_option_keys() -> &'static [&'static str; 1] {
-        &["openai_api_key"]
+    pub const fn mask_option_keys() -> &'static [&'static str; 2] {
+        &["openai_api_key", "notification_integrations"]
     }
 }
@@ -23,10 +23,12 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_number_mapped_type;
@@ -78,6 +80,12 @@ pub fn group_hash_column(c: &Column) -> Vec<u64> {
             let c = BitmapType::try_downcast_column(c).unwrap();
             BitmapType::iter_column(&c).map(|x| x.fast_hash()).collect()
         }
+        DataType::Geometry => {
+            let c = GeometryType::try_downcast_column(c).unwrap();
+            GeometryType::iter_column(&c)
+                .map(|x| x.fast_hash())
+                .collect()
+        }
         DataType::Variant => {
             let c = VariantType::try_downcast_column(c).unwrap();
             VariantType::iter_column(&c)
@@ -86,6 +94,8 @@ pub fn group_hash_column(c: &Column) -> Vec<u64> {
         }
         DataType::Timestamp => group_hash_type_column::<TimestampType>(c),
         DataType::Date => group_hash_type_column::<DateType>(c),
+        DataType::Interval => group_hash_type_column::<IntervalType>(c),
+        DataType::TimestampTz => group_hash_type_column::<TimestampTzType>(c),
         DataType::Nullable(_) => {
             let col = c.as_nullable().unwrap();
             let mut values = group_hash_column(&col.column);
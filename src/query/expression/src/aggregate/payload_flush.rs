@@ -26,9 +26,11 @@ use crate::types::ArgType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::with_number_mapped_type;
 use crate::Column;
 use crate::StateAddr;
@@ -119,9 +121,12 @@ impl Payload {
             },
             DataType::Timestamp => self.flush_type_column::<TimestampType>(col_offset, state),
             DataType::Date => self.flush_type_column::<DateType>(col_offset, state),
+            DataType::Interval => self.flush_type_column::<IntervalType>(col_offset, state),
+            DataType::TimestampTz => self.flush_type_column::<TimestampTzType>(col_offset, state),
             DataType::Binary => Column::Binary(self.flush_binary_column(col_offset, state)),
             DataType::String => Column::String(self.flush_string_column(col_offset, state)),
             DataType::Bitmap => Column::Bitmap(self.flush_binary_column(col_offset, state)),
+            DataType::Geometry => Column::Geometry(self.flush_binary_column(col_offset, state)),
             DataType::Variant => Column::Variant(self.flush_binary_column(col_offset, state)),
             DataType::Nullable(_) => unreachable!(),
             DataType::Array(_) => todo!(),
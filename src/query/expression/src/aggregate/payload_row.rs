@@ -27,9 +27,11 @@ use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::NumberColumn;
 use crate::types::NumberType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::with_decimal_mapped_type;
 use crate::with_number_mapped_type;
@@ -40,7 +42,11 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         DataType::Null | DataType::EmptyArray | DataType::EmptyMap => 0,
         DataType::Boolean => 1,
         // use address instead
-        DataType::Binary | DataType::String | DataType::Bitmap | DataType::Variant => 4 + 8, /* u32 len + address */
+        DataType::Binary
+        | DataType::String
+        | DataType::Bitmap
+        | DataType::Geometry
+        | DataType::Variant => 4 + 8, // u32 len + address
         DataType::Number(n) => n.bit_width() as usize / 8,
         DataType::Decimal(n) => match n {
             crate::types::DecimalDataType::Decimal128(_) => 16,
@@ -48,6 +54,8 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         },
         DataType::Timestamp => 8,
         DataType::Date => 4,
+        DataType::Interval => 16,
+        DataType::TimestampTz => 16,
         DataType::Nullable(_) => 4,
         DataType::Array(_) => todo!(),
         DataType::Map(_) => todo!(),
@@ -92,7 +100,7 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&v.get_bit(index), address[index].add(offset) as *mut u8);
             }
         }
-        Column::Binary(v) | Column::Bitmap(v) | Column::Variant(v) => {
+        Column::Binary(v) | Column::Bitmap(v) | Column::Geometry(v) | Column::Variant(v) => {
             for i in 0..rows {
                 let index = select_index.get_index(i);
                 let data = arena.alloc_slice_copy(v.index_unchecked(index));
@@ -130,6 +138,18 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&buffer[index], address[index].add(offset) as *mut u8);
             }
         }
+        Column::Interval(buffer) => {
+            for i in 0..rows {
+                let index = select_index.get_index(i);
+                store(&buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
+        Column::TimestampTz(buffer) => {
+            for i in 0..rows {
+                let index = select_index.get_index(i);
+                store(&buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
         Column::Nullable(c) => serialize_column_to_rowformat(
             arena,
             &c.column,
@@ -271,8 +291,19 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
-        Column::Binary(v) | Column::Bitmap(v) | Column::Variant(v) => row_match_binary_column(
-            v,
+        Column::Interval(_) => row_match_column_type::<IntervalType>(
+            col,
+            validity,
+            address,
+            select_index,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
+        Column::TimestampTz(_) => row_match_column_type::<TimestampTzType>(
+            col,
             validity,
             address,
             select_index,
@@ -282,6 +313,19 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
+        Column::Binary(v) | Column::Bitmap(v) | Column::Geometry(v) | Column::Variant(v) => {
+            row_match_binary_column(
+                v,
+                validity,
+                address,
+                select_index,
+                count,
+                validity_offset,
+                col_offset,
+                no_match,
+                no_match_count,
+            )
+        }
         Column::String(v) => row_match_binary_column(
             &v.clone().into(),
             validity,
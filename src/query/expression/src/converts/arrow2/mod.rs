@@ -19,5 +19,8 @@ pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
+pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
+pub const ARROW_EXT_TYPE_TIMESTAMP_TZ: &str = "TimestampTz";
 
 pub use to::set_validities;
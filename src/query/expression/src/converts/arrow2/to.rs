@@ -23,6 +23,9 @@ use databend_common_arrow::arrow::offset::OffsetsBuffer;
 use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
+use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INTERVAL;
+use super::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::decimal::DecimalColumn;
 use crate::types::DecimalDataType;
@@ -135,6 +138,11 @@ fn table_type_to_arrow_type(ty: &TableDataType, inside_nullable: bool) -> ArrowD
             Box::new(ArrowDataType::LargeBinary),
             None,
         ),
+        TableDataType::Geometry => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_GEOMETRY.to_string(),
+            Box::new(ArrowDataType::LargeBinary),
+            None,
+        ),
         TableDataType::Tuple {
             fields_name,
             fields_type,
@@ -157,6 +165,18 @@ fn table_type_to_arrow_type(ty: &TableDataType, inside_nullable: bool) -> ArrowD
             Box::new(ArrowDataType::LargeBinary),
             None,
         ),
+        // Interval is stored as the packed `months_days_micros` i128 value.
+        TableDataType::Interval => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_INTERVAL.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
+        // TimestampTz is stored as the packed `timestamp_tz` i128 value.
+        TableDataType::TimestampTz => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
     }
 }
 
@@ -331,6 +351,24 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                let values = unsafe { std::mem::transmute(col.clone()) };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
+            Column::TimestampTz(col) => {
+                let values = unsafe { std::mem::transmute(col.clone()) };
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
@@ -384,6 +422,19 @@ impl Column {
                     .unwrap(),
                 )
             }
+            Column::Geometry(col) => {
+                let offsets: Buffer<i64> =
+                    col.offsets().iter().map(|offset| *offset as i64).collect();
+                Box::new(
+                    databend_common_arrow::arrow::array::BinaryArray::<i64>::try_new(
+                        arrow_type,
+                        unsafe { OffsetsBuffer::new_unchecked(offsets) },
+                        col.data().clone(),
+                        None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Nullable(col) => {
                 let arrow_array = col.column.as_arrow();
                 set_validities(arrow_array.clone(), &col.validity)
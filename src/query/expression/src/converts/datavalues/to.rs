@@ -51,8 +51,12 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             let values = x.iter().map(scalar_to_datavalue).collect();
             DataValue::Struct(values)
         }
-        Scalar::EmptyMap | Scalar::Binary(_) | Scalar::Map(_) | Scalar::Bitmap(_) => {
-            unimplemented!()
-        }
+        Scalar::EmptyMap
+        | Scalar::Binary(_)
+        | Scalar::Map(_)
+        | Scalar::Bitmap(_)
+        | Scalar::Geometry(_)
+        | Scalar::Interval(_)
+        | Scalar::TimestampTz(_) => unimplemented!(),
     }
 }
@@ -51,6 +51,7 @@ pub enum IndexScalar {
     Bitmap(Vec<u8>),
     Tuple(Vec<IndexScalar>),
     Variant(Vec<u8>),
+    Geometry(Vec<u8>),
 }
 
 #[derive(Clone, Debug, EnumAsInner)]
@@ -68,6 +69,7 @@ pub enum IndexColumn {
     Nullable(Box<IndexNullableColumn>),
     Tuple(Vec<IndexColumn>),
     Variant(BinaryColumn),
+    Geometry(BinaryColumn),
 }
 
 #[derive(Clone, Debug)]
@@ -99,6 +101,7 @@ impl From<IndexScalar> for Scalar {
                 Scalar::Tuple(tuple.into_iter().map(|c| c.into()).collect())
             }
             IndexScalar::Variant(variant) => Scalar::Variant(variant),
+            IndexScalar::Geometry(geometry) => Scalar::Geometry(geometry),
         }
     }
 }
@@ -132,6 +135,7 @@ impl From<IndexColumn> for Column {
                 Column::Tuple(tuple.into_iter().map(|c| c.into()).collect())
             }
             IndexColumn::Variant(variant) => Column::Variant(variant),
+            IndexColumn::Geometry(geometry) => Column::Geometry(geometry),
         }
     }
 }
@@ -154,7 +158,11 @@ impl From<Scalar> for IndexScalar {
                 IndexScalar::Tuple(tuple.into_iter().map(|c| c.into()).collect())
             }
             Scalar::Variant(variant) => IndexScalar::Variant(variant),
-            Scalar::EmptyArray | Scalar::EmptyMap => unreachable!(),
+            Scalar::Geometry(geometry) => IndexScalar::Geometry(geometry),
+            Scalar::EmptyArray
+            | Scalar::EmptyMap
+            | Scalar::Interval(_)
+            | Scalar::TimestampTz(_) => unreachable!(),
         }
     }
 }
@@ -189,7 +197,11 @@ impl From<Column> for IndexColumn {
                 IndexColumn::Tuple(tuple.into_iter().map(|c| c.into()).collect())
             }
             Column::Variant(variant) => IndexColumn::Variant(variant),
-            Column::EmptyArray { .. } | Column::EmptyMap { .. } => unreachable!(),
+            Column::Geometry(geometry) => IndexColumn::Geometry(geometry),
+            Column::EmptyArray { .. }
+            | Column::EmptyMap { .. }
+            | Column::Interval(_)
+            | Column::TimestampTz(_) => unreachable!(),
         }
     }
 }
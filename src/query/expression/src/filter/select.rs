@@ -28,10 +28,12 @@ use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
 use crate::types::EmptyArrayType;
+use crate::types::IntervalType;
 use crate::types::NullableType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::VariantType;
 use crate::with_decimal_mapped_type;
 use crate::with_number_mapped_type;
@@ -150,6 +152,30 @@ impl<'a> Selector<'a> {
                 select_strategy,
                 count,
             ),
+            DataType::Interval => self.select_type_values::<IntervalType>(
+                &op,
+                left,
+                right,
+                validity,
+                true_selection,
+                false_selection,
+                mutable_true_idx,
+                mutable_false_idx,
+                select_strategy,
+                count,
+            ),
+            DataType::TimestampTz => self.select_type_values::<TimestampTzType>(
+                &op,
+                left,
+                right,
+                validity,
+                true_selection,
+                false_selection,
+                mutable_true_idx,
+                mutable_false_idx,
+                select_strategy,
+                count,
+            ),
             DataType::String => self.select_type_values::<StringType>(
                 &op,
                 left,
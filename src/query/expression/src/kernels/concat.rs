@@ -281,6 +281,20 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_interval().unwrap()),
+                    capacity,
+                );
+                Column::Interval(builder.into())
+            }
+            Column::TimestampTz(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_timestamp_tz().unwrap()),
+                    capacity,
+                );
+                Column::TimestampTz(builder.into())
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -309,6 +323,10 @@ impl Column {
                 columns.map(|col| col.into_bitmap().unwrap()),
                 capacity,
             )),
+            Column::Geometry(_) => GeometryType::upcast_column(Self::concat_binary_types(
+                columns.map(|col| col.into_geometry().unwrap()),
+                capacity,
+            )),
             Column::Nullable(_) => {
                 let column: Vec<Column> = columns
                     .clone()
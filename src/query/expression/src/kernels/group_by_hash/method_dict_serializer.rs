@@ -48,7 +48,10 @@ impl HashMethod for HashMethodDictionarySerializer {
         let mut serialize_columns = Vec::new();
         for (group_column, _) in group_columns {
             match group_column {
-                Column::Binary(v) | Column::Variant(v) | Column::Bitmap(v) => {
+                Column::Binary(v)
+                | Column::Variant(v)
+                | Column::Bitmap(v)
+                | Column::Geometry(v) => {
                     debug_assert_eq!(v.len(), num_rows);
                     dictionary_columns.push(v.clone());
                 }
@@ -47,7 +47,8 @@ impl HashMethod for HashMethodSingleBinary {
         match keys_state {
             KeysState::Column(Column::Binary(col))
             | KeysState::Column(Column::Variant(col))
-            | KeysState::Column(Column::Bitmap(col)) => Ok(col.iter()),
+            | KeysState::Column(Column::Bitmap(col))
+            | KeysState::Column(Column::Geometry(col)) => Ok(col.iter()),
             KeysState::Column(Column::String(col)) => Ok(col.iter_binary()),
             _ => unreachable!(),
         }
@@ -61,7 +62,8 @@ impl HashMethod for HashMethodSingleBinary {
         match keys_state {
             KeysState::Column(Column::Binary(col))
             | KeysState::Column(Column::Variant(col))
-            | KeysState::Column(Column::Bitmap(col)) => {
+            | KeysState::Column(Column::Bitmap(col))
+            | KeysState::Column(Column::Geometry(col)) => {
                 hashes.extend(col.iter().map(hash_join_fast_string_hash));
                 let (data, offsets) = col.into_buffer();
                 Ok(Box::new(BinaryKeyAccessor::new(data, offsets)))
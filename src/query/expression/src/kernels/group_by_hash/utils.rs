@@ -76,7 +76,7 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
             })
         }
         Column::Boolean(v) => store_advance::<bool>(&v.get_bit(row), row_space),
-        Column::Binary(v) | Column::Bitmap(v) | Column::Variant(v) => {
+        Column::Binary(v) | Column::Bitmap(v) | Column::Geometry(v) | Column::Variant(v) => {
             let value = unsafe { v.index_unchecked(row) };
             let len = value.len();
             store_advance::<u64>(&(len as u64), row_space);
@@ -90,6 +90,9 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
         }
         Column::Timestamp(v) => store_advance::<i64>(&v[row], row_space),
         Column::Date(v) => store_advance::<i32>(&v[row], row_space),
+        Column::Interval(v) => store_advance::<i128>(&v[row].0, row_space),
+        // Values of the same instant are the same key.
+        Column::TimestampTz(v) => store_advance::<i128>(&v[row].normalize().0, row_space),
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             store_advance::<u64>(&(data.len() as u64), row_space);
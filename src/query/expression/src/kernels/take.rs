@@ -141,6 +141,14 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_primitive_types(column, indices);
+                Column::Interval(builder.into())
+            }
+            Column::TimestampTz(column) => {
+                let builder = Self::take_primitive_types(column, indices);
+                Column::TimestampTz(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(indices.len() + 1);
                 offsets.push(0);
@@ -171,6 +179,11 @@ impl Column {
                 indices,
                 string_items_buf.as_mut(),
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(Self::take_binary_types(
+                column,
+                indices,
+                string_items_buf.as_mut(),
+            )),
             Column::Nullable(c) => {
                 let column = c.column.take(indices, string_items_buf);
                 let validity = Column::Boolean(Self::take_boolean_types(&c.validity, indices));
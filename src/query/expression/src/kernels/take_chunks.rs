@@ -40,11 +40,13 @@ use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberColumnVec;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::types::F32;
@@ -283,6 +285,14 @@ impl Column {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<DateType>(columns, builder, indices)
             }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
+            Column::TimestampTz(_) => {
+                let builder = TimestampTzType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimestampTzType>(columns, builder, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(result_size + 1);
                 offsets.push(0);
@@ -311,6 +321,10 @@ impl Column {
                 let builder = BitmapType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<BitmapType>(columns, builder, indices)
             }
+            Column::Geometry(_) => {
+                let builder = GeometryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<GeometryType>(columns, builder, indices)
+            }
             Column::Nullable(_) => {
                 let inner_ty = datatype.as_nullable().unwrap();
                 let inner_columns = columns
@@ -503,6 +517,20 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Date(columns)
             }
+            Column::Interval(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| IntervalType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Interval(columns)
+            }
+            Column::TimestampTz(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| TimestampTzType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::TimestampTz(columns)
+            }
             Column::Array(_) => {
                 let columns = columns
                     .iter()
@@ -524,6 +552,13 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Bitmap(columns)
             }
+            Column::Geometry(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| GeometryType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Geometry(columns)
+            }
             Column::Nullable(_) => {
                 let inner_ty = datatype.as_nullable().unwrap();
                 let inner_columns = columns
@@ -646,6 +681,14 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            ColumnVec::Interval(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Interval(builder.into())
+            }
+            ColumnVec::TimestampTz(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::TimestampTz(builder.into())
+            }
             ColumnVec::Array(columns) => {
                 let data_type = data_type.as_array().unwrap();
                 let mut offsets = Vec::with_capacity(result_size + 1);
@@ -677,6 +720,9 @@ impl Column {
             ColumnVec::Bitmap(columns) => BitmapType::upcast_column(
                 Self::take_block_vec_binary_types(columns, indices, binary_items_buf.as_mut()),
             ),
+            ColumnVec::Geometry(columns) => GeometryType::upcast_column(
+                Self::take_block_vec_binary_types(columns, indices, binary_items_buf.as_mut()),
+            ),
             ColumnVec::Nullable(columns) => {
                 let inner_data_type = data_type.as_nullable().unwrap();
                 let inner_column = Self::take_column_vec_indices(
@@ -135,6 +135,14 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_compacted_primitive_types(column, indices, num_rows);
+                Column::Interval(builder.into())
+            }
+            Column::TimestampTz(column) => {
+                let builder = Self::take_compacted_primitive_types(column, indices, num_rows);
+                Column::TimestampTz(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(num_rows + 1);
                 offsets.push(0);
@@ -165,6 +173,9 @@ impl Column {
             Column::Bitmap(column) => BitmapType::upcast_column(Self::take_compact_binary_types(
                 column, indices, num_rows,
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(
+                Self::take_compact_binary_types(column, indices, num_rows),
+            ),
             Column::Nullable(c) => {
                 let column = c.column.take_compacted_indices(indices, num_rows);
                 let validity =
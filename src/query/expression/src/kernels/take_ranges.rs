@@ -111,6 +111,14 @@ impl Column {
                 let d = Self::take_ranges_primitive_types(column, ranges, num_rows);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::Interval(i)
+            }
+            Column::TimestampTz(column) => {
+                let i = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::TimestampTz(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(num_rows + 1);
                 offsets.push(0);
@@ -144,6 +152,10 @@ impl Column {
                 let column = Self::take_ranges_binary_types(column, ranges, num_rows);
                 Column::Bitmap(column)
             }
+            Column::Geometry(column) => {
+                let column = Self::take_ranges_binary_types(column, ranges, num_rows);
+                Column::Geometry(column)
+            }
 
             Column::Nullable(c) => {
                 let column = Self::take_ranges(&c.column, ranges, num_rows);
@@ -50,6 +50,8 @@ impl TopKSorter {
             DataType::String => self.push_column_internal::<StringType>(col, bitmap),
             DataType::Timestamp => self.push_column_internal::<TimestampType>(col, bitmap),
             DataType::Date => self.push_column_internal::<DateType>(col, bitmap),
+            DataType::Interval => self.push_column_internal::<IntervalType>(col, bitmap),
+            DataType::TimestampTz => self.push_column_internal::<TimestampTzType>(col, bitmap),
             _ => {}
         });
     }
@@ -92,6 +94,10 @@ impl TopKSorter {
                 self.push_column_with_selection_internal::<TimestampType>(col, selection, count),
             DataType::Date =>
                 self.push_column_with_selection_internal::<DateType>(col, selection, count),
+            DataType::Interval =>
+                self.push_column_with_selection_internal::<IntervalType>(col, selection, count),
+            DataType::TimestampTz =>
+                self.push_column_with_selection_internal::<TimestampTzType>(col, selection, count),
             _ => count,
         })
     }
@@ -174,6 +180,8 @@ impl TopKSorter {
             DataType::String => self.never_match_any_internal::<StringType>(col),
             DataType::Timestamp => self.never_match_any_internal::<TimestampType>(col),
             DataType::Date => self.never_match_any_internal::<DateType>(col),
+            DataType::Interval => self.never_match_any_internal::<IntervalType>(col),
+            DataType::TimestampTz => self.never_match_any_internal::<TimestampTzType>(col),
             _ => false,
         })
     }
@@ -19,6 +19,8 @@ use crate::types::decimal::Decimal128Type;
 use crate::types::decimal::Decimal256Type;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::GeometryDomain;
+use crate::types::interval::months_days_micros;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
@@ -26,16 +28,20 @@ use crate::types::number::SimpleDomain;
 use crate::types::number::F32;
 use crate::types::number::F64;
 use crate::types::string::StringDomain;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::GeometryType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::with_decimal_type;
 use crate::with_number_type;
@@ -99,6 +105,9 @@ pub enum Domain {
     String(StringDomain),
     Timestamp(SimpleDomain<i64>),
     Date(SimpleDomain<i32>),
+    Interval(SimpleDomain<months_days_micros>),
+    TimestampTz(SimpleDomain<timestamp_tz>),
+    Geometry(GeometryDomain),
     Nullable(NullableDomain<AnyType>),
     /// `Array(None)` means that the array is empty, thus there is no inner domain information.
     Array(Option<Box<Domain>>),
@@ -177,6 +186,8 @@ impl Domain {
             },
             DataType::Timestamp => Domain::Timestamp(TimestampType::full_domain()),
             DataType::Date => Domain::Date(DateType::full_domain()),
+            DataType::Interval => Domain::Interval(IntervalType::full_domain()),
+            DataType::TimestampTz => Domain::TimestampTz(TimestampTzType::full_domain()),
             DataType::Null => Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -202,6 +213,7 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
+            DataType::Geometry => Domain::Geometry(GeometryType::full_domain()),
             DataType::Binary | DataType::Bitmap | DataType::Variant => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
@@ -254,6 +266,19 @@ impl Domain {
                 min: this.min.min(other.min),
                 max: this.max.max(other.max),
             }),
+            (Domain::Interval(this), Domain::Interval(other)) => Domain::Interval(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
+            (Domain::TimestampTz(this), Domain::TimestampTz(other)) => {
+                Domain::TimestampTz(SimpleDomain {
+                    min: this.min.min(other.min),
+                    max: this.max.max(other.max),
+                })
+            }
+            (Domain::Geometry(this), Domain::Geometry(other)) => {
+                Domain::Geometry(this.merge(other))
+            }
             (
                 Domain::Nullable(NullableDomain {
                     has_null: true,
@@ -395,6 +420,12 @@ impl Domain {
                 Some(Scalar::Timestamp(*min))
             }
             Domain::Date(SimpleDomain { min, max }) if min == max => Some(Scalar::Date(*min)),
+            Domain::Interval(SimpleDomain { min, max }) if min == max => {
+                Some(Scalar::Interval(*min))
+            }
+            Domain::TimestampTz(SimpleDomain { min, max }) if min == max => {
+                Some(Scalar::TimestampTz(*min))
+            }
             Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -17,6 +17,8 @@ use ethnum::i256;
 
 use super::row_converter::null_sentinel;
 use crate::types::binary::BinaryColumnBuilder;
+use crate::types::interval::months_days_micros;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::F32;
 use crate::types::F64;
 
@@ -98,6 +100,29 @@ impl FixedLengthEncoding for F64 {
     }
 }
 
+impl FixedLengthEncoding for months_days_micros {
+    type Encoded = [u8; 32];
+
+    fn encode(self) -> [u8; 32] {
+        // Order by the normalized length first, then by the raw parts to
+        // stay consistent with `Ord for months_days_micros`.
+        let mut b = [0u8; 32];
+        b[..16].copy_from_slice(&self.total_micros().encode());
+        b[16..].copy_from_slice(&self.0.encode());
+        b
+    }
+}
+
+impl FixedLengthEncoding for timestamp_tz {
+    type Encoded = [u8; 8];
+
+    fn encode(self) -> [u8; 8] {
+        // Only the UTC instant is encoded, the offset doesn't take part in
+        // `Ord for timestamp_tz`.
+        self.timestamp().encode()
+    }
+}
+
 pub fn encode<T, I>(
     out: &mut BinaryColumnBuilder,
     iter: I,
@@ -24,6 +24,8 @@ use super::variable;
 use crate::types::binary::BinaryColumn;
 use crate::types::binary::BinaryColumnBuilder;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
+use crate::types::timestamp_tz::timestamp_tz;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
@@ -62,6 +64,7 @@ impl RowConverter {
             | DataType::EmptyMap
             | DataType::Map(_)
             | DataType::Bitmap
+            | DataType::Geometry
             | DataType::Tuple(_)
             | DataType::Generic(_) => false,
             DataType::Nullable(inner) => Self::support_data_type(inner.as_ref()),
@@ -123,6 +126,12 @@ impl RowConverter {
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
+                DataType::Interval => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += months_days_micros::ENCODED_LEN as u64),
+                DataType::TimestampTz => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += timestamp_tz::ENCODED_LEN as u64),
                 DataType::Binary => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -254,6 +263,8 @@ fn encode_column(out: &mut BinaryColumnBuilder, column: &Column, asc: bool, null
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Interval(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::TimestampTz(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Binary(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::String(col) => variable::encode(
             out,
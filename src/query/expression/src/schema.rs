@@ -189,6 +189,9 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Interval,
+    TimestampTz,
+    Geometry,
 }
 
 impl DataSchema {
@@ -1157,6 +1160,9 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Interval => DataType::Interval,
+            TableDataType::TimestampTz => DataType::TimestampTz,
+            TableDataType::Geometry => DataType::Geometry,
         }
     }
 }
@@ -1260,6 +1266,7 @@ impl TableDataType {
                 NumberDataType::Float64 => "DOUBLE".to_string(),
             },
             TableDataType::String => "VARCHAR".to_string(),
+            TableDataType::TimestampTz => "TIMESTAMP_TZ".to_string(),
             TableDataType::Nullable(inner_ty) => format!("{} NULL", inner_ty.sql_name()),
             _ => self.to_string().to_uppercase(),
         }
@@ -1368,6 +1375,9 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
+        DataType::Interval => Ok(TableDataType::Interval),
+        DataType::TimestampTz => Ok(TableDataType::TimestampTz),
+        DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
@@ -0,0 +1,645 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_hashtable::FastHash;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_PER_SEC: i64 = 1_000_000;
+pub const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SEC;
+pub const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+pub const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+/// Days of a month when comparing intervals or cascading fractional months.
+pub const DAYS_PER_MONTH: i64 = 30;
+
+/// An interval of months, days and microseconds.
+///
+/// The three parts are kept separately because the length of a month or a day is not fixed,
+/// e.g. `timestamp + INTERVAL '1 month'` keeps the day of month. They are packed into an `i128`
+/// so that the column can be stored as a decimal128 column in arrow and parquet.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub struct months_days_micros(pub i128);
+
+impl months_days_micros {
+    pub fn new(months: i32, days: i32, micros: i64) -> Self {
+        let months = (months as i128) << 96;
+        let days = ((days as u32) as i128) << 64;
+        let micros = (micros as u64) as i128;
+        months_days_micros(months | days | micros)
+    }
+
+    #[inline]
+    pub fn months(&self) -> i32 {
+        (self.0 >> 96) as i32
+    }
+
+    #[inline]
+    pub fn days(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    #[inline]
+    pub fn microseconds(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// Total microseconds of the interval, a month is 30 days and a day is 24 hours.
+    pub fn total_micros(&self) -> i128 {
+        let days = self.months() as i128 * DAYS_PER_MONTH as i128 + self.days() as i128;
+        days * MICROS_PER_DAY as i128 + self.microseconds() as i128
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_add(other.months())?,
+            self.days().checked_add(other.days())?,
+            self.microseconds().checked_add(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_sub(other.months())?,
+            self.days().checked_sub(other.days())?,
+            self.microseconds().checked_sub(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_neg()?,
+            self.days().checked_neg()?,
+            self.microseconds().checked_neg()?,
+        ))
+    }
+
+    /// Divide the interval, the remainder of months is cascaded to days
+    /// and the remainder of days is cascaded to microseconds.
+    pub fn checked_div(self, divisor: i64) -> Option<Self> {
+        if divisor == 0 {
+            return None;
+        }
+        let divisor = divisor as i128;
+        let months = self.months() as i128;
+        let days = self.days() as i128 + months % divisor * DAYS_PER_MONTH as i128;
+        let micros = self.microseconds() as i128 + days % divisor * MICROS_PER_DAY as i128;
+        Some(Self::new(
+            (months / divisor).try_into().ok()?,
+            (days / divisor).try_into().ok()?,
+            (micros / divisor).try_into().ok()?,
+        ))
+    }
+}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for months_days_micros {
+    /// Intervals are ordered by the total length, `1 month` is less than `30 days 1 second`.
+    /// Intervals of the same length but different parts like `1 month` and `30 days`
+    /// are ordered by the raw value to keep consistent with `Eq`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then(self.0.cmp(&other.0))
+    }
+}
+
+impl FastHash for months_days_micros {
+    #[inline(always)]
+    fn fast_hash(&self) -> u64 {
+        self.0.fast_hash()
+    }
+}
+
+impl Debug for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Display for months_days_micros {
+    /// Format the interval like postgres, e.g. `1 year 2 mons 3 days 04:05:06.789`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let months = self.months();
+        let days = self.days();
+        let micros = self.microseconds();
+
+        let mut parts = vec![];
+        let (years, months) = (months / 12, months % 12);
+        if years != 0 {
+            parts.push(plural(years as i64, "year", "years"));
+        }
+        if months != 0 {
+            parts.push(plural(months as i64, "mon", "mons"));
+        }
+        if days != 0 {
+            parts.push(plural(days as i64, "day", "days"));
+        }
+        if micros != 0 || parts.is_empty() {
+            let sign = if micros < 0 { "-" } else { "" };
+            let abs = micros.unsigned_abs();
+            let secs = abs / MICROS_PER_SEC as u64;
+            let mut time = format!(
+                "{sign}{:02}:{:02}:{:02}",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            );
+            let fraction = abs % MICROS_PER_SEC as u64;
+            if fraction != 0 {
+                let fraction = format!("{fraction:06}");
+                time.push('.');
+                time.push_str(fraction.trim_end_matches('0'));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+fn plural(n: i64, singular: &str, plural: &str) -> String {
+    if n == 1 || n == -1 {
+        format!("{n} {singular}")
+    } else {
+        format!("{n} {plural}")
+    }
+}
+
+/// Parse an interval from string, like `1 day 3 hours`, `-2 years 1 mon 04:05:06.7` or `3 days ago`.
+///
+/// A number without unit is treated as seconds.
+pub fn string_to_interval(s: &str) -> Result<months_days_micros, String> {
+    let err = || format!("invalid interval '{s}'");
+    let mut months: i64 = 0;
+    let mut days: i64 = 0;
+    let mut micros: i128 = 0;
+    let mut ago = false;
+    let mut has_value = false;
+
+    let mut tokens = tokenize_interval(s).ok_or_else(err)?.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        if ago {
+            return Err(err());
+        }
+        match token {
+            IntervalToken::Word(word) if word == "ago" && has_value => ago = true,
+            IntervalToken::Word(_) => return Err(err()),
+            IntervalToken::Time(time) => {
+                micros += parse_interval_time(&time).ok_or_else(err)?;
+                has_value = true;
+            }
+            IntervalToken::Number(number) => {
+                let (int, frac) = parse_interval_number(&number).ok_or_else(err)?;
+                let unit = match tokens.peek() {
+                    Some(IntervalToken::Word(word)) if word != "ago" => {
+                        let unit = IntervalUnit::parse(word).ok_or_else(err)?;
+                        tokens.next();
+                        unit
+                    }
+                    _ => IntervalUnit::Second,
+                };
+                unit.accumulate(int, frac, &mut months, &mut days, &mut micros)
+                    .ok_or_else(err)?;
+                has_value = true;
+            }
+        }
+    }
+    if !has_value {
+        return Err(err());
+    }
+    if ago {
+        months = -months;
+        days = -days;
+        micros = -micros;
+    }
+    Ok(months_days_micros::new(
+        months.try_into().map_err(|_| err())?,
+        days.try_into().map_err(|_| err())?,
+        micros.try_into().map_err(|_| err())?,
+    ))
+}
+
+enum IntervalToken {
+    /// Number with optional sign and fraction, like `-1.5`.
+    Number(String),
+    /// Time like `-04:05:06.7`.
+    Time(String),
+    Word(String),
+}
+
+fn tokenize_interval(s: &str) -> Option<Vec<IntervalToken>> {
+    let mut tokens = vec![];
+    let mut chars = s.trim().chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() || c == ',' {
+            chars.next();
+        } else if c.is_ascii_alphabetic() {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_alphabetic()) {
+                word.push(c.to_ascii_lowercase());
+                chars.next();
+            }
+            tokens.push(IntervalToken::Word(word));
+        } else if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' {
+            let mut number = String::new();
+            number.push(c);
+            chars.next();
+            while let Some(&c) = chars
+                .peek()
+                .filter(|c| c.is_ascii_digit() || **c == '.' || **c == ':')
+            {
+                number.push(c);
+                chars.next();
+            }
+            if number.contains(':') {
+                tokens.push(IntervalToken::Time(number));
+            } else {
+                tokens.push(IntervalToken::Number(number));
+            }
+        } else {
+            return None;
+        }
+    }
+    Some(tokens)
+}
+
+/// Parse the number into the integer part and the fraction part, both parts have the same sign.
+fn parse_interval_number(number: &str) -> Option<(i64, f64)> {
+    let (negative, number) = match number.as_bytes().first()? {
+        b'-' => (true, &number[1..]),
+        b'+' => (false, &number[1..]),
+        _ => (false, number),
+    };
+    let (int, frac) = match number.split_once('.') {
+        Some((int, frac)) => (int, frac),
+        None => (number, ""),
+    };
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    let int = if int.is_empty() {
+        0
+    } else {
+        int.parse::<i64>().ok()?
+    };
+    let frac = if frac.is_empty() {
+        0.0
+    } else {
+        format!("0.{frac}").parse::<f64>().ok()?
+    };
+    if negative {
+        Some((-int, -frac))
+    } else {
+        Some((int, frac))
+    }
+}
+
+/// Parse time like `[-]HH:MM[:SS[.ffffff]]` into microseconds.
+fn parse_interval_time(time: &str) -> Option<i128> {
+    let (negative, time) = match time.as_bytes().first()? {
+        b'-' => (true, &time[1..]),
+        b'+' => (false, &time[1..]),
+        _ => (false, time),
+    };
+    let parts = time.split(':').collect::<Vec<_>>();
+    if parts.len() < 2 || parts.len() > 3 {
+        return None;
+    }
+    let hours = parts[0].parse::<i64>().ok()? as i128;
+    let minutes = parts[1].parse::<i64>().ok()? as i128;
+    let mut micros = (hours * MICROS_PER_HOUR as i128) + (minutes * MICROS_PER_MINUTE as i128);
+    if let Some(seconds) = parts.get(2) {
+        let (int, frac) = parse_interval_number(seconds)?;
+        if int < 0 {
+            return None;
+        }
+        micros += int as i128 * MICROS_PER_SEC as i128;
+        micros += (frac * MICROS_PER_SEC as f64).round() as i128;
+    }
+    if negative {
+        Some(-micros)
+    } else {
+        Some(micros)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum IntervalUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+}
+
+impl IntervalUnit {
+    fn parse(word: &str) -> Option<Self> {
+        let unit = match word {
+            "y" | "yr" | "yrs" | "year" | "years" => IntervalUnit::Year,
+            "quarter" | "quarters" => IntervalUnit::Quarter,
+            "mon" | "mons" | "month" | "months" => IntervalUnit::Month,
+            "w" | "week" | "weeks" => IntervalUnit::Week,
+            "d" | "day" | "days" => IntervalUnit::Day,
+            "h" | "hr" | "hrs" | "hour" | "hours" => IntervalUnit::Hour,
+            "m" | "min" | "mins" | "minute" | "minutes" => IntervalUnit::Minute,
+            "s" | "sec" | "secs" | "second" | "seconds" => IntervalUnit::Second,
+            "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => IntervalUnit::Millisecond,
+            "us" | "usec" | "usecs" | "microsecond" | "microseconds" => IntervalUnit::Microsecond,
+            _ => return None,
+        };
+        Some(unit)
+    }
+
+    /// Add the value of the unit to the interval parts,
+    /// fractions of months and days are cascaded to smaller parts.
+    fn accumulate(
+        self,
+        int: i64,
+        frac: f64,
+        months: &mut i64,
+        days: &mut i64,
+        micros: &mut i128,
+    ) -> Option<()> {
+        match self {
+            IntervalUnit::Year | IntervalUnit::Quarter | IntervalUnit::Month => {
+                let factor = match self {
+                    IntervalUnit::Year => 12,
+                    IntervalUnit::Quarter => 3,
+                    _ => 1,
+                };
+                let frac_months = frac * factor as f64;
+                *months = months
+                    .checked_add(int.checked_mul(factor)?)?
+                    .checked_add(frac_months.trunc() as i64)?;
+                let frac_days = frac_months.fract() * DAYS_PER_MONTH as f64;
+                *days = days.checked_add(frac_days.trunc() as i64)?;
+                *micros += (frac_days.fract() * MICROS_PER_DAY as f64).round() as i128;
+            }
+            IntervalUnit::Week | IntervalUnit::Day => {
+                let factor = if matches!(self, IntervalUnit::Week) {
+                    7
+                } else {
+                    1
+                };
+                let frac_days = frac * factor as f64;
+                *days = days
+                    .checked_add(int.checked_mul(factor)?)?
+                    .checked_add(frac_days.trunc() as i64)?;
+                *micros += (frac_days.fract() * MICROS_PER_DAY as f64).round() as i128;
+            }
+            _ => {
+                let factor = match self {
+                    IntervalUnit::Hour => MICROS_PER_HOUR,
+                    IntervalUnit::Minute => MICROS_PER_MINUTE,
+                    IntervalUnit::Second => MICROS_PER_SEC,
+                    IntervalUnit::Millisecond => 1_000,
+                    _ => 1,
+                };
+                *micros += int as i128 * factor as i128;
+                *micros += (frac * factor as f64).round() as i128;
+            }
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = SimpleDomain<months_days_micros>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<months_days_micros>> {
+        domain.as_interval().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(builder: Self::ColumnBuilder) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Interval(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<months_days_micros>) -> Domain {
+        Domain::Interval(domain)
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: months_days_micros::new(i32::MIN, i32::MIN, i64::MIN),
+            max: months_days_micros::new(i32::MAX, i32::MAX, i64::MAX),
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
@@ -0,0 +1,388 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_hashtable::FastHash;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::number::SimpleDomain;
+use super::timestamp::check_timestamp;
+use super::timestamp::string_to_timestamp;
+use super::timestamp::MICROS_IN_A_SEC;
+use super::timestamp::TIMESTAMP_MAX;
+use super::timestamp::TIMESTAMP_MIN;
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const TIMESTAMP_TZ_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.6f%:z";
+/// Minimum valid timezone offset `-14:00` in seconds.
+pub const TIMEZONE_OFFSET_MIN: i32 = -14 * 3600;
+/// Maximum valid timezone offset `+14:00` in seconds.
+pub const TIMEZONE_OFFSET_MAX: i32 = 14 * 3600;
+
+/// A timestamp with the timezone offset of the value.
+///
+/// The timestamp is the microseconds since the unix epoch in UTC, so that values of different
+/// offsets can be compared by the instant. The offset in seconds is kept for display, e.g.
+/// `2024-01-01 08:00:00.000000+08:00`. They are packed into an `i128` so that the column can
+/// be stored as a decimal128 column in arrow and parquet.
+///
+/// `Eq`, `Ord` and `Hash` only use the instant, values of different offsets at the same
+/// instant are equal.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct timestamp_tz(pub i128);
+
+impl timestamp_tz {
+    pub fn new(timestamp: i64, seconds_offset: i32) -> Self {
+        let offset = (seconds_offset as i128) << 64;
+        let timestamp = (timestamp as u64) as i128;
+        timestamp_tz(offset | timestamp)
+    }
+
+    /// Microseconds since the unix epoch in UTC.
+    #[inline]
+    pub fn timestamp(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// Offset from UTC in seconds, e.g. `28800` for `+08:00`.
+    #[inline]
+    pub fn seconds_offset(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    pub fn to_datetime(&self) -> DateTime<FixedOffset> {
+        let offset = FixedOffset::east_opt(self.seconds_offset())
+            .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+        let micros = self.timestamp();
+        let (mut secs, mut nanos) = (micros / MICROS_IN_A_SEC, (micros % MICROS_IN_A_SEC) * 1_000);
+        if nanos < 0 {
+            secs -= 1;
+            nanos += 1_000_000_000;
+        }
+        offset.timestamp_opt(secs, nanos as u32).unwrap()
+    }
+
+    /// Convert the timestamp to the offset of the timezone at the same instant.
+    pub fn from_timestamp(timestamp: i64, tz: Tz) -> Self {
+        let offset = tz
+            .offset_from_utc_datetime(&timestamp_tz::new(timestamp, 0).to_datetime().naive_utc())
+            .fix()
+            .local_minus_utc();
+        timestamp_tz::new(timestamp, offset)
+    }
+
+    /// The value at the same instant with the `+00:00` offset, values of the same instant
+    /// have the same normalized bits, e.g. for serialized group by keys.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        timestamp_tz::new(self.timestamp(), 0)
+    }
+}
+
+impl PartialEq for timestamp_tz {
+    fn eq(&self, other: &Self) -> bool {
+        self.timestamp() == other.timestamp()
+    }
+}
+
+impl Eq for timestamp_tz {}
+
+impl Hash for timestamp_tz {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.timestamp().hash(state)
+    }
+}
+
+impl PartialOrd for timestamp_tz {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for timestamp_tz {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp().cmp(&other.timestamp())
+    }
+}
+
+impl FastHash for timestamp_tz {
+    #[inline(always)]
+    fn fast_hash(&self) -> u64 {
+        self.timestamp().fast_hash()
+    }
+}
+
+impl Debug for timestamp_tz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Display for timestamp_tz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_datetime().format(TIMESTAMP_TZ_FORMAT))
+    }
+}
+
+/// Parse a timestamp with an optional offset like `2024-01-01 08:00:00+08:00`,
+/// the offset of the timezone is used if the string has no offset.
+pub fn string_to_timestamp_tz(ts_str: &str, tz: Tz) -> Result<timestamp_tz, String> {
+    let err = || format!("invalid timestamp_tz '{ts_str}'");
+    let ts_str = ts_str.trim();
+    let dt = string_to_timestamp(ts_str, tz).ok_or_else(err)?;
+    let offset = match parse_offset_suffix(ts_str) {
+        Some(offset) => offset,
+        None => dt.offset().fix().local_minus_utc(),
+    };
+    if !(TIMEZONE_OFFSET_MIN..=TIMEZONE_OFFSET_MAX).contains(&offset) {
+        return Err(err());
+    }
+    let timestamp = check_timestamp(dt.timestamp_micros())?;
+    Ok(timestamp_tz::new(timestamp, offset))
+}
+
+/// Parse the offset at the end of the timestamp string, like `Z`, `+08`, `+08:00` or `-0530`.
+fn parse_offset_suffix(ts_str: &str) -> Option<i32> {
+    if ts_str.ends_with(['z', 'Z']) {
+        return Some(0);
+    }
+    // Skip the date part `YYYY-MM-DD`, which also contains `-`.
+    let pos = ts_str.rfind(['+', '-']).filter(|pos| *pos > 10)?;
+    let offset = &ts_str[pos..];
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let offset = offset[1..].replace(':', "");
+    let (hours, minutes) = match offset.len() {
+        2 => (offset.parse::<i32>().ok()?, 0),
+        4 => (
+            offset[..2].parse::<i32>().ok()?,
+            offset[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampTzType;
+
+impl ValueType for TimestampTzType {
+    type Scalar = timestamp_tz;
+    type ScalarRef<'a> = timestamp_tz;
+    type Column = Buffer<timestamp_tz>;
+    type Domain = SimpleDomain<timestamp_tz>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, timestamp_tz>>;
+    type ColumnBuilder = Vec<timestamp_tz>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: timestamp_tz) -> timestamp_tz {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::TimestampTz(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::TimestampTz(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<timestamp_tz>> {
+        domain.as_timestamp_tz().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(builder: Self::ColumnBuilder) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::TimestampTz(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::TimestampTz(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::TimestampTz(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<timestamp_tz>) -> Domain {
+        Domain::TimestampTz(domain)
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left.timestamp() == right.timestamp()
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left.timestamp() != right.timestamp()
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left.timestamp() > right.timestamp()
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left.timestamp() >= right.timestamp()
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left.timestamp() < right.timestamp()
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left.timestamp() <= right.timestamp()
+    }
+}
+
+impl ArgType for TimestampTzType {
+    fn data_type() -> DataType {
+        DataType::TimestampTz
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: timestamp_tz::new(TIMESTAMP_MIN, TIMEZONE_OFFSET_MIN),
+            max: timestamp_tz::new(TIMESTAMP_MAX, TIMEZONE_OFFSET_MAX),
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
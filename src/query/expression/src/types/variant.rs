@@ -15,6 +15,8 @@
 use core::cmp::Ordering;
 use std::ops::Range;
 
+use databend_common_io::geometry::geometry_to_geojson;
+use databend_common_io::geometry::wkb_to_geometry;
 use roaring::RoaringTreemap;
 
 use super::binary::BinaryColumn;
@@ -210,6 +212,8 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::String(s) => jsonb::Value::String(s.into()),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => i.to_string().into(),
+        ScalarRef::TimestampTz(i) => i.to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -227,6 +231,8 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
                         ScalarRef::Boolean(v) => v.to_string(),
                         ScalarRef::Timestamp(v) => timestamp_to_string(v, inner_tz).to_string(),
                         ScalarRef::Date(v) => date_to_string(v, inner_tz).to_string(),
+                        ScalarRef::Interval(v) => v.to_string(),
+                        ScalarRef::TimestampTz(v) => v.to_string(),
                         _ => unreachable!(),
                     };
                     let mut val = vec![];
@@ -249,6 +255,16 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             .write_to_vec(buf);
             return;
         }
+        ScalarRef::Geometry(g) => match wkb_to_geometry(g) {
+            Ok(geo) => {
+                let geojson = geometry_to_geojson(&geo);
+                jsonb::parse_value(geojson.as_bytes())
+                    .expect("failed to parse geojson")
+                    .write_to_vec(buf);
+                return;
+            }
+            Err(_) => jsonb::Value::Null,
+        },
         ScalarRef::Tuple(fields) => {
             let values = cast_scalars_to_variants(fields, tz);
             jsonb::build_object(
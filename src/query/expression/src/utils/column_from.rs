@@ -68,9 +68,12 @@ impl_from_data! { BooleanType }
 impl_from_data! { BinaryType }
 impl_from_data! { StringType }
 impl_from_data! { DateType }
+impl_from_data! { IntervalType }
+impl_from_data! { TimestampTzType }
 impl_from_data! { TimestampType }
 impl_from_data! { VariantType }
 impl_from_data! { BitmapType }
+impl_from_data! { GeometryType }
 
 impl<'a> FromData<&'a [u8]> for BinaryType {
     fn from_data(d: Vec<&'a [u8]>) -> Column {
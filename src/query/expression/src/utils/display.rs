@@ -21,6 +21,7 @@ use comfy_table::Cell;
 use comfy_table::Table;
 use databend_common_io::display_decimal_128;
 use databend_common_io::display_decimal_256;
+use databend_common_io::geometry::wkb_to_wkt;
 use itertools::Itertools;
 use num_traits::FromPrimitive;
 use roaring::RoaringTreemap;
@@ -41,6 +42,7 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::GeometryDomain;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -124,6 +126,8 @@ impl<'a> Debug for ScalarRef<'a> {
             ScalarRef::String(s) => write!(f, "{s:?}"),
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
+            ScalarRef::Interval(i) => write!(f, "{i:?}"),
+            ScalarRef::TimestampTz(i) => write!(f, "{i:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -142,6 +146,10 @@ impl<'a> Debug for ScalarRef<'a> {
                 let rb = RoaringTreemap::deserialize_from(*bits).unwrap();
                 write!(f, "{rb:?}")
             }
+            ScalarRef::Geometry(g) => match wkb_to_wkt(g) {
+                Ok(wkt) => write!(f, "{wkt:?}"),
+                Err(_) => write!(f, "0x{}", hex::encode(g)),
+            },
             ScalarRef::Tuple(fields) => {
                 write!(f, "(")?;
                 for (i, field) in fields.iter().enumerate() {
@@ -179,9 +187,12 @@ impl Debug for Column {
             Column::String(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
+            Column::TimestampTz(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
             Column::Bitmap(col) => write!(f, "{col:?}"),
+            Column::Geometry(col) => write!(f, "{col:?}"),
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
@@ -207,6 +218,8 @@ impl<'a> Display for ScalarRef<'a> {
             ScalarRef::String(s) => write!(f, "'{s}'"),
             ScalarRef::Timestamp(t) => write!(f, "'{}'", timestamp_to_string(*t, Tz::UTC)),
             ScalarRef::Date(d) => write!(f, "'{}'", date_to_string(*d as i64, Tz::UTC)),
+            ScalarRef::Interval(i) => write!(f, "'{i}'"),
+            ScalarRef::TimestampTz(i) => write!(f, "'{i}'"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -228,6 +241,10 @@ impl<'a> Display for ScalarRef<'a> {
                 }
                 Ok(())
             }
+            ScalarRef::Geometry(g) => match wkb_to_wkt(g) {
+                Ok(wkt) => write!(f, "{wkt}"),
+                Err(_) => write!(f, "0x{}", hex::encode(g)),
+            },
             ScalarRef::Tuple(fields) => {
                 write!(f, "(")?;
                 for (i, field) in fields.iter().enumerate() {
@@ -483,6 +500,8 @@ impl Display for DataType {
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
+            DataType::Interval => write!(f, "Interval"),
+            DataType::TimestampTz => write!(f, "TimestampTz"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -509,6 +528,7 @@ impl Display for DataType {
                 write!(f, ")")
             }
             DataType::Variant => write!(f, "Variant"),
+            DataType::Geometry => write!(f, "Geometry"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -524,6 +544,8 @@ impl Display for TableDataType {
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
             TableDataType::Date => write!(f, "Date"),
+            TableDataType::Interval => write!(f, "Interval"),
+            TableDataType::TimestampTz => write!(f, "TimestampTz"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
             TableDataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -556,6 +578,7 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Geometry => write!(f, "Geometry"),
         }
     }
 }
@@ -987,6 +1010,16 @@ impl<T: Display> Display for SimpleDomain<T> {
     }
 }
 
+impl Display for GeometryDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{{({} {})..=({} {})}}",
+            self.min_x, self.min_y, self.max_x, self.max_y
+        )
+    }
+}
+
 impl Display for Domain {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -996,6 +1029,9 @@ impl Display for Domain {
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
+            Domain::Interval(domain) => write!(f, "{domain}"),
+            Domain::TimestampTz(domain) => write!(f, "{domain}"),
+            Domain::Geometry(domain) => write!(f, "{domain}"),
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
             Domain::Array(Some(domain)) => write!(f, "[{domain}]"),
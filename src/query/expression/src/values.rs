@@ -28,9 +28,13 @@ use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_arrow::arrow::trusted_len::TrustedLen;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_io::geometry::geometry_to_wkb;
 use databend_common_io::prelude::BinaryRead;
 use enum_as_inner::EnumAsInner;
 use ethnum::i256;
+use geo_types::Geometry;
+use geo_types::GeometryCollection;
+use geo_types::Point;
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use roaring::RoaringTreemap;
@@ -56,6 +60,8 @@ use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
 use crate::types::decimal::DecimalType;
+use crate::types::geometry::GeometryDomain;
+use crate::types::interval::months_days_micros;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableColumnVec;
@@ -72,6 +78,9 @@ use crate::types::string::StringDomain;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::TIMESTAMP_MAX;
 use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::timestamp_tz::timestamp_tz;
+use crate::types::timestamp_tz::TIMEZONE_OFFSET_MAX;
+use crate::types::timestamp_tz::TIMEZONE_OFFSET_MIN;
 use crate::types::variant::JSONB_NULL;
 use crate::types::*;
 use crate::utils::arrow::append_bitmap;
@@ -110,6 +119,8 @@ pub enum Scalar {
     Decimal(DecimalScalar),
     Timestamp(i64),
     Date(i32),
+    Interval(months_days_micros),
+    TimestampTz(timestamp_tz),
     Boolean(bool),
     Binary(Vec<u8>),
     String(String),
@@ -118,6 +129,7 @@ pub enum Scalar {
     Bitmap(Vec<u8>),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    Geometry(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -133,11 +145,14 @@ pub enum ScalarRef<'a> {
     String(&'a str),
     Timestamp(i64),
     Date(i32),
+    Interval(months_days_micros),
+    TimestampTz(timestamp_tz),
     Array(Column),
     Map(Column),
     Bitmap(&'a [u8]),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
+    Geometry(&'a [u8]),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -152,12 +167,15 @@ pub enum Column {
     String(StringColumn),
     Timestamp(Buffer<i64>),
     Date(Buffer<i32>),
+    Interval(Buffer<months_days_micros>),
+    TimestampTz(Buffer<timestamp_tz>),
     Array(Box<ArrayColumn<AnyType>>),
     Map(Box<ArrayColumn<AnyType>>),
     Bitmap(BinaryColumn),
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple(Vec<Column>),
     Variant(BinaryColumn),
+    Geometry(BinaryColumn),
 }
 
 #[derive(Clone, EnumAsInner, Debug, PartialEq)]
@@ -172,12 +190,15 @@ pub enum ColumnVec {
     String(Vec<StringColumn>),
     Timestamp(Vec<Buffer<i64>>),
     Date(Vec<Buffer<i32>>),
+    Interval(Vec<Buffer<months_days_micros>>),
+    TimestampTz(Vec<Buffer<timestamp_tz>>),
     Array(Vec<ArrayColumn<AnyType>>),
     Map(Vec<ArrayColumn<KvPair<AnyType, AnyType>>>),
     Bitmap(Vec<BinaryColumn>),
     Nullable(Box<NullableColumnVec>),
     Tuple(Vec<ColumnVec>),
     Variant(Vec<BinaryColumn>),
+    Geometry(Vec<BinaryColumn>),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -192,12 +213,15 @@ pub enum ColumnBuilder {
     String(StringColumnBuilder),
     Timestamp(Vec<i64>),
     Date(Vec<i32>),
+    Interval(Vec<months_days_micros>),
+    TimestampTz(Vec<timestamp_tz>),
     Array(Box<ArrayColumnBuilder<AnyType>>),
     Map(Box<ArrayColumnBuilder<AnyType>>),
     Bitmap(BinaryColumnBuilder),
     Nullable(Box<NullableColumnBuilder<AnyType>>),
     Tuple(Vec<ColumnBuilder>),
     Variant(BinaryColumnBuilder),
+    Geometry(BinaryColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -347,9 +371,12 @@ impl Scalar {
             Scalar::String(s) => ScalarRef::String(s.as_str()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
+            Scalar::TimestampTz(i) => ScalarRef::TimestampTz(*i),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
+            Scalar::Geometry(b) => ScalarRef::Geometry(b.as_slice()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
         }
@@ -378,6 +405,8 @@ impl Scalar {
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
             DataType::Date => Scalar::Date(0),
+            DataType::Interval => Scalar::Interval(months_days_micros::default()),
+            DataType::TimestampTz => Scalar::TimestampTz(timestamp_tz::default()),
             DataType::Nullable(_) => Scalar::Null,
             DataType::Array(ty) => {
                 let builder = ColumnBuilder::with_capacity(ty, 0);
@@ -395,6 +424,9 @@ impl Scalar {
                 rb.serialize_into(&mut buf).unwrap();
                 Scalar::Bitmap(buf)
             }
+            DataType::Geometry => Scalar::Geometry(geometry_to_wkb(&Geometry::GeometryCollection(
+                GeometryCollection(vec![]),
+            ))),
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
 
@@ -408,6 +440,7 @@ impl Scalar {
             Scalar::Decimal(d) => d.is_positive(),
             Scalar::Timestamp(t) => *t > 0,
             Scalar::Date(d) => *d > 0,
+            Scalar::Interval(i) => i.total_micros() > 0,
             _ => unreachable!("is_positive() called on non-numeric scalar"),
         }
     }
@@ -443,9 +476,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => Scalar::String(s.to_string()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
+            ScalarRef::TimestampTz(i) => Scalar::TimestampTz(*i),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
             ScalarRef::Bitmap(b) => Scalar::Bitmap(b.to_vec()),
+            ScalarRef::Geometry(b) => Scalar::Geometry(b.to_vec()),
             ScalarRef::Tuple(fields) => {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
             }
@@ -487,6 +523,8 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
+            ScalarRef::Interval(i) => Domain::Interval(SimpleDomain { min: *i, max: *i }),
+            ScalarRef::TimestampTz(i) => Domain::TimestampTz(SimpleDomain { min: *i, max: *i }),
             ScalarRef::Array(array) => {
                 if array.len() == 0 {
                     Domain::Array(None)
@@ -521,6 +559,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Binary(_) | ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => {
                 Domain::Undefined
             }
+            ScalarRef::Geometry(g) => Domain::Geometry(GeometryDomain::from_wkb(g)),
         }
     }
 
@@ -544,9 +583,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => s.len(),
             ScalarRef::Timestamp(_) => 8,
             ScalarRef::Date(_) => 4,
+            ScalarRef::Interval(_) => 16,
+            ScalarRef::TimestampTz(_) => 16,
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Bitmap(b) => b.len(),
+            ScalarRef::Geometry(b) => b.len(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
         }
@@ -570,9 +612,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(_) => DataType::String,
             ScalarRef::Timestamp(_) => DataType::Timestamp,
             ScalarRef::Date(_) => DataType::Date,
+            ScalarRef::Interval(_) => DataType::Interval,
+            ScalarRef::TimestampTz(_) => DataType::TimestampTz,
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
             ScalarRef::Bitmap(_) => DataType::Bitmap,
+            ScalarRef::Geometry(_) => DataType::Geometry,
             ScalarRef::Tuple(fields) => {
                 let inner = fields
                     .iter()
@@ -599,7 +644,10 @@ impl<'a> ScalarRef<'a> {
                 (ScalarRef::String(_), DataType::String) => true,
                 (ScalarRef::Timestamp(_), DataType::Timestamp) => true,
                 (ScalarRef::Date(_), DataType::Date) => true,
+                (ScalarRef::Interval(_), DataType::Interval) => true,
+                (ScalarRef::TimestampTz(_), DataType::TimestampTz) => true,
                 (ScalarRef::Bitmap(_), DataType::Bitmap) => true,
+                (ScalarRef::Geometry(_), DataType::Geometry) => true,
                 (ScalarRef::Variant(_), DataType::Variant) => true,
                 (ScalarRef::Array(val), DataType::Array(ty)) => val.data_type() == *ty,
                 (ScalarRef::Map(val), DataType::Map(ty)) => val.data_type() == *ty,
@@ -630,9 +678,12 @@ impl PartialOrd for Scalar {
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
+            (Scalar::TimestampTz(i1), Scalar::TimestampTz(i2)) => i1.partial_cmp(i2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
             (Scalar::Bitmap(b1), Scalar::Bitmap(b2)) => b1.partial_cmp(b2),
+            (Scalar::Geometry(b1), Scalar::Geometry(b2)) => b1.partial_cmp(b2),
             (Scalar::Tuple(t1), Scalar::Tuple(t2)) => t1.partial_cmp(t2),
             (Scalar::Variant(v1), Scalar::Variant(v2)) => {
                 jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
@@ -667,9 +718,12 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::TimestampTz(i1), ScalarRef::TimestampTz(i2)) => i1.partial_cmp(i2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Bitmap(b1), ScalarRef::Bitmap(b2)) => b1.partial_cmp(b2),
+            (ScalarRef::Geometry(b1), ScalarRef::Geometry(b2)) => b1.partial_cmp(b2),
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
             _ => None,
@@ -708,6 +762,8 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::String(v) => v.hash(state),
             ScalarRef::Timestamp(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
+            ScalarRef::Interval(v) => v.hash(state),
+            ScalarRef::TimestampTz(v) => v.hash(state),
             ScalarRef::Array(v) => {
                 let str = serialize_column(v);
                 str.hash(state);
@@ -717,6 +773,7 @@ impl Hash for ScalarRef<'_> {
                 str.hash(state);
             }
             ScalarRef::Bitmap(v) => v.hash(state),
+            ScalarRef::Geometry(v) => v.hash(state),
             ScalarRef::Tuple(v) => {
                 v.hash(state);
             }
@@ -750,9 +807,18 @@ impl PartialOrd for Column {
                 col1.iter().partial_cmp(col2.iter())
             }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::TimestampTz(col1), Column::TimestampTz(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Bitmap(col1), Column::Bitmap(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Geometry(col1), Column::Geometry(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Nullable(col1), Column::Nullable(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
@@ -784,9 +850,12 @@ impl Column {
             Column::String(col) => col.len(),
             Column::Timestamp(col) => col.len(),
             Column::Date(col) => col.len(),
+            Column::Interval(col) => col.len(),
+            Column::TimestampTz(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
             Column::Bitmap(col) => col.len(),
+            Column::Geometry(col) => col.len(),
             Column::Nullable(col) => col.len(),
             Column::Tuple(fields) => fields[0].len(),
             Column::Variant(col) => col.len(),
@@ -805,9 +874,12 @@ impl Column {
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
+            Column::TimestampTz(col) => Some(ScalarRef::TimestampTz(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
             Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
+            Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
             Column::Nullable(col) => Some(col.index(index)?.unwrap_or(ScalarRef::Null)),
             Column::Tuple(fields) => Some(ScalarRef::Tuple(
                 fields
@@ -834,9 +906,12 @@ impl Column {
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
+            Column::TimestampTz(col) => ScalarRef::TimestampTz(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
             Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
+            Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
             Column::Nullable(col) => col.index_unchecked(index).unwrap_or(ScalarRef::Null),
             Column::Tuple(fields) => ScalarRef::Tuple(
                 fields
@@ -884,9 +959,16 @@ impl Column {
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::TimestampTz(col) => {
+                Column::TimestampTz(col.clone().sliced(range.start, range.end - range.start))
+            }
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
             Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
+            Column::Geometry(col) => Column::Geometry(col.slice(range)),
             Column::Nullable(col) => Column::Nullable(Box::new(col.slice(range))),
             Column::Tuple(fields) => Column::Tuple(
                 fields
@@ -944,6 +1026,20 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::Interval(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Interval(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
+            Column::TimestampTz(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::TimestampTz(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
             Column::Array(col) => {
                 if col.len() == 0 || col.values.len() == 0 {
                     Domain::Array(None)
@@ -978,6 +1074,11 @@ impl Column {
                 Domain::Tuple(domains)
             }
             Column::Binary(_) | Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
+            Column::Geometry(col) => Domain::Geometry(
+                col.iter()
+                    .map(GeometryDomain::from_wkb)
+                    .fold(GeometryDomain::empty(), |acc, d| acc.merge(&d)),
+            ),
         }
     }
 
@@ -998,6 +1099,8 @@ impl Column {
             Column::String(_) => DataType::String,
             Column::Timestamp(_) => DataType::Timestamp,
             Column::Date(_) => DataType::Date,
+            Column::Interval(_) => DataType::Interval,
+            Column::TimestampTz(_) => DataType::TimestampTz,
             Column::Array(array) => {
                 let inner = array.values.data_type();
                 DataType::Array(Box::new(inner))
@@ -1007,6 +1110,7 @@ impl Column {
                 DataType::Map(Box::new(inner))
             }
             Column::Bitmap(_) => DataType::Bitmap,
+            Column::Geometry(_) => DataType::Geometry,
             Column::Nullable(inner) => {
                 let inner = inner.column.data_type();
                 inner.wrap_nullable()
@@ -1025,6 +1129,7 @@ impl Column {
             Column::String(x) => x.check_valid(),
             Column::Variant(x) => x.check_valid(),
             Column::Bitmap(x) => x.check_valid(),
+            Column::Geometry(x) => x.check_valid(),
             Column::Map(x) => {
                 for y in x.iter() {
                     y.check_valid()?;
@@ -1124,6 +1229,25 @@ impl Column {
                     .map(|_| SmallRng::from_entropy().gen_range(DATE_MIN..=DATE_MAX))
                     .collect::<Vec<i32>>(),
             ),
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        months_days_micros::new(rng.gen(), rng.gen(), rng.gen())
+                    })
+                    .collect::<Vec<months_days_micros>>(),
+            ),
+            DataType::TimestampTz => TimestampTzType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        timestamp_tz::new(
+                            rng.gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX),
+                            rng.gen_range(TIMEZONE_OFFSET_MIN..=TIMEZONE_OFFSET_MAX),
+                        )
+                    })
+                    .collect::<Vec<timestamp_tz>>(),
+            ),
             DataType::Nullable(ty) => Column::Nullable(Box::new(NullableColumn {
                 column: Column::random(ty, len),
                 validity: Bitmap::from(
@@ -1170,6 +1294,14 @@ impl Column {
                     })
                     .collect_vec(),
             ),
+            DataType::Geometry => GeometryType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let (x, y): (f64, f64) = SmallRng::from_entropy().gen();
+                        geometry_to_wkb(&Geometry::Point(Point::new(x, y)))
+                    })
+                    .collect_vec(),
+            ),
             DataType::Tuple(fields) => {
                 let fields = fields
                     .iter()
@@ -1241,9 +1373,12 @@ impl Column {
             Column::String(col) => col.memory_size(),
             Column::Timestamp(col) => col.len() * 8,
             Column::Date(col) => col.len() * 4,
+            Column::Interval(col) => col.len() * 16,
+            Column::TimestampTz(col) => col.len() * 16,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Bitmap(col) => col.memory_size(),
+            Column::Geometry(col) => col.memory_size(),
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             Column::Variant(col) => col.memory_size(),
@@ -1263,10 +1398,15 @@ impl Column {
             Column::Number(NumberColumn::Int16(col)) => col.len() * 2,
             Column::Number(NumberColumn::Int32(col)) | Column::Date(col) => col.len() * 4,
             Column::Number(NumberColumn::Int64(col)) | Column::Timestamp(col) => col.len() * 8,
+            Column::Interval(col) => col.len() * 16,
+            Column::TimestampTz(col) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Boolean(c) => c.len(),
-            Column::Binary(col) | Column::Bitmap(col) | Column::Variant(col) => col.memory_size(),
+            Column::Binary(col)
+            | Column::Bitmap(col)
+            | Column::Geometry(col)
+            | Column::Variant(col) => col.memory_size(),
             Column::String(col) => col.memory_size(),
             Column::Array(col) | Column::Map(col) => col.values.serialize_size() + col.len() * 8,
             Column::Nullable(c) => c.column.serialize_size() + c.len(),
@@ -1357,6 +1497,8 @@ impl ColumnBuilder {
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
+            Column::TimestampTz(col) => ColumnBuilder::TimestampTz(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
             }
@@ -1364,6 +1506,7 @@ impl ColumnBuilder {
                 ColumnBuilder::Map(Box::new(ArrayColumnBuilder::from_column(col)))
             }
             Column::Bitmap(col) => ColumnBuilder::Bitmap(BinaryColumnBuilder::from_column(col)),
+            Column::Geometry(col) => ColumnBuilder::Geometry(BinaryColumnBuilder::from_column(col)),
             Column::Nullable(box col) => {
                 ColumnBuilder::Nullable(Box::new(NullableColumnBuilder::from_column(col)))
             }
@@ -1417,11 +1560,14 @@ impl ColumnBuilder {
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
+            ScalarRef::TimestampTz(i) => ColumnBuilder::TimestampTz(vec![*i; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
             }
             ScalarRef::Map(col) => ColumnBuilder::Map(Box::new(ArrayColumnBuilder::repeat(col, n))),
             ScalarRef::Bitmap(b) => ColumnBuilder::Bitmap(BinaryColumnBuilder::repeat(b, n)),
+            ScalarRef::Geometry(b) => ColumnBuilder::Geometry(BinaryColumnBuilder::repeat(b, n)),
             ScalarRef::Tuple(fields) => {
                 let fields_ty = match data_type {
                     DataType::Tuple(fields_ty) => fields_ty,
@@ -1451,9 +1597,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
+            ColumnBuilder::TimestampTz(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
             ColumnBuilder::Bitmap(builder) => builder.len(),
+            ColumnBuilder::Geometry(builder) => builder.len(),
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple(fields) => fields[0].len(),
             ColumnBuilder::Variant(builder) => builder.len(),
@@ -1486,9 +1635,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Timestamp(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
+            ColumnBuilder::Interval(col) => col.len() * 16,
+            ColumnBuilder::TimestampTz(col) => col.len() * 16,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
@@ -1512,6 +1664,8 @@ impl ColumnBuilder {
             ColumnBuilder::String(_) => DataType::String,
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
             ColumnBuilder::Date(_) => DataType::Date,
+            ColumnBuilder::Interval(_) => DataType::Interval,
+            ColumnBuilder::TimestampTz(_) => DataType::TimestampTz,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
                 DataType::Array(Box::new(inner))
@@ -1521,6 +1675,7 @@ impl ColumnBuilder {
                 DataType::Map(Box::new(inner))
             }
             ColumnBuilder::Bitmap(_) => DataType::Bitmap,
+            ColumnBuilder::Geometry(_) => DataType::Geometry,
             ColumnBuilder::Nullable(col) => DataType::Nullable(Box::new(col.builder.data_type())),
             ColumnBuilder::Tuple(fields) => {
                 DataType::Tuple(fields.iter().map(|f| f.data_type()).collect::<Vec<_>>())
@@ -1561,6 +1716,8 @@ impl ColumnBuilder {
             }
             DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
             DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(Vec::with_capacity(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
                 validity: MutableBitmap::with_capacity(capacity),
@@ -1596,6 +1753,10 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Bitmap(BinaryColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Geometry => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Geometry(BinaryColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Variant => {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Variant(BinaryColumnBuilder::with_capacity(capacity, data_capacity))
@@ -1626,6 +1787,10 @@ impl ColumnBuilder {
                 builder.push(value);
             }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => builder.push(value),
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                builder.push(value)
+            }
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
             }
@@ -1636,6 +1801,10 @@ impl ColumnBuilder {
                 builder.put_slice(value);
                 builder.commit_row();
             }
+            (ColumnBuilder::Geometry(builder), ScalarRef::Geometry(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
             (ColumnBuilder::Nullable(builder), ScalarRef::Null) => {
                 builder.push_null();
             }
@@ -1668,9 +1837,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
+            ColumnBuilder::Interval(builder) => builder.push(months_days_micros::default()),
+            ColumnBuilder::TimestampTz(builder) => builder.push(timestamp_tz::default()),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
             ColumnBuilder::Bitmap(builder) => builder.commit_row(),
+            ColumnBuilder::Geometry(builder) => builder.commit_row(),
             ColumnBuilder::Nullable(builder) => builder.push_null(),
             ColumnBuilder::Tuple(fields) => {
                 for field in fields {
@@ -1707,7 +1879,8 @@ impl ColumnBuilder {
             }
             ColumnBuilder::Binary(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder) => {
                 let offset = reader.read_scalar::<u64>()? as usize;
                 builder.data.resize(offset + builder.data.len(), 0);
                 let last = *builder.offsets.last().unwrap() as usize;
@@ -1733,6 +1906,12 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                builder.push(months_days_micros(i128::de_binary(reader)));
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                builder.push(timestamp_tz(i128::de_binary(reader)));
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_scalar::<u64>()?;
                 for _ in 0..len {
@@ -1803,7 +1982,8 @@ impl ColumnBuilder {
             }
             ColumnBuilder::Binary(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder) => {
                 for row in 0..rows {
                     let reader = &reader[step * row..];
                     builder.put_slice(reader);
@@ -1837,6 +2017,18 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(months_days_micros(i128::de_binary(&mut reader)));
+                }
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(timestamp_tz(i128::de_binary(&mut reader)));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -1910,9 +2102,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
+            ColumnBuilder::TimestampTz(builder) => builder.pop().map(Scalar::TimestampTz),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
             ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
+            ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
             ColumnBuilder::Nullable(builder) => Some(builder.pop()?.unwrap_or(Scalar::Null)),
             ColumnBuilder::Tuple(fields) => {
                 if fields[0].len() > 0 {
@@ -1965,6 +2160,12 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::TimestampTz(builder), Column::TimestampTz(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -1974,6 +2175,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Bitmap(builder), Column::Bitmap(other)) => {
                 builder.append_column(other);
             }
+            (ColumnBuilder::Geometry(builder), Column::Geometry(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Nullable(builder), Column::Nullable(other)) => {
                 builder.append_column(other);
             }
@@ -2003,9 +2207,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Column::String(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
+            ColumnBuilder::Interval(builder) => Column::Interval(builder.into()),
+            ColumnBuilder::TimestampTz(builder) => Column::TimestampTz(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
             ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
+            ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
             ColumnBuilder::Nullable(builder) => Column::Nullable(Box::new(builder.build())),
             ColumnBuilder::Tuple(fields) => {
                 assert!(fields.iter().map(|field| field.len()).all_equal());
@@ -2028,9 +2235,12 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
+            ColumnBuilder::Interval(builder) => Scalar::Interval(builder[0]),
+            ColumnBuilder::TimestampTz(builder) => Scalar::TimestampTz(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
             ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
+            ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
             ColumnBuilder::Nullable(builder) => builder.build_scalar().unwrap_or(Scalar::Null),
             ColumnBuilder::Tuple(fields) => Scalar::Tuple(
                 fields
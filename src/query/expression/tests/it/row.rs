@@ -210,6 +210,34 @@ fn test_bool() {
     }
 }
 
+#[test]
+fn test_timestamp_tz() {
+    use databend_common_expression::types::timestamp_tz::timestamp_tz;
+
+    let converter =
+        RowConverter::new(vec![SortField::new(DataType::TimestampTz.wrap_nullable())]).unwrap();
+
+    // 00:00:00+00:00, 08:00:00+08:00, 00:00:01+00:00 and 09:00:00+09:00.
+    let col = TimestampTzType::from_opt_data(vec![
+        Some(timestamp_tz::new(0, 0)),
+        Some(timestamp_tz::new(0, 8 * 3600)),
+        Some(timestamp_tz::new(1_000_000, 0)),
+        None,
+        Some(timestamp_tz::new(1_000_000, 9 * 3600)),
+    ]);
+    let num_rows = col.len();
+
+    let rows = converter.convert_columns(&[col], num_rows);
+
+    unsafe {
+        // Only the instant is compared, the offset is ignored.
+        assert_eq!(rows.index_unchecked(0), rows.index_unchecked(1));
+        assert_eq!(rows.index_unchecked(2), rows.index_unchecked(4));
+        assert!(rows.index_unchecked(2) > rows.index_unchecked(1));
+        assert!(rows.index_unchecked(3) < rows.index_unchecked(0));
+    }
+}
+
 #[test]
 fn test_null_encoding() {
     let col = Column::Null { len: 10 };
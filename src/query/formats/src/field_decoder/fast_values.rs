@@ -33,10 +33,14 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::NumberColumnBuilder;
 use databend_common_expression::with_decimal_type;
@@ -54,6 +58,7 @@ use databend_common_io::cursor_ext::DateTimeResType;
 use databend_common_io::cursor_ext::ReadBytesExt;
 use databend_common_io::cursor_ext::ReadCheckPointExt;
 use databend_common_io::cursor_ext::ReadNumberExt;
+use databend_common_io::geometry::parse_to_wkb;
 use databend_common_io::parse_bitmap;
 use databend_common_io::prelude::FormatSettings;
 use jsonb::parse_value;
@@ -145,6 +150,8 @@ impl FastFieldDecoderValues {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, positions),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, positions),
+            ColumnBuilder::Interval(c) => self.read_interval(c, reader, positions),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, reader, positions),
             ColumnBuilder::Binary(_c) => todo!("new string"),
             ColumnBuilder::String(c) => self.read_string(c, reader, positions),
             ColumnBuilder::Array(c) => self.read_array(c, reader, positions),
@@ -152,6 +159,7 @@ impl FastFieldDecoderValues {
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, reader, positions),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, positions),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, positions),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, positions),
             _ => unimplemented!(),
         }
     }
@@ -318,6 +326,37 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_interval<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<months_days_micros>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let interval =
+            string_to_interval(buf.to_str().unwrap_or("not utf8")).map_err(ErrorCode::BadBytes)?;
+        column.push(interval);
+        Ok(())
+    }
+
+    fn read_timestamp_tz<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<timestamp_tz>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let ts = string_to_timestamp_tz(
+            buf.to_str().unwrap_or("not utf8"),
+            self.common_settings().timezone,
+        )
+        .map_err(ErrorCode::BadBytes)?;
+        column.push(ts);
+        Ok(())
+    }
+
     fn read_array<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayColumnBuilder<AnyType>,
@@ -451,6 +490,20 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut BinaryColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let wkb = parse_to_wkb(&buf)?;
+        column.put_slice(&wkb);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant<R: AsRef<[u8]>>(
         &self,
         column: &mut BinaryColumnBuilder,
@@ -27,10 +27,14 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::NumberColumnBuilder;
 use databend_common_expression::with_decimal_type;
@@ -38,13 +42,16 @@ use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
 use databend_common_io::cursor_ext::BufferReadDateTimeExt;
 use databend_common_io::cursor_ext::DateTimeResType;
+use databend_common_io::geometry::parse_to_wkb;
 use databend_common_io::parse_bitmap;
+use databend_common_meta_app::principal::BinaryFormat;
 use lexical_core::FromLexical;
 use num::cast::AsPrimitive;
 use num_traits::NumCast;
 use roaring::RoaringTreemap;
 use serde_json::Value;
 
+use crate::binary::decode_binary;
 use crate::FieldDecoder;
 use crate::FileFormatOptionsExt;
 
@@ -92,13 +99,16 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
-            ColumnBuilder::Binary(_c) => unimplemented!("binary literal is not supported"),
+            ColumnBuilder::Interval(c) => self.read_interval(c, value),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
             ColumnBuilder::String(c) => self.read_string(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, value),
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, value),
             _ => unimplemented!(),
         }
     }
@@ -241,6 +251,20 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_binary(&self, column: &mut BinaryColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(s) => {
+                let data = decode_binary(s.as_bytes(), BinaryFormat::Hex)?;
+                column.put_slice(&data);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes(
+                "Incorrect json value, must be hex string",
+            )),
+        }
+    }
+
     fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -263,6 +287,28 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let interval = string_to_interval(v).map_err(ErrorCode::BadBytes)?;
+                column.push(interval);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect interval value")),
+        }
+    }
+
+    fn read_timestamp_tz(&self, column: &mut Vec<timestamp_tz>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let ts = string_to_timestamp_tz(v, self.timezone).map_err(ErrorCode::BadBytes)?;
+                column.push(ts);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect timestamp_tz value")),
+        }
+    }
+
     fn read_timestamp(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -294,6 +340,17 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_geometry(&self, column: &mut BinaryColumnBuilder, value: &Value) -> Result<()> {
+        let wkb = match value {
+            Value::String(v) => parse_to_wkb(v.as_bytes())?,
+            Value::Object(_) => parse_to_wkb(value.to_string().as_bytes())?,
+            _ => return Err(ErrorCode::BadBytes("Incorrect Geometry value")),
+        };
+        column.put_slice(&wkb);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_bitmap(&self, column: &mut BinaryColumnBuilder, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
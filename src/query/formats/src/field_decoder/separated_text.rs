@@ -27,8 +27,12 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::Number;
 use databend_common_expression::types::NumberColumnBuilder;
@@ -48,6 +52,7 @@ use databend_common_io::cursor_ext::read_num_text_exact;
 use databend_common_io::cursor_ext::BufferReadDateTimeExt;
 use databend_common_io::cursor_ext::DateTimeResType;
 use databend_common_io::cursor_ext::ReadBytesExt;
+use databend_common_io::geometry::parse_to_wkb;
 use databend_common_io::parse_bitmap;
 use databend_common_meta_app::principal::CsvFileFormatParams;
 use databend_common_meta_app::principal::TsvFileFormatParams;
@@ -178,11 +183,14 @@ impl SeparatedTextDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, data),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, data),
+            ColumnBuilder::Interval(c) => self.read_interval(c, data),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, data),
             ColumnBuilder::Array(c) => self.read_array(c, data),
             ColumnBuilder::Map(c) => self.read_map(c, data),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, data),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, data),
             ColumnBuilder::Variant(c) => self.read_variant(c, data),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, data),
             ColumnBuilder::EmptyArray { .. } => {
                 unreachable!("EmptyArray")
             }
@@ -291,6 +299,23 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, data: &[u8]) -> Result<()> {
+        let interval =
+            string_to_interval(data.to_str().unwrap_or("not utf8")).map_err(ErrorCode::BadBytes)?;
+        column.push(interval);
+        Ok(())
+    }
+
+    fn read_timestamp_tz(&self, column: &mut Vec<timestamp_tz>, data: &[u8]) -> Result<()> {
+        let ts = string_to_timestamp_tz(
+            data.to_str().unwrap_or("not utf8"),
+            self.common_settings().timezone,
+        )
+        .map_err(ErrorCode::BadBytes)?;
+        column.push(ts);
+        Ok(())
+    }
+
     fn read_timestamp(&self, column: &mut Vec<i64>, data: &[u8]) -> Result<()> {
         let ts = if !data.contains(&b'-') {
             read_num_text_exact(data)?
@@ -325,6 +350,13 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_geometry(&self, column: &mut BinaryColumnBuilder, data: &[u8]) -> Result<()> {
+        let wkb = parse_to_wkb(data)?;
+        column.put_slice(&wkb);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant(&self, column: &mut BinaryColumnBuilder, data: &[u8]) -> Result<()> {
         match parse_value(data) {
             Ok(value) => {
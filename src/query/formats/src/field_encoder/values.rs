@@ -19,19 +19,24 @@ use databend_common_expression::types::array::ArrayColumn;
 use databend_common_expression::types::binary::BinaryColumn;
 use databend_common_expression::types::date::date_to_string;
 use databend_common_expression::types::decimal::DecimalColumn;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::string::StringColumn;
 use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
 use databend_common_expression::types::NumberColumn;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::Column;
+use databend_common_io::constants::FALSE_BYTES_LOWER;
 use databend_common_io::constants::FALSE_BYTES_NUM;
 use databend_common_io::constants::INF_BYTES_LONG;
 use databend_common_io::constants::INF_BYTES_LOWER;
 use databend_common_io::constants::NAN_BYTES_LOWER;
 use databend_common_io::constants::NAN_BYTES_SNAKE;
 use databend_common_io::constants::NULL_BYTES_UPPER;
+use databend_common_io::constants::TRUE_BYTES_LOWER;
 use databend_common_io::constants::TRUE_BYTES_NUM;
+use databend_common_io::geometry::wkb_to_wkt;
 use lexical_core::ToLexical;
 use micromarshal::Marshal;
 use micromarshal::Unmarshal;
@@ -97,6 +102,23 @@ impl FieldEncoderValues {
         }
     }
 
+    // PostgreSQL accepts "true" and "false" as the text of BOOLEAN, and the clients
+    // parse "NaN" and "Infinity" as float.
+    pub fn create_for_postgres_handler(timezone: Tz) -> Self {
+        FieldEncoderValues {
+            common_settings: OutputCommonSettings {
+                true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
+                false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
+                null_bytes: NULL_BYTES_UPPER.as_bytes().to_vec(),
+                nan_bytes: NAN_BYTES_SNAKE.as_bytes().to_vec(),
+                inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
+                timezone,
+                binary_format: Default::default(),
+            },
+            quote_char: b'\'',
+        }
+    }
+
     pub fn write_field(
         &self,
         column: &Column,
@@ -129,8 +151,11 @@ impl FieldEncoderValues {
             Column::String(c) => self.write_string(c, row_index, out_buf, in_nested),
             Column::Date(c) => self.write_date(c, row_index, out_buf, in_nested),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, in_nested),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, in_nested),
+            Column::TimestampTz(c) => self.write_timestamp_tz(c, row_index, out_buf, in_nested),
             Column::Bitmap(b) => self.write_bitmap(b, row_index, out_buf, in_nested),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, in_nested),
+            Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, in_nested),
 
             Column::Array(box c) => self.write_array(c, row_index, out_buf),
             Column::Map(box c) => self.write_map(c, row_index, out_buf),
@@ -262,6 +287,42 @@ impl FieldEncoderValues {
         self.write_string_inner(s.as_bytes(), out_buf, in_nested);
     }
 
+    fn write_interval(
+        &self,
+        column: &Buffer<months_days_micros>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = v.to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
+    fn write_timestamp_tz(
+        &self,
+        column: &Buffer<timestamp_tz>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = v.to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
+    fn write_geometry(
+        &self,
+        column: &BinaryColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = wkb_to_wkt(v).unwrap_or_else(|_| format!("0x{}", hex::encode(v)));
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
     fn write_bitmap(
         &self,
         _column: &BinaryColumn,
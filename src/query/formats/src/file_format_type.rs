@@ -16,10 +16,12 @@ use chrono_tz::Tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
+use databend_common_meta_app::principal::ArrowIpcFormat;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_settings::Settings;
 
+use crate::output_format::ArrowOutputFormat;
 use crate::output_format::CSVOutputFormat;
 use crate::output_format::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format::CSVWithNamesOutputFormat;
@@ -96,7 +98,12 @@ impl FileFormatOptionsExt {
         schema: TableSchemaRef,
         settings: &Settings,
     ) -> Result<Box<dyn OutputFormat>> {
-        let params = FileFormatParams::default_by_type(typ.typ.clone())?;
+        let mut params = FileFormatParams::default_by_type(typ.typ.clone())?;
+        if let FileFormatParams::Arrow(p) = &mut params {
+            if typ.suffixes.is_stream {
+                p.ipc_format = ArrowIpcFormat::Stream;
+            }
+        }
         let mut options = FileFormatOptionsExt::create_from_clickhouse_format(typ, settings)?;
         options.get_output_format(schema, params)
     }
@@ -166,8 +173,13 @@ impl FileFormatOptionsExt {
                     _ => unreachable!(),
                 }
             }
-            FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
+            FileFormatParams::Parquet(params) => {
+                Box::new(ParquetOutputFormat::create(schema, params, self))
+            }
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
+            FileFormatParams::Arrow(params) => {
+                Box::new(ArrowOutputFormat::create(schema, params, self))
+            }
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
                     "Unsupported output file format:{:?}",
@@ -185,6 +197,7 @@ impl FileFormatTypeExt for StageFileFormatType {
             StageFileFormatType::Tsv => "text/tab-separated-values; charset=UTF-8",
             StageFileFormatType::Csv => "text/csv; charset=UTF-8",
             StageFileFormatType::Parquet => "application/octet-stream",
+            StageFileFormatType::Arrow => "application/vnd.apache.arrow.file",
             StageFileFormatType::NdJson => "application/x-ndjson; charset=UTF-8",
             StageFileFormatType::Json => "application/json; charset=UTF-8",
             _ => "text/plain; charset=UTF-8",
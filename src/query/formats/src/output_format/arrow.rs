@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_ipc::writer::FileWriter;
+use arrow_ipc::writer::StreamWriter;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+use databend_common_meta_app::principal::ArrowFileFormatParams;
+use databend_common_meta_app::principal::ArrowIpcFormat;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+/// Writes all the blocks as one Arrow IPC file or stream when finalized.
+pub struct ArrowOutputFormat {
+    schema: TableSchemaRef,
+    params: ArrowFileFormatParams,
+    data_blocks: Vec<DataBlock>,
+}
+
+impl ArrowOutputFormat {
+    pub fn create(
+        schema: TableSchemaRef,
+        params: &ArrowFileFormatParams,
+        _options: &FileFormatOptionsExt,
+    ) -> Self {
+        Self {
+            schema,
+            params: params.clone(),
+            data_blocks: vec![],
+        }
+    }
+}
+
+impl OutputFormat for ArrowOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        self.data_blocks.push(block.clone());
+        Ok(vec![])
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        self.data_blocks.iter().map(|b| b.memory_size()).sum()
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        let blocks = std::mem::take(&mut self.data_blocks);
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        let data_schema = DataSchema::from(self.schema.clone());
+        let batches = blocks
+            .into_iter()
+            .map(|block| block.to_record_batch(&data_schema))
+            .collect::<Result<Vec<_>>>()?;
+        let arrow_schema = batches[0].schema();
+
+        let buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
+        let buf = match self.params.ipc_format {
+            ArrowIpcFormat::File => {
+                let mut writer = FileWriter::try_new(buf, &arrow_schema)?;
+                for batch in &batches {
+                    writer.write(batch)?;
+                }
+                writer.finish()?;
+                writer.into_inner()?
+            }
+            ArrowIpcFormat::Stream => {
+                let mut writer = StreamWriter::try_new(buf, &arrow_schema)?;
+                for batch in &batches {
+                    writer.write(batch)?;
+                }
+                writer.finish()?;
+                writer.into_inner()?
+            }
+        };
+        Ok(buf)
+    }
+}
@@ -17,6 +17,7 @@ use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::DataBlock;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
+use databend_common_io::geometry::wkb_to_wkt;
 use databend_common_io::prelude::FormatSettings;
 use roaring::RoaringTreemap;
 use serde_json::Map as JsonMap;
@@ -96,6 +97,8 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => JsonValue::String(v.to_string()),
+        ScalarRef::TimestampTz(v) => JsonValue::String(v.to_string()),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
@@ -141,6 +144,9 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let b = jsonb::from_slice(x).unwrap();
             b.into()
         }
+        ScalarRef::Geometry(x) => {
+            JsonValue::String(wkb_to_wkt(x).unwrap_or_else(|_| hex::encode_upper(x)))
+        }
     }
 }
 
@@ -12,29 +12,69 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::Schema as ArrowSchema;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
-use databend_storages_common_blocks::blocks_to_parquet;
-use databend_storages_common_table_meta::table::TableCompression;
+use databend_common_meta_app::principal::ParquetCompression;
+use databend_common_meta_app::principal::ParquetFileFormatParams;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::basic::GzipLevel;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::EnabledStatistics;
+use parquet::file::properties::WriterProperties;
 
 use crate::output_format::OutputFormat;
 use crate::FileFormatOptionsExt;
 
-#[derive(Default)]
 pub struct ParquetOutputFormat {
     schema: TableSchemaRef,
+    params: ParquetFileFormatParams,
     data_blocks: Vec<DataBlock>,
 }
 
 impl ParquetOutputFormat {
-    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+    pub fn create(
+        schema: TableSchemaRef,
+        params: &ParquetFileFormatParams,
+        _options: &FileFormatOptionsExt,
+    ) -> Self {
         Self {
             schema,
+            params: params.clone(),
             data_blocks: vec![],
         }
     }
+
+    fn writer_properties(&self) -> WriterProperties {
+        let compression = match self.params.compression {
+            ParquetCompression::None => Compression::UNCOMPRESSED,
+            ParquetCompression::Snappy => Compression::SNAPPY,
+            ParquetCompression::Gzip => Compression::GZIP(GzipLevel::default()),
+            ParquetCompression::Lz4 => Compression::LZ4_RAW,
+            ParquetCompression::Zstd => Compression::ZSTD(ZstdLevel::default()),
+        };
+        // column index and offset index are only written with page level statistics.
+        let statistics = if self.params.enable_page_index {
+            EnabledStatistics::Page
+        } else {
+            EnabledStatistics::Chunk
+        };
+        let mut builder = WriterProperties::builder()
+            .set_compression(compression)
+            .set_dictionary_enabled(self.params.enable_dictionary)
+            .set_statistics_enabled(statistics)
+            .set_bloom_filter_enabled(self.params.enable_bloom_filter);
+        if self.params.row_group_size > 0 {
+            builder = builder.set_max_row_group_size(self.params.row_group_size as usize);
+        }
+        builder.build()
+    }
 }
 
 impl OutputFormat for ParquetOutputFormat {
@@ -52,8 +92,35 @@ impl OutputFormat for ParquetOutputFormat {
         if blocks.is_empty() {
             return Ok(vec![]);
         }
+        let arrow_schema = Arc::new(ArrowSchema::from(self.schema.as_ref()));
         let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let _ = blocks_to_parquet(&self.schema, blocks, &mut buf, TableCompression::Zstd)?;
+        let mut writer = ArrowWriter::try_new(
+            &mut buf,
+            arrow_schema.clone(),
+            Some(self.writer_properties()),
+        )?;
+        for block in blocks {
+            let columns = block
+                .convert_to_full()
+                .columns()
+                .iter()
+                .map(|entry| {
+                    entry
+                        .value
+                        .to_owned()
+                        .into_column()
+                        .unwrap()
+                        .into_arrow_rs()
+                })
+                .collect::<Vec<_>>();
+            let batch = RecordBatch::try_new(arrow_schema.clone(), columns)?;
+            writer.write(&batch)?;
+            if self.params.row_group_size == 0 {
+                // keep one row group for each data block.
+                writer.flush()?;
+            }
+        }
+        writer.close()?;
         Ok(buf)
     }
 }
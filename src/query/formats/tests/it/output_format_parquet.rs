@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use bytes::Bytes;
+use databend_common_exception::Result;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_meta_app::principal::FileFormatOptionsAst;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_settings::Settings;
+use parquet::basic::Compression;
+use parquet::basic::Type as PhysicalType;
+use parquet::basic::ZstdLevel;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::reader::FileReader;
+use parquet::file::reader::SerializedFileReader;
+
+use crate::output_format_utils::get_simple_block;
+
+fn unload_parquet(options: &[(&str, &str)], num_blocks: usize) -> Result<ParquetMetaData> {
+    let (schema, block) = get_simple_block(false);
+    let settings = Settings::create("default".to_string());
+    let mut ast = BTreeMap::<String, String>::new();
+    ast.insert("type".to_string(), "parquet".to_string());
+    for (k, v) in options {
+        ast.insert(k.to_string(), v.to_string());
+    }
+    let params = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(ast), false)?;
+    let mut options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    let mut output_format = options.get_output_format(schema, params)?;
+    for _ in 0..num_blocks {
+        output_format.serialize_block(&block)?;
+    }
+    let buffer = output_format.finalize()?;
+    let reader = SerializedFileReader::new(Bytes::from(buffer))?;
+    Ok(reader.metadata().clone())
+}
+
+#[test]
+fn test_parquet_default_options() -> Result<()> {
+    let meta = unload_parquet(&[], 2)?;
+
+    // One row group for each data block.
+    assert_eq!(meta.file_metadata().num_rows(), 6);
+    assert_eq!(meta.num_row_groups(), 2);
+    for col in meta.row_groups().iter().flat_map(|grp| grp.columns()) {
+        assert_eq!(col.compression(), Compression::ZSTD(ZstdLevel::default()));
+        assert!(col.dictionary_page_offset().is_none());
+        // The arrow writer always builds the column index of byte arrays.
+        if col.column_type() != PhysicalType::BYTE_ARRAY {
+            assert!(col.column_index_offset().is_none());
+        }
+        assert!(col.bloom_filter_offset().is_none());
+        assert!(col.statistics().is_some());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parquet_writer_options() -> Result<()> {
+    let meta = unload_parquet(
+        &[
+            ("compression", "snappy"),
+            ("row_group_size", "4"),
+            ("enable_dictionary", "true"),
+            ("enable_page_index", "true"),
+            ("enable_bloom_filter", "true"),
+        ],
+        2,
+    )?;
+
+    assert_eq!(meta.file_metadata().num_rows(), 6);
+    let rows = meta
+        .row_groups()
+        .iter()
+        .map(|grp| grp.num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![4, 2]);
+    for col in meta.row_groups().iter().flat_map(|grp| grp.columns()) {
+        assert_eq!(col.compression(), Compression::SNAPPY);
+        // Booleans are never dictionary encoded.
+        if col.column_type() != PhysicalType::BOOLEAN {
+            assert!(col.dictionary_page_offset().is_some());
+        }
+        assert!(col.column_index_offset().is_some());
+        assert!(col.bloom_filter_offset().is_some());
+    }
+
+    let meta = unload_parquet(&[("compression", "none")], 1)?;
+    assert_eq!(meta.num_row_groups(), 1);
+    for col in meta.row_groups()[0].columns() {
+        assert_eq!(col.compression(), Compression::UNCOMPRESSED);
+    }
+    Ok(())
+}
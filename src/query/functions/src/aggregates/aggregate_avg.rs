@@ -21,6 +21,7 @@ use borsh::BorshSerialize;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::decimal::*;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::*;
 use databend_common_expression::utils::arithmetics_type::ResultTypeOfUnary;
 use databend_common_expression::with_number_mapped_type;
@@ -213,6 +214,54 @@ where
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+struct IntervalAvgState {
+    pub value: months_days_micros,
+    pub count: u64,
+}
+
+impl UnaryState<IntervalType, IntervalType> for IntervalAvgState {
+    fn add(&mut self, other: months_days_micros) -> Result<()> {
+        self.count += 1;
+        self.value = self
+            .value
+            .checked_add(other)
+            .ok_or_else(|| ErrorCode::Overflow("Interval overflow"))?;
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.count += rhs.count;
+        self.value = self
+            .value
+            .checked_add(rhs.value)
+            .ok_or_else(|| ErrorCode::Overflow("Interval overflow"))?;
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<months_days_micros>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        let value = self
+            .value
+            .checked_div(self.count as i64)
+            .ok_or_else(|| ErrorCode::Overflow("Interval overflow"))?;
+        builder.push(value);
+        Ok(())
+    }
+
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        borsh_serialize_state(writer, self)
+    }
+
+    fn deserialize(reader: &mut &[u8]) -> Result<Self>
+    where Self: Sized {
+        borsh_deserialize_state(reader)
+    }
+}
+
 pub fn try_create_aggregate_avg_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -303,6 +352,15 @@ pub fn try_create_aggregate_avg_function(
                 Ok(Arc::new(func))
             }
         }
+        DataType::Interval => {
+            let return_type = IntervalType::data_type();
+            AggregateUnaryFunction::<IntervalAvgState, IntervalType, IntervalType>::try_create_unary(
+                display_name,
+                return_type,
+                params,
+                arguments[0].clone(),
+            )
+        }
         _ => Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
             display_name, arguments[0]
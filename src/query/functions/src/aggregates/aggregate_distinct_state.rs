@@ -78,6 +78,15 @@ pub struct AggregateDistinctStringState {
     set: ShortStringHashSet<[u8]>,
 }
 
+/// The value of the row as the distinct key, timestamp_tz values of the same instant
+/// are normalized to the same key.
+fn distinct_value(col: &Column, row: usize) -> Scalar {
+    match unsafe { AnyType::index_column_unchecked(col, row).to_owned() } {
+        Scalar::TimestampTz(v) => Scalar::TimestampTz(v.normalize()),
+        v => v,
+    }
+}
+
 impl DistinctStateFunc for AggregateDistinctState {
     fn new() -> Self {
         AggregateDistinctState {
@@ -105,7 +114,7 @@ impl DistinctStateFunc for AggregateDistinctState {
     fn add(&mut self, columns: &[Column], row: usize) -> Result<()> {
         let values = columns
             .iter()
-            .map(|col| unsafe { AnyType::index_column_unchecked(col, row).to_owned() })
+            .map(|col| distinct_value(col, row))
             .collect::<Vec<_>>();
         let mut buffer = Vec::with_capacity(values.len() * std::mem::size_of::<Scalar>());
         borsh_serialize_state(&mut buffer, &values)?;
@@ -123,7 +132,7 @@ impl DistinctStateFunc for AggregateDistinctState {
             if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
                 let values = columns
                     .iter()
-                    .map(|col| unsafe { AnyType::index_column_unchecked(col, row).to_owned() })
+                    .map(|col| distinct_value(col, row))
                     .collect::<Vec<_>>();
 
                 let mut buffer = Vec::with_capacity(values.len() * std::mem::size_of::<Scalar>());
@@ -18,6 +18,7 @@ use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::decimal::*;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::number::*;
 use databend_common_expression::types::*;
 use databend_common_expression::utils::arithmetics_type::ResultTypeOfUnary;
@@ -183,6 +184,44 @@ where
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+pub struct IntervalSumState {
+    pub value: months_days_micros,
+}
+
+impl UnaryState<IntervalType, IntervalType> for IntervalSumState {
+    fn add(&mut self, other: months_days_micros) -> Result<()> {
+        self.value = self
+            .value
+            .checked_add(other)
+            .ok_or_else(|| ErrorCode::Overflow("Interval overflow"))?;
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.add(rhs.value)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<months_days_micros>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        builder.push(self.value);
+        Ok(())
+    }
+
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        borsh_serialize_state(writer, &self.value)
+    }
+
+    fn deserialize(reader: &mut &[u8]) -> Result<Self>
+    where Self: Sized {
+        let value = borsh_deserialize_state(reader)?;
+        Ok(Self { value })
+    }
+}
+
 pub fn try_create_aggregate_sum_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -263,6 +302,15 @@ pub fn try_create_aggregate_sum_function(
                 )
             }
         }
+        DataType::Interval => {
+            let return_type = IntervalType::data_type();
+            AggregateUnaryFunction::<IntervalSumState, IntervalType, IntervalType>::try_create_unary(
+                display_name,
+                return_type,
+                params,
+                arguments[0].clone(),
+            )
+        }
         _ => Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
             display_name, arguments[0]
@@ -71,8 +71,11 @@ pub const GENERAL_CAST_RULES: AutoCastRules = &[
     (DataType::String, DataType::Binary),
     (DataType::String, DataType::Timestamp),
     (DataType::String, DataType::Date),
+    (DataType::String, DataType::Interval),
+    (DataType::String, DataType::Geometry),
     (DataType::String, DataType::Boolean),
     (DataType::Date, DataType::Timestamp),
+    (DataType::TimestampTz, DataType::Timestamp),
     (
         DataType::Number(NumberDataType::UInt8),
         DataType::Number(NumberDataType::UInt16),
@@ -27,6 +27,8 @@ use databend_common_expression::types::date::date_to_string;
 use databend_common_expression::types::date::string_to_date;
 use databend_common_expression::types::date::DATE_MAX;
 use databend_common_expression::types::date::DATE_MIN;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::Int64Type;
@@ -41,13 +43,17 @@ use databend_common_expression::types::timestamp::string_to_timestamp;
 use databend_common_expression::types::timestamp::timestamp_to_string;
 use databend_common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use databend_common_expression::types::timestamp::MICROS_IN_A_SEC;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::timestamp_tz::timestamp_tz;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::Float64Type;
 use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::IntervalType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::TimestampTzType;
 use databend_common_expression::types::F64;
 use databend_common_expression::utils::date_helper::*;
 use databend_common_expression::vectorize_1_arg;
@@ -75,6 +81,11 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_timestamp_to_date(registry);
     register_number_to_date(registry);
 
+    // cast(xx AS timestamp_tz), to_timestamp_tz(xx), convert_timezone(tz, xx)
+    // `to_string(timestamp_tz)` must be registered before `to_string(timestamp)`,
+    // otherwise the offset is lost by the auto cast from timestamp_tz to timestamp.
+    register_timestamp_tz_functions(registry);
+
     // cast([date | timestamp] AS string)
     // to_string([date | timestamp])
     register_to_string(registry);
@@ -100,6 +111,10 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
+
+    // cast(xx AS interval), to_interval(xx)
+    // [date | timestamp | interval] +/- interval
+    register_interval_functions(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -232,21 +247,21 @@ fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
             output.push(calc_date_to_timestamp(val, tz));
         })(val, ctx)
     }
+}
 
-    fn calc_date_to_timestamp(val: i32, tz: Tz) -> i64 {
-        let ts = (val as i64) * 24 * 3600 * MICROS_IN_A_SEC;
-        let epoch_time_with_ltz = tz
-            .from_utc_datetime(
-                &NaiveDate::from_ymd_opt(1970, 1, 1)
-                    .unwrap()
-                    .and_hms_micro_opt(0, 0, 0, 0)
-                    .unwrap(),
-            )
-            .naive_local()
-            .timestamp_micros();
-
-        ts - epoch_time_with_ltz
-    }
+fn calc_date_to_timestamp(val: i32, tz: Tz) -> i64 {
+    let ts = (val as i64) * 24 * 3600 * MICROS_IN_A_SEC;
+    let epoch_time_with_ltz = tz
+        .from_utc_datetime(
+            &NaiveDate::from_ymd_opt(1970, 1, 1)
+                .unwrap()
+                .and_hms_micro_opt(0, 0, 0, 0)
+                .unwrap(),
+        )
+        .naive_local()
+        .timestamp_micros();
+
+    ts - epoch_time_with_ltz
 }
 
 fn register_number_to_timestamp(registry: &mut FunctionRegistry) {
@@ -1199,7 +1214,7 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         ),
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
         "minus",
         |_, lhs, rhs| {
             (|| {
@@ -1208,14 +1223,14 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
                 let rm = rhs.max;
                 let rn = rhs.min;
 
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
+                Some(FunctionDomain::Domain(SimpleDomain::<months_days_micros> {
+                    min: months_days_micros::new(0, 0, ln.checked_sub(rm)?),
+                    max: months_days_micros::new(0, 0, lm.checked_sub(rn)?),
                 }))
             })()
             .unwrap_or(FunctionDomain::Full)
         },
-        |a, b, _| a - b,
+        |a, b, _| months_days_micros::new(0, 0, a - b),
     );
 
     registry.register_passthrough_nullable_2_arg::<DateType, DateType, Float64Type, _, _>(
@@ -1476,3 +1491,315 @@ fn months_between(date_a: i32, date_b: i32) -> f64 {
     // Total difference including fractional part
     total_months_diff as f64 + day_fraction
 }
+
+fn register_interval_functions(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(months_days_micros::default());
+                }
+            }
+        })(val, ctx)
+    }
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{val}").unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|val, output, ctx| {
+            match val.checked_neg() {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_add(b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_sub(b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |a, b, output, ctx| match add_interval(a, b, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |a, b, output, ctx| match add_interval(b, a, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |a, b, output, ctx| {
+                    let res = b
+                        .checked_neg()
+                        .ok_or_else(|| "interval out of range".to_string())
+                        .and_then(|b| add_interval(a, b, ctx.func_ctx.tz));
+                    match res {
+                        Ok(v) => output.push(v),
+                        Err(err) => {
+                            ctx.set_error(output.len(), err);
+                            output.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |a, b, output, ctx| match add_interval(
+                calc_date_to_timestamp(a, ctx.func_ctx.tz.tz),
+                b,
+                ctx.func_ctx.tz,
+            ) {
+                Ok(v) => output.push(v),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |a, b, output, ctx| {
+                let res = b
+                    .checked_neg()
+                    .ok_or_else(|| "interval out of range".to_string())
+                    .and_then(|b| {
+                        add_interval(
+                            calc_date_to_timestamp(a, ctx.func_ctx.tz.tz),
+                            b,
+                            ctx.func_ctx.tz,
+                        )
+                    });
+                match res {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    /// Add the months, days and microseconds of the interval to the timestamp in order,
+    /// the same as `add_months`, `add_days` and adding microseconds one by one.
+    fn add_interval(us: i64, interval: months_days_micros, tz: TzLUT) -> Result<i64, String> {
+        let mut us = us;
+        if interval.months() != 0 {
+            us = AddMonthsImpl::eval_timestamp(us, tz, interval.months())?;
+        }
+        if interval.days() != 0 {
+            us = AddDaysImpl::eval_timestamp(us, interval.days())?;
+        }
+        let us = us
+            .checked_add(interval.microseconds())
+            .ok_or_else(|| "timestamp out of range".to_string())?;
+        check_timestamp(us)
+    }
+}
+
+fn register_timestamp_tz_functions(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_timestamp_tz),
+    );
+
+    fn eval_string_to_timestamp_tz(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<StringType, TimestampTzType>(|val, output, ctx| {
+            match string_to_timestamp_tz(val, ctx.func_ctx.tz.tz) {
+                Ok(ts) => output.push(ts),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(timestamp_tz::default());
+                }
+            }
+        })(val, ctx)
+    }
+
+    // The offset of the session timezone at the instant is kept.
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampType, TimestampTzType>(|val, ctx| {
+            timestamp_tz::from_timestamp(val, ctx.func_ctx.tz.tz)
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampType, NullableType<TimestampTzType>>(
+            |val, output, ctx| {
+                output.push(timestamp_tz::from_timestamp(val, ctx.func_ctx.tz.tz));
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "to_timestamp",
+        |_, domain| {
+            FunctionDomain::Domain(SimpleDomain {
+                min: domain.min.timestamp(),
+                max: domain.max.timestamp(),
+            })
+        },
+        vectorize_1_arg::<TimestampTzType, TimestampType>(|val, _| val.timestamp()),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "try_to_timestamp",
+        |_, domain| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(SimpleDomain {
+                    min: domain.min.timestamp(),
+                    max: domain.max.timestamp(),
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<TimestampTzType, NullableType<TimestampType>>(
+            |val, output, _| output.push(val.timestamp()),
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, StringType>(|val, output, _| {
+            write!(output.data, "{val}").unwrap();
+            output.commit_row();
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, NullableType<StringType>>(
+            |val, output, _| {
+                write!(output.builder.data, "{val}").unwrap();
+                output.builder.commit_row();
+                output.validity.push(true);
+            },
+        ),
+    );
+
+    // convert_timezone(target_tz, ts): the same instant at the offset of the target timezone.
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampTzType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampTzType, TimestampTzType>(
+                |tz, val, output, ctx| match tz.parse::<Tz>() {
+                    Ok(tz) => output.push(timestamp_tz::from_timestamp(val.timestamp(), tz)),
+                    Err(_) => {
+                        ctx.set_error(output.len(), format!("invalid timezone '{tz}'"));
+                        output.push(timestamp_tz::default());
+                    }
+                },
+            ),
+        );
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampType, TimestampTzType>(
+                |tz, val, output, ctx| match tz.parse::<Tz>() {
+                    Ok(tz) => output.push(timestamp_tz::from_timestamp(val, tz)),
+                    Err(_) => {
+                        ctx.set_error(output.len(), format!("invalid timezone '{tz}'"));
+                        output.push(timestamp_tz::default());
+                    }
+                },
+            ),
+        );
+}
@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::F64;
+use databend_common_expression::vectorize_with_builder_3_arg;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_io::tokenizer::Tokenizer;
+
+// The full-text search functions take the text, the query and the tokenizer of the inverted
+// index. The tokenizer is filled in by the type checker from the inverted index of the column,
+// users call them as `match(col, 'query')` and `score()`.
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, BooleanType>(
+            |text, query, tokenizer, output, ctx| match tokenizer.parse::<Tokenizer>() {
+                Ok(tokenizer) => output.push(tokenizer.matches(text, query)),
+                Err(err) => {
+                    ctx.set_error(output.len(), err.message());
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, Float64Type, _, _>(
+        "score",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, Float64Type>(
+            |text, query, tokenizer, output, ctx| match tokenizer.parse::<Tokenizer>() {
+                Ok(tokenizer) => output.push(F64::from(tokenizer.score(text, query))),
+                Err(err) => {
+                    ctx.set_error(output.len(), err.message());
+                    output.push(F64::from(0.0));
+                }
+            },
+        ),
+    );
+}
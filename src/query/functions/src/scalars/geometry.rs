@@ -0,0 +1,471 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::geometry::GeometryDomain;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::GeometryType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::types::F64;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use databend_common_io::geometry::geojson_to_geometry;
+use databend_common_io::geometry::geometry_to_geojson;
+use databend_common_io::geometry::geometry_to_wkb;
+use databend_common_io::geometry::geometry_to_wkt;
+use databend_common_io::geometry::parse_to_wkb;
+use databend_common_io::geometry::wkb_to_geometry;
+use databend_common_io::geometry::wkt_to_geometry;
+use geo::Area;
+use geo::BooleanOps;
+use geo::ConvexHull;
+use geo::Coord;
+use geo::EuclideanDistance;
+use geo::Geometry;
+use geo::Intersects;
+use geo::LineString;
+use geo::MultiPoint;
+use geo::MultiPolygon;
+use geo::Point;
+use geo::Polygon;
+use geo::Relate;
+
+/// The number of segments approximating the circle around a point in `st_buffer`.
+const BUFFER_SEGMENTS: usize = 32;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS geometry), to_geometry(xx)
+    // The text is WKT, hex-encoded WKB or GeoJSON, the binary is WKB.
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_geometry,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_geometry),
+    );
+    registry.register_passthrough_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        eval_binary_to_geometry,
+    );
+    registry.register_combine_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_binary_to_geometry),
+    );
+
+    fn eval_string_to_geometry(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<GeometryType> {
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+            push_wkb(parse_to_wkb(s.as_bytes()), builder, ctx)
+        })(val, ctx)
+    }
+
+    fn eval_binary_to_geometry(
+        val: ValueRef<BinaryType>,
+        ctx: &mut EvalContext,
+    ) -> Value<GeometryType> {
+        vectorize_with_builder_1_arg::<BinaryType, GeometryType>(|b, builder, ctx| {
+            push_wkb(
+                wkb_to_geometry(b).map(|geo| geometry_to_wkb(&geo)),
+                builder,
+                ctx,
+            )
+        })(val, ctx)
+    }
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromtext",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+            push_wkb(
+                wkt_to_geometry(s).map(|geo| geometry_to_wkb(&geo)),
+                builder,
+                ctx,
+            )
+        }),
+    );
+    registry.register_aliases("st_geomfromtext", &[
+        "st_geometryfromtext",
+        "st_geomfromwkt",
+    ]);
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "st_geomfromwkb",
+        |_, _| FunctionDomain::MayThrow,
+        eval_binary_to_geometry,
+    );
+    registry.register_aliases("st_geomfromwkb", &["st_geometryfromwkb"]);
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+            push_wkb(
+                geojson_to_geometry(s).map(|geo| geometry_to_wkb(&geo)),
+                builder,
+                ctx,
+            )
+        }),
+    );
+    registry.register_aliases("st_geomfromgeojson", &["st_geometryfromgeojson"]);
+
+    // cast(geometry AS string) is the WKT, cast(geometry AS binary) is the WKB.
+    for name in ["to_string", "st_astext"] {
+        registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<GeometryType, StringType>(|wkb, builder, ctx| {
+                match wkb_to_geometry(wkb) {
+                    Ok(geo) => builder.put_str(&geometry_to_wkt(&geo)),
+                    Err(e) => ctx.set_error(builder.len(), e.message()),
+                }
+                builder.commit_row();
+            }),
+        );
+    }
+    registry.register_aliases("st_astext", &["st_aswkt"]);
+
+    for name in ["to_binary", "st_aswkb"] {
+        registry.register_passthrough_nullable_1_arg::<GeometryType, BinaryType, _, _>(
+            name,
+            |_, _| FunctionDomain::Full,
+            vectorize_with_builder_1_arg::<GeometryType, BinaryType>(|wkb, builder, _| {
+                builder.put_slice(wkb);
+                builder.commit_row();
+            }),
+        );
+    }
+    registry.register_aliases("st_aswkb", &["st_asbinary"]);
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, VariantType, _, _>(
+        "st_asgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, VariantType>(|wkb, builder, ctx| {
+            match wkb_to_geometry(wkb) {
+                Ok(geo) => {
+                    let json = geometry_to_geojson(&geo);
+                    jsonb::parse_value(json.as_bytes())
+                        .unwrap()
+                        .write_to_vec(&mut builder.data);
+                }
+                Err(e) => ctx.set_error(builder.len(), e.message()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Float64Type, Float64Type, GeometryType, _, _>(
+        "st_point",
+        |_, x, y| {
+            FunctionDomain::Domain(GeometryDomain {
+                min_x: *x.min,
+                min_y: *y.min,
+                max_x: *x.max,
+                max_y: *y.max,
+            })
+        },
+        vectorize_with_builder_2_arg::<Float64Type, Float64Type, GeometryType>(
+            |x, y, builder, _| {
+                let point = Geometry::Point(Point::new(*x, *y));
+                builder.put_slice(&geometry_to_wkb(&point));
+                builder.commit_row();
+            },
+        ),
+    );
+    registry.register_aliases("st_point", &["st_makepoint"]);
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_x",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|wkb, builder, ctx| {
+            match wkb_to_geometry(wkb) {
+                Ok(Geometry::Point(p)) => builder.push(F64::from(p.x())),
+                Ok(_) => {
+                    ctx.set_error(builder.len(), "st_x only supports POINT");
+                    builder.push(F64::default());
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.message());
+                    builder.push(F64::default());
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_y",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|wkb, builder, ctx| {
+            match wkb_to_geometry(wkb) {
+                Ok(Geometry::Point(p)) => builder.push(F64::from(p.y())),
+                Ok(_) => {
+                    ctx.set_error(builder.len(), "st_y only supports POINT");
+                    builder.push(F64::default());
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.message());
+                    builder.push(F64::default());
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_area",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|wkb, builder, ctx| {
+            match wkb_to_geometry(wkb) {
+                Ok(geo) => builder.push(F64::from(geo.unsigned_area())),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.message());
+                    builder.push(F64::default());
+                }
+            }
+        }),
+    );
+
+    // The distance to an empty geometry is NULL.
+    registry.register_combine_nullable_2_arg::<GeometryType, GeometryType, Float64Type, _, _>(
+        "st_distance",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, NullableType<Float64Type>>(
+            |l, r, builder, ctx| match (wkb_to_geometry(l), wkb_to_geometry(r)) {
+                (Ok(l), Ok(r)) => match geometry_distance(&l, &r) {
+                    Some(distance) => builder.push(F64::from(distance)),
+                    None => builder.push_null(),
+                },
+                (Err(e), _) | (_, Err(e)) => {
+                    ctx.set_error(builder.len(), e.message());
+                    builder.push_null();
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, Float64Type, GeometryType, _, _>(
+        "st_buffer",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, Float64Type, GeometryType>(
+            |wkb, distance, builder, ctx| {
+                if *distance < 0.0 {
+                    ctx.set_error(
+                        builder.len(),
+                        "st_buffer does not support negative distance",
+                    );
+                    builder.commit_row();
+                    return;
+                }
+                let buffer = wkb_to_geometry(wkb).map(|geo| geometry_buffer(&geo, *distance));
+                push_wkb(buffer.map(|geo| geometry_to_wkb(&geo)), builder, ctx)
+            },
+        ),
+    );
+
+    // The spatial predicates can not be true if the bounding boxes do not intersect,
+    // which lets the range index prune blocks with the bounding box statistics.
+    register_spatial_predicate(registry, "st_intersects", |l, r| l.intersects(r));
+    register_spatial_predicate(registry, "st_contains", |l, r| l.relate(r).is_contains());
+    register_spatial_predicate(registry, "st_within", |l, r| l.relate(r).is_within());
+}
+
+fn register_spatial_predicate(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    predicate: fn(&Geometry<f64>, &Geometry<f64>) -> bool,
+) {
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        name,
+        |_, l, r| {
+            if l.intersects(r) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(BooleanDomain {
+                    has_false: true,
+                    has_true: false,
+                })
+            }
+        },
+        move |l, r, ctx| {
+            vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+                |l, r, builder, ctx| match (wkb_to_geometry(l), wkb_to_geometry(r)) {
+                    (Ok(l), Ok(r)) => builder.push(predicate(&l, &r)),
+                    (Err(e), _) | (_, Err(e)) => {
+                        ctx.set_error(builder.len(), e.message());
+                        builder.push(false);
+                    }
+                },
+            )(l, r, ctx)
+        },
+    );
+}
+
+fn push_wkb(wkb: Result<Vec<u8>>, builder: &mut BinaryColumnBuilder, ctx: &mut EvalContext) {
+    match wkb {
+        Ok(wkb) => builder.put_slice(&wkb),
+        Err(e) => ctx.set_error(builder.len(), e.message()),
+    }
+    builder.commit_row();
+}
+
+/// The non-empty points, linestrings and polygons a geometry is made of.
+enum Part {
+    Point(Point<f64>),
+    LineString(LineString<f64>),
+    Polygon(Polygon<f64>),
+}
+
+fn collect_parts(geo: &Geometry<f64>, parts: &mut Vec<Part>) {
+    match geo {
+        Geometry::Point(p) => parts.push(Part::Point(*p)),
+        Geometry::Line(l) => parts.push(Part::LineString(LineString::from(*l))),
+        Geometry::LineString(l) if !l.0.is_empty() => parts.push(Part::LineString(l.clone())),
+        Geometry::LineString(_) => {}
+        Geometry::Polygon(p) if !p.exterior().0.is_empty() => parts.push(Part::Polygon(p.clone())),
+        Geometry::Polygon(_) => {}
+        Geometry::MultiPoint(mp) => parts.extend(mp.iter().map(|p| Part::Point(*p))),
+        Geometry::MultiLineString(ml) => ml
+            .iter()
+            .filter(|l| !l.0.is_empty())
+            .for_each(|l| parts.push(Part::LineString(l.clone()))),
+        Geometry::MultiPolygon(mp) => mp
+            .iter()
+            .filter(|p| !p.exterior().0.is_empty())
+            .for_each(|p| parts.push(Part::Polygon(p.clone()))),
+        Geometry::GeometryCollection(gc) => gc.iter().for_each(|g| collect_parts(g, parts)),
+        Geometry::Rect(r) => parts.push(Part::Polygon(r.to_polygon())),
+        Geometry::Triangle(t) => parts.push(Part::Polygon(t.to_polygon())),
+    }
+}
+
+fn part_distance(l: &Part, r: &Part) -> f64 {
+    match (l, r) {
+        (Part::Point(l), Part::Point(r)) => l.euclidean_distance(r),
+        (Part::Point(l), Part::LineString(r)) => l.euclidean_distance(r),
+        (Part::Point(l), Part::Polygon(r)) => l.euclidean_distance(r),
+        (Part::LineString(l), Part::Point(r)) => l.euclidean_distance(r),
+        (Part::LineString(l), Part::LineString(r)) => l.euclidean_distance(r),
+        (Part::LineString(l), Part::Polygon(r)) => l.euclidean_distance(r),
+        (Part::Polygon(l), Part::Point(r)) => l.euclidean_distance(r),
+        (Part::Polygon(l), Part::LineString(r)) => l.euclidean_distance(r),
+        (Part::Polygon(l), Part::Polygon(r)) => l.euclidean_distance(r),
+    }
+}
+
+/// The minimum euclidean distance between two geometries, `None` if either is empty.
+fn geometry_distance(l: &Geometry<f64>, r: &Geometry<f64>) -> Option<f64> {
+    let mut l_parts = vec![];
+    let mut r_parts = vec![];
+    collect_parts(l, &mut l_parts);
+    collect_parts(r, &mut r_parts);
+    if l_parts.is_empty() || r_parts.is_empty() {
+        return None;
+    }
+    if l.intersects(r) {
+        return Some(0.0);
+    }
+    l_parts
+        .iter()
+        .flat_map(|l| r_parts.iter().map(move |r| part_distance(l, r)))
+        .reduce(f64::min)
+}
+
+fn circle(center: Coord<f64>, radius: f64) -> Vec<Coord<f64>> {
+    (0..BUFFER_SEGMENTS)
+        .map(|i| {
+            let angle = std::f64::consts::TAU * i as f64 / BUFFER_SEGMENTS as f64;
+            Coord {
+                x: center.x + radius * angle.cos(),
+                y: center.y + radius * angle.sin(),
+            }
+        })
+        .collect()
+}
+
+/// The polygon covering all the points within `distance` of the geometry.
+///
+/// It is the union of the circles around the points, the capsules around the
+/// segments of the linestrings and rings, and the polygons themselves.
+fn geometry_buffer(geo: &Geometry<f64>, distance: f64) -> Geometry<f64> {
+    let mut parts = vec![];
+    collect_parts(geo, &mut parts);
+
+    let mut pieces = vec![];
+    for part in parts {
+        let paths = match part {
+            Part::Point(p) => vec![vec![p.0]],
+            Part::LineString(l) => vec![l.0],
+            Part::Polygon(p) => {
+                let rings = p
+                    .interiors()
+                    .iter()
+                    .chain([p.exterior()])
+                    .map(|ring| ring.0.clone())
+                    .collect::<Vec<_>>();
+                pieces.push(p);
+                rings
+            }
+        };
+        // Points and lines have no area to keep without a distance.
+        if distance == 0.0 {
+            continue;
+        }
+        for coords in paths {
+            if coords.len() == 1 {
+                pieces.push(Polygon::new(
+                    LineString::from(circle(coords[0], distance)),
+                    vec![],
+                ));
+            }
+            for segment in coords.windows(2) {
+                let capsule = MultiPoint::from(
+                    circle(segment[0], distance)
+                        .into_iter()
+                        .chain(circle(segment[1], distance))
+                        .map(Point::from)
+                        .collect::<Vec<_>>(),
+                )
+                .convex_hull();
+                pieces.push(capsule);
+            }
+        }
+    }
+
+    let union = pieces
+        .into_iter()
+        .fold(MultiPolygon::new(vec![]), |acc, piece| {
+            acc.union(&MultiPolygon::new(vec![piece]))
+        });
+    match union.0.len() {
+        1 => Geometry::Polygon(union.0.into_iter().next().unwrap()),
+        _ => Geometry::MultiPolygon(union),
+    }
+}
@@ -24,8 +24,10 @@ mod comparison;
 mod control;
 mod datetime;
 mod decimal;
+mod full_text;
 mod geo;
 mod geo_h3;
+mod geometry;
 mod hash;
 mod map;
 mod math;
@@ -62,4 +64,6 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register_to_decimal(registry);
     vector::register(registry);
     bitmap::register(registry);
+    geometry::register(registry);
+    full_text::register(registry);
 }
@@ -536,6 +536,8 @@ fn transform_data_type(target_type: databend_common_ast::ast::TypeName) -> DataT
         databend_common_ast::ast::TypeName::String => DataType::String,
         databend_common_ast::ast::TypeName::Timestamp => DataType::Timestamp,
         databend_common_ast::ast::TypeName::Date => DataType::Date,
+        databend_common_ast::ast::TypeName::Interval => DataType::Interval,
+        databend_common_ast::ast::TypeName::TimestampTz => DataType::TimestampTz,
         databend_common_ast::ast::TypeName::Array(item_type) => {
             DataType::Array(Box::new(transform_data_type(*item_type)))
         }
@@ -552,6 +554,7 @@ fn transform_data_type(target_type: databend_common_ast::ast::TypeName) -> DataT
             DataType::Nullable(Box::new(transform_data_type(*inner_type)))
         }
         databend_common_ast::ast::TypeName::Variant => DataType::Variant,
+        databend_common_ast::ast::TypeName::Geometry => DataType::Geometry,
         databend_common_ast::ast::TypeName::NotNull(inner_type) => transform_data_type(*inner_type),
     }
 }
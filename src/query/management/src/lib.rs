@@ -19,11 +19,13 @@ mod connection;
 mod file_format;
 mod network_policy;
 mod password_policy;
+mod pipe;
 mod quota;
 mod role;
 mod serde;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
 
@@ -37,6 +39,8 @@ pub use network_policy::NetworkPolicyApi;
 pub use network_policy::NetworkPolicyMgr;
 pub use password_policy::PasswordPolicyApi;
 pub use password_policy::PasswordPolicyMgr;
+pub use pipe::PipeApi;
+pub use pipe::PipeMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
@@ -48,6 +52,8 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use task::TaskApi;
+pub use task::TaskMgr;
 pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
@@ -0,0 +1,245 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::UNIX_EPOCH;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MatchSeqExt;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::task::task_api::TaskApi;
+
+static TASK_API_KEY_PREFIX: &str = "__fd_tasks";
+static TASK_RUN_API_KEY_PREFIX: &str = "__fd_task_runs";
+static TASK_ID_GEN_API_KEY_PREFIX: &str = "__fd_task_id_gen";
+
+// How long the run history of a task is kept.
+const TASK_RUN_LIFE_TIME: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+pub struct TaskMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    task_prefix: String,
+    task_run_prefix: String,
+    task_id_gen_key: String,
+}
+
+impl TaskMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create task)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(TaskMgr {
+            kv_api,
+            task_prefix: format!("{}/{}", TASK_API_KEY_PREFIX, tenant),
+            task_run_prefix: format!("{}/{}", TASK_RUN_API_KEY_PREFIX, tenant),
+            task_id_gen_key: format!("{}/{}", TASK_ID_GEN_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_task_key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.task_prefix, escape_for_key(name)?))
+    }
+
+    fn make_task_run_prefix(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/",
+            self.task_run_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    fn new_task_run_life_time(&self) -> MetaSpec {
+        let expire_at = std::time::SystemTime::now()
+            .add(TASK_RUN_LIFE_TIME)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+
+        MetaSpec::new_expire(expire_at.as_secs())
+    }
+
+    // Every update of the id generator key bumps the global seq of meta,
+    // which is used as a unique task id.
+    async fn fetch_task_id(&self) -> Result<u64> {
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &self.task_id_gen_key,
+                MatchSeq::GE(0),
+                Operation::Update(vec![]),
+                None,
+            ))
+            .await?;
+
+        match res.result {
+            Some(SeqV { seq, .. }) => Ok(seq),
+            None => Err(ErrorCode::IllegalTask("Failed to generate task id")),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskApi for TaskMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_task(&self, mut task: Task) -> Result<u64> {
+        let key = self.make_task_key(task.task_name.as_str())?;
+        task.task_id = self.fetch_task_id().await?;
+        let value = Operation::Update(serialize_struct(&task, ErrorCode::IllegalTask, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, MatchSeq::Exact(0), value, None));
+
+        let res_seq = upsert_kv.await?.added_seq_or_else(|_v| {
+            ErrorCode::TaskAlreadyExists(format!("Task '{}' already exists.", task.task_name))
+        })?;
+
+        Ok(res_seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_task(&self, task: Task, match_seq: MatchSeq) -> Result<u64> {
+        let key = self.make_task_key(task.task_name.as_str())?;
+        let value = Operation::Update(serialize_struct(&task, ErrorCode::IllegalTask, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        match upsert_kv.result {
+            Some(SeqV { seq: s, .. }) if upsert_kv.is_changed() => Ok(s),
+            _ => Err(ErrorCode::UnknownTask(format!(
+                "Task '{}' does not exist or has been changed.",
+                task.task_name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_task_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownTask(format!(
+                "Cannot delete task '{}'. It may not exist.",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Task>> {
+        let key = self.make_task_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownTask(format!("Task '{}' does not exist.", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalTask, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownTask(format!(
+                "Task '{}' does not exist.",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_tasks(&self) -> Result<Vec<SeqV<Task>>> {
+        let prefix = format!("{}/", self.task_prefix);
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut tasks = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let task = deserialize_struct(&value.data, ErrorCode::IllegalTask, || "")?;
+            tasks.push(SeqV::new(value.seq, task));
+        }
+        Ok(tasks)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn upsert_task_run(&self, run: TaskRun) -> Result<u64> {
+        let key = format!(
+            "{}{}",
+            self.make_task_run_prefix(&run.task.task_name)?,
+            escape_for_key(&run.run_id)?
+        );
+        let value = Operation::Update(serialize_struct(&run, ErrorCode::IllegalTask, || "")?);
+        let meta = Some(self.new_task_run_life_time());
+
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), value, meta))
+            .await?;
+
+        match res.result {
+            Some(SeqV { seq, .. }) => Ok(seq),
+            None => Err(ErrorCode::IllegalTask(format!(
+                "Failed to save run '{}' of task '{}'.",
+                run.run_id, run.task.task_name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_task_runs(&self, task_name: Option<&str>) -> Result<Vec<TaskRun>> {
+        let prefix = match task_name {
+            Some(name) => self.make_task_run_prefix(name)?,
+            None => format!("{}/", self.task_run_prefix),
+        };
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut runs = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let run = deserialize_struct(&value.data, ErrorCode::IllegalTask, || "")?;
+            runs.push(run);
+        }
+        Ok(runs)
+    }
+}
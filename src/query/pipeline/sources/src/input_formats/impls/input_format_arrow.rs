@@ -0,0 +1,317 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::FileReader;
+use arrow_ipc::reader::StreamReader;
+use arrow_schema::ArrowError;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchema;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::ArrowFileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_settings::Settings;
+use databend_common_storage::StageFileInfo;
+use log::debug;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// Leading bytes of the Arrow IPC file format, the streaming format has no magic.
+const ARROW_MAGIC: &[u8; 6] = b"ARROW1";
+
+/// Arrow IPC files and streams are loaded as a whole, and each record batch in them
+/// is converted to a block of the input schema, matching columns by name.
+pub struct InputFormatArrow;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatArrow {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        Ok(file_infos
+            .into_iter()
+            .map(|info| {
+                let size = info.size as usize;
+                let file = Arc::new(FileInfo {
+                    path: info.path,
+                    size,
+                    num_splits: 1,
+                    compress_alg: None,
+                });
+                Arc::new(SplitInfo {
+                    file,
+                    seq_in_file: 0,
+                    offset: 0,
+                    size,
+                    num_file_splits: 1,
+                    format_info: None,
+                })
+            })
+            .collect())
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ArrowFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ArrowFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct ArrowFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for ArrowFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = ArrowRecordBatch;
+    type AligningState = ArrowAligningState;
+    type BlockBuilder = ArrowBlockBuilder;
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<ArrowAligningState> {
+        Ok(ArrowAligningState {
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<ArrowBlockBuilder> {
+        Ok(ArrowBlockBuilder {
+            ctx: ctx.clone(),
+            func_ctx: ctx.table_context.get_function_context()?,
+        })
+    }
+}
+
+pub struct ArrowRecordBatch {
+    path: String,
+    batch: RecordBatch,
+}
+
+impl Debug for ArrowRecordBatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ArrowRecordBatch")
+    }
+}
+
+impl serde::Serialize for ArrowRecordBatch {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!()
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for ArrowRecordBatch {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> Result<Self, D::Error> {
+        unimplemented!()
+    }
+}
+
+impl RowBatchTrait for ArrowRecordBatch {
+    fn size(&self) -> usize {
+        self.batch.get_array_memory_size()
+    }
+
+    fn rows(&self) -> usize {
+        self.batch.num_rows()
+    }
+}
+
+#[typetag::serde(name = "row_batch_arrow")]
+impl BlockMetaInfo for ArrowRecordBatch {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("ArrowRecordBatch as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("ArrowRecordBatch as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct ArrowAligningState {
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for ArrowAligningState {
+    type Pipe = ArrowFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<ArrowRecordBatch>> {
+        if let Some(data) = read_batch {
+            self.buffers.push(data);
+            return Ok(vec![]);
+        }
+        let path = &self.split_info.file.path;
+        let data = self.buffers.concat();
+        debug!("aligning arrow file {} of {} bytes", path, data.len());
+        let batches = read_record_batches(data, path)?;
+        Ok(batches
+            .into_iter()
+            .map(|batch| ArrowRecordBatch {
+                path: path.clone(),
+                batch,
+            })
+            .collect())
+    }
+}
+
+pub struct ArrowBlockBuilder {
+    ctx: Arc<InputContext>,
+    func_ctx: FunctionContext,
+}
+
+impl ArrowBlockBuilder {
+    fn build_block(&self, batch: ArrowRecordBatch) -> Result<DataBlock> {
+        let ArrowRecordBatch { path, batch } = batch;
+        let file_schema = TableSchema::try_from(batch.schema().as_ref())?;
+        let (block, _) = DataBlock::from_record_batch(&DataSchema::from(&file_schema), &batch)?;
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let params = ArrowFileFormatParams::downcast_unchecked(&self.ctx.file_format_params);
+
+        let mut columns = Vec::with_capacity(self.ctx.schema.num_fields());
+        for (i, to_field) in self.ctx.schema.fields().iter().enumerate() {
+            let data_type: DataType = to_field.data_type().into();
+            if let Some(projection) = &self.ctx.projection {
+                if !projection.contains(&i) {
+                    let scalar = Scalar::default_value(&data_type);
+                    columns.push(BlockEntry::new(data_type, Value::Scalar(scalar)));
+                    continue;
+                }
+            }
+            let position = file_schema
+                .fields()
+                .iter()
+                .position(|f| f.name().eq_ignore_ascii_case(to_field.name()));
+            let entry = match position {
+                Some(pos) => {
+                    let from_field = file_schema.field(pos);
+                    if from_field.data_type() == to_field.data_type() {
+                        block.get_by_offset(pos).clone()
+                    } else {
+                        let expr = Expr::ColumnRef {
+                            span: None,
+                            id: pos,
+                            data_type: from_field.data_type().into(),
+                            display_name: from_field.name().clone(),
+                        };
+                        let expr = check_cast(None, false, expr, &data_type, &BUILTIN_FUNCTIONS)?;
+                        BlockEntry::new(data_type, evaluator.run(&expr)?)
+                    }
+                }
+                None => {
+                    let scalar = match params.missing_field_as {
+                        NullAs::Error => {
+                            return Err(ErrorCode::BadBytes(format!(
+                                "column {} not found in arrow file {path}",
+                                to_field.name()
+                            )));
+                        }
+                        NullAs::Null if to_field.is_nullable() => Scalar::Null,
+                        _ => match &self.ctx.default_values {
+                            Some(default_values) => default_values[i].clone(),
+                            None => Scalar::default_value(&data_type),
+                        },
+                    };
+                    BlockEntry::new(data_type, Value::Scalar(scalar))
+                }
+            };
+            columns.push(entry);
+        }
+        Ok(DataBlock::new(columns, block.num_rows()))
+    }
+}
+
+impl BlockBuilderTrait for ArrowBlockBuilder {
+    type Pipe = ArrowFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<ArrowRecordBatch>) -> Result<Vec<DataBlock>> {
+        match batch {
+            Some(batch) => Ok(vec![self.build_block(batch)?]),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+/// Decode all the record batches in an Arrow IPC file or stream.
+fn read_record_batches(data: Vec<u8>, path: &str) -> Result<Vec<RecordBatch>> {
+    if data.starts_with(ARROW_MAGIC) {
+        let reader =
+            FileReader::try_new(Cursor::new(data), None).map_err(|e| arrow_error(path, e))?;
+        reader
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| arrow_error(path, e))
+    } else {
+        let reader =
+            StreamReader::try_new(Cursor::new(data), None).map_err(|e| arrow_error(path, e))?;
+        reader
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| arrow_error(path, e))
+    }
+}
+
+pub async fn read_arrow_schema_async(operator: &Operator, path: &str) -> Result<TableSchema> {
+    let data = operator.read(path).await?;
+    let schema = if data.starts_with(ARROW_MAGIC) {
+        FileReader::try_new(Cursor::new(data), None)
+            .map_err(|e| arrow_error(path, e))?
+            .schema()
+    } else {
+        StreamReader::try_new(Cursor::new(data), None)
+            .map_err(|e| arrow_error(path, e))?
+            .schema()
+    };
+    TableSchema::try_from(schema.as_ref())
+}
+
+fn arrow_error(path: &str, e: ArrowError) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to read arrow file {path}: {e}"))
+}
@@ -0,0 +1,466 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Reader;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_formats::FieldDecoder;
+use databend_common_formats::FieldJsonAstDecoder;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_meta_app::principal::AvroFileFormatParams;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_storage::FileParseError;
+use opendal::Operator;
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
+
+use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+/// Used when the writer schema carries no information for a value.
+static EMPTY_SCHEMA: JsonValue = JsonValue::Null;
+
+/// Avro object container files are decoded with the writer schema embedded in the file,
+/// each record is converted to a JSON object and then resolved against the table schema
+/// by field name, the same way as NDJSON.
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+}
+
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+        rounding_mode: bool,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options, rounding_mode))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let format_params =
+            AvroFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params);
+
+        let path = &batch.split_info.file.path;
+        let reader = Reader::new(batch.data.as_slice())
+            .map_err(|e| ErrorCode::BadBytes(format!("fail to read avro file {path}: {e}")))?;
+        let writer_schema = serde_json::to_value(reader.writer_schema())
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid avro schema in {path}: {e}")))?;
+        let named_types = NamedTypes::collect(&writer_schema);
+
+        for (i, value) in reader.enumerate() {
+            let value = value.map_err(|e| {
+                ErrorCode::BadBytes(format!("fail to decode avro file {path} at row {i}: {e}"))
+            })?;
+            let res = avro_to_json(value, &writer_schema, &named_types)
+                .map_err(|message| FileParseError::InvalidAvroRow { message })
+                .and_then(|json| {
+                    InputFormatNDJson::read_json(
+                        field_decoder,
+                        json,
+                        columns,
+                        &builder.ctx.schema,
+                        &builder.ctx.default_values,
+                        &format_params.null_field_as,
+                        &format_params.missing_field_as,
+                    )
+                });
+            if let Err(e) = res {
+                builder.ctx.on_error(
+                    e,
+                    Some((columns, builder.num_rows)),
+                    &mut builder.file_status,
+                    path,
+                    batch.start_row_in_split + i,
+                )?
+            } else {
+                builder.num_rows += 1;
+                builder.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The named types (records, enums and fixed) of an Avro schema by their full names,
+/// the schema refers to a named type by its name after the definition.
+struct NamedTypes<'a> {
+    types: HashMap<String, &'a JsonValue>,
+}
+
+impl<'a> NamedTypes<'a> {
+    fn collect(schema: &'a JsonValue) -> Self {
+        let mut named_types = NamedTypes {
+            types: HashMap::new(),
+        };
+        named_types.collect_schema(schema, None);
+        named_types
+    }
+
+    fn collect_schema(&mut self, schema: &'a JsonValue, namespace: Option<&'a str>) {
+        match schema {
+            JsonValue::Array(branches) => {
+                for branch in branches {
+                    self.collect_schema(branch, namespace);
+                }
+            }
+            JsonValue::Object(obj) => {
+                let mut namespace = namespace;
+                if let Some(name) = obj.get("name").and_then(JsonValue::as_str) {
+                    if let Some(ns) = obj.get("namespace").and_then(JsonValue::as_str) {
+                        namespace = Some(ns);
+                    }
+                    let full_name = match (name.rsplit_once('.'), namespace) {
+                        (Some((ns, _)), _) => {
+                            namespace = Some(ns);
+                            name.to_string()
+                        }
+                        (None, Some(ns)) if !ns.is_empty() => format!("{ns}.{name}"),
+                        (None, _) => name.to_string(),
+                    };
+                    self.types.insert(full_name, schema);
+                }
+                if let Some(fields) = obj.get("fields").and_then(JsonValue::as_array) {
+                    for field in fields {
+                        if let Some(typ) = field.get("type") {
+                            self.collect_schema(typ, namespace);
+                        }
+                    }
+                }
+                for key in ["items", "values"] {
+                    if let Some(child) = obj.get(key) {
+                        self.collect_schema(child, namespace);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the definition if the schema is a reference to a named type.
+    fn resolve(&self, schema: &'a JsonValue) -> &'a JsonValue {
+        match schema {
+            JsonValue::String(name) => self.types.get(name).copied().unwrap_or(schema),
+            _ => schema,
+        }
+    }
+}
+
+/// Convert an Avro value into the JSON representation understood by `FieldJsonAstDecoder`:
+/// dates as days, timestamps as microseconds, decimals as strings, bytes as hex strings
+/// and records as objects, which can be loaded into `Tuple` or `Variant` columns.
+fn avro_to_json(
+    value: AvroValue,
+    schema: &JsonValue,
+    named_types: &NamedTypes,
+) -> std::result::Result<JsonValue, String> {
+    let schema = named_types.resolve(schema);
+    let json = match value {
+        AvroValue::Null => JsonValue::Null,
+        AvroValue::Boolean(v) => JsonValue::Bool(v),
+        AvroValue::Int(v) | AvroValue::Date(v) | AvroValue::TimeMillis(v) => JsonValue::from(v),
+        AvroValue::Long(v) | AvroValue::TimeMicros(v) => JsonValue::from(v),
+        AvroValue::TimestampMillis(v) | AvroValue::LocalTimestampMillis(v) => {
+            JsonValue::from(v.checked_mul(1000).ok_or("timestamp out of range")?)
+        }
+        AvroValue::TimestampMicros(v) | AvroValue::LocalTimestampMicros(v) => JsonValue::from(v),
+        AvroValue::Float(v) => float_to_json(v as f64),
+        AvroValue::Double(v) => float_to_json(v),
+        AvroValue::Bytes(v) | AvroValue::Fixed(_, v) => {
+            if schema.get("logicalType").and_then(JsonValue::as_str) == Some("decimal") {
+                let scale = schema.get("scale").and_then(JsonValue::as_u64).unwrap_or(0);
+                JsonValue::String(decimal_to_string(&v, scale as usize)?)
+            } else {
+                JsonValue::String(hex::encode_upper(v))
+            }
+        }
+        AvroValue::String(v) | AvroValue::Enum(_, v) => JsonValue::String(v),
+        AvroValue::Uuid(v) => JsonValue::String(v.to_string()),
+        AvroValue::Decimal(v) => {
+            let bytes = Vec::<u8>::try_from(&v).map_err(|e| e.to_string())?;
+            let scale = schema.get("scale").and_then(JsonValue::as_u64).unwrap_or(0);
+            JsonValue::String(decimal_to_string(&bytes, scale as usize)?)
+        }
+        AvroValue::Union(i, v) => {
+            let branch = schema
+                .as_array()
+                .and_then(|branches| branches.get(i as usize))
+                .unwrap_or(&EMPTY_SCHEMA);
+            avro_to_json(*v, branch, named_types)?
+        }
+        AvroValue::Array(items) => {
+            let item_schema = schema.get("items").unwrap_or(&EMPTY_SCHEMA);
+            JsonValue::Array(
+                items
+                    .into_iter()
+                    .map(|item| avro_to_json(item, item_schema, named_types))
+                    .collect::<std::result::Result<_, _>>()?,
+            )
+        }
+        AvroValue::Map(entries) => {
+            let value_schema = schema.get("values").unwrap_or(&EMPTY_SCHEMA);
+            let mut map = JsonMap::with_capacity(entries.len());
+            for (k, v) in entries {
+                map.insert(k, avro_to_json(v, value_schema, named_types)?);
+            }
+            JsonValue::Object(map)
+        }
+        AvroValue::Record(fields) => {
+            let field_schemas = schema.get("fields").and_then(JsonValue::as_array);
+            let mut map = JsonMap::with_capacity(fields.len());
+            for (name, v) in fields {
+                let field_schema = field_schemas
+                    .and_then(|f| {
+                        f.iter().find(|f| {
+                            f.get("name").and_then(JsonValue::as_str) == Some(name.as_str())
+                        })
+                    })
+                    .and_then(|f| f.get("type"))
+                    .unwrap_or(&EMPTY_SCHEMA);
+                let v = avro_to_json(v, field_schema, named_types)?;
+                map.insert(name, v);
+            }
+            JsonValue::Object(map)
+        }
+        v => return Err(format!("unsupported avro value {v:?}")),
+    };
+    Ok(json)
+}
+
+fn float_to_json(v: f64) -> JsonValue {
+    serde_json::Number::from_f64(v)
+        .map(JsonValue::Number)
+        .unwrap_or_else(|| JsonValue::String(v.to_string()))
+}
+
+/// Avro decimals are the big-endian two's-complement bytes of the unscaled value.
+fn decimal_to_string(bytes: &[u8], scale: usize) -> std::result::Result<String, String> {
+    if bytes.len() > 16 {
+        return Err(format!(
+            "avro decimal of {} bytes is too large, at most 16 bytes are supported",
+            bytes.len()
+        ));
+    }
+    let negative = bytes.first().map_or(false, |b| b & 0x80 != 0);
+    let mut unscaled: i128 = if negative { -1 } else { 0 };
+    for b in bytes {
+        unscaled = (unscaled << 8) | *b as i128;
+    }
+
+    let digits = unscaled.unsigned_abs().to_string();
+    let sign = if unscaled < 0 { "-" } else { "" };
+    if scale == 0 {
+        Ok(format!("{sign}{digits}"))
+    } else if digits.len() <= scale {
+        Ok(format!("{sign}0.{digits:0>scale$}"))
+    } else {
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        Ok(format!("{sign}{int}.{frac}"))
+    }
+}
+
+/// Read the writer schema of an Avro file and convert it to a `TableSchema`,
+/// the top level record fields become the columns.
+#[async_backtrace::framed]
+pub async fn read_avro_schema_async(operator: &Operator, path: &str) -> Result<TableSchema> {
+    let data = operator.read(path).await?;
+    let reader = Reader::new(data.as_slice())
+        .map_err(|e| ErrorCode::BadBytes(format!("fail to read avro file {path}: {e}")))?;
+    let schema = serde_json::to_value(reader.writer_schema())
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid avro schema in {path}: {e}")))?;
+    let named_types = NamedTypes::collect(&schema);
+
+    let Some(fields) = schema.get("fields").and_then(JsonValue::as_array) else {
+        return Err(ErrorCode::BadBytes(format!(
+            "the schema of avro file {path} must be a record, but got {schema}"
+        )));
+    };
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let name = field
+                .get("name")
+                .and_then(JsonValue::as_str)
+                .unwrap_or_default();
+            let data_type = avro_schema_to_data_type(
+                field.get("type").unwrap_or(&EMPTY_SCHEMA),
+                &named_types,
+                &mut vec![],
+            )?;
+            Ok(TableField::new(name, data_type))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(TableSchema::new(fields))
+}
+
+/// `expanding` are the named types being converted, a recursive reference to them
+/// is kept as semi-structured data.
+fn avro_schema_to_data_type<'a>(
+    schema: &'a JsonValue,
+    named_types: &NamedTypes<'a>,
+    expanding: &mut Vec<&'a str>,
+) -> Result<TableDataType> {
+    let data_type = match schema {
+        JsonValue::String(name) => match named_types.types.get(name).copied() {
+            Some(_) if expanding.contains(&name.as_str()) => TableDataType::Variant,
+            Some(definition) => {
+                expanding.push(name);
+                let data_type = avro_schema_to_data_type(definition, named_types, expanding);
+                expanding.pop();
+                data_type?
+            }
+            None => primitive_to_data_type(name),
+        },
+        JsonValue::Array(branches) => {
+            let non_null = branches
+                .iter()
+                .filter(|b| b.as_str() != Some("null"))
+                .collect::<Vec<_>>();
+            match non_null.as_slice() {
+                [] => TableDataType::Null,
+                [branch] => {
+                    avro_schema_to_data_type(branch, named_types, expanding)?.wrap_nullable()
+                }
+                _ => TableDataType::Nullable(Box::new(TableDataType::Variant)),
+            }
+        }
+        JsonValue::Object(obj) => {
+            let typ = obj
+                .get("type")
+                .and_then(JsonValue::as_str)
+                .unwrap_or_default();
+            match (obj.get("logicalType").and_then(JsonValue::as_str), typ) {
+                (Some("date"), "int") => TableDataType::Date,
+                (
+                    Some(
+                        "timestamp-millis"
+                        | "timestamp-micros"
+                        | "local-timestamp-millis"
+                        | "local-timestamp-micros",
+                    ),
+                    "long",
+                ) => TableDataType::Timestamp,
+                (Some("decimal"), "bytes" | "fixed") => {
+                    let precision = obj
+                        .get("precision")
+                        .and_then(JsonValue::as_u64)
+                        .unwrap_or(0);
+                    let scale = obj.get("scale").and_then(JsonValue::as_u64).unwrap_or(0);
+                    TableDataType::Decimal(DecimalDataType::from_size(DecimalSize {
+                        precision: precision as u8,
+                        scale: scale as u8,
+                    })?)
+                }
+                (_, "record") => {
+                    let fields = obj
+                        .get("fields")
+                        .and_then(JsonValue::as_array)
+                        .cloned()
+                        .unwrap_or_default();
+                    let mut fields_name = Vec::with_capacity(fields.len());
+                    let mut fields_type = Vec::with_capacity(fields.len());
+                    for field in fields.iter() {
+                        let name = field
+                            .get("name")
+                            .and_then(JsonValue::as_str)
+                            .unwrap_or_default();
+                        fields_name.push(name.to_string());
+                        fields_type.push(avro_schema_to_data_type(
+                            field.get("type").unwrap_or(&EMPTY_SCHEMA),
+                            named_types,
+                            expanding,
+                        )?);
+                    }
+                    TableDataType::Tuple {
+                        fields_name,
+                        fields_type,
+                    }
+                }
+                (_, "array") => TableDataType::Array(Box::new(avro_schema_to_data_type(
+                    obj.get("items").unwrap_or(&EMPTY_SCHEMA),
+                    named_types,
+                    expanding,
+                )?)),
+                (_, "map") => TableDataType::Map(Box::new(TableDataType::Tuple {
+                    fields_name: vec!["key".to_string(), "value".to_string()],
+                    fields_type: vec![
+                        TableDataType::String,
+                        avro_schema_to_data_type(
+                            obj.get("values").unwrap_or(&EMPTY_SCHEMA),
+                            named_types,
+                            expanding,
+                        )?,
+                    ],
+                })),
+                (_, "enum") => TableDataType::String,
+                (_, "fixed") => TableDataType::Binary,
+                (_, typ) => primitive_to_data_type(typ),
+            }
+        }
+        _ => TableDataType::Variant,
+    };
+    Ok(data_type)
+}
+
+fn primitive_to_data_type(name: &str) -> TableDataType {
+    match name {
+        "null" => TableDataType::Null,
+        "boolean" => TableDataType::Boolean,
+        "int" => TableDataType::Number(NumberDataType::Int32),
+        "long" => TableDataType::Number(NumberDataType::Int64),
+        "float" => TableDataType::Number(NumberDataType::Float32),
+        "double" => TableDataType::Number(NumberDataType::Float64),
+        "bytes" => TableDataType::Binary,
+        "string" => TableDataType::String,
+        _ => TableDataType::Variant,
+    }
+}
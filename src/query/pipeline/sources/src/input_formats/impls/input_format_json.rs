@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_formats::FieldDecoder;
+use databend_common_formats::FieldJsonAstDecoder;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::StageFileFormatType;
+use serde_json::Value as JsonValue;
+
+use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+/// A JSON file holds a single document, each element is a row if it is an array,
+/// otherwise the whole document is one row.
+pub struct InputFormatJson {}
+
+impl InputFormatJson {
+    pub fn create() -> Self {
+        Self {}
+    }
+}
+
+impl InputFormatTextBase for InputFormatJson {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Json
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+        rounding_mode: bool,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options, rounding_mode))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+
+        let path = &batch.split_info.file.path;
+        let json: JsonValue = serde_json::from_slice(&batch.data)
+            .map_err(|e| ErrorCode::BadBytes(format!("fail to parse JSON file {path}: {e}")))?;
+        let rows = match json {
+            JsonValue::Array(rows) => rows,
+            json => vec![json],
+        };
+
+        for (i, row) in rows.into_iter().enumerate() {
+            if let Err(e) = InputFormatNDJson::read_json(
+                field_decoder,
+                row,
+                columns,
+                &builder.ctx.schema,
+                &builder.ctx.default_values,
+                &NullAs::Null,
+                &NullAs::Error,
+            ) {
+                builder.ctx.on_error(
+                    e,
+                    Some((columns, builder.num_rows)),
+                    &mut builder.file_status,
+                    path,
+                    batch.start_row_in_split + i,
+                )?
+            } else {
+                builder.num_rows += 1;
+                builder.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(())
+    }
+}
@@ -50,10 +50,32 @@ impl InputFormatNDJson {
         null_field_as: &NullAs,
         missing_field_as: &NullAs,
     ) -> std::result::Result<(), FileParseError> {
-        let mut json: serde_json::Value =
+        let json: serde_json::Value =
             serde_json::from_reader(buf).map_err(|e| FileParseError::InvalidNDJsonRow {
                 message: e.to_string(),
             })?;
+        Self::read_json(
+            field_decoder,
+            json,
+            columns,
+            schema,
+            default_values,
+            null_field_as,
+            missing_field_as,
+        )
+    }
+
+    /// Decode one JSON object into the columns, shared with the formats whose rows
+    /// are converted into JSON values, e.g. Avro.
+    pub(crate) fn read_json(
+        field_decoder: &FieldJsonAstDecoder,
+        mut json: serde_json::Value,
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+        default_values: &Option<Vec<Scalar>>,
+        null_field_as: &NullAs,
+        missing_field_as: &NullAs,
+    ) -> std::result::Result<(), FileParseError> {
         // todo: this is temporary
         if field_decoder.is_select {
             field_decoder
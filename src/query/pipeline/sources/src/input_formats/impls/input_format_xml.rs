@@ -27,11 +27,13 @@ use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::XmlFileFormatParams;
 use databend_common_storage::FileParseError;
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
 use xml::reader::XmlEvent;
 use xml::ParserConfig;
 
 use crate::input_formats::error_utils::truncate_column_data;
-use crate::input_formats::AligningStateTextBased;
+use crate::input_formats::AligningStateWholeFile;
 use crate::input_formats::BlockBuilder;
 use crate::input_formats::InputContext;
 use crate::input_formats::InputFormatTextBase;
@@ -50,7 +52,12 @@ impl InputFormatXML {
         columns: &mut [ColumnBuilder],
         schema: &TableSchemaRef,
         ident_case_sensitive: bool,
+        is_select: bool,
     ) -> std::result::Result<(), FileParseError> {
+        if is_select {
+            return Self::read_row_as_variant(field_decoder, row_data, columns);
+        }
+
         let raw_data = if !ident_case_sensitive {
             row_data
                 .drain()
@@ -88,43 +95,30 @@ impl InputFormatXML {
         }
         Ok(())
     }
-}
-
-pub struct AligningStateWholeFile {
-    #[allow(unused)]
-    split_info: Arc<SplitInfo>,
-    bufs: Vec<Vec<u8>>,
-}
-
-impl AligningStateWholeFile {
-    fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
-        Ok(Self {
-            split_info: split_info.clone(),
-            bufs: vec![],
-        })
-    }
-}
-
-impl AligningStateTextBased for AligningStateWholeFile {
-    fn align(&mut self, buf: &[u8]) -> Result<Vec<RowBatch>> {
-        self.bufs.push(buf.to_vec());
-        Ok(vec![])
-    }
-
-    fn align_flush(&mut self) -> Result<Vec<RowBatch>> {
-        let data = self.bufs.concat();
 
-        Ok(vec![RowBatch {
-            data,
-            row_ends: vec![],
-            field_ends: vec![],
-            batch_id: 0,
-            split_info: self.split_info.clone(),
-            start_offset_in_split: 0,
-            start_row_in_split: 0,
-            start_row_of_split: Some(0),
-            num_fields: vec![],
-        }])
+    /// Read the row as an object of its fields into the single variant column of stage queries.
+    fn read_row_as_variant(
+        field_decoder: &SeparatedTextDecoder,
+        row_data: &HashMap<String, Vec<u8>>,
+        columns: &mut [ColumnBuilder],
+    ) -> std::result::Result<(), FileParseError> {
+        let object = row_data
+            .iter()
+            .map(|(k, v)| {
+                let v = String::from_utf8_lossy(v).to_string();
+                (k.clone(), JsonValue::String(v))
+            })
+            .collect::<JsonMap<_, _>>();
+        let data = JsonValue::Object(object).to_string();
+        field_decoder
+            .read_field(&mut columns[0], data.as_bytes())
+            .map_err(|e| FileParseError::ColumnDecodeError {
+                column_index: 0,
+                column_name: "$1".to_string(),
+                column_type: "Variant".to_string(),
+                decode_error: e.message(),
+                column_data: truncate_column_data(data),
+            })
     }
 }
 
@@ -242,6 +236,7 @@ impl InputFormatTextBase for InputFormatXML {
                                 columns,
                                 &builder.ctx.schema,
                                 builder.ident_case_sensitive,
+                                builder.ctx.file_format_options_ext.is_select,
                             ) {
                                 builder
                                     .ctx
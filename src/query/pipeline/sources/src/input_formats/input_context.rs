@@ -45,7 +45,10 @@ use databend_common_storage::FileParseError;
 use databend_common_storage::FileStatus;
 use opendal::Operator;
 
+use crate::input_formats::impls::InputFormatArrow;
+use crate::input_formats::impls::InputFormatAvro;
 use crate::input_formats::impls::InputFormatCSV;
+use crate::input_formats::impls::InputFormatJson;
 use crate::input_formats::impls::InputFormatNDJson;
 use crate::input_formats::impls::InputFormatParquet;
 use crate::input_formats::impls::InputFormatTSV;
@@ -155,8 +158,11 @@ impl InputContext {
             FileFormatParams::Tsv(_) => Ok(Arc::new(InputFormatTSV::create())),
             FileFormatParams::Csv(_) => Ok(Arc::new(InputFormatCSV::create())),
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
+            FileFormatParams::Json(_) => Ok(Arc::new(InputFormatJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
+            FileFormatParams::Avro(_) => Ok(Arc::new(InputFormatAvro::create())),
+            FileFormatParams::Arrow(_) => Ok(Arc::new(InputFormatArrow {})),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -323,6 +323,46 @@ impl AligningStateTextBased for AligningStateRowDelimiter {
     }
 }
 
+/// Buffers the whole file into one batch, for formats that can only be decoded
+/// from the complete file, e.g. XML and Avro.
+pub struct AligningStateWholeFile {
+    #[allow(unused)]
+    split_info: Arc<SplitInfo>,
+    bufs: Vec<Vec<u8>>,
+}
+
+impl AligningStateWholeFile {
+    pub fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+        Ok(Self {
+            split_info: split_info.clone(),
+            bufs: vec![],
+        })
+    }
+}
+
+impl AligningStateTextBased for AligningStateWholeFile {
+    fn align(&mut self, buf: &[u8]) -> Result<Vec<RowBatch>> {
+        self.bufs.push(buf.to_vec());
+        Ok(vec![])
+    }
+
+    fn align_flush(&mut self) -> Result<Vec<RowBatch>> {
+        let data = self.bufs.concat();
+
+        Ok(vec![RowBatch {
+            data,
+            row_ends: vec![],
+            field_ends: vec![],
+            batch_id: 0,
+            split_info: self.split_info.clone(),
+            start_offset_in_split: 0,
+            start_row_in_split: 0,
+            start_row_of_split: Some(0),
+            num_fields: vec![],
+        }])
+    }
+}
+
 pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
     type AligningState: AligningStateTextBased;
 
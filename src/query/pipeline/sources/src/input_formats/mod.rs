@@ -24,6 +24,8 @@ mod source_aligner;
 mod transform_deserializer;
 
 pub use beyond_end_reader::BeyondEndReader;
+pub use impls::read_arrow_schema_async;
+pub use impls::read_avro_schema_async;
 pub use input_context::InputContext;
 pub use input_context::InputPlan;
 pub use input_context::StreamPlan;
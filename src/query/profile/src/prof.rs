@@ -65,6 +65,7 @@ pub enum OperatorType {
     Limit,
     TableScan,
     CteScan,
+    RecursiveCteScan,
     Sort,
     UnionAll,
     Project,
@@ -95,6 +96,7 @@ impl Display for OperatorType {
             OperatorType::Exchange => write!(f, "Exchange"),
             OperatorType::Insert => write!(f, "Insert"),
             OperatorType::CteScan => write!(f, "CteScan"),
+            OperatorType::RecursiveCteScan => write!(f, "RecursiveCteScan"),
             OperatorType::ConstantTableScan => write!(f, "ConstantTableScan"),
             OperatorType::Udf => write!(f, "Udf"),
         }
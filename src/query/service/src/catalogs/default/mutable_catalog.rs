@@ -84,6 +84,8 @@ use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReply;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -490,6 +492,21 @@ impl Catalog for MutableCatalog {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        info!(
+            "updating multi table meta. table ids: {:?}",
+            req.update_table_metas
+                .iter()
+                .map(|req| req.table_id)
+                .collect::<Vec<_>>()
+        );
+        Ok(self.ctx.meta.update_multi_table_meta(req).await?)
+    }
+
     async fn set_table_column_mask_policy(
         &self,
         req: SetTableColumnMaskPolicyReq,
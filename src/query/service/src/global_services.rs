@@ -43,6 +43,7 @@ use crate::clusters::ClusterDiscovery;
 use crate::locks::LockManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
+use crate::tasks::TaskScheduler;
 
 pub struct GlobalServices;
 
@@ -109,6 +110,7 @@ impl GlobalServices {
         )
         .await?;
         RoleCacheManager::init()?;
+        TaskScheduler::init(config)?;
         ShareEndpointManager::init()?;
         QueryProfileManager::init();
 
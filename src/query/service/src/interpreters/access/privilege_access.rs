@@ -603,6 +603,22 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Super]).await?
             }
 
+            // Vector Index.
+            Plan::RefreshVectorIndex(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Super]).await?
+            }
+
+            // Inverted Index.
+            Plan::CreateInvertedIndex(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Create]).await?
+            }
+            Plan::DropInvertedIndex(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Drop]).await?
+            }
+            Plan::RefreshInvertedIndex(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Super]).await?
+            }
+
             // Table.
             Plan::ShowCreateTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Select]).await?
@@ -632,6 +648,12 @@ impl AccessChecker for PrivilegeAccess {
             Plan::SetOptions(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter]).await?
             }
+            Plan::AddTablePartition(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter]).await?
+            }
+            Plan::DropTablePartition(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter]).await?
+            }
             Plan::AddTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter]).await?
             }
@@ -799,6 +821,16 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropView(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop]).await?
             }
+            Plan::CreateMaterializedView(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create]).await?;
+                self.validate_table_access(&plan.catalog, &plan.source_database, &plan.source_table, vec![UserPrivilegeType::Select]).await?
+            }
+            Plan::DropMaterializedView(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop]).await?
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.view_name, vec![UserPrivilegeType::Alter]).await?
+            }
             Plan::CreateStream(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create]).await?
             }
@@ -901,6 +933,10 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
             | Plan::DropConnection(_)
+            | Plan::CreatePipe(_)
+            | Plan::AlterPipe(_)
+            | Plan::DropPipe(_)
+            | Plan::DescPipe(_)
             | Plan::CreateTask(_)   // TODO: need to build ownership info for task
             | Plan::ShowTasks(_)    // TODO: need to build ownership info for task
             | Plan::DescribeTask(_) // TODO: need to build ownership info for task
@@ -934,6 +970,8 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            // Transaction statements only change the session state
+            Plan::Begin | Plan::Commit | Plan::Abort => {}
         }
 
         Ok(())
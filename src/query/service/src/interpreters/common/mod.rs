@@ -24,8 +24,10 @@ pub use grant::validate_grant_object_exists;
 pub use query_log::InterpreterQueryLog;
 pub use stream::build_update_stream_meta_seq;
 pub use table::check_referenced_computed_columns;
+pub use task::check_error_integration;
 pub use task::get_client_config;
 pub use task::make_schedule_options;
+pub use task::make_task_schedule_options;
 pub use task::make_warehouse_options;
 pub use util::check_deduplicate_label;
 pub use util::create_push_down_filters;
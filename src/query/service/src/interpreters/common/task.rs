@@ -20,7 +20,10 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::ClientConfig;
 use databend_common_cloud_control::pb::schedule_options::ScheduleType;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal;
 
 use crate::sessions::QueryContext;
 
@@ -46,6 +49,24 @@ pub fn make_schedule_options(
     }
 }
 
+/// Converts the schedule of a statement into the one persisted by the built-in scheduler.
+pub fn make_task_schedule_options(opt: ScheduleOptions) -> principal::ScheduleOptions {
+    match opt {
+        ScheduleOptions::IntervalSecs(secs) => principal::ScheduleOptions {
+            interval: Some(secs as i32),
+            cron: None,
+            time_zone: None,
+            schedule_type: principal::ScheduleType::IntervalType,
+        },
+        ScheduleOptions::CronExpression(expr, timezone) => principal::ScheduleOptions {
+            interval: None,
+            cron: Some(expr),
+            time_zone: timezone,
+            schedule_type: principal::ScheduleType::CronType,
+        },
+    }
+}
+
 pub fn make_warehouse_options(
     opt: WarehouseOptions,
 ) -> databend_common_cloud_control::pb::WarehouseOptions {
@@ -59,6 +80,20 @@ pub fn make_warehouse_options(
     ret
 }
 
+/// The error integration of the built-in scheduler must be a configured notification integration.
+pub fn check_error_integration(error_integration: &Option<String>) -> Result<()> {
+    if let Some(name) = error_integration {
+        let config = GlobalConfig::instance();
+        if !config.query.notification_integrations.contains_key(name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Unknown notification integration '{}'",
+                name
+            )));
+        }
+    }
+    Ok(())
+}
+
 pub fn get_client_config(ctx: Arc<QueryContext>) -> Result<ClientConfig> {
     let tenant = ctx.get_tenant();
     let user = ctx.get_current_user()?.identity().to_string();
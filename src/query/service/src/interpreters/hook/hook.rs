@@ -58,6 +58,7 @@ impl HookOperator {
     /// 1. Compact if needed.
     /// 2. Refresh aggregating index if needed.
     /// 3. Refresh virtual columns if needed.
+    /// 4. Refresh materialized views if needed.
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn execute(&self, pipeline: &mut Pipeline) {
@@ -93,6 +94,7 @@ impl HookOperator {
     /// Execute the refresh hook operator.
     // 1. Refresh aggregating index.
     // 2. Refresh virtual columns.
+    // 3. Refresh materialized views.
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn execute_refresh(&self, pipeline: &mut Pipeline) {
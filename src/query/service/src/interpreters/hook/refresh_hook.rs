@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_base::runtime::GlobalIORuntime;
@@ -26,20 +27,24 @@ use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::plans::RefreshIndexPlan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
 use databend_common_sql::plans::RefreshVirtualColumnPlan;
 use databend_common_sql::BindContext;
 use databend_common_sql::Binder;
 use databend_common_sql::Metadata;
 use databend_common_sql::NameResolutionContext;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::table::MaterializedViews;
 use log::info;
 use parking_lot::RwLock;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::RefreshIndexInterpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
 use crate::interpreters::RefreshVirtualColumnInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 
 pub struct RefreshDesc {
@@ -72,10 +77,10 @@ pub async fn hook_refresh(ctx: Arc<QueryContext>, pipeline: &mut Pipeline, desc:
 }
 
 async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
-    let table_id = ctx
+    let table = ctx
         .get_table(&desc.catalog, &desc.database, &desc.table)
-        .await?
-        .get_id();
+        .await?;
+    let table_id = table.get_id();
 
     let mut plans = Vec::new();
 
@@ -100,6 +105,18 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
         }
     }
 
+    // Generate materialized views.
+    if ctx
+        .get_settings()
+        .get_enable_refresh_materialized_view_after_write()?
+    {
+        // The views read the table at its latest snapshot.
+        ctx.evict_table_from_cache(&desc.catalog, &desc.database, &desc.table)?;
+        let materialized_view_plans =
+            generate_refresh_materialized_view_plans(ctx.clone(), &desc, table.options())?;
+        plans.extend(materialized_view_plans);
+    }
+
     let mut tasks = Vec::with_capacity(std::cmp::min(
         ctx.get_settings().get_max_threads()? as usize,
         plans.len(),
@@ -112,27 +129,17 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
                 Plan::RefreshIndex(agg_index_plan) => {
                     let refresh_agg_index_interpreter =
                         RefreshIndexInterpreter::try_create(ctx_cloned.clone(), *agg_index_plan)?;
-                    let mut build_res = refresh_agg_index_interpreter.execute2().await?;
-                    if build_res.main_pipeline.is_empty() {
-                        return Ok(());
-                    }
-
-                    let settings = ctx_cloned.get_settings();
-                    let query_id = ctx_cloned.get_id();
-                    build_res.set_max_threads(settings.get_max_threads()? as usize);
-                    let settings = ExecutorSettings::try_create(&settings, query_id)?;
-
-                    if build_res.main_pipeline.is_complete_pipeline()? {
-                        let mut pipelines = build_res.sources_pipelines;
-                        pipelines.push(build_res.main_pipeline);
-
-                        let complete_executor =
-                            PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
-                        ctx_cloned.set_executor(complete_executor.get_inner())?;
-                        complete_executor.execute()
-                    } else {
-                        Ok(())
-                    }
+                    let build_res = refresh_agg_index_interpreter.execute2().await?;
+                    execute_refresh_pipeline(ctx_cloned, build_res)
+                }
+                Plan::RefreshMaterializedView(materialized_view_plan) => {
+                    let refresh_materialized_view_interpreter =
+                        RefreshMaterializedViewInterpreter::try_create(
+                            ctx_cloned.clone(),
+                            *materialized_view_plan,
+                        )?;
+                    let build_res = refresh_materialized_view_interpreter.execute2().await?;
+                    execute_refresh_pipeline(ctx_cloned, build_res)
                 }
                 Plan::RefreshVirtualColumn(virtual_column_plan) => {
                     let refresh_virtual_column_interpreter =
@@ -157,6 +164,31 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
     Ok(())
 }
 
+fn execute_refresh_pipeline(
+    ctx: Arc<QueryContext>,
+    mut build_res: PipelineBuildResult,
+) -> Result<()> {
+    if build_res.main_pipeline.is_empty() {
+        return Ok(());
+    }
+
+    let settings = ctx.get_settings();
+    let query_id = ctx.get_id();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let settings = ExecutorSettings::try_create(&settings, query_id)?;
+
+    if build_res.main_pipeline.is_complete_pipeline()? {
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+
+        let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+        ctx.set_executor(complete_executor.get_inner())?;
+        complete_executor.execute()
+    } else {
+        Ok(())
+    }
+}
+
 async fn generate_refresh_index_plan(
     ctx: Arc<QueryContext>,
     catalog: &str,
@@ -253,3 +285,24 @@ async fn generate_refresh_virtual_column_plan(
 
     Ok(Some(Plan::RefreshVirtualColumn(Box::new(plan))))
 }
+
+fn generate_refresh_materialized_view_plans(
+    ctx: Arc<QueryContext>,
+    desc: &RefreshDesc,
+    options: &BTreeMap<String, String>,
+) -> Result<Vec<Plan>> {
+    let views = MaterializedViews::from_options(options)?;
+    let plans = views
+        .0
+        .into_iter()
+        .map(|view| {
+            Plan::RefreshMaterializedView(Box::new(RefreshMaterializedViewPlan {
+                tenant: ctx.get_tenant(),
+                catalog: desc.catalog.clone(),
+                database: view.database,
+                view_name: view.name,
+            }))
+        })
+        .collect();
+    Ok(plans)
+}
@@ -58,6 +58,7 @@ pub trait Interpreter: Sync + Send {
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
+                ctx.txn_mgr().lock().set_fail();
                 InterpreterMetrics::record_query_error(&ctx);
                 log_query_finished(&ctx, Some(build_error.clone()), false);
                 return Err(build_error);
@@ -102,7 +103,11 @@ pub trait Interpreter: Sync + Send {
 
             let err_opt = match may_error {
                 Ok(_) => None,
-                Err(e) => Some(e.clone()),
+                Err(e) => {
+                    // The changes of the failed statement may be partially buffered.
+                    query_ctx.txn_mgr().lock().set_fail();
+                    Some(e.clone())
+                }
             };
 
             InterpreterMetrics::record_query_finished(&query_ctx, err_opt.clone());
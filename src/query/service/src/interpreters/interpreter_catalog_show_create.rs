@@ -64,10 +64,13 @@ impl Interpreter for ShowCreateCatalogInterpreter {
                     op.storage_params.unwrap_or(Box::new(StorageParams::None))
                 ),
             ),
-            CatalogOption::Iceberg(op) => (
-                String::from("iceberg"),
-                format!("STORAGE PARAMS\n{}", op.storage_params),
-            ),
+            CatalogOption::Iceberg(op) => (String::from("iceberg"), match op.rest {
+                Some(rest) => format!(
+                    "REST CATALOG\nuri={},warehouse={}\nSTORAGE PARAMS\n{}",
+                    rest.uri, rest.warehouse, op.storage_params
+                ),
+                None => format!("STORAGE PARAMS\n{}", op.storage_params),
+            }),
         };
 
         let block = DataBlock::new(
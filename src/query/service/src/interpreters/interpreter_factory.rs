@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::ExplainKind;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use log::error;
 
@@ -68,15 +70,88 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        // Only COMMIT or ROLLBACK can end a failed transaction.
+        if ctx.txn_mgr().lock().is_fail() && !matches!(plan, Plan::Commit | Plan::Abort) {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "Current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
+
+        let txn_active = ctx.txn_mgr().lock().is_active();
+        if txn_active {
+            // A rejected statement fails the transaction, as if it failed to execute.
+            Self::check_txn_plan(&ctx, plan).await.map_err(|e| {
+                ctx.txn_mgr().lock().set_fail();
+                e
+            })?;
+        }
+
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
         access_checker.check(plan).await.map_err(|e| {
             error!("Access.denied(v2): {:?}", e);
+            ctx.txn_mgr().lock().set_fail();
             e
         })?;
         Self::get_inner(ctx, plan)
     }
 
+    /// Only the statements whose changes are buffered by the explicit transaction can run in it,
+    /// that is, the reads and the writes to FUSE tables, the others would commit immediately.
+    async fn check_txn_plan(ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        let plan = match plan {
+            Plan::ExplainAnalyze { plan } => plan.as_ref(),
+            plan => plan,
+        };
+        let (catalog, database, table) = match plan {
+            Plan::Insert(plan) => (&plan.catalog, &plan.database, &plan.table),
+            Plan::Replace(plan) => (&plan.catalog, &plan.database, &plan.table),
+            Plan::Update(plan) => (&plan.catalog, &plan.database, &plan.table),
+            Plan::MergeInto(plan) => (&plan.catalog, &plan.database, &plan.table),
+            Plan::TruncateTable(plan) => (&plan.catalog, &plan.database, &plan.table),
+            Plan::Delete(plan) => (&plan.catalog_name, &plan.database_name, &plan.table_name),
+            Plan::CopyIntoTable(plan) => {
+                let table = ctx
+                    .get_table(
+                        plan.catalog_info.catalog_name(),
+                        &plan.database_name,
+                        &plan.table_name,
+                    )
+                    .await?;
+                return check_txn_table(table.engine(), &plan.database_name, &plan.table_name);
+            }
+            Plan::Query { .. }
+            | Plan::Explain { .. }
+            | Plan::ExplainAst { .. }
+            | Plan::ExplainSyntax { .. }
+            | Plan::ShowCreateCatalog(_)
+            | Plan::ShowCreateDatabase(_)
+            | Plan::ShowCreateTable(_)
+            | Plan::DescribeTable(_)
+            | Plan::ExistsTable(_)
+            | Plan::UseDatabase(_)
+            | Plan::ShowRoles(_)
+            | Plan::ShowGrants(_)
+            | Plan::ShowFileFormats(_)
+            | Plan::DescConnection(_)
+            | Plan::ShowConnections(_)
+            | Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::Kill(_)
+            | Plan::Begin
+            | Plan::Commit
+            | Plan::Abort => return Ok(()),
+            _ => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "{} is not supported in an explicit transaction, only queries and writes to FUSE tables are",
+                    plan.format_indent()?
+                )));
+            }
+        };
+        let table = ctx.get_table(catalog, database, table).await?;
+        check_txn_table(table.engine(), database, table.name())
+    }
+
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         match plan {
             Plan::Query {
@@ -181,6 +256,12 @@ impl InterpreterFactory {
                 ctx,
                 *set_options.clone(),
             )?)),
+            Plan::AddTablePartition(add_table_partition) => Ok(Arc::new(
+                AddTablePartitionInterpreter::try_create(ctx, *add_table_partition.clone())?,
+            )),
+            Plan::DropTablePartition(drop_table_partition) => Ok(Arc::new(
+                DropTablePartitionInterpreter::try_create(ctx, *drop_table_partition.clone())?,
+            )),
             Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
                 RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
             )),
@@ -238,6 +319,23 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(Arc::new(CreateMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *create_materialized_view.clone(),
+                )?))
+            }
+            Plan::DropMaterializedView(drop_materialized_view) => Ok(Arc::new(
+                DropMaterializedViewInterpreter::try_create(ctx, *drop_materialized_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(Arc::new(RefreshMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *refresh_materialized_view.clone(),
+                )?))
+            }
+
             // Streams
             Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
                 ctx,
@@ -262,6 +360,18 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::RefreshVectorIndex(refresh_vector_index) => Ok(Arc::new(
+                RefreshVectorIndexInterpreter::try_create(ctx, *refresh_vector_index.clone())?,
+            )),
+            Plan::CreateInvertedIndex(create_inverted_index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *create_inverted_index.clone())?,
+            )),
+            Plan::DropInvertedIndex(drop_inverted_index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *drop_inverted_index.clone())?,
+            )),
+            Plan::RefreshInvertedIndex(refresh_inverted_index) => Ok(Arc::new(
+                RefreshInvertedIndexInterpreter::try_create(ctx, *refresh_inverted_index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -399,6 +509,10 @@ impl InterpreterFactory {
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
 
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
                 CreateShareEndpointInterpreter::try_create(ctx, *p.clone())?,
@@ -486,6 +600,14 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
 
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterPipe(p) => Ok(Arc::new(AlterPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescPipe(p) => Ok(Arc::new(DescPipeInterpreter::try_create(ctx, *p.clone())?)),
+
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -518,3 +640,13 @@ impl InterpreterFactory {
         }
     }
 }
+
+fn check_txn_table(engine: &str, database: &str, table: &str) -> Result<()> {
+    if engine != "FUSE" {
+        return Err(ErrorCode::Unimplemented(format!(
+            "Table {}.{} with engine {} can't be written in an explicit transaction, only FUSE tables can",
+            database, table, engine
+        )));
+    }
+    Ok(())
+}
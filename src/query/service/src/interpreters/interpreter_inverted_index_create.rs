@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CreateInvertedIndexPlan;
+use databend_storages_common_table_meta::table::InvertedIndexDefinition;
+use databend_storages_common_table_meta::table::InvertedIndexes;
+use databend_storages_common_table_meta::table::OPT_KEY_INVERTED_INDEXES;
+use uuid::Uuid;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateInvertedIndexPlan,
+}
+
+impl CreateInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateInvertedIndexPlan) -> Result<Self> {
+        Ok(CreateInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let mut indexes = InvertedIndexes::from_options(table.options())?;
+        if indexes.get(&self.plan.index_name).is_some() {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::IndexAlreadyExists(format!(
+                    "Inverted index '{}' already exists on table {}.{}",
+                    self.plan.index_name, self.plan.database, self.plan.table
+                )))
+            };
+        }
+
+        // The existing blocks are indexed by `REFRESH INVERTED INDEX`,
+        // the new blocks are indexed when they are written.
+        indexes.0.push(InvertedIndexDefinition {
+            name: self.plan.index_name.clone(),
+            column_ids: self.plan.column_ids.clone(),
+            tokenizer: self.plan.tokenizer.clone(),
+            version: Uuid::new_v4().simple().to_string(),
+        });
+
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_INVERTED_INDEXES.to_string(),
+                indexes.to_option_value()?,
+            )]),
+        };
+        catalog
+            .upsert_table_option(tenant.as_str(), &self.plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
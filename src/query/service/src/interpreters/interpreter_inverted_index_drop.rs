@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::DropInvertedIndexPlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::InvertedIndexes;
+use databend_storages_common_table_meta::table::OPT_KEY_INVERTED_INDEXES;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropInvertedIndexPlan,
+}
+
+impl DropInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropInvertedIndexPlan) -> Result<Self> {
+        Ok(DropInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "DropInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let mut indexes = InvertedIndexes::from_options(table.options())?;
+        let Some(position) = indexes
+            .0
+            .iter()
+            .position(|index| index.name == self.plan.index_name)
+        else {
+            return if self.plan.if_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::UnknownIndex(format!(
+                    "Inverted index '{}' does not exist on table {}.{}",
+                    self.plan.index_name, self.plan.database, self.plan.table
+                )))
+            };
+        };
+        let index = indexes.0.remove(position);
+
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_INVERTED_INDEXES.to_string(),
+                indexes.to_option_value()?,
+            )]),
+        };
+        catalog
+            .upsert_table_option(tenant.as_str(), &self.plan.database, req)
+            .await?;
+
+        // The files of the index are never read again, remove them on a best-effort basis.
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let prefix = fuse_table
+            .meta_location_generator()
+            .gen_inverted_index_prefix(&index.version);
+        if let Err(e) = fuse_table.get_operator().remove_all(&prefix).await {
+            warn!(
+                "failed to remove the files of inverted index {}: {}",
+                prefix, e
+            );
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::RefreshInvertedIndexPlan;
+use databend_common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshInvertedIndexPlan,
+}
+
+impl RefreshInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshInvertedIndexPlan) -> Result<Self> {
+        Ok(RefreshInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "RefreshInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.clone();
+        let db_name = self.plan.database.clone();
+        let tbl_name = self.plan.table.clone();
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &db_name, &tbl_name)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let indexes = fuse_table.inverted_indexes();
+        let Some(index) = indexes.get(&self.plan.index_name) else {
+            return Err(ErrorCode::UnknownIndex(format!(
+                "Inverted index '{}' does not exist on table {}.{}",
+                self.plan.index_name, db_name, tbl_name
+            )));
+        };
+        fuse_table
+            .do_refresh_inverted_index(self.ctx.clone(), index)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
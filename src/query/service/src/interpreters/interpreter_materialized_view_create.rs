@@ -0,0 +1,147 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Engine;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CreateMaterializedViewPlan;
+use databend_common_sql::plans::CreateTablePlan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
+use databend_storages_common_table_meta::table::MaterializedViews;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.plan.tenant.as_str();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        if catalog
+            .exists_table(tenant, &self.plan.database, &self.plan.view_name)
+            .await?
+        {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "{}.{} as materialized view already exists",
+                    self.plan.database, self.plan.view_name
+                )))
+            };
+        }
+
+        // Register the view on the source table, the change tracking is required
+        // to refresh the view incrementally.
+        let source = catalog
+            .get_table(tenant, &self.plan.source_database, &self.plan.source_table)
+            .await?;
+        if source.get_id() != self.plan.source_table_id {
+            return Err(ErrorCode::UnknownTable(format!(
+                "table '{}.{}' has been changed during creating materialized view",
+                self.plan.source_database, self.plan.source_table
+            )));
+        }
+        let mut views = MaterializedViews::from_options(source.options())?;
+        views.add(&self.plan.database, &self.plan.view_name);
+        let req = UpsertTableOptionReq {
+            table_id: source.get_id(),
+            seq: MatchSeq::Exact(source.get_table_info().ident.seq),
+            options: HashMap::from([
+                (
+                    OPT_KEY_CHANGE_TRACKING.to_string(),
+                    Some("true".to_string()),
+                ),
+                (
+                    OPT_KEY_MATERIALIZED_VIEWS.to_string(),
+                    views.to_option_value()?,
+                ),
+            ]),
+        };
+        catalog
+            .upsert_table_option(tenant, &self.plan.source_database, req)
+            .await?;
+
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            self.plan.query.clone(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID.to_string(),
+            self.plan.source_table_id.to_string(),
+        );
+        let create_plan = CreateTablePlan {
+            if_not_exists: self.plan.if_not_exists,
+            tenant: self.plan.tenant.clone(),
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.view_name.clone(),
+            schema: self.plan.schema.clone(),
+            engine: Engine::Fuse,
+            engine_options: BTreeMap::new(),
+            storage_params: None,
+            read_only_attach: false,
+            part_prefix: String::new(),
+            options,
+            field_comments: vec![],
+            cluster_key: None,
+            as_select: None,
+        };
+        CreateTableInterpreter::try_create(self.ctx.clone(), create_plan)?
+            .execute2()
+            .await?;
+
+        // Populate the view.
+        let refresh_plan = RefreshMaterializedViewPlan {
+            tenant: self.plan.tenant.clone(),
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            view_name: self.plan.view_name.clone(),
+        };
+        RefreshMaterializedViewInterpreter::try_create(self.ctx.clone(), refresh_plan)?
+            .execute2()
+            .await
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::binder::materialized_view_source;
+use databend_common_sql::normalize_identifier;
+use databend_common_sql::plans::DropMaterializedViewPlan;
+use databend_common_sql::NameResolutionContext;
+use databend_storages_common_table_meta::table::MaterializedViews;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropMaterializedViewPlan,
+}
+
+impl DropMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropMaterializedViewPlan) -> Result<Self> {
+        Ok(DropMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "DropMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.plan.tenant.as_str();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let view = match catalog
+            .get_table(tenant, &self.plan.database, &self.plan.view_name)
+            .await
+        {
+            Ok(view) => view,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE && self.plan.if_exists => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+        let Some(query) = view.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not a materialized view, please use `DROP TABLE {}.{}`",
+                &self.plan.database,
+                &self.plan.view_name,
+                &self.plan.database,
+                &self.plan.view_name
+            )));
+        };
+
+        let db = catalog.get_database(tenant, &self.plan.database).await?;
+        catalog
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: self.plan.if_exists,
+                tenant: self.plan.tenant.clone(),
+                table_name: self.plan.view_name.clone(),
+                tb_id: view.get_id(),
+                db_id: db.get_db_info().ident.db_id,
+            })
+            .await?;
+
+        // Unregister the view from the source table, if it still exists.
+        let tokens = tokenize_sql(query)?;
+        let (stmt, _) = parse_sql(&tokens, self.ctx.get_settings().get_sql_dialect()?)?;
+        let Statement::Query(query) = stmt else {
+            return Ok(PipelineBuildResult::create());
+        };
+        let Some((_, database, table)) = materialized_view_source(&query) else {
+            return Ok(PipelineBuildResult::create());
+        };
+        let name_resolution_ctx =
+            NameResolutionContext::try_from(self.ctx.get_settings().as_ref())?;
+        let source_database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &name_resolution_ctx).name)
+            .unwrap_or_else(|| self.plan.database.clone());
+        let source_table = normalize_identifier(table, &name_resolution_ctx).name;
+        let Ok(source) = catalog
+            .get_table(tenant, &source_database, &source_table)
+            .await
+        else {
+            return Ok(PipelineBuildResult::create());
+        };
+        if view
+            .options()
+            .get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID)
+            != Some(&source.get_id().to_string())
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let mut views = MaterializedViews::from_options(source.options())?;
+        views.remove(&self.plan.database, &self.plan.view_name);
+        let req = UpsertTableOptionReq {
+            table_id: source.get_id(),
+            seq: MatchSeq::Exact(source.get_table_info().ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_MATERIALIZED_VIEWS.to_string(),
+                views.to_option_value()?,
+            )]),
+        };
+        catalog
+            .upsert_table_option(tenant, &source_database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
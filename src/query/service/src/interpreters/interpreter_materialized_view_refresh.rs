@@ -0,0 +1,521 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::GroupBy;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::binder::materialized_view_source;
+use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::normalize_identifier;
+use databend_common_sql::optimizer::SExpr;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::NameResolutionContext;
+use databend_common_sql::Planner;
+use databend_common_storages_fuse::io::SnapshotsIO;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_stream::stream_table::StreamTable;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_storages_common_table_meta::table::MODE_APPEND_ONLY;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::locks::LockManager;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline_without_render_result_set;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let view = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &self.plan.view_name)
+            .await?;
+        if !view.options().contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not a materialized view",
+                &self.plan.database, &self.plan.view_name
+            )));
+        }
+
+        // Add table lock, and reload the view in case it was refreshed concurrently.
+        let table_lock = LockManager::create_table_lock(view.get_table_info().clone())?;
+        let lock_guard = table_lock.try_lock(self.ctx.clone()).await?;
+        let view = view.refresh(self.ctx.as_ref()).await?;
+        let options = view.options();
+
+        let sql = options
+            .get(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+            .cloned()
+            .unwrap_or_default();
+        let tokens = tokenize_sql(&sql)?;
+        let (stmt, _) = parse_sql(&tokens, self.ctx.get_settings().get_sql_dialect()?)?;
+        let Statement::Query(mut query) = stmt else {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid materialized view query: {sql}"
+            )));
+        };
+        let Some((catalog_ident, database, table)) = materialized_view_source(&query) else {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid materialized view query: {sql}"
+            )));
+        };
+        let (catalog_ident, database, table) =
+            (catalog_ident.clone(), database.clone(), table.clone());
+
+        let name_resolution_ctx =
+            NameResolutionContext::try_from(self.ctx.get_settings().as_ref())?;
+        let source_database = database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &name_resolution_ctx).name)
+            .unwrap_or_else(|| self.plan.database.clone());
+        let source_table = normalize_identifier(&table, &name_resolution_ctx).name;
+        let source = self
+            .ctx
+            .get_table(&self.plan.catalog, &source_database, &source_table)
+            .await?;
+        if options
+            .get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID)
+            .map(|id| id != &source.get_id().to_string())
+            .unwrap_or(true)
+        {
+            return Err(ErrorCode::UnknownTable(format!(
+                "The table '{source_database}.{source_table}' of materialized view '{}.{}' has been dropped",
+                &self.plan.database, &self.plan.view_name
+            )));
+        }
+
+        let source_snapshot = source
+            .options()
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .cloned()
+            .unwrap_or_default();
+        let last_source_snapshot = options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT);
+        if last_source_snapshot == Some(&source_snapshot) {
+            // Already up to date.
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // Record the snapshot of the source table along with the refreshed data.
+        let mut view_info = view.get_table_info().clone();
+        view_info.meta.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT.to_string(),
+            source_snapshot,
+        );
+        let view = catalog.get_table_by_info(&view_info)?;
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let (mut plan, _) = planner.plan_sql(&sql).await?;
+
+        // If the source table is only appended since the last refresh, only the appended rows
+        // need to be read: they are inserted into the view if the query is not aggregated,
+        // or aggregated and merged with the view if the aggregate functions are mergeable.
+        let (incremental, merge_columns) = match (last_source_snapshot, &plan) {
+            (Some(last_source_snapshot), Plan::Query { s_expr, .. }) => {
+                let aggregated = contains_aggregate(s_expr);
+                let merge_columns = if aggregated {
+                    mergeable_aggregate(&query)
+                } else {
+                    None
+                };
+                let incremental = (!aggregated || merge_columns.is_some())
+                    && source.change_tracking_enabled()
+                    && is_append_only(source.as_ref(), last_source_snapshot).await;
+                (incremental, merge_columns)
+            }
+            _ => (false, None),
+        };
+        if incremental {
+            let last_source_snapshot = last_source_snapshot.cloned().unwrap_or_default();
+            let stream_name = format!("_materialized_view_source_{}", view.get_id());
+            let stream = create_source_stream(
+                source.as_ref(),
+                &source_database,
+                &stream_name,
+                last_source_snapshot,
+            )?;
+            self.ctx
+                .evict_table_from_cache(&self.plan.catalog, &source_database, &stream_name)?;
+            self.ctx
+                .attach_table(&self.plan.catalog, &source_database, &stream_name, stream);
+
+            let stream_sql = format!(
+                "SELECT * FROM {}{}.{stream_name} AS _change_append",
+                catalog_ident
+                    .as_ref()
+                    .map(|ident| format!("{ident}."))
+                    .unwrap_or_default(),
+                database
+                    .as_ref()
+                    .map(|ident| ident.to_string())
+                    .unwrap_or_else(|| source_database.clone()),
+            );
+            let tokens = tokenize_sql(&stream_sql)?;
+            let (stmt, _) = parse_sql(&tokens, self.ctx.get_settings().get_sql_dialect()?)?;
+            let Statement::Query(stream_query) = stmt else {
+                unreachable!()
+            };
+            replace_source(&mut query, *stream_query);
+
+            let mut sql = query.to_string();
+            if let Some(merge_columns) = &merge_columns {
+                self.ctx.evict_table_from_cache(
+                    &self.plan.catalog,
+                    &self.plan.database,
+                    &self.plan.view_name,
+                )?;
+                let columns = view
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| field.name().clone())
+                    .collect::<Vec<_>>();
+                sql = merge_query(
+                    &[
+                        &self.plan.catalog,
+                        &self.plan.database,
+                        &self.plan.view_name,
+                    ],
+                    &columns,
+                    merge_columns,
+                    &sql,
+                );
+            }
+            let mut planner = Planner::new(self.ctx.clone());
+            (plan, _) = planner.plan_sql(&sql).await?;
+        }
+        // The merged aggregation replaces the data of the view.
+        let overwrite = !incremental || merge_columns.is_some();
+
+        let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } = &plan
+        else {
+            unreachable!()
+        };
+        let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+        let mut select_plan = builder.build(s_expr, bind_context.column_set()).await?;
+
+        let select_schema = plan.schema();
+        let insert_schema = Arc::new(DataSchema::from(view.schema().as_ref()));
+        if select_schema.fields().len() != insert_schema.fields().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Fields in the query of materialized view is not equal with the view, query fields: {}, view fields: {}",
+                select_schema.fields().len(),
+                insert_schema.fields().len(),
+            )));
+        }
+        let cast_needed = select_schema != insert_schema;
+        let insert_select = |plan_id, input| {
+            PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
+                plan_id,
+                input,
+                catalog_info: catalog.info(),
+                table_info: view.get_table_info().clone(),
+                select_schema: select_schema.clone(),
+                select_column_bindings: bind_context.columns.clone(),
+                insert_schema: insert_schema.clone(),
+                cast_needed,
+            }))
+        };
+        let insert_select_plan = match select_plan {
+            PhysicalPlan::Exchange(ref mut exchange) => {
+                // insert can be dispatched to different nodes
+                let input = exchange.input.clone();
+                exchange.input = Box::new(insert_select(exchange.plan_id, input));
+                select_plan
+            }
+            other_plan => {
+                // insert should wait until all nodes finished
+                insert_select(other_plan.get_id(), Box::new(other_plan))
+            }
+        };
+
+        let mut build_res =
+            build_query_pipeline_without_render_result_set(&self.ctx, &insert_select_plan, false)
+                .await?;
+        view.commit_insertion(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            None,
+            vec![],
+            overwrite,
+            None,
+            None,
+        )?;
+        build_res.main_pipeline.add_lock_guard(lock_guard);
+
+        info!(
+            "Refresh materialized view {}.{}, incremental: {}, merge aggregation: {}",
+            &self.plan.database,
+            &self.plan.view_name,
+            incremental,
+            merge_columns.is_some()
+        );
+        Ok(build_res)
+    }
+}
+
+fn contains_aggregate(s_expr: &SExpr) -> bool {
+    matches!(
+        s_expr.plan(),
+        RelOperator::Aggregate(_) | RelOperator::Window(_)
+    ) || s_expr
+        .children()
+        .iter()
+        .any(|child| contains_aggregate(child))
+}
+
+/// How a column of an aggregating materialized view is merged with the aggregated appended rows.
+enum MergeColumn {
+    /// A group by key.
+    Key,
+    /// An aggregate merged by the function, e.g. the counts are merged by `SUM`.
+    Aggregate(&'static str),
+}
+
+/// Returns how the columns of the view are merged, if the query groups by the keys in the
+/// select list and all the other columns are `SUM`, `COUNT`, `MIN` or `MAX` without `DISTINCT`.
+fn mergeable_aggregate(query: &Query) -> Option<Vec<MergeColumn>> {
+    let SetExpr::Select(select) = &query.body else {
+        return None;
+    };
+    let group_by = match &select.group_by {
+        None => vec![],
+        Some(GroupBy::Normal(exprs)) => exprs.iter().collect(),
+        Some(_) => return None,
+    };
+    let mut columns = Vec::with_capacity(select.select_list.len());
+    let mut keys = vec![false; group_by.len()];
+    for (i, target) in select.select_list.iter().enumerate() {
+        let SelectTarget::AliasedExpr { expr, .. } = target else {
+            return None;
+        };
+        if let Some(merge_function) = merge_function(expr) {
+            columns.push(MergeColumn::Aggregate(merge_function));
+            continue;
+        }
+        // The key must be grouped by its expression or position.
+        let position = group_by
+            .iter()
+            .position(|group_by_expr| match group_by_expr {
+                Expr::Literal {
+                    lit: Literal::UInt64(pos),
+                    ..
+                } => *pos == i as u64 + 1,
+                _ => group_by_expr.to_string() == expr.to_string(),
+            })?;
+        keys[position] = true;
+        columns.push(MergeColumn::Key);
+    }
+    // The groups of the view are the groups of the query only if all the keys are selected.
+    if keys.iter().all(|key| *key) {
+        Some(columns)
+    } else {
+        None
+    }
+}
+
+fn merge_function(expr: &Expr) -> Option<&'static str> {
+    match expr {
+        Expr::CountAll { window: None, .. } => Some("SUM"),
+        Expr::FunctionCall {
+            distinct: false,
+            name,
+            params,
+            window: None,
+            lambda: None,
+            ..
+        } if params.is_empty() => match name.name.to_lowercase().as_str() {
+            "sum" | "count" => Some("SUM"),
+            "min" => Some("MIN"),
+            "max" => Some("MAX"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Builds the query which merges the rows of the view with the aggregated appended rows.
+fn merge_query(
+    view: &[&str],
+    columns: &[String],
+    merge_columns: &[MergeColumn],
+    delta_sql: &str,
+) -> String {
+    let quote = |name: &str| {
+        Identifier {
+            name: name.to_string(),
+            quote: Some('`'),
+            span: None,
+        }
+        .to_string()
+    };
+    let view = view.iter().map(|name| quote(name)).collect::<Vec<_>>();
+    let columns = columns.iter().map(|name| quote(name)).collect::<Vec<_>>();
+    let select_list = columns
+        .iter()
+        .zip(merge_columns)
+        .map(|(column, merge_column)| match merge_column {
+            MergeColumn::Key => column.clone(),
+            MergeColumn::Aggregate(function) => format!("{function}({column}) AS {column}"),
+        })
+        .collect::<Vec<_>>();
+    let keys = columns
+        .iter()
+        .zip(merge_columns)
+        .filter(|(_, merge_column)| matches!(merge_column, MergeColumn::Key))
+        .map(|(column, _)| column.clone())
+        .collect::<Vec<_>>();
+    let mut sql = format!(
+        "SELECT {} FROM (SELECT {} FROM {} UNION ALL {delta_sql}) AS _merge",
+        select_list.join(", "),
+        columns.join(", "),
+        view.join("."),
+    );
+    if !keys.is_empty() {
+        sql.push_str(&format!(" GROUP BY {}", keys.join(", ")));
+    }
+    sql
+}
+
+/// Returns true if all the segments of the last snapshot are still in the current snapshot,
+/// which means the table is only appended since then.
+async fn is_append_only(table: &dyn Table, last_snapshot: &str) -> bool {
+    if last_snapshot.is_empty() {
+        return true;
+    }
+    let Ok(fuse_table) = FuseTable::try_from_table(table) else {
+        return false;
+    };
+    let Ok(Some(snapshot)) = fuse_table.read_table_snapshot().await else {
+        return false;
+    };
+    let Ok((last_snapshot, _)) =
+        SnapshotsIO::read_snapshot(last_snapshot.to_string(), fuse_table.get_operator()).await
+    else {
+        return false;
+    };
+    let segments = snapshot.segments.iter().collect::<HashSet<_>>();
+    last_snapshot
+        .segments
+        .iter()
+        .all(|segment| segments.contains(segment))
+}
+
+/// Creates an append only stream on the source table, starting at the last refreshed snapshot.
+fn create_source_stream(
+    source: &dyn Table,
+    database: &str,
+    name: &str,
+    last_snapshot: String,
+) -> Result<Arc<dyn Table>> {
+    let source_info = source.get_table_info();
+    let mut options = BTreeMap::new();
+    options.insert(OPT_KEY_MODE.to_string(), MODE_APPEND_ONLY.to_string());
+    options.insert(OPT_KEY_TABLE_NAME.to_string(), source_info.name.clone());
+    options.insert(OPT_KEY_DATABASE_NAME.to_string(), database.to_string());
+    options.insert(OPT_KEY_TABLE_ID.to_string(), source.get_id().to_string());
+    options.insert(
+        OPT_KEY_TABLE_VER.to_string(),
+        source_info.ident.seq.to_string(),
+    );
+    if !last_snapshot.is_empty() {
+        options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), last_snapshot);
+    }
+    let meta = TableMeta {
+        schema: source.schema(),
+        engine: STREAM_ENGINE.to_string(),
+        catalog: source_info.meta.catalog.clone(),
+        options,
+        ..Default::default()
+    };
+    let table_info = TableInfo::new(database, name, TableIdent::default(), meta);
+    Ok(StreamTable::try_create(table_info)?.into())
+}
+
+/// Replaces the table of the materialized view query with the given query,
+/// which keeps the table name as alias.
+fn replace_source(query: &mut Query, source: Query) {
+    let SetExpr::Select(select) = &mut query.body else {
+        return;
+    };
+    let TableReference::Table { table, alias, .. } = &select.from[0] else {
+        return;
+    };
+    let alias = alias.clone().unwrap_or_else(|| TableAlias {
+        name: table.clone(),
+        columns: vec![],
+    });
+    select.from[0] = TableReference::Subquery {
+        span: None,
+        lateral: false,
+        subquery: Box::new(source),
+        alias: Some(alias),
+    };
+}
@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::AlterPipePlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterPipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterPipePlan,
+}
+
+impl AlterPipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterPipePlan) -> Result<Self> {
+        Ok(AlterPipeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterPipeInterpreter {
+    fn name(&self) -> &str {
+        "AlterPipeInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_pipe_execute");
+
+        let plan = self.plan.clone();
+        UserApiProvider::instance()
+            .update_pipe(
+                &plan.tenant,
+                &plan.name,
+                plan.execution_paused,
+                plan.comment,
+                plan.if_exists,
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -36,6 +36,7 @@ use databend_common_meta_types::MatchSeq;
 use databend_common_sql::field_default_value;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_sql::VectorIndexColumns;
 use databend_common_storage::DataOperator;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
@@ -46,8 +47,10 @@ use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
 use databend_common_storages_fuse::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
+use databend_common_vector::VectorDistance;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::VectorIndex;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
@@ -57,11 +60,15 @@ use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_DISTANCE;
 use log::error;
 
 use crate::interpreters::InsertInterpreter;
@@ -321,7 +328,10 @@ impl CreateTableInterpreter {
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check vector_index_columns and vector_index_distance.
+        is_valid_vector_index_columns(&table_meta.options, schema)?;
+        is_valid_vector_index_distance(&table_meta.options)?;
         is_valid_change_tracking(&table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
@@ -428,11 +438,15 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_VECTOR_INDEX_COLUMNS);
+    r.insert(OPT_KEY_VECTOR_INDEX_DISTANCE);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -497,6 +511,25 @@ pub fn is_valid_bloom_index_columns(
     Ok(())
 }
 
+pub fn is_valid_vector_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_VECTOR_INDEX_COLUMNS) {
+        VectorIndexColumns::verify_definition(value, schema, VectorIndex::supported_type)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_vector_index_distance(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_VECTOR_INDEX_DISTANCE) {
+        value
+            .parse::<VectorDistance>()
+            .map_err(|e| ErrorCode::TableOptionInvalid(e.message()))?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()> {
     if let Some(value) = options.get(OPT_KEY_CHANGE_TRACKING) {
         value.to_lowercase().parse::<bool>()?;
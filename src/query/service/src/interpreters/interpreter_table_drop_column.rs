@@ -23,10 +23,13 @@ use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::DropTableColumnPlan;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_sql::VectorIndexColumns;
 use databend_common_storages_share::save_share_table_info;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::table::InvertedIndexes;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::interpreter_table_add_column::generate_new_snapshot;
@@ -82,6 +85,20 @@ impl Interpreter for DropTableColumnInterpreter {
             )));
         }
 
+        // The indexed columns can't be dropped before the inverted index.
+        let column_id = table_info
+            .schema()
+            .field_with_name(self.plan.column.as_str())?
+            .column_id();
+        if let Some(index) =
+            InvertedIndexes::from_options(table_info.options())?.get_by_column(column_id)
+        {
+            return Err(ErrorCode::ColumnReferencedByInvertedIndex(format!(
+                "column `{}` is used by inverted index `{}`, drop the index first",
+                self.plan.column, index.name
+            )));
+        }
+
         let mut schema: DataSchema = table_info.schema().into();
         let field = schema.field_with_name(self.plan.column.as_str())?;
         if field.computed_expr().is_none() {
@@ -110,6 +127,14 @@ impl Interpreter for DropTableColumnInterpreter {
                 }
             }
         }
+        if let Some(value) = opts.get_mut(OPT_KEY_VECTOR_INDEX_COLUMNS) {
+            let VectorIndexColumns(mut cols) = value.parse::<VectorIndexColumns>()?;
+            if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                // remove from the vector index columns.
+                cols.remove(pos);
+                *value = cols.join(",");
+            }
+        }
 
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::DropTablePartitionPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTablePartitionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTablePartitionPlan,
+}
+
+impl DropTablePartitionInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTablePartitionPlan) -> Result<Self> {
+        Ok(DropTablePartitionInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTablePartitionInterpreter {
+    fn name(&self) -> &str {
+        "DropTablePartitionInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_catalog(&self.plan.catalog)
+            .await?
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+
+        table
+            .drop_partition(
+                self.ctx.clone(),
+                self.plan.partition.clone(),
+                self.plan.if_exists,
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
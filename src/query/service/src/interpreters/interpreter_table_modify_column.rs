@@ -42,6 +42,7 @@ use databend_common_sql::plans::ModifyTableColumnPlan;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_sql::Planner;
+use databend_common_sql::VectorIndexColumns;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_share::save_share_table_info;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
@@ -49,7 +50,9 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::UserApiProvider;
 use databend_enterprise_data_mask_feature::get_datamask_handler;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::VectorIndex;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 
 use super::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
@@ -235,6 +238,10 @@ impl ModifyTableColumnInterpreter {
                 bloom_index_cols = cols;
             }
         }
+        let vector_index_cols = match table_info.options().get(OPT_KEY_VECTOR_INDEX_COLUMNS) {
+            Some(v) => v.parse::<VectorIndexColumns>()?,
+            None => VectorIndexColumns::default(),
+        };
 
         let mut table_info = table.get_table_info().clone();
         table_info.meta.fill_field_comments();
@@ -262,6 +269,16 @@ impl ModifyTableColumnInterpreter {
                             data_type
                         )));
                     }
+                    // If the column is defined in vector index columns,
+                    // check whether the data type is supported for vector index.
+                    if vector_index_cols.0.iter().any(|v| v.as_str() == column)
+                        && !VectorIndex::supported_type(data_type)
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Unsupported data type '{}' for vector index",
+                            data_type
+                        )));
+                    }
                     new_schema.fields[i].data_type = data_type.clone();
                     table_info.meta.field_comments[i] = comment.to_string();
                 }
@@ -23,10 +23,12 @@ use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::RenameTableColumnPlan;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_sql::VectorIndexColumns;
 use databend_common_storages_share::save_share_table_info;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::interpreter_table_create::is_valid_column;
@@ -116,6 +118,14 @@ impl Interpreter for RenameTableColumnInterpreter {
                     }
                 }
             }
+            if let Some(value) = opts.get_mut(OPT_KEY_VECTOR_INDEX_COLUMNS) {
+                let VectorIndexColumns(mut cols) = value.parse::<VectorIndexColumns>()?;
+                if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
+                    // replace the vector index columns with new column name.
+                    cols[pos] = self.plan.new_column.clone();
+                    *value = cols.join(",");
+                }
+            }
 
             let table_id = table_info.ident.table_id;
             let table_version = table_info.ident.seq;
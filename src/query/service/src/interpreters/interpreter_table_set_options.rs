@@ -22,6 +22,7 @@ use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::SetOptionsPlan;
 use databend_common_storages_fuse::TableContext;
+use databend_storages_common_table_meta::table::is_reserved_opt_key;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use log::error;
@@ -31,6 +32,8 @@ use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_change_tracking;
 use super::interpreter_table_create::is_valid_create_opt;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_vector_index_columns;
+use super::interpreter_table_create::is_valid_vector_index_distance;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -79,6 +82,12 @@ impl Interpreter for SetOptionsInterpreter {
         }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
+            if is_reserved_opt_key(&key) {
+                error!("{}", &error_str);
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "can't change {key} for alter table statement",
+                )));
+            }
             if !is_valid_create_opt(&key) {
                 error!("{}", &error_str);
                 return Err(ErrorCode::TableOptionInvalid(format!(
@@ -99,6 +108,9 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check vector_index_columns and vector_index_distance.
+        is_valid_vector_index_columns(&self.plan.set_options, table.schema())?;
+        is_valid_vector_index_distance(&self.plan.set_options)?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
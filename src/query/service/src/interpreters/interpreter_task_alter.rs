@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::AlterTaskOptions;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
@@ -24,10 +25,16 @@ use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::AlterTaskPlan;
+use databend_common_users::UserApiProvider;
 
+use crate::interpreters::common::check_error_integration;
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -81,6 +88,7 @@ impl AlterTaskInterpreter {
                 comments,
                 warehouse,
                 suspend_task_after_num_failures,
+                ..
             } => {
                 req.alter_task_type = AlterTaskType::Set as i32;
                 req.schedule_options = schedule.map(make_schedule_options);
@@ -114,6 +122,92 @@ impl AlterTaskInterpreter {
         }
         req
     }
+
+    #[async_backtrace::framed]
+    async fn alter_local_task(&self) -> Result<()> {
+        let plan = &self.plan;
+        let user_api = UserApiProvider::instance();
+        let seq_task = match user_api.get_task(&plan.tenant, &plan.task_name).await {
+            Ok(seq_task) => seq_task,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_TASK => return Ok(()),
+            Err(e) => return Err(e),
+        };
+
+        let now = Utc::now();
+        let mut task = seq_task.data;
+        match plan.alter_options.clone() {
+            AlterTaskOptions::Resume => {
+                task.status = TaskStatus::Started;
+                task.next_scheduled_at = task
+                    .schedule_options
+                    .as_ref()
+                    .and_then(|s| s.next_scheduled_at(now));
+            }
+            AlterTaskOptions::Suspend => {
+                task.status = TaskStatus::Suspended;
+                task.next_scheduled_at = None;
+                task.last_suspended_at = Some(now);
+            }
+            AlterTaskOptions::Set {
+                warehouse,
+                schedule,
+                suspend_task_after_num_failures,
+                error_integration,
+                comments,
+            } => {
+                if let Some(schedule) = schedule {
+                    let schedule = make_task_schedule_options(schedule);
+                    if task.status == TaskStatus::Started {
+                        task.next_scheduled_at = schedule.next_scheduled_at(now);
+                    }
+                    task.schedule_options = Some(schedule);
+                }
+                if let Some(warehouse) = warehouse {
+                    task.warehouse_options = Some(principal::WarehouseOptions {
+                        warehouse: Some(warehouse),
+                        using_warehouse_size: None,
+                    });
+                }
+                if suspend_task_after_num_failures.is_some() {
+                    task.suspend_task_after_num_failures = suspend_task_after_num_failures;
+                }
+                if error_integration.is_some() {
+                    check_error_integration(&error_integration)?;
+                    task.error_integration = error_integration;
+                }
+                if comments.is_some() {
+                    task.comment = comments;
+                }
+            }
+            AlterTaskOptions::Unset { warehouse } => {
+                if warehouse {
+                    task.warehouse_options = None;
+                }
+            }
+            AlterTaskOptions::ModifyAs(sql) => {
+                task.query_text = sql;
+            }
+            AlterTaskOptions::ModifyWhen(sql) => {
+                task.when_condition = Some(sql);
+            }
+            AlterTaskOptions::AddAfter(tasks) => {
+                for name in tasks {
+                    if !task.after.contains(&name) {
+                        task.after.push(name);
+                    }
+                }
+            }
+            AlterTaskOptions::RemoveAfter(tasks) => {
+                task.after.retain(|name| !tasks.contains(name));
+            }
+        }
+        task.updated_at = now;
+
+        user_api
+            .update_task(&plan.tenant, task, MatchSeq::Exact(seq_task.seq))
+            .await?;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -127,9 +221,9 @@ impl Interpreter for AlterTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Tasks are managed by the built-in scheduler.
+            self.alter_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
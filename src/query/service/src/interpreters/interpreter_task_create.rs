@@ -14,17 +14,23 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::CreateTaskRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_meta_app::principal::WarehouseOptions;
 use databend_common_sql::plans::CreateTaskPlan;
+use databend_common_users::UserApiProvider;
 
+use crate::interpreters::common::check_error_integration;
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule_options;
 use crate::interpreters::common::make_warehouse_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -43,14 +49,17 @@ impl CreateTaskInterpreter {
 }
 
 impl CreateTaskInterpreter {
-    fn build_request(&self) -> CreateTaskRequest {
-        let plan = self.plan.clone();
-        let owner = self
-            .ctx
+    fn owner(&self) -> String {
+        self.ctx
             .get_current_role()
             .unwrap_or_default()
             .identity()
-            .to_string();
+            .to_string()
+    }
+
+    fn build_request(&self) -> CreateTaskRequest {
+        let plan = self.plan.clone();
+        let owner = self.owner();
         CreateTaskRequest {
             task_name: plan.task_name,
             tenant_id: plan.tenant,
@@ -65,6 +74,33 @@ impl CreateTaskInterpreter {
             when_condition: plan.when_condition,
         }
     }
+
+    // The task is created suspended, and scheduled once it is resumed.
+    fn build_task(&self) -> Task {
+        let plan = self.plan.clone();
+        let now = Utc::now();
+        Task {
+            task_id: 0,
+            task_name: plan.task_name,
+            query_text: plan.sql,
+            when_condition: plan.when_condition,
+            after: plan.after,
+            comment: Some(plan.comment),
+            owner: self.owner(),
+            schedule_options: plan.schedule_opts.map(make_task_schedule_options),
+            warehouse_options: Some(WarehouseOptions {
+                warehouse: plan.warehouse_opts.warehouse,
+                using_warehouse_size: None,
+            }),
+            next_scheduled_at: None,
+            suspend_task_after_num_failures: plan.suspend_task_after_num_failures,
+            error_integration: plan.error_integration,
+            status: TaskStatus::Suspended,
+            created_at: now,
+            updated_at: now,
+            last_suspended_at: None,
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -78,9 +114,13 @@ impl Interpreter for CreateTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Tasks are managed by the built-in scheduler.
+            check_error_integration(&self.plan.error_integration)?;
+            let task = self.build_task();
+            UserApiProvider::instance()
+                .add_task(&self.plan.tenant, task, self.plan.if_not_exists)
+                .await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -18,10 +18,11 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::DescribeTaskRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DescribeTaskPlan;
+use databend_common_storages_system::make_pb_task;
 use databend_common_storages_system::parse_tasks_to_datablock;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::Interpreter;
@@ -62,9 +63,12 @@ impl Interpreter for DescribeTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot describe task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Tasks are managed by the built-in scheduler.
+            let task = UserApiProvider::instance()
+                .get_task(&self.plan.tenant, &self.plan.task_name)
+                .await?;
+            let result = parse_tasks_to_datablock(vec![make_pb_task(task.data)])?;
+            return PipelineBuildResult::from_blocks(vec![result]);
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
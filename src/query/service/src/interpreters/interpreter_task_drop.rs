@@ -18,9 +18,9 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::DropTaskRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DropTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::Interpreter;
@@ -61,9 +61,11 @@ impl Interpreter for DropTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot drop task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Tasks are managed by the built-in scheduler.
+            UserApiProvider::instance()
+                .drop_task(&self.plan.tenant, &self.plan.task_name, self.plan.if_exists)
+                .await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -18,7 +18,6 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ExecuteTaskRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::ExecuteTaskPlan;
 
@@ -26,6 +25,7 @@ use crate::interpreters::common::get_client_config;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::tasks::TaskScheduler;
 
 #[derive(Debug)]
 pub struct ExecuteTaskInterpreter {
@@ -50,9 +50,11 @@ impl Interpreter for ExecuteTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot execute task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Tasks are managed by the built-in scheduler.
+            TaskScheduler::instance()
+                .execute_task(&self.plan.task_name)
+                .await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TruncateTableReq;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The transaction is finished whether the commit succeeds or not.
+        let (commit_req, truncated_tables) = {
+            let txn_mgr = self.ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            let is_fail = txn_mgr.is_fail();
+            let commit_req = txn_mgr.commit_req();
+            let truncated_tables = txn_mgr.truncated_tables();
+            txn_mgr.clear();
+            if is_fail {
+                return Err(ErrorCode::CurrentTransactionIsAborted(
+                    "Current transaction is aborted, the changes are rolled back",
+                ));
+            }
+            (commit_req, truncated_tables)
+        };
+
+        if let Some((catalog, req)) = commit_req {
+            let catalog = self.ctx.get_catalog(&catalog).await?;
+            catalog.update_multi_table_meta(req).await?;
+
+            for table_info in truncated_tables {
+                catalog
+                    .truncate_table(&table_info, TruncateTableReq {
+                        table_id: table_info.ident.table_id,
+                        batch_size: None,
+                    })
+                    .await?;
+            }
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::Result;
+use databend_common_sql::plans::RefreshVectorIndexPlan;
+use databend_common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshVectorIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshVectorIndexPlan,
+}
+
+impl RefreshVectorIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshVectorIndexPlan) -> Result<Self> {
+        Ok(RefreshVectorIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshVectorIndexInterpreter {
+    fn name(&self) -> &str {
+        "RefreshVectorIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.clone();
+        let db_name = self.plan.database.clone();
+        let tbl_name = self.plan.table.clone();
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &db_name, &tbl_name)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        fuse_table.do_refresh_vector_index(self.ctx.clone()).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -46,7 +46,13 @@ mod interpreter_index_create;
 mod interpreter_index_drop;
 mod interpreter_index_refresh;
 mod interpreter_insert;
+mod interpreter_inverted_index_create;
+mod interpreter_inverted_index_drop;
+mod interpreter_inverted_index_refresh;
 mod interpreter_kill;
+mod interpreter_materialized_view_create;
+mod interpreter_materialized_view_drop;
+mod interpreter_materialized_view_refresh;
 mod interpreter_merge_into;
 mod interpreter_metrics;
 mod interpreter_network_policies_show;
@@ -58,6 +64,10 @@ mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_pipe_alter;
+mod interpreter_pipe_create;
+mod interpreter_pipe_desc;
+mod interpreter_pipe_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -87,11 +97,13 @@ mod interpreter_show_object_grant_privileges;
 mod interpreter_stream_create;
 mod interpreter_stream_drop;
 mod interpreter_table_add_column;
+mod interpreter_table_add_partition;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_partition;
 mod interpreter_table_exists;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
@@ -110,6 +122,9 @@ mod interpreter_task_describe;
 mod interpreter_task_drop;
 mod interpreter_task_execute;
 mod interpreter_tasks_show;
+mod interpreter_txn_abort;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -123,6 +138,7 @@ mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
 mod interpreter_user_udf_drop;
 mod interpreter_vacuum_drop_tables;
+mod interpreter_vector_index_refresh;
 mod interpreter_view_alter;
 mod interpreter_view_create;
 mod interpreter_view_drop;
@@ -152,7 +168,13 @@ pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
+pub use interpreter_inverted_index_create::CreateInvertedIndexInterpreter;
+pub use interpreter_inverted_index_drop::DropInvertedIndexInterpreter;
+pub use interpreter_inverted_index_refresh::RefreshInvertedIndexInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_materialized_view_create::CreateMaterializedViewInterpreter;
+pub use interpreter_materialized_view_drop::DropMaterializedViewInterpreter;
+pub use interpreter_materialized_view_refresh::RefreshMaterializedViewInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_network_policies_show::ShowNetworkPoliciesInterpreter;
 pub use interpreter_network_policy_alter::AlterNetworkPolicyInterpreter;
@@ -163,6 +185,10 @@ pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
+pub use interpreter_pipe_alter::AlterPipeInterpreter;
+pub use interpreter_pipe_create::CreatePipeInterpreter;
+pub use interpreter_pipe_desc::DescPipeInterpreter;
+pub use interpreter_pipe_drop::DropPipeInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
@@ -189,11 +215,13 @@ pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInter
 pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_partition::AddTablePartitionInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_partition::DropTablePartitionInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
@@ -204,6 +232,9 @@ pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_txn_abort::AbortInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
@@ -217,6 +248,7 @@ pub use interpreter_user_udf_alter::AlterUserUDFInterpreter;
 pub use interpreter_user_udf_create::CreateUserUDFInterpreter;
 pub use interpreter_user_udf_drop::DropUserUDFInterpreter;
 pub use interpreter_vacuum_drop_tables::VacuumDropTablesInterpreter;
+pub use interpreter_vector_index_refresh::RefreshVectorIndexInterpreter;
 pub use interpreter_view_alter::AlterViewInterpreter;
 pub use interpreter_view_create::CreateViewInterpreter;
 pub use interpreter_view_drop::DropViewInterpreter;
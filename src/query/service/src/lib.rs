@@ -56,6 +56,7 @@ pub mod sessions;
 pub mod spillers;
 pub mod stream;
 pub mod table_functions;
+pub mod tasks;
 pub mod test_kits;
 
 mod global_services;
@@ -47,7 +47,7 @@ use crate::sessions::QueryContext;
 
 impl PipelineBuilder {
     pub(crate) fn build_range_join(&mut self, range_join: &RangeJoin) -> Result<()> {
-        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join));
+        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join)?);
         self.expand_right_side_pipeline(range_join, state.clone())?;
         self.build_left_side(range_join, state)?;
         if self.enable_profiling {
@@ -102,6 +102,7 @@ impl PipelineBuilder {
             self.main_pipeline.get_scopes(),
         );
         right_side_builder.cte_state = self.cte_state.clone();
+        right_side_builder.r_cte_scan_blocks = self.r_cte_scan_blocks.clone();
         let mut right_res = right_side_builder.finalize(&range_join.right)?;
         right_res.main_pipeline.add_sink(|input| {
             let transform = Sinker::<TransformRangeJoinRight>::create(
@@ -174,6 +175,7 @@ impl PipelineBuilder {
             self.main_pipeline.get_scopes(),
         );
         build_side_builder.cte_state = self.cte_state.clone();
+        build_side_builder.r_cte_scan_blocks = self.r_cte_scan_blocks.clone();
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -337,6 +339,7 @@ impl PipelineBuilder {
             self.main_pipeline.get_scopes(),
         );
         left_side_builder.cte_state = self.cte_state.clone();
+        left_side_builder.r_cte_scan_blocks = self.r_cte_scan_blocks.clone();
         let mut left_side_pipeline = left_side_builder.finalize(left_side)?;
         assert!(left_side_pipeline.main_pipeline.is_pulling_pipeline()?);
 
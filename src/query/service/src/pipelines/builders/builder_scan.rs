@@ -17,6 +17,7 @@ use std::sync::Mutex;
 use std::time::Instant;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::ProcessorPtr;
@@ -27,6 +28,7 @@ use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::executor::physical_plans::ConstantTableScan;
 use databend_common_sql::executor::physical_plans::CteScan;
+use databend_common_sql::executor::physical_plans::RecursiveCteScan;
 use databend_common_sql::executor::physical_plans::TableScan;
 
 use crate::pipelines::processors::transforms::MaterializedCteSource;
@@ -131,4 +133,24 @@ impl PipelineBuilder {
             1,
         )
     }
+
+    pub(crate) fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let blocks = self
+            .r_cte_scan_blocks
+            .get(&scan.table_name)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Working table of recursive cte {} is not initialized",
+                    scan.table_name
+                ))
+            })?;
+        let block = if blocks.is_empty() {
+            DataBlock::empty_with_schema(scan.output_schema()?)
+        } else {
+            DataBlock::concat(&blocks)?
+        };
+        self.main_pipeline
+            .add_source(|output| OneBlockSource::create(output, block.clone()), 1)
+    }
 }
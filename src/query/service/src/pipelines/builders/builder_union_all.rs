@@ -22,11 +22,16 @@ use databend_common_sql::executor::physical_plans::UnionAll;
 use databend_common_sql::executor::PhysicalPlan;
 
 use crate::pipelines::processors::transforms::TransformMergeBlock;
+use crate::pipelines::processors::transforms::TransformRecursiveCteSource;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 
 impl PipelineBuilder {
     pub fn build_union_all(&mut self, union_all: &UnionAll) -> Result<()> {
+        if !union_all.cte_scan_names.is_empty() {
+            return self.build_recursive_cte_source(union_all);
+        }
+
         self.build_pipeline(&union_all.left)?;
         let union_all_receiver = self.expand_union_all(&union_all.right, union_all)?;
         self.main_pipeline
@@ -53,6 +58,26 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_recursive_cte_source(&mut self, union_all: &UnionAll) -> Result<()> {
+        let scopes = self.main_pipeline.get_scopes();
+        self.main_pipeline.add_source(
+            |output| {
+                TransformRecursiveCteSource::try_create(
+                    self.ctx.clone(),
+                    output,
+                    self.func_ctx.clone(),
+                    self.settings.clone(),
+                    self.enable_profiling,
+                    self.proc_profs.clone(),
+                    scopes.clone(),
+                    self.cte_state.clone(),
+                    union_all.clone(),
+                )
+            },
+            1,
+        )
+    }
+
     fn expand_union_all(
         &mut self,
         input: &PhysicalPlan,
@@ -68,6 +93,7 @@ impl PipelineBuilder {
             self.main_pipeline.get_scopes(),
         );
         pipeline_builder.cte_state = self.cte_state.clone();
+        pipeline_builder.r_cte_scan_blocks = self.r_cte_scan_blocks.clone();
 
         let mut build_res = pipeline_builder.finalize(input)?;
 
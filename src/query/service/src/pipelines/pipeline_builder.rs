@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::FunctionContext;
 use databend_common_pipeline_core::Pipeline;
@@ -49,6 +50,8 @@ pub struct PipelineBuilder {
 
     // Cte -> state, each cte has it's own state
     pub cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+    // Recursive cte name -> rows produced by the previous iteration
+    pub r_cte_scan_blocks: HashMap<String, Arc<Vec<DataBlock>>>,
 
     pub(crate) enable_profiling: bool,
     pub(crate) proc_profs: SharedProcessorProfiles,
@@ -74,6 +77,7 @@ impl PipelineBuilder {
             proc_profs: prof_span_set,
             exchange_injector: DefaultExchangeInjector::create(),
             cte_state: HashMap::new(),
+            r_cte_scan_blocks: HashMap::new(),
             merge_into_probe_data_fields: None,
             join_state: None,
         }
@@ -118,6 +122,7 @@ impl PipelineBuilder {
             PhysicalPlan::TableScan(scan) => self.build_table_scan(scan),
             PhysicalPlan::CteScan(scan) => self.build_cte_scan(scan),
             PhysicalPlan::ConstantTableScan(scan) => self.build_constant_table_scan(scan),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
             PhysicalPlan::Filter(filter) => self.build_filter(filter),
             PhysicalPlan::Project(project) => self.build_project(project),
             PhysicalPlan::EvalScalar(eval_scalar) => self.build_eval_scalar(eval_scalar),
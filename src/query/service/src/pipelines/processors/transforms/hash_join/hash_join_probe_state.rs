@@ -171,6 +171,9 @@ impl HashJoinProbeState {
             | JoinType::Right
             | JoinType::Full => self.probe_join(input, probe_state),
             JoinType::Cross => self.cross_join(input, probe_state),
+            JoinType::Asof | JoinType::LeftAsof => Err(ErrorCode::Internal(
+                "Asof join should be executed by range join",
+            )),
         }
     }
 
@@ -29,6 +29,7 @@ mod transform_filter;
 mod transform_limit;
 mod transform_materialized_cte;
 mod transform_merge_block;
+mod transform_recursive_cte_source;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
 mod transform_runtime_cast_schema;
@@ -55,6 +56,7 @@ pub use transform_materialized_cte::MaterializedCteSink;
 pub use transform_materialized_cte::MaterializedCteSource;
 pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_cte_source::TransformRecursiveCteSource;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_sql::plans::JoinType;
+
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+// The keys block of asof join contains: [match key, equi keys..., row index]
+impl RangeJoinState {
+    pub fn asof_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let tasks = self.tasks.read();
+        let (left_idx, _) = tasks[task_id];
+        let left_sorted_blocks = self.left_sorted_blocks.read();
+        let right_sorted_blocks = self.right_sorted_blocks.read();
+        let left_table = self.left_table.read();
+        let right_table = self.right_table.read();
+
+        let left_keys_block = &left_sorted_blocks[left_idx];
+        let left_len = left_keys_block.num_rows();
+        let left_columns = key_columns(left_keys_block);
+        let right_columns = right_sorted_blocks
+            .first()
+            .map(key_columns)
+            .unwrap_or_default();
+        let right_len = right_sorted_blocks.first().map_or(0, |b| b.num_rows());
+        let num_keys = self.conditions.len();
+        let right_row_indices = match right_columns.get(num_keys) {
+            Some(column) => Int64Type::try_downcast_column(column).ok_or_else(|| {
+                ErrorCode::Internal("the row index column of asof join must be Int64")
+            })?,
+            None => Buffer::default(),
+        };
+        let op = self.conditions[0].operator.as_str();
+
+        let mut left_indices = Vec::with_capacity(left_len);
+        let mut right_indices = Vec::with_capacity(left_len);
+        let mut validity = MutableBitmap::with_capacity(left_len);
+        for i in 0..left_len {
+            let left_keys = left_columns[0..num_keys]
+                .iter()
+                .map(|column| column.index(i).unwrap())
+                .collect::<Vec<_>>();
+            let matched = if right_len == 0 || left_keys.iter().any(|key| key.is_null()) {
+                None
+            } else {
+                // Right rows are sorted by equi keys and then match key, find the rows
+                // with the same equi keys and then the closest match key in them.
+                let cmp_equi_keys = |j: usize| {
+                    for (column, key) in right_columns[1..num_keys].iter().zip(&left_keys[1..]) {
+                        let order = column.index(j).unwrap().cmp(key);
+                        if order != Ordering::Equal {
+                            return order;
+                        }
+                    }
+                    Ordering::Equal
+                };
+                let start = partition_point(0, right_len, |j| cmp_equi_keys(j) == Ordering::Less);
+                let end =
+                    partition_point(start, right_len, |j| cmp_equi_keys(j) == Ordering::Equal);
+                let match_key = |j: usize| right_columns[0].index(j).unwrap();
+                match op {
+                    "gte" => {
+                        let pos = partition_point(start, end, |j| match_key(j) <= left_keys[0]);
+                        (pos > start).then(|| pos - 1)
+                    }
+                    "gt" => {
+                        let pos = partition_point(start, end, |j| match_key(j) < left_keys[0]);
+                        (pos > start).then(|| pos - 1)
+                    }
+                    "lte" => {
+                        let pos = partition_point(start, end, |j| match_key(j) < left_keys[0]);
+                        (pos < end).then_some(pos)
+                    }
+                    "lt" => {
+                        let pos = partition_point(start, end, |j| match_key(j) <= left_keys[0]);
+                        (pos < end).then_some(pos)
+                    }
+                    _ => unreachable!(),
+                }
+            };
+
+            match matched {
+                Some(j) => {
+                    left_indices.push(i as u32);
+                    right_indices.push((-right_row_indices[j] - 1) as u32);
+                    validity.push(true);
+                }
+                None if self.join_type == JoinType::LeftAsof => {
+                    left_indices.push(i as u32);
+                    right_indices.push(0);
+                    validity.push(false);
+                }
+                None => (),
+            }
+        }
+
+        if left_indices.is_empty() {
+            return Ok(vec![]);
+        }
+        let num_rows = left_indices.len();
+        let mut result_block = left_table[left_idx].take(&left_indices, &mut None)?;
+        match right_table.first().filter(|block| !block.is_empty()) {
+            Some(right_block) => {
+                let right_block = right_block.take(&right_indices, &mut None)?;
+                let validity: Bitmap = validity.into();
+                for entry in right_block.columns() {
+                    if self.join_type == JoinType::LeftAsof {
+                        let column = entry
+                            .value
+                            .convert_to_full_column(&entry.data_type, num_rows)
+                            .wrap_nullable(Some(validity.clone()));
+                        result_block.add_column(BlockEntry::new(
+                            entry.data_type.wrap_nullable(),
+                            Value::Column(column),
+                        ));
+                    } else {
+                        result_block.add_column(entry.clone());
+                    }
+                }
+            }
+            None => {
+                for field in self.right_schema.fields() {
+                    result_block.add_column(BlockEntry::new(
+                        field.data_type().wrap_nullable(),
+                        Value::Scalar(Scalar::Null),
+                    ));
+                }
+            }
+        }
+        Ok(vec![result_block])
+    }
+
+    // Sort the right keys block by equi keys and match key, rows with null keys are removed
+    // because they never match any rows.
+    pub(crate) fn sort_asof_keys(&self, keys_block: &DataBlock) -> Result<DataBlock> {
+        let columns = key_columns(keys_block);
+        let num_keys = self.conditions.len();
+        let mut indices = (0..keys_block.num_rows() as u32)
+            .filter(|i| {
+                columns[0..num_keys]
+                    .iter()
+                    .all(|column| !column.index(*i as usize).unwrap().is_null())
+            })
+            .collect::<Vec<_>>();
+        indices.sort_by(|a, b| {
+            let (a, b) = (*a as usize, *b as usize);
+            for column in columns[1..num_keys].iter().chain(columns[0..1].iter()) {
+                let order = column.index(a).unwrap().cmp(&column.index(b).unwrap());
+                if order != Ordering::Equal {
+                    return order;
+                }
+            }
+            Ordering::Equal
+        });
+        keys_block.take(&indices, &mut None)
+    }
+}
+
+fn key_columns(keys_block: &DataBlock) -> Vec<Column> {
+    keys_block
+        .columns()
+        .iter()
+        .map(|entry| {
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, keys_block.num_rows())
+        })
+        .collect()
+}
+
+// Binary search the first position in [start, end) that doesn't satisfy the predicate,
+// the predicate must be true for a prefix of the range.
+fn partition_point(start: usize, end: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (start, end);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
@@ -23,6 +23,7 @@ use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Evaluator;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::RemoteExpr;
@@ -31,6 +32,7 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::executor::physical_plans::RangeJoin;
 use databend_common_sql::executor::physical_plans::RangeJoinCondition;
 use databend_common_sql::executor::physical_plans::RangeJoinType;
+use databend_common_sql::plans::JoinType;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 
@@ -47,7 +49,10 @@ pub struct RangeJoinState {
     // For iejoin, it's L1: sort by the first join key
     pub(crate) left_sorted_blocks: RwLock<Vec<DataBlock>>,
     pub(crate) conditions: Vec<RangeJoinCondition>,
-    // pub(crate) join_type: JoinType,
+    pub(crate) join_type: JoinType,
+    pub(crate) range_join_type: RangeJoinType,
+    // Used to generate null columns for left asof join when right table is empty
+    pub(crate) right_schema: DataSchemaRef,
     pub(crate) other_conditions: Vec<RemoteExpr>,
     // Pipeline event related
     pub(crate) partition_finished: Mutex<bool>,
@@ -64,21 +69,23 @@ pub struct RangeJoinState {
 }
 
 impl RangeJoinState {
-    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Self {
+    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Result<Self> {
         let ie_join_state = if matches!(range_join.range_join_type, RangeJoinType::IEJoin) {
             Some(IEJoinState::new(range_join))
         } else {
             None
         };
 
-        Self {
+        Ok(Self {
             ctx,
             left_table: RwLock::new(vec![]),
             right_table: RwLock::new(vec![]),
             right_sorted_blocks: Default::default(),
             left_sorted_blocks: Default::default(),
             conditions: range_join.conditions.clone(),
-            // join_type: range_join.join_type.clone(),
+            join_type: range_join.join_type.clone(),
+            range_join_type: range_join.range_join_type.clone(),
+            right_schema: range_join.right.output_schema()?,
             other_conditions: range_join.other_conditions.clone(),
             partition_finished: Mutex::new(false),
             finished_notify: Arc::new(WatchNotify::new()),
@@ -88,7 +95,7 @@ impl RangeJoinState {
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
             ie_join_state,
-        }
+        })
     }
 
     pub(crate) fn sink_right(&self, block: DataBlock) -> Result<()> {
@@ -176,7 +183,14 @@ impl RangeJoinState {
         let left_table = self.left_table.read();
         // Right table is bigger than left table
         let mut right_table = self.right_table.write();
-        if !left_table.is_empty()
+        let is_asof = matches!(self.range_join_type, RangeJoinType::Asof);
+        if is_asof && right_table.len() > 1 {
+            // Each left row of asof join needs to be probed against the whole right table
+            let merged_right_table = DataBlock::concat(&right_table)?;
+            right_table.clear();
+            right_table.push(merged_right_table);
+        } else if !is_asof
+            && !left_table.is_empty()
             && !right_table.is_empty()
             && left_table.len() * right_table.len() < max_threads
         {
@@ -258,12 +272,25 @@ impl RangeJoinState {
             right_sorted_blocks.push(keys_block);
             current_rows += right_block.num_rows();
         }
+        if is_asof {
+            if let Some(keys_block) = right_sorted_blocks.pop() {
+                right_sorted_blocks.push(self.sort_asof_keys(&keys_block)?);
+            }
+        }
+
         // Add tasks
         let mut row_offset = self.row_offset.write();
         let mut left_offset = 0;
         let mut right_offset = 0;
         let mut tasks = self.tasks.write();
         for (left_idx, left_block) in left_sorted_blocks.iter().enumerate() {
+            if is_asof {
+                // Asof join probes the whole right table in one task
+                row_offset.push((left_offset, 0));
+                tasks.push((left_idx, 0));
+                left_offset += left_block.num_rows();
+                continue;
+            }
             for (right_idx, right_block) in right_sorted_blocks.iter().enumerate() {
                 row_offset.push((left_offset, right_offset));
                 tasks.push((left_idx, right_idx));
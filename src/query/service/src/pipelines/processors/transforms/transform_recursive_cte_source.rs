@@ -0,0 +1,187 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FunctionContext;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::PlanScope;
+use databend_common_pipeline_sources::SyncSource;
+use databend_common_pipeline_sources::SyncSourcer;
+use databend_common_profile::SharedProcessorProfiles;
+use databend_common_settings::Settings;
+use databend_common_sql::executor::physical_plans::UnionAll;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::IndexType;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::processors::transforms::MaterializedCteState;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+
+/// Source of a recursive cte.
+///
+/// The anchor member is executed first, then the recursive member is executed
+/// repeatedly with the rows produced by the previous iteration as its working
+/// table, until an iteration produces no rows.
+pub struct TransformRecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    settings: Arc<Settings>,
+    enable_profiling: bool,
+    proc_profs: SharedProcessorProfiles,
+    scopes: Vec<PlanScope>,
+    cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+
+    union_plan: UnionAll,
+    max_depth: u64,
+    depth: u64,
+    finished: bool,
+    working_table: Option<Vec<DataBlock>>,
+}
+
+impl TransformRecursiveCteSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        settings: Arc<Settings>,
+        enable_profiling: bool,
+        proc_profs: SharedProcessorProfiles,
+        scopes: Vec<PlanScope>,
+        cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+        union_plan: UnionAll,
+    ) -> Result<ProcessorPtr> {
+        let max_depth = settings.get_max_cte_recursive_depth()?;
+        SyncSourcer::create(ctx.clone(), output_port, TransformRecursiveCteSource {
+            ctx,
+            func_ctx,
+            settings,
+            enable_profiling,
+            proc_profs,
+            scopes,
+            cte_state,
+            union_plan,
+            max_depth,
+            depth: 0,
+            finished: false,
+            working_table: None,
+        })
+    }
+
+    fn execute_plan(
+        &self,
+        plan: &PhysicalPlan,
+        working_table: Option<Arc<Vec<DataBlock>>>,
+    ) -> Result<Vec<DataBlock>> {
+        let mut pipeline_builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            QueryContext::create_from(self.ctx.clone()),
+            self.enable_profiling,
+            self.proc_profs.clone(),
+            self.scopes.clone(),
+        );
+        pipeline_builder.cte_state = self.cte_state.clone();
+        if let Some(working_table) = working_table {
+            for name in self.union_plan.cte_scan_names.iter() {
+                pipeline_builder
+                    .r_cte_scan_blocks
+                    .insert(name.clone(), working_table.clone());
+            }
+        }
+
+        let mut build_res = pipeline_builder.finalize(plan)?;
+        build_res.set_max_threads(self.settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&self.settings, self.ctx.get_id())?;
+        let mut executor = PipelinePullingExecutor::from_pipelines(build_res, executor_settings)?;
+        executor.start();
+
+        let mut blocks = vec![];
+        while let Some(block) = executor.pull_data()? {
+            if !block.is_empty() {
+                blocks.push(block);
+            }
+        }
+        Ok(blocks)
+    }
+
+    // Reorder the columns of the block produced by one side of the union to the union schema.
+    fn project_block(&self, block: DataBlock, is_left: bool) -> Result<DataBlock> {
+        let plan = match is_left {
+            true => &self.union_plan.left,
+            false => &self.union_plan.right,
+        };
+        let schema = plan.output_schema()?;
+        let num_rows = block.num_rows();
+        let columns = self
+            .union_plan
+            .pairs
+            .iter()
+            .map(|(left, right)| {
+                let name = if is_left { left } else { right };
+                Ok(block.get_by_offset(schema.index_of(name)?).clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataBlock::new(columns, num_rows))
+    }
+}
+
+impl SyncSource for TransformRecursiveCteSource {
+    const NAME: &'static str = "TransformRecursiveCteSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+        self.ctx.check_aborting()?;
+
+        let is_left = self.depth == 0;
+        if self.depth > self.max_depth {
+            return Err(ErrorCode::Overflow(format!(
+                "Recursive cte {:?} exceeds the maximum recursion depth {}, see setting `max_cte_recursive_depth`",
+                self.union_plan.cte_scan_names, self.max_depth
+            )));
+        }
+
+        let working_table = self.working_table.take().map(Arc::new);
+        let blocks = if is_left {
+            self.execute_plan(&self.union_plan.left, None)?
+        } else {
+            self.execute_plan(&self.union_plan.right, working_table)?
+        };
+        self.depth += 1;
+
+        let blocks = blocks
+            .into_iter()
+            .map(|block| self.project_block(block, is_left))
+            .collect::<Result<Vec<_>>>()?;
+        if blocks.is_empty() {
+            self.finished = true;
+            return Ok(None);
+        }
+
+        let block = DataBlock::concat(&blocks)?;
+        self.working_table = Some(vec![block.clone()]);
+        Ok(Some(block))
+    }
+}
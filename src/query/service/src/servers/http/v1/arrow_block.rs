@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::Schema as ArrowSchema;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+
+/// Rows of a result page kept as data blocks, sent to the client as an Arrow IPC stream.
+#[derive(Debug, Clone)]
+pub struct ArrowBlock {
+    pub(crate) blocks: Vec<DataBlock>,
+    pub(crate) schema: DataSchemaRef,
+}
+
+impl ArrowBlock {
+    pub fn empty() -> Self {
+        Self {
+            blocks: vec![],
+            schema: Arc::new(DataSchema::empty()),
+        }
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.blocks.iter().map(|b| b.num_rows()).sum()
+    }
+
+    pub fn schema(&self) -> &DataSchemaRef {
+        &self.schema
+    }
+
+    /// Encodes the blocks as an Arrow IPC stream, `metadata` is attached to the schema message.
+    pub fn to_ipc_stream(&self, metadata: Vec<(String, String)>) -> Result<Vec<u8>> {
+        let batches = self
+            .blocks
+            .iter()
+            .map(|b| b.clone().to_record_batch(&self.schema))
+            .collect::<Result<Vec<_>>>()?;
+        let mut arrow_schema = match batches.first() {
+            Some(batch) => batch.schema().as_ref().clone(),
+            None => ArrowSchema::from(self.schema.as_ref()),
+        };
+        arrow_schema.metadata.extend(metadata);
+
+        let mut writer = StreamWriter::try_new(vec![], &arrow_schema)?;
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+        Ok(writer.into_inner()?)
+    }
+}
@@ -23,6 +23,7 @@ use log::warn;
 use minitrace::full_name;
 use minitrace::prelude::*;
 use poem::error::Error as PoemError;
+use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
 use poem::get;
 use poem::http::StatusCode;
@@ -31,6 +32,7 @@ use poem::web::Json;
 use poem::web::Path;
 use poem::EndpointExt;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
@@ -41,17 +43,20 @@ use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
 use super::query::RemoveReason;
 use crate::servers::http::middleware::MetricsMiddleware;
+use crate::servers::http::v1::query::PageData;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::HttpSessionConf;
-use crate::servers::http::v1::JsonBlock;
 use crate::sessions::QueryAffect;
 
 const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
 
+const ARROW_STREAM_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+const ARROW_RESPONSE_HEADER_KEY: &str = "response_header";
+
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
 }
@@ -137,14 +142,14 @@ impl QueryResponse {
         id: String,
         r: HttpQueryResponseInternal,
         is_final: bool,
-    ) -> impl IntoResponse {
+    ) -> PoemResult<Response> {
         let state = r.state.clone();
         let (data, next_uri) = if is_final {
-            (JsonBlock::empty(), None)
+            (PageData::empty(), None)
         } else {
             match state.state {
                 ExecuteStateKind::Running => match r.data {
-                    None => (JsonBlock::empty(), Some(make_state_uri(&id))),
+                    None => (PageData::empty(), Some(make_state_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
@@ -153,9 +158,9 @@ impl QueryResponse {
                         (d.page.data, uri)
                     }
                 },
-                ExecuteStateKind::Failed => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                ExecuteStateKind::Failed => (PageData::empty(), Some(make_final_uri(&id))),
                 ExecuteStateKind::Succeeded => match r.data {
-                    None => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                    None => (PageData::empty(), Some(make_final_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
@@ -177,10 +182,14 @@ impl QueryResponse {
             progresses: state.progresses.clone(),
             running_time_ms: state.running_time_ms,
         };
-        let rows = data.data.len();
+        let rows = data.num_rows();
+        let (json_data, arrow_data) = match data {
+            PageData::Json(block) => (block.into(), None),
+            PageData::Arrow(block) => (vec![], Some(block)),
+        };
 
-        Json(QueryResponse {
-            data: data.into(),
+        let resp = QueryResponse {
+            data: json_data,
             state: state.state,
             schema: QueryResponseField::from_schema(schema),
             session_id: Some(session_id),
@@ -195,10 +204,27 @@ impl QueryResponse {
             final_uri: Some(make_final_uri(&id)),
             kill_uri: Some(make_kill_uri(&id)),
             error: r.state.error.as_ref().map(QueryError::from_error_code),
-        })
-        .with_header(HEADER_QUERY_ID, id.clone())
-        .with_header(HEADER_QUERY_STATE, state.state.to_string())
-        .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+        };
+
+        let resp = match arrow_data {
+            None => Json(resp).into_response(),
+            Some(block) => {
+                // The rows are in the Arrow IPC stream, and the other fields of the
+                // response are kept in the schema metadata.
+                let header = serde_json::to_string(&resp).map_err(InternalServerError)?;
+                let body = block
+                    .to_ipc_stream(vec![(ARROW_RESPONSE_HEADER_KEY.to_string(), header)])
+                    .map_err(InternalServerError)?;
+                Response::builder()
+                    .content_type(ARROW_STREAM_CONTENT_TYPE)
+                    .body(body)
+            }
+        };
+        Ok(resp
+            .with_header(HEADER_QUERY_ID, id.clone())
+            .with_header(HEADER_QUERY_STATE, state.state.to_string())
+            .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+            .into_response())
     }
 
     pub(crate) fn fail_to_start_sql(err: &ErrorCode) -> impl IntoResponse {
@@ -250,7 +276,7 @@ async fn query_final_handler(
                         StatusCode::BAD_REQUEST,
                     ));
                 }
-                Ok(QueryResponse::from_internal(query_id, response, true))
+                QueryResponse::from_internal(query_id, response, true)
             }
             Err(reason) => Err(query_id_not_found_or_removed(
                 &query_id,
@@ -315,7 +341,7 @@ async fn query_state_handler(
         match http_query_manager.try_get_query(&query_id).await {
             Ok(query) => {
                 let response = query.get_response_state_only().await;
-                Ok(QueryResponse::from_internal(query_id, response, false))
+                QueryResponse::from_internal(query_id, response, false)
             }
             Err(reason) => Err(query_id_not_found_or_removed(
                 &query_id,
@@ -349,7 +375,7 @@ async fn query_page_handler(
                     poem::Error::from_string(err.message(), StatusCode::NOT_FOUND)
                 })?;
                 query.update_expire_time(false).await;
-                Ok(QueryResponse::from_internal(query_id, resp, false))
+                QueryResponse::from_internal(query_id, resp, false)
             }
             Err(reason) => Err(query_id_not_found_or_removed(
                 &query_id,
@@ -398,7 +424,7 @@ pub(crate) async fn query_handler(
                     &query.id, &resp.state, rows, next_page, mask_connection_info(&sql)
                 );
                 query.update_expire_time(false).await;
-                Ok(QueryResponse::from_internal(query.id.to_string(), resp, false).into_response())
+                QueryResponse::from_internal(query.id.to_string(), resp, false)
             }
             Err(e) => {
                 error!("{}: http query fail to start sql, error: {:?}", &ctx.query_id, e);
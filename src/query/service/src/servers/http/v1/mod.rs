@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod arrow_block;
 mod http_query_handlers;
 pub mod json_block;
 mod load;
@@ -19,6 +20,7 @@ mod query;
 mod stage;
 mod suggestions;
 
+pub(crate) use arrow_block::ArrowBlock;
 pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
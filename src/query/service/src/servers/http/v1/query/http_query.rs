@@ -53,6 +53,7 @@ use crate::sessions::short_sql;
 use crate::sessions::QueryAffect;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
 
 fn default_as_true() -> bool {
     true
@@ -68,6 +69,8 @@ pub struct HttpQueryRequest {
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    #[serde(default)]
+    pub data_format: HttpQueryDataFormat,
 }
 
 impl Debug for HttpQueryRequest {
@@ -79,10 +82,23 @@ impl Debug for HttpQueryRequest {
             .field("pagination", &self.pagination)
             .field("string_fields", &self.string_fields)
             .field("stage_attachment", &self.stage_attachment)
+            .field("data_format", &self.data_format)
             .finish()
     }
 }
 
+/// The encoding of the rows in the page responses.
+///
+/// With `arrow`, a page response carries an Arrow IPC stream as body, and the
+/// rest of the response is kept as JSON in the schema metadata.
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HttpQueryDataFormat {
+    #[default]
+    Json,
+    Arrow,
+}
+
 const DEFAULT_MAX_ROWS_IN_BUFFER: usize = 5 * 1000 * 1000;
 const DEFAULT_MAX_ROWS_PER_PAGE: usize = 10000;
 const DEFAULT_WAIT_TIME_SECS: u32 = 1;
@@ -342,6 +358,19 @@ impl HttpQuery {
         let query_id_clone = query_id.clone();
 
         let (plan, plan_extras) = ExecuteState::plan_sql(&sql, ctx.clone()).await?;
+        // The transaction is kept in the session, which is dropped after the query
+        // unless it is kept on the server for the next queries.
+        let session_kept = request.session_id.is_some()
+            || request
+                .session
+                .as_ref()
+                .and_then(|conf| conf.keep_server_session_secs)
+                .map_or(false, |secs| secs > 0);
+        if matches!(plan, Plan::Begin) && !session_kept {
+            return Err(ErrorCode::BadArguments(
+                "BEGIN needs a session kept on the server, set keep_server_session_secs in the session and send the following queries with the session_id",
+            ));
+        }
         let schema = plan.schema();
 
         let span = if let Some(parent) = SpanContext::current_local_parent() {
@@ -393,6 +422,7 @@ impl HttpQuery {
             block_receiver,
             schema,
             format_settings,
+            request.data_format,
         )));
 
         let query = HttpQuery {
@@ -28,6 +28,7 @@ pub use execute_state::Progresses;
 pub use expirable::ExpiringState;
 pub use expiring_map::ExpiringMap;
 pub use http_query::HttpQuery;
+pub use http_query::HttpQueryDataFormat;
 pub use http_query::HttpQueryRequest;
 pub use http_query::HttpQueryResponseInternal;
 pub use http_query::HttpSessionConf;
@@ -37,6 +38,7 @@ pub use http_query_context::HttpQueryContext;
 pub use http_query_manager::HttpQueryManager;
 pub(crate) use http_query_manager::RemoveReason;
 pub use page_manager::Page;
+pub use page_manager::PageData;
 pub use page_manager::PageManager;
 pub use page_manager::ResponseData;
 pub use page_manager::Wait;
@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::VecDeque;
 use std::time::Instant;
 
 use databend_common_base::base::tokio;
@@ -23,10 +22,11 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_io::prelude::FormatSettings;
 use log::debug;
 use log::info;
-use serde_json::Value as JsonValue;
 
 use crate::servers::http::v1::json_block::block_to_json_value;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
+use crate::servers::http::v1::query::HttpQueryDataFormat;
+use crate::servers::http::v1::ArrowBlock;
 use crate::servers::http::v1::JsonBlock;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -37,10 +37,36 @@ pub enum Wait {
 
 #[derive(Clone)]
 pub struct Page {
-    pub data: JsonBlock,
+    pub data: PageData,
     pub total_rows: usize,
 }
 
+#[derive(Clone)]
+pub enum PageData {
+    Json(JsonBlock),
+    Arrow(ArrowBlock),
+}
+
+impl PageData {
+    pub fn empty() -> Self {
+        PageData::Json(JsonBlock::empty())
+    }
+
+    pub fn num_rows(&self) -> usize {
+        match self {
+            PageData::Json(block) => block.num_rows(),
+            PageData::Arrow(block) => block.num_rows(),
+        }
+    }
+
+    pub fn schema(&self) -> &DataSchemaRef {
+        match self {
+            PageData::Json(block) => block.schema(),
+            PageData::Arrow(block) => block.schema(),
+        }
+    }
+}
+
 pub struct ResponseData {
     pub page: Page,
     pub next_page_no: Option<usize>,
@@ -55,9 +81,10 @@ pub struct PageManager {
     block_end: bool,
     schema: DataSchemaRef,
     last_page: Option<Page>,
-    row_buffer: VecDeque<Vec<JsonValue>>,
+    block_buffer: Option<DataBlock>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     format_settings: FormatSettings,
+    data_format: HttpQueryDataFormat,
 }
 
 impl PageManager {
@@ -67,6 +94,7 @@ impl PageManager {
         block_receiver: SizedChannelReceiver<DataBlock>,
         schema: DataSchemaRef,
         format_settings: FormatSettings,
+        data_format: HttpQueryDataFormat,
     ) -> PageManager {
         PageManager {
             query_id,
@@ -75,11 +103,12 @@ impl PageManager {
             total_pages: 0,
             end: false,
             block_end: false,
-            row_buffer: Default::default(),
+            block_buffer: None,
             schema,
             block_receiver,
             max_rows_per_page,
             format_settings,
+            data_format,
         }
     }
 
@@ -130,41 +159,33 @@ impl PageManager {
         }
     }
 
-    fn append_block(
-        &mut self,
-        rows: &mut Vec<Vec<JsonValue>>,
-        block: DataBlock,
-        remain: usize,
-    ) -> Result<()> {
-        let format_settings = &self.format_settings;
-        let mut iter = block_to_json_value(&block, format_settings)?
-            .into_iter()
-            .peekable();
-        let chunk: Vec<_> = iter.by_ref().take(remain).collect();
-        rows.extend(chunk);
-        self.row_buffer = iter.by_ref().collect();
-        Ok(())
+    fn append_block(&mut self, blocks: &mut Vec<DataBlock>, block: DataBlock, remain: usize) {
+        let num_rows = block.num_rows();
+        if num_rows > remain {
+            blocks.push(block.slice(0..remain));
+            self.block_buffer = Some(block.slice(remain..num_rows));
+        } else if num_rows > 0 {
+            blocks.push(block);
+        }
     }
 
     #[async_backtrace::framed]
-    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(JsonBlock, bool)> {
-        let mut res: Vec<Vec<JsonValue>> = Vec::with_capacity(self.max_rows_per_page);
-        while res.len() < self.max_rows_per_page {
-            if let Some(row) = self.row_buffer.pop_front() {
-                res.push(row)
-            } else {
-                break;
-            }
+    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(PageData, bool)> {
+        let mut blocks: Vec<DataBlock> = vec![];
+        let mut num_rows = 0;
+        if let Some(block) = self.block_buffer.take() {
+            num_rows += block.num_rows().min(self.max_rows_per_page);
+            self.append_block(&mut blocks, block, self.max_rows_per_page);
         }
         loop {
-            assert!(self.max_rows_per_page >= res.len());
-            let remain = self.max_rows_per_page - res.len();
+            assert!(self.max_rows_per_page >= num_rows);
+            let remain = self.max_rows_per_page - num_rows;
             if remain == 0 {
                 break;
             }
-            match tp {
+            let block = match tp {
                 Wait::Async => match self.block_receiver.try_recv() {
-                    Some(block) => self.append_block(&mut res, block, remain)?,
+                    Some(block) => block,
                     None => break,
                 },
                 Wait::Deadline(t) => {
@@ -177,7 +198,7 @@ impl PageManager {
                                 &self.query_id,
                                 block.num_rows()
                             );
-                            self.append_block(&mut res, block, remain)?;
+                            block
                         }
                         Ok(None) => {
                             info!("{}: http query reach end of blocks", &self.query_id);
@@ -189,20 +210,34 @@ impl PageManager {
                         }
                     }
                 }
-            }
+            };
+            num_rows += block.num_rows().min(remain);
+            self.append_block(&mut blocks, block, remain);
         }
 
-        let block = JsonBlock {
-            schema: self.schema.clone(),
-            data: res,
+        let data = match self.data_format {
+            HttpQueryDataFormat::Json => {
+                let mut data = Vec::with_capacity(num_rows);
+                for block in &blocks {
+                    data.extend(block_to_json_value(block, &self.format_settings)?);
+                }
+                PageData::Json(JsonBlock {
+                    schema: self.schema.clone(),
+                    data,
+                })
+            }
+            HttpQueryDataFormat::Arrow => PageData::Arrow(ArrowBlock {
+                schema: self.schema.clone(),
+                blocks,
+            }),
         };
 
         // try to report 'no more data' earlier to client to avoid unnecessary http call
         if !self.block_end {
             self.block_end = self.block_receiver.is_empty();
         }
-        let end = self.block_end && self.row_buffer.is_empty();
-        Ok((block, end))
+        let end = self.block_end && self.block_buffer.is_none();
+        Ok((data, end))
     }
 
     #[async_backtrace::framed]
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_ast::ast::Statement;
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::tokio::io::AsyncWrite;
@@ -29,6 +31,8 @@ use databend_common_expression::SendableDataBlockStream;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
 use databend_common_users::CertifiedInfo;
 use databend_common_users::UserApiProvider;
@@ -38,6 +42,9 @@ use log::info;
 use minitrace::full_name;
 use minitrace::prelude::*;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
@@ -48,6 +55,8 @@ use rand::RngCore;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::mysql_prepared_statement::PreparedStatement;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -59,8 +68,14 @@ use crate::sessions::Session;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
+// The max number of the prepared statements of a session.
+const MAX_PREPARED_STATEMENTS: usize = 1024;
+
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    // The statements prepared by `COM_STMT_PREPARE` of the session.
+    prepared_statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -281,36 +296,120 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
-        _: &str,
+        query: &str,
         writer: StatementMetaWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        match self.prepare(query).await {
+            Ok((id, params, columns)) => writer.reply(id, &params, &columns).await?,
+            Err(error) => {
+                let error = error.display_with_sql(query);
+                error!("OnPrepare Error: {:?}", error);
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+                    .await?
+            }
+        }
         Ok(())
     }
 
     #[async_backtrace::framed]
-    async fn do_execute<W: AsyncWrite + Unpin>(
+    async fn prepare(&mut self, query: &str) -> Result<(u32, Vec<Column>, Vec<Column>)> {
+        info!("Prepare query: {}", query);
+        if self.prepared_statements.len() >= MAX_PREPARED_STATEMENTS {
+            return Err(ErrorCode::BadArguments(format!(
+                "Can't prepare more than {} statements in a session, close the unused ones",
+                MAX_PREPARED_STATEMENTS
+            )));
+        }
+
+        let mut statement = PreparedStatement::try_create(query)?;
+        let columns = match self.federated_server_command_check(query) {
+            Some((schema, _)) => convert_schema(&schema)?,
+            None => {
+                let dialect = self.session.get_settings().get_sql_dialect()?;
+                statement.parse(dialect)?;
+
+                // Plan the query with NULL parameters to get the schema of the result set.
+                let context = self.session.create_query_context().await?;
+                let mut planner = Planner::new(context);
+                match planner.plan_sql(&statement.null_query()).await {
+                    Ok((plan, _)) if plan.has_result_set() => convert_schema(&plan.schema())?,
+                    Ok(_) => vec![],
+                    Err(error) if error.code() == ErrorCode::SYNTAX_EXCEPTION => {
+                        return Err(error);
+                    }
+                    // The query may be valid with the real parameters, e.g. `LIMIT ?`,
+                    // the columns are reported by the result set of `COM_STMT_EXECUTE`.
+                    Err(_) => vec![],
+                }
+            }
+        };
+
+        // The types of the parameters are decided by the values sent by the client.
+        let params = (0..statement.num_params())
+            .map(|_| Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect();
+
+        // The ids of the closed statements may be reused after wrapping around,
+        // skip the ones in use, there is always a free one as the statements are limited.
+        let mut id = self.next_statement_id.wrapping_add(1);
+        while id == 0 || self.prepared_statements.contains_key(&id) {
+            id = id.wrapping_add(1);
+        }
+        self.next_statement_id = id;
+        self.prepared_statements.insert(id, statement);
+        Ok((id, params, columns))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_execute<W: AsyncWrite + Send + Unpin>(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+
+        let instant = Instant::now();
+        let bound = match self.prepared_statements.get(&id) {
+            Some(statement) => self
+                .session
+                .get_settings()
+                .get_sql_dialect()
+                .and_then(|dialect| statement.bind(params, dialect)),
+            None => Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement id {}",
+                id
+            ))),
+        };
+        let query_result = match bound {
+            Ok((query, Some(statement))) => self
+                .do_query_statement(&query, statement)
+                .await
+                .map_err(|err| err.display_with_sql(&query)),
+            Ok((query, None)) => self
+                .do_query(&query)
+                .await
+                .map_err(|err| err.display_with_sql(&query)),
+            Err(error) => Err(error),
+        };
+
+        let format = self.session.get_format_settings();
+        let write_result = writer.write(query_result, &format).await;
+        observe_mysql_process_request_duration(instant.elapsed());
+
+        write_result
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, stmt_id: u32) {
+        self.prepared_statements.remove(&stmt_id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -355,34 +454,57 @@ impl InteractiveWorkerBase {
 
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
+                Self::execute_plan(context, plan, extras, query).await
+            }
+        }
+    }
 
-                context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = plan.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
-                }
+    /// Execute the parsed statement of a prepared statement with the parameters bound.
+    #[async_backtrace::framed]
+    async fn do_query_statement(
+        &mut self,
+        query: &str,
+        statement: Statement,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        info!("Prepared query: {}", query);
+        let context = self.session.create_query_context().await?;
+
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_stmt(statement).await?;
+        Self::execute_plan(context, plan, extras, query).await
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_plan(
+        context: Arc<QueryContext>,
+        plan: Plan,
+        extras: PlanExtras,
+        query: &str,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+
+        let has_result_set = plan.has_result_set();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = plan.schema();
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -456,7 +578,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 0,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
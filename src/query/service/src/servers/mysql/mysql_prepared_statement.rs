@@ -0,0 +1,317 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::ReplaceStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::token::Tokenizer;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::walk_expr_mut;
+use databend_common_ast::walk_statement_mut;
+use databend_common_ast::Dialect;
+use databend_common_ast::VisitorMut;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use opensrv_mysql::ParamParser;
+use opensrv_mysql::ValueInner;
+
+/// A statement prepared by `COM_STMT_PREPARE`.
+///
+/// The query is tokenized once to locate the `?` placeholders, and parsed once with the
+/// placeholders as `NULL`. Each `COM_STMT_EXECUTE` binds the parameters into a copy of
+/// the parsed statement, the values of `INSERT ... VALUES (?, ?)` are bound into the raw
+/// values, so that they go through the same fast path as the plain queries.
+pub struct PreparedStatement {
+    query: String,
+    // The byte ranges of the placeholders in the query.
+    placeholders: Vec<(usize, usize)>,
+    // The parsed statement of `null_query`, `None` if the query is answered by the federated
+    // commands, which are not parsed.
+    statement: Option<Statement>,
+}
+
+impl PreparedStatement {
+    pub fn try_create(query: &str) -> Result<PreparedStatement> {
+        let mut placeholders = vec![];
+        let mut prev_kind = None;
+        for token in Tokenizer::new(query) {
+            let token = token?;
+            // `?` is also a JSON operator, it's an operator if it follows an operand.
+            let follows_operand = prev_kind.map_or(false, |kind: TokenKind| {
+                kind.is_literal()
+                    || matches!(
+                        kind,
+                        TokenKind::Ident
+                            | TokenKind::ColumnPosition
+                            | TokenKind::RParen
+                            | TokenKind::RBracket
+                    )
+            });
+            if token.kind == TokenKind::Placeholder && !follows_operand {
+                placeholders.push((token.span.start(), token.span.end()));
+            }
+            prev_kind = Some(token.kind);
+        }
+
+        Ok(PreparedStatement {
+            query: query.to_string(),
+            placeholders,
+            statement: None,
+        })
+    }
+
+    /// Parse the query with all the placeholders bound to NULL.
+    pub fn parse(&mut self, dialect: Dialect) -> Result<()> {
+        let null_query = self.null_query();
+        let tokens = tokenize_sql(&null_query)?;
+        let (statement, _) = parse_sql(&tokens, dialect)?;
+        self.statement = Some(statement);
+        Ok(())
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.placeholders.len()
+    }
+
+    /// The query with all the placeholders bound to NULL, which is used to
+    /// get the schema of the result set when preparing.
+    pub fn null_query(&self) -> String {
+        self.null_query_positions().0
+    }
+
+    /// Bind the parameters of `COM_STMT_EXECUTE` to the placeholders.
+    ///
+    /// Returns the query with the parameters, which is logged and returned to the client,
+    /// and the parsed statement with the parameters if the query is parsed.
+    pub fn bind(
+        &self,
+        params: ParamParser<'_>,
+        dialect: Dialect,
+    ) -> Result<(String, Option<Statement>)> {
+        let values = params
+            .into_iter()
+            .map(|param| param_to_sql(param.value.into_inner()))
+            .collect::<Result<Vec<_>>>()?;
+        if values.len() != self.placeholders.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The prepared statement expects {} parameters, but got {}",
+                self.placeholders.len(),
+                values.len()
+            )));
+        }
+        let (query, _) = self.render(&values);
+
+        let Some(mut statement) = self.statement.clone() else {
+            return Ok((query, None));
+        };
+        // The positions of the placeholders in the parsed `null_query`.
+        let (_, null_positions) = self.null_query_positions();
+        let mut binder = ParamBinder {
+            params: BTreeMap::new(),
+            bound: 0,
+        };
+        for (position, value) in null_positions.into_iter().zip(&values) {
+            if let Some(rest_position) = raw_values_position(&statement, position) {
+                binder
+                    .params
+                    .insert(position, ParamValue::Raw(rest_position, value));
+            } else {
+                let tokens = tokenize_sql(value)?;
+                let expr = parse_expr(&tokens, dialect)?;
+                binder.params.insert(position, ParamValue::Expr(expr));
+            }
+        }
+        binder.bind(&mut statement);
+
+        // Some placeholders are not in the expressions visited by the binder,
+        // e.g. the options of the statements, the query is parsed again.
+        if binder.bound != values.len() {
+            return Ok((query, None));
+        }
+        Ok((query, Some(statement)))
+    }
+
+    // The query with all the placeholders bound to NULL, and the positions of the NULLs.
+    fn null_query_positions(&self) -> (String, Vec<usize>) {
+        let values = vec![Literal::Null.to_string(); self.placeholders.len()];
+        self.render(&values)
+    }
+
+    // Replace the placeholders with the values, return the query and the positions of the values.
+    fn render(&self, values: &[String]) -> (String, Vec<usize>) {
+        let mut query = String::with_capacity(self.query.len());
+        let mut positions = Vec::with_capacity(values.len());
+        let mut pos = 0;
+        for ((start, end), value) in self.placeholders.iter().zip(values) {
+            query.push_str(&self.query[pos..*start]);
+            positions.push(query.len());
+            query.push_str(value);
+            pos = *end;
+        }
+        query.push_str(&self.query[pos..]);
+        (query, positions)
+    }
+}
+
+enum ParamValue<'a> {
+    // The parameter in the raw values of `INSERT`, with its position in the raw values.
+    Raw(usize, &'a str),
+    Expr(Expr),
+}
+
+/// Replace the `NULL` literals of the placeholders with the parameters.
+struct ParamBinder<'a> {
+    // The position of the placeholder in the parsed query -> the parameter.
+    params: BTreeMap<usize, ParamValue<'a>>,
+    bound: usize,
+}
+
+impl<'a> ParamBinder<'a> {
+    fn bind(&mut self, statement: &mut Statement) {
+        let mut bound = 0;
+        match statement {
+            Statement::Insert(InsertStmt {
+                source: InsertSource::Values { rest_str, .. },
+                ..
+            })
+            | Statement::Replace(ReplaceStmt {
+                source: InsertSource::Values { rest_str, .. },
+                ..
+            }) => {
+                // Replace from the end, so that the positions of the previous ones are unchanged.
+                let null_len = Literal::Null.to_string().len();
+                for param in self.params.values().rev() {
+                    if let ParamValue::Raw(position, value) = param {
+                        rest_str.replace_range(*position..*position + null_len, value);
+                        bound += 1;
+                    }
+                }
+            }
+            _ => (),
+        }
+        self.bound += bound;
+        walk_statement_mut(self, statement);
+    }
+}
+
+impl<'a> VisitorMut for ParamBinder<'a> {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Literal {
+            span: Some(span),
+            lit: Literal::Null,
+        } = expr
+        {
+            if let Some(ParamValue::Expr(param)) = self.params.remove(&span.start()) {
+                *expr = param;
+                self.bound += 1;
+            }
+            return;
+        }
+        walk_expr_mut(self, expr);
+    }
+}
+
+// The position in the raw values of `INSERT ... VALUES`, if the placeholder is in it.
+fn raw_values_position(statement: &Statement, position: usize) -> Option<usize> {
+    match statement {
+        Statement::Insert(InsertStmt {
+            source: InsertSource::Values { start, .. },
+            ..
+        })
+        | Statement::Replace(ReplaceStmt {
+            source: InsertSource::Values { start, .. },
+            ..
+        }) if position >= *start => Some(position - start),
+        _ => None,
+    }
+}
+
+fn param_to_sql(value: ValueInner<'_>) -> Result<String> {
+    Ok(match value {
+        ValueInner::NULL => Literal::Null.to_string(),
+        ValueInner::UInt(v) => Literal::UInt64(v).to_string(),
+        // Negative numbers are wrapped to avoid being taken as a comment after `-`.
+        ValueInner::Int(v) if v < 0 => format!("({v})"),
+        ValueInner::Int(v) => v.to_string(),
+        ValueInner::Double(v) if v.is_finite() && v < 0.0 => format!("({v:?})"),
+        ValueInner::Double(v) if v.is_finite() => format!("{v:?}"),
+        ValueInner::Double(v) => format!("{}::DOUBLE", Literal::String(v.to_string())),
+        ValueInner::Bytes(v) => match std::str::from_utf8(v) {
+            Ok(v) => Literal::String(v.to_string()).to_string(),
+            Err(_) => format!("FROM_HEX('{}')", hex::encode(v)),
+        },
+        ValueInner::Date(v) | ValueInner::Datetime(v) => {
+            Literal::String(decode_datetime(v)?).to_string()
+        }
+        ValueInner::Time(v) => Literal::String(decode_time(v)?).to_string(),
+    })
+}
+
+// The binary format of DATE, DATETIME and TIMESTAMP parameters:
+// length (0, 4, 7 or 11), year (2), month (1), day (1), hour (1), minute (1), second (1), microsecond (4)
+fn decode_datetime(buf: &[u8]) -> Result<String> {
+    if !matches!(buf.len(), 0 | 4 | 7 | 11) {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid length {} of binary datetime parameter",
+            buf.len()
+        )));
+    }
+    if buf.is_empty() {
+        return Ok("0000-00-00 00:00:00".to_string());
+    }
+
+    let year = u16::from_le_bytes([buf[0], buf[1]]);
+    let mut value = format!("{:04}-{:02}-{:02}", year, buf[2], buf[3]);
+    if buf.len() >= 7 {
+        write!(value, " {:02}:{:02}:{:02}", buf[4], buf[5], buf[6]).unwrap();
+    }
+    if buf.len() == 11 {
+        let micros = u32::from_le_bytes([buf[7], buf[8], buf[9], buf[10]]);
+        write!(value, ".{:06}", micros).unwrap();
+    }
+    Ok(value)
+}
+
+// The binary format of TIME parameters:
+// length (0, 8 or 12), is_negative (1), days (4), hour (1), minute (1), second (1), microsecond (4)
+fn decode_time(buf: &[u8]) -> Result<String> {
+    if !matches!(buf.len(), 0 | 8 | 12) {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid length {} of binary time parameter",
+            buf.len()
+        )));
+    }
+    if buf.is_empty() {
+        return Ok("00:00:00".to_string());
+    }
+
+    let sign = if buf[0] == 1 { "-" } else { "" };
+    let days = u32::from_le_bytes([buf[1], buf[2], buf[3], buf[4]]);
+    let hours = days * 24 + buf[5] as u32;
+    let mut value = format!("{}{:02}:{:02}:{:02}", sign, hours, buf[6], buf[7]);
+    if buf.len() == 12 {
+        let micros = u32::from_le_bytes([buf[8], buf[9], buf[10], buf[11]]);
+        write!(value, ".{:06}", micros).unwrap();
+    }
+    Ok(value)
+}
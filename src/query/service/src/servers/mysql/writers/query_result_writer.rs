@@ -15,6 +15,7 @@
 use databend_common_base::base::tokio::io::AsyncWrite;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
@@ -67,6 +68,8 @@ impl QueryResult {
 
 pub struct DFQueryResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<QueryResultWriter<'a, W>>,
+    // Rows are written in the binary protocol for the prepared statements.
+    binary: bool,
 }
 
 fn write_field<W: AsyncWrite + Unpin>(
@@ -82,9 +85,70 @@ fn write_field<W: AsyncWrite + Unpin>(
     Ok(())
 }
 
+fn convert_field_type(field: &DataField) -> Result<ColumnType> {
+    match field.data_type().remove_nullable() {
+        DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
+        DataType::EmptyArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::EmptyMap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
+        DataType::Binary => Ok(ColumnType::MYSQL_TYPE_BLOB),
+        DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+            NumberDataType::Int16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+            NumberDataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+            NumberDataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+            NumberDataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+            NumberDataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+            NumberDataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+            NumberDataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
+            NumberDataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+            NumberDataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
+        },
+        DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
+        DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+        DataType::Interval => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::TimestampTz => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+        DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "Unsupported column type:{:?}",
+            field.data_type()
+        ))),
+    }
+}
+
+fn make_column_from_field(field: &DataField) -> Result<Column> {
+    convert_field_type(field).map(|column_type| Column {
+        table: "".to_string(),
+        column: field.name().to_string(),
+        coltype: column_type,
+        colflags: ColumnFlags::empty(),
+    })
+}
+
+pub fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
+    schema.fields().iter().map(make_column_from_field).collect()
+}
+
 impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
     pub fn create(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
-        DFQueryResultWriter::<'a, W> { inner: Some(inner) }
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            binary: false,
+        }
+    }
+
+    pub fn create_binary(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            binary: true,
+        }
     }
 
     #[async_backtrace::framed]
@@ -97,9 +161,9 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
             match query_result {
                 Ok((query_result, query_format)) => {
                     if let Some(format) = query_format {
-                        Self::ok(query_result, writer, &format).await?
+                        Self::ok(query_result, writer, &format, self.binary).await?
                     } else {
-                        Self::ok(query_result, writer, format).await?
+                        Self::ok(query_result, writer, format, self.binary).await?
                     }
                 }
                 Err(error) => Self::err(&error, writer).await?,
@@ -113,6 +177,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         mut query_result: QueryResult,
         dataset_writer: QueryResultWriter<'a, W>,
         format: &FormatSettings,
+        binary: bool,
     ) -> Result<()> {
         // XXX: num_columns == 0 may is error?
         if !query_result.has_result_set {
@@ -149,54 +214,6 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
             return Ok(());
         }
 
-        fn convert_field_type(field: &DataField) -> Result<ColumnType> {
-            match field.data_type().remove_nullable() {
-                DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
-                DataType::EmptyArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::EmptyMap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                DataType::Binary => Ok(ColumnType::MYSQL_TYPE_BLOB),
-                DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Number(num_ty) => match num_ty {
-                    NumberDataType::Int8 => Ok(ColumnType::MYSQL_TYPE_TINY),
-                    NumberDataType::Int16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                    NumberDataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                    NumberDataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
-                    NumberDataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_TINY),
-                    NumberDataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
-                    NumberDataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                    NumberDataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
-                    NumberDataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                    NumberDataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
-                },
-                DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
-                DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
-                _ => Err(ErrorCode::Unimplemented(format!(
-                    "Unsupported column type:{:?}",
-                    field.data_type()
-                ))),
-            }
-        }
-
-        fn make_column_from_field(field: &DataField) -> Result<Column> {
-            convert_field_type(field).map(|column_type| Column {
-                table: "".to_string(),
-                column: field.name().to_string(),
-                coltype: column_type,
-                colflags: ColumnFlags::empty(),
-            })
-        }
-
-        fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
-            schema.fields().iter().map(make_column_from_field).collect()
-        }
-
         let _tz = format.timezone;
         match convert_schema(&query_result.schema) {
             Err(error) => Self::err(&error, dataset_writer).await,
@@ -265,6 +282,13 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                     NumberScalar::Int64(v) => {
                                         row_writer.write_col(v)?;
                                     }
+                                    // The binary protocol requires the native float values.
+                                    NumberScalar::Float32(v) if binary => {
+                                        row_writer.write_col(v.0)?;
+                                    }
+                                    NumberScalar::Float64(v) if binary => {
+                                        row_writer.write_col(v.0)?;
+                                    }
                                     _ => {
                                         write_field(
                                             &mut row_writer,
@@ -275,6 +299,13 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                         )?;
                                     }
                                 },
+                                ScalarRef::Date(v) if binary => {
+                                    row_writer.write_col(v.to_date(format.timezone))?;
+                                }
+                                ScalarRef::Timestamp(v) if binary => {
+                                    row_writer
+                                        .write_col(v.to_timestamp(format.timezone).naive_local())?;
+                                }
                                 ScalarRef::Bitmap(_) => {
                                     let bitmap_result = "<bitmap binary>".as_bytes();
                                     row_writer.write_col(bitmap_result)?;
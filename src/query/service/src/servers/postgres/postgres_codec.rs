@@ -0,0 +1,474 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The messages of the PostgreSQL frontend/backend protocol version 3.0.
+//!
+//! See also: https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+const PROTOCOL_VERSION_3: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// Reject the messages larger than 1GiB, which is also the limit of PostgreSQL.
+const MAX_MESSAGE_LENGTH: usize = 1 << 30;
+// The messages before the authentication are limited as PostgreSQL does, so that an
+// unauthenticated client can't make the server allocate a huge buffer.
+pub const MAX_STARTUP_MESSAGE_LENGTH: usize = 10000;
+
+pub const FORMAT_TEXT: i16 = 0;
+pub const FORMAT_BINARY: i16 = 1;
+
+/// The first message sent by the frontend, which has no message type byte.
+pub enum StartupMessage {
+    SslRequest,
+    GssEncRequest,
+    CancelRequest,
+    Startup { params: Vec<(String, String)> },
+}
+
+/// The messages sent by the frontend after the startup.
+pub enum FrontendMessage {
+    Password(String),
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    // The limit of the rows to return is ignored, all the rows are returned at once.
+    Execute {
+        portal: String,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+}
+
+/// The description of a column in `RowDescription`.
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub format: i16,
+}
+
+/// The messages sent by the backend.
+pub enum BackendMessage<'a> {
+    AuthenticationOk,
+    AuthenticationCleartextPassword,
+    ParameterStatus(&'a str, &'a str),
+    BackendKeyData { process_id: i32, secret_key: i32 },
+    ReadyForQuery(u8),
+    RowDescription(&'a [FieldDescription]),
+    // The fields are encoded by `DataRowEncoder`.
+    DataRow { num_fields: i16, fields: &'a [u8] },
+    CommandComplete(&'a str),
+    EmptyQueryResponse,
+    ErrorResponse { code: &'a str, message: &'a str },
+    ParseComplete,
+    BindComplete,
+    CloseComplete,
+    ParameterDescription(&'a [u32]),
+    NoData,
+}
+
+impl BackendMessage<'_> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let start = buf.len();
+        buf.push(self.tag());
+        // The length is filled after the body is encoded.
+        buf.extend_from_slice(&[0; 4]);
+
+        match self {
+            BackendMessage::AuthenticationOk => put_i32(buf, 0),
+            BackendMessage::AuthenticationCleartextPassword => put_i32(buf, 3),
+            BackendMessage::ParameterStatus(name, value) => {
+                put_cstr(buf, name);
+                put_cstr(buf, value);
+            }
+            BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            } => {
+                put_i32(buf, *process_id);
+                put_i32(buf, *secret_key);
+            }
+            BackendMessage::ReadyForQuery(status) => buf.push(*status),
+            BackendMessage::RowDescription(fields) => {
+                put_i16(buf, fields.len() as i16);
+                for field in fields.iter() {
+                    put_cstr(buf, &field.name);
+                    // The oid of the table and the attribute number of the column.
+                    put_i32(buf, 0);
+                    put_i16(buf, 0);
+                    put_i32(buf, field.type_oid as i32);
+                    put_i16(buf, field.type_size);
+                    // The type modifier.
+                    put_i32(buf, -1);
+                    put_i16(buf, field.format);
+                }
+            }
+            BackendMessage::DataRow { num_fields, fields } => {
+                put_i16(buf, *num_fields);
+                buf.extend_from_slice(fields);
+            }
+            BackendMessage::CommandComplete(tag) => put_cstr(buf, tag),
+            BackendMessage::ErrorResponse { code, message } => {
+                buf.push(b'S');
+                put_cstr(buf, "ERROR");
+                buf.push(b'V');
+                put_cstr(buf, "ERROR");
+                buf.push(b'C');
+                put_cstr(buf, code);
+                buf.push(b'M');
+                put_cstr(buf, message);
+                buf.push(0);
+            }
+            BackendMessage::ParameterDescription(types) => {
+                put_i16(buf, types.len() as i16);
+                for oid in types.iter() {
+                    put_i32(buf, *oid as i32);
+                }
+            }
+            BackendMessage::EmptyQueryResponse
+            | BackendMessage::ParseComplete
+            | BackendMessage::BindComplete
+            | BackendMessage::CloseComplete
+            | BackendMessage::NoData => {}
+        }
+
+        let len = (buf.len() - start - 1) as i32;
+        buf[start + 1..start + 5].copy_from_slice(&len.to_be_bytes());
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            BackendMessage::AuthenticationOk | BackendMessage::AuthenticationCleartextPassword => {
+                b'R'
+            }
+            BackendMessage::ParameterStatus(_, _) => b'S',
+            BackendMessage::BackendKeyData { .. } => b'K',
+            BackendMessage::ReadyForQuery(_) => b'Z',
+            BackendMessage::RowDescription(_) => b'T',
+            BackendMessage::DataRow { .. } => b'D',
+            BackendMessage::CommandComplete(_) => b'C',
+            BackendMessage::EmptyQueryResponse => b'I',
+            BackendMessage::ErrorResponse { .. } => b'E',
+            BackendMessage::ParseComplete => b'1',
+            BackendMessage::BindComplete => b'2',
+            BackendMessage::CloseComplete => b'3',
+            BackendMessage::ParameterDescription(_) => b't',
+            BackendMessage::NoData => b'n',
+        }
+    }
+}
+
+/// Encode the fields of a `DataRow`, each field is a length followed by the value,
+/// the length of NULL is -1.
+#[derive(Default)]
+pub struct DataRowEncoder {
+    num_fields: i16,
+    buf: Vec<u8>,
+}
+
+impl DataRowEncoder {
+    pub fn clear(&mut self) {
+        self.num_fields = 0;
+        self.buf.clear();
+    }
+
+    pub fn write_null(&mut self) {
+        self.num_fields += 1;
+        put_i32(&mut self.buf, -1);
+    }
+
+    /// Write a field with the closure which appends the value to the buffer.
+    pub fn write_field<F: FnOnce(&mut Vec<u8>) -> Result<()>>(&mut self, f: F) -> Result<()> {
+        self.num_fields += 1;
+        let start = self.buf.len();
+        put_i32(&mut self.buf, 0);
+        f(&mut self.buf)?;
+        let len = (self.buf.len() - start - 4) as i32;
+        self.buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+        Ok(())
+    }
+
+    pub fn message(&self) -> BackendMessage<'_> {
+        BackendMessage::DataRow {
+            num_fields: self.num_fields,
+            fields: &self.buf,
+        }
+    }
+}
+
+pub struct PostgresWriter<W: AsyncWrite + Unpin> {
+    inner: W,
+    buf: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> PostgresWriter<W> {
+    pub fn create(inner: W) -> Self {
+        PostgresWriter { inner, buf: vec![] }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn send(&mut self, message: BackendMessage<'_>) -> Result<()> {
+        self.buf.clear();
+        message.encode(&mut self.buf);
+        self.inner.write_all(&self.buf).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn flush(&mut self) -> Result<()> {
+        self.inner.flush().await?;
+        Ok(())
+    }
+}
+
+#[async_backtrace::framed]
+pub async fn read_startup_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+    let len = reader.read_i32().await? as usize;
+    if !(8..=MAX_STARTUP_MESSAGE_LENGTH).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid length {} of startup message",
+            len
+        )));
+    }
+    let mut body = vec![0; len - 4];
+    reader.read_exact(&mut body).await?;
+
+    let mut body = MessageBody::new(&body);
+    match body.read_i32()? {
+        SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest),
+        PROTOCOL_VERSION_3 => {
+            let mut params = vec![];
+            loop {
+                let name = body.read_cstr()?;
+                if name.is_empty() {
+                    break;
+                }
+                params.push((name, body.read_cstr()?));
+            }
+            Ok(StartupMessage::Startup { params })
+        }
+        version => Err(ErrorCode::Unimplemented(format!(
+            "Unsupported frontend protocol {}.{}, only 3.0 is supported",
+            version >> 16,
+            version & 0xffff
+        ))),
+    }
+}
+
+/// Read the startup messages until the client starts up, cancels or asks for TLS.
+/// `SSLRequest` is accepted and returned only if `accept_tls` is set, the TLS handshake
+/// goes next. Otherwise it is rejected as `GSSENCRequest`, and the client goes on without
+/// encryption.
+#[async_backtrace::framed]
+pub async fn negotiate<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    accept_tls: bool,
+) -> Result<StartupMessage> {
+    loop {
+        match read_startup_message(stream).await? {
+            StartupMessage::SslRequest if accept_tls => {
+                stream.write_all(b"S").await?;
+                stream.flush().await?;
+                return Ok(StartupMessage::SslRequest);
+            }
+            StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                stream.write_all(b"N").await?;
+                stream.flush().await?;
+            }
+            message => return Ok(message),
+        }
+    }
+}
+
+/// Read a message after the startup, returns `None` if the connection is closed.
+#[async_backtrace::framed]
+pub async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+    read_message_with_limit(reader, MAX_MESSAGE_LENGTH).await
+}
+
+/// Read a message no larger than `max_len`, which is used before the authentication.
+#[async_backtrace::framed]
+pub async fn read_message_with_limit<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    max_len: usize,
+) -> Result<Option<FrontendMessage>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let len = reader.read_i32().await? as usize;
+    if !(4..=max_len).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid length {} of message '{}'",
+            len, tag as char
+        )));
+    }
+    let mut body = vec![0; len - 4];
+    reader.read_exact(&mut body).await?;
+
+    let mut body = MessageBody::new(&body);
+    let message = match tag {
+        b'p' => FrontendMessage::Password(body.read_cstr()?),
+        b'Q' => FrontendMessage::Query(body.read_cstr()?),
+        b'P' => {
+            let name = body.read_cstr()?;
+            let query = body.read_cstr()?;
+            let num_types = body.read_i16()?;
+            let param_types = (0..num_types)
+                .map(|_| body.read_i32().map(|oid| oid as u32))
+                .collect::<Result<_>>()?;
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = body.read_cstr()?;
+            let statement = body.read_cstr()?;
+            let num_formats = body.read_i16()?;
+            let param_formats = (0..num_formats)
+                .map(|_| body.read_i16())
+                .collect::<Result<_>>()?;
+            let num_params = body.read_i16()?;
+            let params = (0..num_params)
+                .map(|_| match body.read_i32()? {
+                    -1 => Ok(None),
+                    len => body.read_bytes(len as usize).map(|v| Some(v.to_vec())),
+                })
+                .collect::<Result<_>>()?;
+            let num_formats = body.read_i16()?;
+            let result_formats = (0..num_formats)
+                .map(|_| body.read_i16())
+                .collect::<Result<_>>()?;
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => FrontendMessage::Describe {
+            kind: body.read_u8()?,
+            name: body.read_cstr()?,
+        },
+        b'E' => FrontendMessage::Execute {
+            portal: body.read_cstr()?,
+        },
+        b'C' => FrontendMessage::Close {
+            kind: body.read_u8()?,
+            name: body.read_cstr()?,
+        },
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'X' => FrontendMessage::Terminate,
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Unsupported frontend message '{}'",
+                tag as char
+            )));
+        }
+    };
+    Ok(Some(message))
+}
+
+struct MessageBody<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> MessageBody<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        MessageBody { buf }
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.buf.len() < len {
+            return Err(ErrorCode::BadBytes("Unexpected end of message"));
+        }
+        let (bytes, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_i16(&mut self) -> Result<i16> {
+        let bytes = self.read_bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_i32(&mut self) -> Result<i32> {
+        let bytes = self.read_bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_cstr(&mut self) -> Result<String> {
+        let pos = self
+            .buf
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or_else(|| ErrorCode::BadBytes("Unterminated string in message"))?;
+        let value = String::from_utf8(self.buf[..pos].to_vec())
+            .map_err(|e| ErrorCode::BadBytes(format!("Invalid utf8 string in message: {}", e)))?;
+        self.buf = &self.buf[pos + 1..];
+        Ok(value)
+    }
+}
+
+fn put_i16(buf: &mut Vec<u8>, v: i16) {
+    buf.extend_from_slice(&v.to_be_bytes());
+}
+
+fn put_i32(buf: &mut Vec<u8>, v: i32) {
+    buf.extend_from_slice(&v.to_be_bytes());
+}
+
+fn put_cstr(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(s.as_bytes());
+    buf.push(0);
+}
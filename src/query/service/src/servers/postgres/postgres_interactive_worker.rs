@@ -0,0 +1,600 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_catalog::txn::TxnState;
+use databend_common_config::DATABEND_COMMIT_VERSION;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::StreamExt;
+use log::error;
+use log::info;
+use once_cell::sync::Lazy;
+use rand::Rng;
+use regex::Regex;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::postgres_codec::read_message;
+use crate::servers::postgres::postgres_codec::read_message_with_limit;
+use crate::servers::postgres::postgres_codec::BackendMessage;
+use crate::servers::postgres::postgres_codec::DataRowEncoder;
+use crate::servers::postgres::postgres_codec::FrontendMessage;
+use crate::servers::postgres::postgres_codec::PostgresWriter;
+use crate::servers::postgres::postgres_codec::MAX_STARTUP_MESSAGE_LENGTH;
+use crate::servers::postgres::postgres_prepared_statement::PreparedStatement;
+use crate::servers::postgres::postgres_types::convert_schema;
+use crate::servers::postgres::postgres_types::format_of;
+use crate::servers::postgres::postgres_types::ValueEncoder;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+
+// The session parameters set by the PostgreSQL drivers, which have no counterpart
+// in Databend, e.g. `SET extra_float_digits = 3` of JDBC.
+static IGNORED_SET_STATEMENT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^\s*SET\s+(SESSION\s+)?(extra_float_digits|application_name|client_encoding|client_min_messages|datestyle|intervalstyle|search_path|standard_conforming_strings)\b",
+    )
+    .unwrap()
+});
+
+/// A portal created by the `Bind` message, which is the query with the parameters bound.
+struct Portal {
+    query: String,
+    result_formats: Vec<i16>,
+}
+
+/// The tag of `CommandComplete`, which tells the kind of the command and the number
+/// of the rows it returned or affected.
+enum CommandTag {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Merge,
+    Command(String),
+}
+
+impl CommandTag {
+    fn from_plan(plan: &Plan, query: &str) -> CommandTag {
+        match plan {
+            _ if plan.has_result_set() => CommandTag::Select,
+            Plan::Insert(_) | Plan::Replace(_) => CommandTag::Insert,
+            Plan::Update(_) => CommandTag::Update,
+            Plan::Delete(_) => CommandTag::Delete,
+            Plan::MergeInto(_) => CommandTag::Merge,
+            Plan::Abort => CommandTag::Command("ROLLBACK".to_string()),
+            _ => CommandTag::from_query(query),
+        }
+    }
+
+    fn from_query(query: &str) -> CommandTag {
+        let command = query
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches(';');
+        CommandTag::Command(command.to_ascii_uppercase())
+    }
+
+    fn format(&self, rows: u64) -> String {
+        match self {
+            CommandTag::Select => format!("SELECT {}", rows),
+            // The oid of the inserted row is always 0.
+            CommandTag::Insert => format!("INSERT 0 {}", rows),
+            CommandTag::Update => format!("UPDATE {}", rows),
+            CommandTag::Delete => format!("DELETE {}", rows),
+            CommandTag::Merge => format!("MERGE {}", rows),
+            CommandTag::Command(command) => command.clone(),
+        }
+    }
+}
+
+struct QueryResult {
+    blocks: SendableDataBlockStream,
+    schema: DataSchemaRef,
+    has_result_set: bool,
+    tag: CommandTag,
+    // The context to get the number of the affected rows, after the stream is finished.
+    context: Option<Arc<QueryContext>>,
+}
+
+pub struct InteractiveWorker {
+    session: Arc<Session>,
+    client_addr: String,
+    // The statements and portals of the extended query protocol, the unnamed ones are keyed by "".
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+}
+
+impl InteractiveWorker {
+    pub fn create(session: Arc<Session>, client_addr: String) -> InteractiveWorker {
+        InteractiveWorker {
+            session,
+            client_addr,
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run<R: AsyncRead + Unpin, W: AsyncWrite + Unpin>(
+        mut self,
+        params: Vec<(String, String)>,
+        mut reader: R,
+        writer: W,
+    ) -> Result<()> {
+        let mut writer = PostgresWriter::create(writer);
+        if let Err(error) = self.startup(&params, &mut reader, &mut writer).await {
+            error!(
+                "PostgreSQL handler startup failed, client_address: {}, failure_cause: {}",
+                self.client_addr, error
+            );
+            Self::write_error(&error, &mut writer).await?;
+            writer.flush().await?;
+            return Ok(());
+        }
+        self.write_ready(&mut writer).await?;
+
+        // After an error of the extended query protocol, the messages are discarded until `Sync`.
+        let mut skip_until_sync = false;
+        while let Some(message) = read_message(&mut reader).await? {
+            match message {
+                FrontendMessage::Terminate => break,
+                FrontendMessage::Sync => {
+                    skip_until_sync = false;
+                    self.write_ready(&mut writer).await?;
+                }
+                _ if skip_until_sync => {}
+                FrontendMessage::Flush => writer.flush().await?,
+                FrontendMessage::Query(query) => {
+                    if let Err(error) = self.do_simple_query(&query, &mut writer).await {
+                        let error = error.display_with_sql(&query);
+                        error!("OnQuery Error: {:?}", error);
+                        Self::write_error(&error, &mut writer).await?;
+                    }
+                    self.write_ready(&mut writer).await?;
+                }
+                message => {
+                    if let Err(error) = self.do_extended_query(message, &mut writer).await {
+                        error!("OnExtendedQuery Error: {:?}", error);
+                        Self::write_error(&error, &mut writer).await?;
+                        skip_until_sync = true;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn startup<R: AsyncRead + Unpin, W: AsyncWrite + Unpin>(
+        &mut self,
+        params: &[(String, String)],
+        reader: &mut R,
+        writer: &mut PostgresWriter<W>,
+    ) -> Result<()> {
+        let get_param = |name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        let user = get_param("user").ok_or_else(|| {
+            ErrorCode::AuthenticateFailure("No user name is specified in the startup message")
+        })?;
+        self.authenticate(user, reader, writer).await?;
+        writer.send(BackendMessage::AuthenticationOk).await?;
+
+        if let Some(database) = get_param("database") {
+            let query = format!("USE `{}`", database.replace('`', "``"));
+            let mut result = self.do_query(&query).await?;
+            while let Some(block) = result.blocks.next().await {
+                block?;
+            }
+        }
+
+        let version = format!("{}-{}", POSTGRES_VERSION, *DATABEND_COMMIT_VERSION);
+        let timezone = self.session.get_settings().get_timezone()?;
+        let parameters = [
+            ("server_version", version.as_str()),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, YMD"),
+            ("TimeZone", timezone.as_str()),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+        ];
+        for (name, value) in parameters {
+            writer
+                .send(BackendMessage::ParameterStatus(name, value))
+                .await?;
+        }
+
+        // The key data is used by the cancel request, which is not supported yet.
+        let (process_id, secret_key) = {
+            let mut rng = rand::thread_rng();
+            (rng.gen(), rng.gen())
+        };
+        writer
+            .send(BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            })
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn authenticate<R: AsyncRead + Unpin, W: AsyncWrite + Unpin>(
+        &self,
+        user: &str,
+        reader: &mut R,
+        writer: &mut PostgresWriter<W>,
+    ) -> Result<()> {
+        let tenant = self.session.get_current_tenant();
+        let identity = UserIdentity::new(user, "%");
+        let client_ip = self.client_addr.split(':').collect::<Vec<_>>()[0];
+        let user_info = UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, identity, Some(client_ip))
+            .await?;
+
+        // Don't ask the users without password for the password, because `psql`
+        // refuses to send an empty password.
+        let password = match user_info.auth_info {
+            AuthInfo::None => None,
+            _ => {
+                writer
+                    .send(BackendMessage::AuthenticationCleartextPassword)
+                    .await?;
+                writer.flush().await?;
+                match read_message_with_limit(reader, MAX_STARTUP_MESSAGE_LENGTH).await? {
+                    Some(FrontendMessage::Password(password)) => Some(password.into_bytes()),
+                    _ => {
+                        return Err(ErrorCode::AuthenticateFailure(
+                            "Expect a password message from the client",
+                        ));
+                    }
+                }
+            }
+        };
+
+        let credential = Credential::Password {
+            name: user.to_string(),
+            password,
+            client_ip: Some(client_ip.to_string()),
+        };
+        AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_simple_query<W: AsyncWrite + Unpin>(
+        &mut self,
+        query: &str,
+        writer: &mut PostgresWriter<W>,
+    ) -> Result<()> {
+        if is_empty_query(query) {
+            return writer.send(BackendMessage::EmptyQueryResponse).await;
+        }
+
+        let result = self.do_query(query).await?;
+        if result.has_result_set {
+            let fields = convert_schema(&result.schema, &[]);
+            writer.send(BackendMessage::RowDescription(&fields)).await?;
+        }
+        self.write_result(result, &[], writer).await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_extended_query<W: AsyncWrite + Unpin>(
+        &mut self,
+        message: FrontendMessage,
+        writer: &mut PostgresWriter<W>,
+    ) -> Result<()> {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            } => {
+                info!("Prepare query: {}", query);
+                let statement = PreparedStatement::try_create(&query, param_types)?;
+                self.statements.insert(name, statement);
+                writer.send(BackendMessage::ParseComplete).await
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let query = self
+                    .get_statement(&statement)?
+                    .bind(&params, &param_formats)?;
+                self.portals.insert(portal, Portal {
+                    query,
+                    result_formats,
+                });
+                writer.send(BackendMessage::BindComplete).await
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let statement = self.get_statement(&name)?;
+                let param_types = statement.param_types();
+                let query = statement.null_query();
+                writer
+                    .send(BackendMessage::ParameterDescription(&param_types))
+                    .await?;
+                // The query may be valid with the real parameters, e.g. `LIMIT $1`,
+                // the columns are described again by the portal.
+                let schema = match self.plan_schema(&query).await {
+                    Err(error) if error.code() != ErrorCode::SYNTAX_EXCEPTION => None,
+                    schema => schema?,
+                };
+                Self::write_description(schema, &[], writer).await
+            }
+            FrontendMessage::Describe { kind: b'P', name } => {
+                let portal = self.get_portal(&name)?;
+                let (query, formats) = (portal.query.clone(), portal.result_formats.clone());
+                let schema = self.plan_schema(&query).await?;
+                Self::write_description(schema, &formats, writer).await
+            }
+            FrontendMessage::Execute { portal } => {
+                let portal = self.get_portal(&portal)?;
+                let (query, formats) = (portal.query.clone(), portal.result_formats.clone());
+                if is_empty_query(&query) {
+                    return writer.send(BackendMessage::EmptyQueryResponse).await;
+                }
+                let result = self
+                    .do_query(&query)
+                    .await
+                    .map_err(|error| error.display_with_sql(&query))?;
+                self.write_result(result, &formats, writer).await
+            }
+            FrontendMessage::Close { kind, name } => {
+                match kind {
+                    b'S' => {
+                        self.statements.remove(&name);
+                    }
+                    b'P' => {
+                        self.portals.remove(&name);
+                    }
+                    _ => return Err(unknown_describe_kind(kind)),
+                }
+                writer.send(BackendMessage::CloseComplete).await
+            }
+            FrontendMessage::Describe { kind, .. } => Err(unknown_describe_kind(kind)),
+            FrontendMessage::Password(_)
+            | FrontendMessage::Query(_)
+            | FrontendMessage::Sync
+            | FrontendMessage::Flush
+            | FrontendMessage::Terminate => Err(ErrorCode::Internal(
+                "Unexpected message of the extended query protocol",
+            )),
+        }
+    }
+
+    fn get_statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("Unknown prepared statement '{}'", name))
+        })
+    }
+
+    fn get_portal(&self, name: &str) -> Result<&Portal> {
+        self.portals
+            .get(name)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("Unknown portal '{}'", name)))
+    }
+
+    // Plan the query to get the schema of the result set.
+    #[async_backtrace::framed]
+    async fn plan_schema(&self, query: &str) -> Result<Option<DataSchemaRef>> {
+        if is_empty_query(query) || IGNORED_SET_STATEMENT.is_match(query) {
+            return Ok(None);
+        }
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let (plan, _) = planner.plan_sql(query).await?;
+        Ok(plan.has_result_set().then(|| plan.schema()))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_query(&mut self, query: &str) -> Result<QueryResult> {
+        if IGNORED_SET_STATEMENT.is_match(query) {
+            info!("Ignored query: {}", query);
+            return Ok(QueryResult {
+                blocks: futures::stream::empty().boxed(),
+                schema: DataSchemaRefExt::create(vec![]),
+                has_result_set: false,
+                tag: CommandTag::Command("SET".to_string()),
+                context: None,
+            });
+        }
+
+        info!("Normal query: {}", query);
+        let context = self.session.create_query_context().await?;
+
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+            Ok(interpreter) => interpreter,
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                return Err(e);
+            }
+        };
+
+        let blocks = interpreter.execute(context.clone()).await?;
+        Ok(QueryResult {
+            blocks,
+            schema: plan.schema(),
+            has_result_set: plan.has_result_set(),
+            tag: CommandTag::from_plan(&plan, query),
+            context: Some(context),
+        })
+    }
+
+    // Write the rows of the result set and `CommandComplete`.
+    #[async_backtrace::framed]
+    async fn write_result<W: AsyncWrite + Unpin>(
+        &self,
+        mut result: QueryResult,
+        formats: &[i16],
+        writer: &mut PostgresWriter<W>,
+    ) -> Result<()> {
+        let mut rows = 0;
+        if result.has_result_set {
+            let timezone = match &result.context {
+                Some(context) => context.get_format_settings()?.timezone,
+                None => self.session.get_format_settings().timezone,
+            };
+            rows = Self::write_rows(&mut result.blocks, timezone, formats, writer).await?;
+        } else {
+            // For statements without result sets, we still need to pull the stream because errors may occur in the stream.
+            while let Some(block) = result.blocks.next().await {
+                block?;
+            }
+            if let Some(context) = &result.context {
+                rows = context.get_write_progress_value().rows as u64;
+            }
+        }
+
+        writer
+            .send(BackendMessage::CommandComplete(&result.tag.format(rows)))
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn write_rows<W: AsyncWrite + Unpin>(
+        blocks: &mut SendableDataBlockStream,
+        timezone: Tz,
+        formats: &[i16],
+        writer: &mut PostgresWriter<W>,
+    ) -> Result<u64> {
+        let encoder = ValueEncoder::create(timezone);
+        let mut row = DataRowEncoder::default();
+        let mut rows = 0;
+        while let Some(block) = blocks.next().await {
+            let block = block?;
+            let num_rows = block.num_rows();
+            let columns = block
+                .convert_to_full()
+                .columns()
+                .iter()
+                .map(|column| column.value.clone().into_column().unwrap())
+                .collect::<Vec<_>>();
+
+            for row_index in 0..num_rows {
+                row.clear();
+                for (col_index, column) in columns.iter().enumerate() {
+                    if column.index(row_index).unwrap().is_null() {
+                        row.write_null();
+                    } else {
+                        let format = format_of(formats, col_index);
+                        row.write_field(|buf| encoder.write_value(column, row_index, format, buf))?;
+                    }
+                }
+                writer.send(row.message()).await?;
+            }
+            rows += num_rows as u64;
+        }
+        Ok(rows)
+    }
+
+    #[async_backtrace::framed]
+    async fn write_description<W: AsyncWrite + Unpin>(
+        schema: Option<DataSchemaRef>,
+        formats: &[i16],
+        writer: &mut PostgresWriter<W>,
+    ) -> Result<()> {
+        match schema {
+            Some(schema) => {
+                let fields = convert_schema(&schema, formats);
+                writer.send(BackendMessage::RowDescription(&fields)).await
+            }
+            None => writer.send(BackendMessage::NoData).await,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn write_ready<W: AsyncWrite + Unpin>(
+        &self,
+        writer: &mut PostgresWriter<W>,
+    ) -> Result<()> {
+        let status = match self.session.txn_mgr().lock().state() {
+            TxnState::AutoCommit => b'I',
+            TxnState::Active => b'T',
+            TxnState::Fail => b'E',
+        };
+        writer.send(BackendMessage::ReadyForQuery(status)).await?;
+        writer.flush().await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write_error<W: AsyncWrite + Unpin>(
+        error: &ErrorCode,
+        writer: &mut PostgresWriter<W>,
+    ) -> Result<()> {
+        let code = match error.code() {
+            ErrorCode::SYNTAX_EXCEPTION => "42601",
+            ErrorCode::UNKNOWN_DATABASE => "3D000",
+            ErrorCode::UNKNOWN_TABLE => "42P01",
+            ErrorCode::PERMISSION_DENIED => "42501",
+            ErrorCode::AUTHENTICATE_FAILURE | ErrorCode::UNKNOWN_USER => "28P01",
+            ErrorCode::CURRENT_TRANSACTION_IS_ABORTED => "25P02",
+            ErrorCode::ABORTED_QUERY => "57014",
+            ErrorCode::TOO_MANY_USER_CONNECTIONS => "53300",
+            _ => "XX000",
+        };
+        writer
+            .send(BackendMessage::ErrorResponse {
+                code,
+                message: &error.to_string(),
+            })
+            .await
+    }
+}
+
+// The query only has whitespaces and semicolons.
+fn is_empty_query(query: &str) -> bool {
+    query.chars().all(|c| c.is_ascii_whitespace() || c == ';')
+}
+
+fn unknown_describe_kind(kind: u8) -> ErrorCode {
+    ErrorCode::BadArguments(format!(
+        "Unknown kind '{}' to describe or close, expect 'S' or 'P'",
+        kind as char
+    ))
+}
@@ -0,0 +1,270 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::token::Tokenizer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::interval::months_days_micros;
+
+use crate::servers::postgres::postgres_codec::FORMAT_BINARY;
+use crate::servers::postgres::postgres_codec::FORMAT_TEXT;
+use crate::servers::postgres::postgres_types::format_of;
+use crate::servers::postgres::postgres_types::BOOL_OID;
+use crate::servers::postgres::postgres_types::BYTEA_OID;
+use crate::servers::postgres::postgres_types::DATE_OID;
+use crate::servers::postgres::postgres_types::FLOAT4_OID;
+use crate::servers::postgres::postgres_types::FLOAT8_OID;
+use crate::servers::postgres::postgres_types::INT2_OID;
+use crate::servers::postgres::postgres_types::INT4_OID;
+use crate::servers::postgres::postgres_types::INT8_OID;
+use crate::servers::postgres::postgres_types::INTERVAL_OID;
+use crate::servers::postgres::postgres_types::JSON_OID;
+use crate::servers::postgres::postgres_types::NUMERIC_OID;
+use crate::servers::postgres::postgres_types::TEXT_OID;
+use crate::servers::postgres::postgres_types::TIMESTAMPTZ_OID;
+use crate::servers::postgres::postgres_types::TIMESTAMP_OID;
+use crate::servers::postgres::postgres_types::UNKNOWN_OID;
+use crate::servers::postgres::postgres_types::VARCHAR_OID;
+
+/// A statement prepared by the `Parse` message.
+///
+/// The `$n` placeholders are replaced with the SQL literals of the parameters
+/// of the `Bind` message, like the prepared statements of the MySQL handler.
+pub struct PreparedStatement {
+    query: String,
+    // The byte ranges and the parameter indexes (from 0) of the placeholders.
+    placeholders: Vec<(usize, usize, usize)>,
+    param_types: Vec<u32>,
+}
+
+impl PreparedStatement {
+    pub fn try_create(query: &str, mut param_types: Vec<u32>) -> Result<PreparedStatement> {
+        let mut placeholders = vec![];
+        for token in Tokenizer::new(query) {
+            let token = token?;
+            if token.kind == TokenKind::ColumnPosition {
+                let index = token.text()[1..].parse::<usize>().map_err(|_| {
+                    ErrorCode::SyntaxException(format!("Invalid placeholder {}", token.text()))
+                })?;
+                if index == 0 {
+                    return Err(ErrorCode::SyntaxException("Invalid placeholder $0"));
+                }
+                placeholders.push((token.span.start(), token.span.end(), index - 1));
+            }
+        }
+
+        // The types of the parameters not given by the client are unspecified.
+        let num_params = placeholders.iter().map(|(_, _, i)| i + 1).max();
+        if let Some(num_params) = num_params {
+            if param_types.len() < num_params {
+                param_types.resize(num_params, 0);
+            }
+        }
+
+        Ok(PreparedStatement {
+            query: query.to_string(),
+            placeholders,
+            param_types,
+        })
+    }
+
+    /// The types of the parameters in `ParameterDescription`, the unspecified
+    /// parameters are sent in text.
+    pub fn param_types(&self) -> Vec<u32> {
+        self.param_types
+            .iter()
+            .map(|oid| if *oid == 0 { TEXT_OID } else { *oid })
+            .collect()
+    }
+
+    /// The query with all the placeholders bound to NULL, which is used to
+    /// get the schema of the result set when describing the statement.
+    pub fn null_query(&self) -> String {
+        let values = vec![Literal::Null.to_string(); self.param_types.len()];
+        self.render(&values)
+    }
+
+    /// Bind the parameters of the `Bind` message to the placeholders.
+    pub fn bind(&self, params: &[Option<Vec<u8>>], formats: &[i16]) -> Result<String> {
+        if params.len() != self.param_types.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The prepared statement expects {} parameters, but got {}",
+                self.param_types.len(),
+                params.len()
+            )));
+        }
+        let values = params
+            .iter()
+            .enumerate()
+            .map(|(index, param)| match param {
+                None => Ok(Literal::Null.to_string()),
+                Some(value) => match format_of(formats, index) {
+                    FORMAT_TEXT => text_param_to_sql(self.param_types[index], value),
+                    FORMAT_BINARY => binary_param_to_sql(self.param_types[index], value),
+                    format => Err(ErrorCode::BadArguments(format!(
+                        "Unknown format code {}",
+                        format
+                    ))),
+                },
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.render(&values))
+    }
+
+    fn render(&self, values: &[String]) -> String {
+        let mut query = String::with_capacity(self.query.len());
+        let mut pos = 0;
+        for (start, end, index) in self.placeholders.iter() {
+            query.push_str(&self.query[pos..*start]);
+            query.push_str(&values[*index]);
+            pos = *end;
+        }
+        query.push_str(&self.query[pos..]);
+        query
+    }
+}
+
+fn text_param_to_sql(oid: u32, value: &[u8]) -> Result<String> {
+    let text = std::str::from_utf8(value)
+        .map_err(|e| ErrorCode::BadBytes(format!("Invalid utf8 parameter: {}", e)))?;
+    Ok(match oid {
+        BOOL_OID => match text.to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => "TRUE".to_string(),
+            "f" | "false" | "n" | "no" | "off" | "0" => "FALSE".to_string(),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Invalid boolean parameter '{}'",
+                    text
+                )));
+            }
+        },
+        INT2_OID | INT4_OID | INT8_OID | FLOAT4_OID | FLOAT8_OID | NUMERIC_OID => {
+            // Only the valid numbers are put into the query as they are.
+            match text.parse::<f64>() {
+                Ok(v) if v.is_finite() && text.starts_with('-') => format!("({})", text),
+                Ok(v) if v.is_finite() => text.to_string(),
+                Ok(_) => format!("{}::DOUBLE", Literal::String(text.to_string())),
+                Err(_) => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Invalid numeric parameter '{}'",
+                        text
+                    )));
+                }
+            }
+        }
+        BYTEA_OID => match text.strip_prefix("\\x") {
+            Some(v) => format!("FROM_HEX({})", Literal::String(v.to_string())),
+            None => format!("FROM_HEX('{}')", hex::encode(value)),
+        },
+        _ => Literal::String(text.to_string()).to_string(),
+    })
+}
+
+fn binary_param_to_sql(oid: u32, value: &[u8]) -> Result<String> {
+    let invalid_length = || {
+        ErrorCode::BadBytes(format!(
+            "Invalid length {} of binary parameter with type oid {}",
+            value.len(),
+            oid
+        ))
+    };
+    let number = |v: String| {
+        if v.starts_with('-') {
+            format!("({})", v)
+        } else {
+            v
+        }
+    };
+    Ok(match oid {
+        BOOL_OID => match value {
+            [0] => "FALSE".to_string(),
+            [_] => "TRUE".to_string(),
+            _ => return Err(invalid_length()),
+        },
+        INT2_OID => {
+            let v: [u8; 2] = value.try_into().map_err(|_| invalid_length())?;
+            number(i16::from_be_bytes(v).to_string())
+        }
+        INT4_OID => {
+            let v: [u8; 4] = value.try_into().map_err(|_| invalid_length())?;
+            number(i32::from_be_bytes(v).to_string())
+        }
+        INT8_OID => {
+            let v: [u8; 8] = value.try_into().map_err(|_| invalid_length())?;
+            number(i64::from_be_bytes(v).to_string())
+        }
+        FLOAT4_OID => {
+            let v: [u8; 4] = value.try_into().map_err(|_| invalid_length())?;
+            float_to_sql(f32::from_be_bytes(v) as f64)
+        }
+        FLOAT8_OID => {
+            let v: [u8; 8] = value.try_into().map_err(|_| invalid_length())?;
+            float_to_sql(f64::from_be_bytes(v))
+        }
+        DATE_OID => {
+            let v: [u8; 4] = value.try_into().map_err(|_| invalid_length())?;
+            let date = postgres_epoch().date() + Duration::days(i32::from_be_bytes(v) as i64);
+            Literal::String(date.format("%Y-%m-%d").to_string()).to_string()
+        }
+        TIMESTAMP_OID => {
+            let v: [u8; 8] = value.try_into().map_err(|_| invalid_length())?;
+            let ts = postgres_epoch() + Duration::microseconds(i64::from_be_bytes(v));
+            Literal::String(ts.format("%Y-%m-%d %H:%M:%S%.6f").to_string()).to_string()
+        }
+        TIMESTAMPTZ_OID => {
+            let v: [u8; 8] = value.try_into().map_err(|_| invalid_length())?;
+            let ts = postgres_epoch() + Duration::microseconds(i64::from_be_bytes(v));
+            Literal::String(ts.format("%Y-%m-%d %H:%M:%S%.6f+00:00").to_string()).to_string()
+        }
+        INTERVAL_OID => {
+            let v: [u8; 16] = value.try_into().map_err(|_| invalid_length())?;
+            let micros = i64::from_be_bytes(v[0..8].try_into().unwrap());
+            let days = i32::from_be_bytes(v[8..12].try_into().unwrap());
+            let months = i32::from_be_bytes(v[12..16].try_into().unwrap());
+            let interval = months_days_micros::new(months, days, micros);
+            format!("INTERVAL {}", Literal::String(interval.to_string()))
+        }
+        BYTEA_OID => format!("FROM_HEX('{}')", hex::encode(value)),
+        TEXT_OID | VARCHAR_OID | JSON_OID | UNKNOWN_OID | 0 => text_param_to_sql(TEXT_OID, value)?,
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Binary format of the parameter with type oid {} is not supported",
+                oid
+            )));
+        }
+    })
+}
+
+fn float_to_sql(v: f64) -> String {
+    if !v.is_finite() {
+        format!("{}::DOUBLE", Literal::String(v.to_string()))
+    } else if v < 0.0 {
+        format!("({:?})", v)
+    } else {
+        format!("{:?}", v)
+    }
+}
+
+// The epoch of the binary format of DATE and TIMESTAMP.
+fn postgres_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::Shutdown;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::io::split;
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::base::tokio::io::BufReader;
+use databend_common_base::base::tokio::io::BufWriter;
+use databend_common_base::base::tokio::net::TcpStream;
+use databend_common_base::runtime::Runtime;
+use databend_common_base::runtime::Thread;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::ToErrorCode;
+use log::error;
+use log::info;
+use log::warn;
+use rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+
+use crate::servers::postgres::postgres_codec::negotiate;
+use crate::servers::postgres::postgres_codec::StartupMessage;
+use crate::servers::postgres::postgres_interactive_worker::InteractiveWorker;
+use crate::sessions::Session;
+
+// default size of resultset write buffer: 100KB
+const DEFAULT_RESULT_SET_WRITE_BUFFER_SIZE: usize = 100 * 1024;
+
+pub struct PostgresConnection;
+
+impl PostgresConnection {
+    pub fn run_on_stream(
+        session: Arc<Session>,
+        stream: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+    ) -> Result<()> {
+        let blocking_stream = Self::convert_stream(stream)?;
+        PostgresConnection::attach_session(&session, &blocking_stream)?;
+
+        let non_blocking_stream = TcpStream::from_std(blocking_stream)?;
+        let query_executor =
+            Runtime::with_worker_threads(1, Some("postgres-query-executor".to_string()))?;
+        Thread::spawn(move || {
+            let join_handle = query_executor.spawn(GLOBAL_TASK, async move {
+                let client_addr = match non_blocking_stream.peer_addr() {
+                    Ok(addr) => addr.to_string(),
+                    Err(e) => {
+                        warn!(
+                            "Failed to get postgres conn peer address for {:?}: {}",
+                            non_blocking_stream, e
+                        );
+                        return;
+                    }
+                };
+
+                let interactive_worker = InteractiveWorker::create(session, client_addr);
+                if let Err(error) = Self::serve(interactive_worker, non_blocking_stream, tls).await
+                {
+                    error!("Unexpected error occurred during query: {:?}", error);
+                }
+            });
+            let _ = futures::executor::block_on(join_handle);
+        });
+        Ok(())
+    }
+
+    // The TLS handshake takes place after the server accepts `SSLRequest`, then the client
+    // sends the startup message again over the encrypted stream.
+    #[async_backtrace::framed]
+    async fn serve(
+        interactive_worker: InteractiveWorker,
+        mut stream: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+    ) -> Result<()> {
+        match (negotiate(&mut stream, tls.is_some()).await?, tls) {
+            (StartupMessage::SslRequest, Some(tls)) => {
+                let mut stream = TlsAcceptor::from(tls).accept(stream).await?;
+                let message = negotiate(&mut stream, false).await?;
+                Self::serve_on(interactive_worker, message, stream).await
+            }
+            (message, _) => Self::serve_on(interactive_worker, message, stream).await,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn serve_on<S: AsyncRead + AsyncWrite + Unpin>(
+        interactive_worker: InteractiveWorker,
+        message: StartupMessage,
+        stream: S,
+    ) -> Result<()> {
+        match message {
+            StartupMessage::Startup { params } => {
+                let (r, w) = split(stream);
+                let r = BufReader::new(r);
+                let w = BufWriter::with_capacity(DEFAULT_RESULT_SET_WRITE_BUFFER_SIZE, w);
+                interactive_worker.run(params, r, w).await
+            }
+            StartupMessage::CancelRequest => {
+                info!("PostgreSQL cancel request is not supported, ignored");
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn attach_session(session: &Arc<Session>, blocking_stream: &std::net::TcpStream) -> Result<()> {
+        let host = blocking_stream.peer_addr().ok();
+        let blocking_stream_ref = blocking_stream.try_clone()?;
+        session.attach(host, move || {
+            if let Err(error) = blocking_stream_ref.shutdown(Shutdown::Both) {
+                error!("Cannot shutdown PostgreSQL session io {}", error);
+            }
+        });
+
+        Ok(())
+    }
+
+    fn convert_stream(stream: TcpStream) -> Result<std::net::TcpStream> {
+        let stream = stream.into_std().map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+        stream.set_nonblocking(false).map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+
+        Ok(stream)
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono_tz::Tz;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::date_helper::DateConverter;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Column;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::ScalarRef;
+use databend_common_formats::field_encoder::FieldEncoderValues;
+
+use crate::servers::postgres::postgres_codec::FieldDescription;
+use crate::servers::postgres::postgres_codec::FORMAT_BINARY;
+use crate::servers::postgres::postgres_codec::FORMAT_TEXT;
+
+// The oids of the builtin types, see `pg_type.dat` of PostgreSQL.
+pub const BOOL_OID: u32 = 16;
+pub const BYTEA_OID: u32 = 17;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const JSON_OID: u32 = 114;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const UNKNOWN_OID: u32 = 705;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const TIMESTAMPTZ_OID: u32 = 1184;
+pub const INTERVAL_OID: u32 = 1186;
+pub const NUMERIC_OID: u32 = 1700;
+
+// The days and microseconds from 1970-01-01 to 2000-01-01, the epoch of the binary format.
+const POSTGRES_EPOCH_DAYS: i32 = 10957;
+const POSTGRES_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+/// The PostgreSQL type of a column. Nested types are sent in the text format of Databend.
+pub fn data_type_to_oid(data_type: &DataType) -> u32 {
+    match data_type.remove_nullable() {
+        DataType::Boolean => BOOL_OID,
+        DataType::Binary => BYTEA_OID,
+        DataType::String => VARCHAR_OID,
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => INT2_OID,
+            NumberDataType::UInt16 | NumberDataType::Int32 => INT4_OID,
+            NumberDataType::UInt32 | NumberDataType::Int64 => INT8_OID,
+            // UInt64 may overflow BIGINT.
+            NumberDataType::UInt64 => NUMERIC_OID,
+            NumberDataType::Float32 => FLOAT4_OID,
+            NumberDataType::Float64 => FLOAT8_OID,
+        },
+        DataType::Decimal(_) => NUMERIC_OID,
+        DataType::Date => DATE_OID,
+        DataType::Timestamp => TIMESTAMP_OID,
+        DataType::TimestampTz => TIMESTAMPTZ_OID,
+        DataType::Interval => INTERVAL_OID,
+        DataType::Variant => JSON_OID,
+        DataType::Null
+        | DataType::EmptyArray
+        | DataType::EmptyMap
+        | DataType::Array(_)
+        | DataType::Map(_)
+        | DataType::Bitmap
+        | DataType::Geometry
+        | DataType::Tuple(_) => TEXT_OID,
+        DataType::Nullable(_) | DataType::Generic(_) => unreachable!(),
+    }
+}
+
+fn type_size(oid: u32) -> i16 {
+    match oid {
+        BOOL_OID => 1,
+        INT2_OID => 2,
+        INT4_OID | FLOAT4_OID | DATE_OID => 4,
+        INT8_OID | FLOAT8_OID | TIMESTAMP_OID | TIMESTAMPTZ_OID => 8,
+        INTERVAL_OID => 16,
+        _ => -1,
+    }
+}
+
+/// The format of the i-th column or parameter, the only format applies to all of them,
+/// and no format means all of them are in text.
+pub fn format_of(formats: &[i16], index: usize) -> i16 {
+    match formats {
+        [] => FORMAT_TEXT,
+        [format] => *format,
+        _ => formats.get(index).copied().unwrap_or(FORMAT_TEXT),
+    }
+}
+
+pub fn convert_schema(schema: &DataSchemaRef, formats: &[i16]) -> Vec<FieldDescription> {
+    schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let type_oid = data_type_to_oid(field.data_type());
+            FieldDescription {
+                name: field.name().to_string(),
+                type_oid,
+                type_size: type_size(type_oid),
+                format: format_of(formats, index),
+            }
+        })
+        .collect()
+}
+
+/// Encode the values of a result set in the format requested by the client.
+pub struct ValueEncoder {
+    timezone: Tz,
+    text_encoder: FieldEncoderValues,
+}
+
+impl ValueEncoder {
+    pub fn create(timezone: Tz) -> Self {
+        ValueEncoder {
+            timezone,
+            text_encoder: FieldEncoderValues::create_for_postgres_handler(timezone),
+        }
+    }
+
+    /// Write the value of the column at `row_index`, which must not be NULL.
+    pub fn write_value(
+        &self,
+        column: &Column,
+        row_index: usize,
+        format: i16,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        let value = unsafe { column.index_unchecked(row_index) };
+        match (value, format) {
+            (ScalarRef::Binary(v), FORMAT_TEXT) => {
+                buf.extend_from_slice(b"\\x");
+                buf.extend_from_slice(hex::encode(v).as_bytes());
+            }
+            (_, FORMAT_TEXT) => self.text_encoder.write_field(column, row_index, buf, false),
+            (value, FORMAT_BINARY) => self.write_binary(column, row_index, value, buf)?,
+            (_, format) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Unknown format code {}",
+                    format
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn write_binary(
+        &self,
+        column: &Column,
+        row_index: usize,
+        value: ScalarRef,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        match value {
+            ScalarRef::Boolean(v) => buf.push(v as u8),
+            ScalarRef::Binary(v) => buf.extend_from_slice(v),
+            ScalarRef::String(v) => buf.extend_from_slice(v.as_bytes()),
+            ScalarRef::Number(number) => match number {
+                NumberScalar::Int8(v) => buf.extend_from_slice(&(v as i16).to_be_bytes()),
+                NumberScalar::UInt8(v) => buf.extend_from_slice(&(v as i16).to_be_bytes()),
+                NumberScalar::Int16(v) => buf.extend_from_slice(&v.to_be_bytes()),
+                NumberScalar::UInt16(v) => buf.extend_from_slice(&(v as i32).to_be_bytes()),
+                NumberScalar::Int32(v) => buf.extend_from_slice(&v.to_be_bytes()),
+                NumberScalar::UInt32(v) => buf.extend_from_slice(&(v as i64).to_be_bytes()),
+                NumberScalar::Int64(v) => buf.extend_from_slice(&v.to_be_bytes()),
+                NumberScalar::Float32(v) => buf.extend_from_slice(&v.0.to_be_bytes()),
+                NumberScalar::Float64(v) => buf.extend_from_slice(&v.0.to_be_bytes()),
+                NumberScalar::UInt64(_) => return Err(binary_unsupported("NUMERIC")),
+            },
+            ScalarRef::Decimal(_) => return Err(binary_unsupported("NUMERIC")),
+            ScalarRef::Date(v) => {
+                buf.extend_from_slice(&(v - POSTGRES_EPOCH_DAYS).to_be_bytes());
+            }
+            ScalarRef::Timestamp(v) => {
+                let local = v.to_timestamp(self.timezone).naive_local();
+                let micros = local.timestamp_micros() - POSTGRES_EPOCH_MICROS;
+                buf.extend_from_slice(&micros.to_be_bytes());
+            }
+            ScalarRef::TimestampTz(v) => {
+                let micros = v.timestamp() - POSTGRES_EPOCH_MICROS;
+                buf.extend_from_slice(&micros.to_be_bytes());
+            }
+            ScalarRef::Interval(v) => {
+                buf.extend_from_slice(&v.microseconds().to_be_bytes());
+                buf.extend_from_slice(&v.days().to_be_bytes());
+                buf.extend_from_slice(&v.months().to_be_bytes());
+            }
+            // The binary format of TEXT and JSON is the same as the text format.
+            _ => self.text_encoder.write_field(column, row_index, buf, false),
+        }
+        Ok(())
+    }
+}
+
+fn binary_unsupported(type_name: &str) -> ErrorCode {
+    ErrorCode::Unimplemented(format!(
+        "Binary format of {} is not supported, please use the text format",
+        type_name
+    ))
+}
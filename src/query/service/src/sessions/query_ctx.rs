@@ -48,6 +48,7 @@ use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::txn::TxnManagerRef;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -738,6 +739,15 @@ impl TableContext for QueryContext {
         table: &str,
     ) -> Result<Arc<dyn Table>> {
         let table = self.shared.get_table(catalog, database, table).await?;
+        // The table may be mutated by the previous statements of the explicit transaction.
+        let buffered_table_info = self.txn_mgr().lock().get_table_from_buffer(table.get_id());
+        let table = match buffered_table_info {
+            Some(table_info) => {
+                let catalog = self.get_catalog(catalog).await?;
+                catalog.get_table_by_info(&table_info)?
+            }
+            None => table,
+        };
         // the better place to do this is in the QueryContextShared::get_table_to_cache() method,
         // but there is no way to access dyn TableContext.
         let table: Arc<dyn Table> = if table.engine() == "ICEBERG" {
@@ -984,6 +994,10 @@ impl TableContext for QueryContext {
         }
         false
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
 }
 
 impl TrySpawn for QueryContext {
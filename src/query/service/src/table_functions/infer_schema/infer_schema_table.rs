@@ -42,6 +42,8 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::input_formats::read_arrow_schema_async;
+use databend_common_pipeline_sources::input_formats::read_avro_schema_async;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::binder::resolve_file_location;
@@ -49,6 +51,7 @@ use databend_common_storage::init_stage_operator;
 use databend_common_storage::read_parquet_schema_async;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_orc::read_orc_schema_async;
 use opendal::Scheme;
 
 use crate::pipelines::processors::OutputPort;
@@ -255,9 +258,16 @@ impl AsyncSource for InferSchemaSource {
                     TableSchema::try_from(&arrow_schema)?
                 }
             }
+            StageFileFormatType::Avro => {
+                read_avro_schema_async(&operator, &first_file.path).await?
+            }
+            StageFileFormatType::Orc => read_orc_schema_async(&operator, &first_file.path).await?,
+            StageFileFormatType::Arrow => {
+                read_arrow_schema_async(&operator, &first_file.path).await?
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, ORC, Avro and Arrow",
                 ));
             }
         };
@@ -19,8 +19,10 @@ use databend_common_catalog::table_args::TableArgs;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_types::MetaId;
+use databend_common_storages_delta::DeltaHistoryTable;
 use databend_common_storages_fuse::table_functions::FuseColumnTable;
 use databend_common_storages_fuse::table_functions::FuseEncodingTable;
+use databend_common_storages_iceberg::IcebergSnapshotTable;
 use databend_common_storages_stream::stream_status_table_func::StreamStatusTable;
 use itertools::Itertools;
 use parking_lot::RwLock;
@@ -134,6 +136,15 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(FuseStatisticTable::create)),
         );
 
+        creators.insert(
+            "iceberg_snapshot".to_string(),
+            (next_id(), Arc::new(IcebergSnapshotTable::create)),
+        );
+        creators.insert(
+            "delta_history".to_string(),
+            (next_id(), Arc::new(DeltaHistoryTable::create)),
+        );
+
         creators.insert(
             "clustering_information".to_string(),
             (next_id(), Arc::new(ClusteringInformationTable::create)),
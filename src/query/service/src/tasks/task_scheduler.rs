@@ -0,0 +1,368 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::TaskRunState;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::error;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+// How often the scheduler looks for due tasks.
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The built-in task scheduler, which runs the tasks persisted in the meta service
+/// when cloud control is not configured.
+///
+/// Every query node runs the scheduler. A due task is claimed by advancing its
+/// `next_scheduled_at` with a CAS on the seq of the task, so each scheduled run
+/// is executed by only one node.
+pub struct TaskScheduler {
+    tenant: String,
+    // The root tasks whose graph is running on this node.
+    running: Mutex<HashSet<u64>>,
+}
+
+impl TaskScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(TaskScheduler {
+            tenant: conf.query.tenant_id.clone(),
+            running: Mutex::new(HashSet::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TaskScheduler> {
+        GlobalInstance::get()
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            let mut interval = tokio::time::interval(TICK_INTERVAL);
+            loop {
+                interval.tick().await;
+                if let Err(cause) = scheduler.schedule_due_tasks().await {
+                    warn!("Failed to schedule tasks: {:?}", cause);
+                }
+            }
+        });
+    }
+
+    /// Runs the task and its dependents once in background, regardless of its schedule.
+    #[async_backtrace::framed]
+    pub async fn execute_task(self: &Arc<Self>, name: &str) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        let task = user_api.get_task(&self.tenant, name).await?.data;
+        if !self.spawn_task_graph(task, Utc::now()) {
+            info!("Skip executing task '{}', it is still running", name);
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn schedule_due_tasks(self: &Arc<Self>) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        let now = Utc::now();
+        for seq_task in user_api.get_tasks(&self.tenant).await? {
+            let task = seq_task.data;
+            // Tasks with predecessors are triggered by their predecessors.
+            if task.status != TaskStatus::Started || !task.after.is_empty() {
+                continue;
+            }
+            let Some(schedule_options) = &task.schedule_options else {
+                continue;
+            };
+            // The next run is computed when the task is resumed or its schedule is set,
+            // None means the schedule has no more runs, e.g. a cron of the past years.
+            let scheduled_at = match task.next_scheduled_at {
+                Some(scheduled_at) if scheduled_at <= now => scheduled_at,
+                _ => continue,
+            };
+
+            // Runs missed while no node is alive are not caught up,
+            // the next run is always computed from now.
+            let mut claimed = task.clone();
+            claimed.next_scheduled_at = schedule_options.next_scheduled_at(now);
+            let seq = MatchSeq::Exact(seq_task.seq);
+            match user_api.update_task(&self.tenant, claimed, seq).await {
+                Ok(_) => {}
+                // Claimed by another node, or altered in the meantime.
+                Err(cause) if cause.code() == ErrorCode::UNKNOWN_TASK => continue,
+                Err(cause) => return Err(cause),
+            }
+
+            let name = task.task_name.clone();
+            if !self.spawn_task_graph(task, scheduled_at) {
+                info!(
+                    "Skip task '{}' scheduled at {}, the previous run is still running",
+                    name, scheduled_at
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // Returns false if the graph of the root task is already running on this node.
+    fn spawn_task_graph(self: &Arc<Self>, root: Task, scheduled_at: DateTime<Utc>) -> bool {
+        let root_task_id = root.task_id;
+        if !self.running.lock().insert(root_task_id) {
+            return false;
+        }
+
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            if let Err(cause) = scheduler.run_task_graph(root, scheduled_at).await {
+                warn!("Failed to run task graph {}: {:?}", root_task_id, cause);
+            }
+            scheduler.running.lock().remove(&root_task_id);
+        });
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn run_task_graph(&self, root: Task, scheduled_at: DateTime<Utc>) -> Result<()> {
+        let root_task_id = root.task_id;
+        let mut succeeded = HashSet::new();
+        let mut pending = VecDeque::from([root]);
+        while let Some(task) = pending.pop_front() {
+            if succeeded.contains(&task.task_name) {
+                continue;
+            }
+            match self.run_task(&task, root_task_id, scheduled_at).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(cause) => {
+                    warn!("Failed to run task '{}': {:?}", task.task_name, cause);
+                    continue;
+                }
+            }
+            succeeded.insert(task.task_name.clone());
+
+            // A dependent runs once all of its predecessors succeeded in this graph run.
+            let tasks = UserApiProvider::instance().get_tasks(&self.tenant).await?;
+            for dependent in tasks.into_iter().map(|v| v.data) {
+                if dependent.status == TaskStatus::Started
+                    && dependent.after.contains(&task.task_name)
+                    && dependent.after.iter().all(|v| succeeded.contains(v))
+                {
+                    pending.push_back(dependent);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Returns true if the task is executed successfully, false if it is skipped by
+    // its WHEN condition or failed.
+    #[async_backtrace::framed]
+    async fn run_task(
+        &self,
+        task: &Task,
+        root_task_id: u64,
+        scheduled_at: DateTime<Utc>,
+    ) -> Result<bool> {
+        let session = Self::create_session(task).await?;
+        if let Some(condition) = &task.when_condition {
+            let ctx = session.create_query_context().await?;
+            let blocks = execute_sql(ctx, &format!("SELECT {}", condition)).await?;
+            if !is_condition_satisfied(&blocks) {
+                info!(
+                    "Skip task '{}', the condition '{}' is not satisfied",
+                    task.task_name, condition
+                );
+                return Ok(false);
+            }
+        }
+
+        let ctx = session.create_query_context().await?;
+        let user_api = UserApiProvider::instance();
+        let mut run = TaskRun {
+            task: task.clone(),
+            run_id: uuid::Uuid::new_v4().to_string(),
+            query_id: ctx.get_id(),
+            attempt_number: 1,
+            state: TaskRunState::Executing,
+            scheduled_at,
+            completed_at: None,
+            error_code: 0,
+            error_message: None,
+            root_task_id,
+        };
+        user_api.upsert_task_run(&self.tenant, run.clone()).await?;
+
+        let res = execute_sql(ctx, &task.query_text).await;
+        run.completed_at = Some(Utc::now());
+        match &res {
+            Ok(_) => run.state = TaskRunState::Succeeded,
+            Err(cause) => {
+                run.state = TaskRunState::Failed;
+                run.error_code = cause.code() as i64;
+                run.error_message = Some(cause.message());
+            }
+        }
+        user_api.upsert_task_run(&self.tenant, run.clone()).await?;
+
+        if res.is_err() {
+            self.on_task_failed(task, &run).await?;
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_task_failed(&self, task: &Task, run: &TaskRun) -> Result<()> {
+        if let Some(error_integration) = &task.error_integration {
+            // The failure of the notification doesn't affect the task.
+            if let Err(cause) = notify_task_failed(error_integration, run).await {
+                error!(
+                    "Failed to notify the failure of task '{}' to integration '{}': {:?}",
+                    task.task_name, error_integration, cause
+                );
+            }
+        }
+
+        let Some(max_failures) = task.suspend_task_after_num_failures.filter(|v| *v > 0) else {
+            return Ok(());
+        };
+        let user_api = UserApiProvider::instance();
+        let mut runs = user_api
+            .get_task_runs(&self.tenant, Some(&task.task_name))
+            .await?;
+        runs.sort_by(|a, b| b.scheduled_at.cmp(&a.scheduled_at));
+        let failures = runs
+            .iter()
+            .take_while(|v| v.state == TaskRunState::Failed)
+            .count() as u64;
+        if failures < max_failures {
+            return Ok(());
+        }
+
+        let seq_task = user_api.get_task(&self.tenant, &task.task_name).await?;
+        let now = Utc::now();
+        let mut suspended = seq_task.data;
+        suspended.status = TaskStatus::Suspended;
+        suspended.next_scheduled_at = None;
+        suspended.last_suspended_at = Some(now);
+        suspended.updated_at = now;
+        user_api
+            .update_task(&self.tenant, suspended, MatchSeq::Exact(seq_task.seq))
+            .await?;
+        warn!(
+            "Task '{}' is suspended after {} consecutive failures",
+            task.task_name, failures
+        );
+        Ok(())
+    }
+
+    // The task is executed with the privileges of its owner role.
+    async fn create_session(task: &Task) -> Result<Arc<Session>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Local)
+            .await?;
+        let user = UserInfo::new_no_auth(&format!("task-{}", task.task_name), "%");
+        let owner = Some(task.owner.clone()).filter(|v| !v.is_empty());
+        session.set_authed_user(user, owner).await?;
+        Ok(session)
+    }
+}
+
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<Vec<DataBlock>> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, extras) = planner.plan_sql(sql).await?;
+    ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+/// Posts the failed run to the webhook of the notification integration as JSON.
+async fn notify_task_failed(error_integration: &str, run: &TaskRun) -> Result<()> {
+    let config = GlobalConfig::instance();
+    let webhook_url = config
+        .query
+        .notification_integrations
+        .get(error_integration)
+        .ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Unknown notification integration '{}'",
+                error_integration
+            ))
+        })?;
+    let message = serde_json::json!({
+        "task_name": run.task.task_name,
+        "run_id": run.run_id,
+        "query_id": run.query_id,
+        "state": run.state.to_string(),
+        "scheduled_at": run.scheduled_at.to_rfc3339(),
+        "completed_at": run.completed_at.map(|t| t.to_rfc3339()),
+        "error_code": run.error_code,
+        "error_message": run.error_message,
+    });
+    reqwest::Client::new()
+        .post(webhook_url)
+        .timeout(NOTIFICATION_TIMEOUT)
+        .json(&message)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| ErrorCode::Internal(format!("Failed to send the notification: {}", e)))?;
+    Ok(())
+}
+
+fn is_condition_satisfied(blocks: &[DataBlock]) -> bool {
+    blocks
+        .iter()
+        .find(|block| block.num_rows() > 0 && block.num_columns() > 0)
+        .map(|block| {
+            matches!(
+                block.get_by_offset(0).value.index(0),
+                Some(ScalarRef::Boolean(true))
+            )
+        })
+        .unwrap_or(false)
+}
@@ -95,6 +95,14 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn add_notification_integration(mut self, name: &str, webhook_url: &str) -> ConfigBuilder {
+        self.conf
+            .query
+            .notification_integrations
+            .insert(name.to_string(), webhook_url.to_string());
+        self
+    }
+
     pub fn http_handler_result_timeout(mut self, value: impl Into<u64>) -> ConfigBuilder {
         self.conf.query.http_handler_result_timeout_secs = value.into();
         self
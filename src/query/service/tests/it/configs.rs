@@ -37,6 +37,9 @@ fn test_env_config_s3() -> Result<()> {
             ("QUERY_CLUSTER_ID", Some("cluster-1")),
             ("QUERY_MYSQL_HANDLER_HOST", Some("127.0.0.1")),
             ("QUERY_MYSQL_HANDLER_PORT", Some("3306")),
+            ("QUERY_POSTGRES_HANDLER_ENABLED", Some("true")),
+            ("QUERY_POSTGRES_HANDLER_HOST", Some("1.2.3.4")),
+            ("QUERY_POSTGRES_HANDLER_PORT", Some("5432")),
             ("QUERY_MAX_ACTIVE_SESSIONS", Some("255")),
             ("QUERY_CLICKHOUSE_HANDLER_HOST", Some("1.2.3.4")),
             ("QUERY_CLICKHOUSE_HANDLER_PORT", Some("9000")),
@@ -102,6 +105,9 @@ fn test_env_config_s3() -> Result<()> {
             assert_eq!("cluster-1", configured.query.cluster_id);
             assert_eq!("127.0.0.1", configured.query.mysql_handler_host);
             assert_eq!(3306, configured.query.mysql_handler_port);
+            assert!(configured.query.postgres_handler_enabled);
+            assert_eq!("1.2.3.4", configured.query.postgres_handler_host);
+            assert_eq!(5432, configured.query.postgres_handler_port);
             assert_eq!(255, configured.query.max_active_sessions);
             assert_eq!("1.2.3.4", configured.query.clickhouse_http_handler_host);
             assert_eq!(8124, configured.query.clickhouse_http_handler_port);
@@ -38,4 +38,5 @@ mod spillers;
 mod sql;
 mod storages;
 mod table_functions;
+mod tasks;
 mod tests;
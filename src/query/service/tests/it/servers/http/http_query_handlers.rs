@@ -14,9 +14,11 @@
 
 use std::collections::BTreeMap;
 use std::fs::File;
+use std::io::Cursor;
 use std::io::Read;
 use std::time::Duration;
 
+use arrow_ipc::reader::StreamReader;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use databend_common_base::base::get_free_tcp_port;
@@ -599,6 +601,73 @@ async fn test_pagination() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_arrow_data_format() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select number, number::string from numbers(10)";
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 1, "max_rows_per_page": 4}, "data_format": "arrow"});
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .typed_header(basic)
+        .body(serde_json::to_vec(&json)?);
+    let mut response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+
+    let mut total_rows = 0;
+    loop {
+        assert_eq!(response.status(), StatusCode::OK);
+        let content_type = response.content_type().unwrap_or_default().to_string();
+        if content_type != "application/vnd.apache.arrow.stream" {
+            // responses without a page of rows (state and final) are always JSON
+            let (_, result) = check_response(response).await?;
+            assert!(result.error.is_none(), "{:?}", result);
+            match result.next_uri {
+                Some(next_uri) => {
+                    response = get_uri(&ep, &next_uri).await;
+                    continue;
+                }
+                None => {
+                    assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+                    break;
+                }
+            }
+        }
+        let page_rows: usize = response.headers()["X-DATABEND-QUERY-PAGE-ROWS"]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let body = response.into_body().into_vec().await.unwrap();
+        let reader = StreamReader::try_new(Cursor::new(body), None)?;
+        let schema = reader.schema();
+        assert_eq!(schema.fields().len(), 2);
+        let result: QueryResponse = serde_json::from_str(&schema.metadata()["response_header"])?;
+        assert!(result.error.is_none(), "{:?}", result);
+        assert!(result.data.is_empty(), "{:?}", result);
+        assert_eq!(result.schema.len(), 2, "{:?}", result);
+
+        let mut rows = 0;
+        for batch in reader {
+            rows += batch?.num_rows();
+        }
+        assert_eq!(rows, page_rows);
+        assert!(rows <= 4);
+        total_rows += rows;
+
+        response = get_uri(&ep, &result.next_uri.unwrap()).await;
+    }
+    assert_eq!(total_rows, 10);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_http_session() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -637,6 +706,118 @@ async fn test_http_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_txn() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let pagination = serde_json::json!({"wait_time_secs": 3});
+
+    // The transaction would be lost with the session dropped after the query.
+    let json = serde_json::json!({"sql": "begin", "pagination": pagination});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(
+        result.error.as_ref().map(|e| e.code),
+        Some(ErrorCode::BAD_ARGUMENTS),
+        "{:?}",
+        result
+    );
+
+    let json = serde_json::json!({"sql": "create table t_txn(a int) engine=fuse", "pagination": pagination});
+    let (_, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let json = serde_json::json!({"sql": "begin", "pagination": pagination, "session": {"keep_server_session_secs": 10}});
+    let (_, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+    let session_id = result.session_id.unwrap();
+
+    let query = |sql: &str, in_session: bool| {
+        if in_session {
+            serde_json::json!({"sql": sql, "pagination": pagination, "session_id": session_id})
+        } else {
+            serde_json::json!({"sql": sql, "pagination": pagination})
+        }
+    };
+
+    let (_, result) = post_json_to_endpoint(
+        &ep,
+        &query("insert into t_txn values (1), (2)", true),
+        HeaderMap::default(),
+    )
+    .await?;
+    assert!(result.error.is_none(), "{:?}", result);
+
+    // The changes are only visible in the transaction before committed.
+    let (_, result) = post_json_to_endpoint(
+        &ep,
+        &query("select count(*) from t_txn", true),
+        HeaderMap::default(),
+    )
+    .await?;
+    assert_eq!(result.data[0][0], "2", "{:?}", result);
+    let (_, result) = post_json_to_endpoint(
+        &ep,
+        &query("select count(*) from t_txn", false),
+        HeaderMap::default(),
+    )
+    .await?;
+    assert_eq!(result.data[0][0], "0", "{:?}", result);
+
+    let (_, result) =
+        post_json_to_endpoint(&ep, &query("commit", true), HeaderMap::default()).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+    let (_, result) = post_json_to_endpoint(
+        &ep,
+        &query("select count(*) from t_txn", false),
+        HeaderMap::default(),
+    )
+    .await?;
+    assert_eq!(result.data[0][0], "2", "{:?}", result);
+
+    // A statement failing to plan fails the transaction, only ROLLBACK or COMMIT can end it.
+    for sql in [
+        "begin",
+        "insert into t_txn values (3)",
+        "select * from t_txn_not_exists",
+    ] {
+        let (_, result) =
+            post_json_to_endpoint(&ep, &query(sql, true), HeaderMap::default()).await?;
+        assert_eq!(
+            result.error.is_some(),
+            sql.contains("not_exists"),
+            "{:?}",
+            result
+        );
+    }
+    let (_, result) = post_json_to_endpoint(
+        &ep,
+        &query("select count(*) from t_txn", true),
+        HeaderMap::default(),
+    )
+    .await?;
+    assert_eq!(
+        result.error.as_ref().map(|e| e.code),
+        Some(ErrorCode::CURRENT_TRANSACTION_IS_ABORTED),
+        "{:?}",
+        result
+    );
+
+    let (_, result) =
+        post_json_to_endpoint(&ep, &query("rollback", true), HeaderMap::default()).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+    let (_, result) = post_json_to_endpoint(
+        &ep,
+        &query("select count(*) from t_txn", true),
+        HeaderMap::default(),
+    )
+    .await?;
+    assert_eq!(result.data[0][0], "2", "{:?}", result);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_result_timeout() -> Result<()> {
     let config = ConfigBuilder::create().build();
@@ -54,6 +54,72 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    let statement = connection
+        .prep("SELECT number, to_string(number) FROM numbers(10) WHERE number > ? AND number < ? ORDER BY number")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(statement.num_params(), 2);
+    assert_eq!(statement.num_columns(), 2);
+
+    let result: Vec<(u64, String)> = connection
+        .exec(&statement, (6, 9))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(result, vec![(7, "7".to_string()), (8, "8".to_string())]);
+
+    let result: Vec<(u64, String)> = connection
+        .exec(&statement, (0, 2))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(result, vec![(1, "1".to_string())]);
+
+    // The placeholders in strings are not parameters.
+    let result: Option<(String, f64)> = connection
+        .exec_first("SELECT concat('?', ?), ?", ("a", -1.5))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(result, Some(("?a".to_string(), -1.5)));
+
+    connection
+        .close(statement)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close failed")?;
+
+    // The parameters of `INSERT ... VALUES` are bound into the raw values.
+    connection
+        .query_drop("CREATE TABLE t_prepared(a INT, b STRING)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create table failed")?;
+    let statement = connection
+        .prep("INSERT INTO t_prepared VALUES (?, ?)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    for params in [(1, "x"), (-2, "it's")] {
+        connection
+            .exec_drop(&statement, params)
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    }
+    let result: Vec<(i32, String)> = connection
+        .query("SELECT a, b FROM t_prepared ORDER BY a")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(result, vec![(-2, "it's".to_string()), (1, "x".to_string())]);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_connect_with_tls() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -0,0 +1,287 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::net::TcpStream;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::servers::MySQLTlsConfig;
+use databend_query::servers::PostgresHandler;
+use databend_query::test_kits::TestFixture;
+use rustls::Certificate;
+use rustls::ClientConfig;
+use rustls::RootCertStore;
+use rustls::ServerName;
+use tokio_rustls::TlsConnector;
+
+use crate::tests::tls_constants::TEST_CA_CERT;
+use crate::tests::tls_constants::TEST_CN_NAME;
+use crate::tests::tls_constants::TEST_SERVER_CERT;
+use crate::tests::tls_constants::TEST_SERVER_KEY;
+
+const SSL_REQUEST: [u8; 8] = [0, 0, 0, 8, 4, 210, 22, 47];
+
+// The messages are written and read with the raw protocol, as there is no PostgreSQL
+// client in the dependencies.
+struct Client<S> {
+    stream: S,
+}
+
+impl Client<TcpStream> {
+    async fn connect(port: u16, database: &str) -> Result<Self> {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+
+        // SSLRequest is rejected without TLS config, then the startup goes on without TLS.
+        stream.write_all(&SSL_REQUEST).await?;
+        assert_eq!(stream.read_u8().await?, b'N');
+
+        Client::startup(stream, database).await
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Client<S> {
+    async fn startup(mut stream: S, database: &str) -> Result<Self> {
+        let mut body = 196608i32.to_be_bytes().to_vec();
+        for value in ["user", "root", "database", database, ""] {
+            body.extend_from_slice(value.as_bytes());
+            body.push(0);
+        }
+        let mut message = ((body.len() + 4) as i32).to_be_bytes().to_vec();
+        message.extend_from_slice(&body);
+        stream.write_all(&message).await?;
+
+        let mut client = Client { stream };
+        let messages = client.read_until_ready().await?;
+        assert_eq!(messages[0], (b'R', 0i32.to_be_bytes().to_vec()));
+        assert!(messages.iter().any(|(tag, _)| *tag == b'S'));
+        assert!(messages.iter().any(|(tag, _)| *tag == b'K'));
+        Ok(client)
+    }
+
+    async fn send(&mut self, tag: u8, body: &[u8]) -> Result<()> {
+        let mut message = vec![tag];
+        message.extend_from_slice(&((body.len() + 4) as i32).to_be_bytes());
+        message.extend_from_slice(body);
+        self.stream.write_all(&message).await?;
+        Ok(())
+    }
+
+    async fn read_until_ready(&mut self) -> Result<Vec<(u8, Vec<u8>)>> {
+        let mut messages = vec![];
+        loop {
+            let tag = self.stream.read_u8().await?;
+            let len = self.stream.read_i32().await? as usize;
+            let mut body = vec![0; len - 4];
+            self.stream.read_exact(&mut body).await?;
+            messages.push((tag, body));
+            if tag == b'Z' {
+                return Ok(messages);
+            }
+        }
+    }
+}
+
+fn cstr(value: &str) -> Vec<u8> {
+    let mut buf = value.as_bytes().to_vec();
+    buf.push(0);
+    buf
+}
+
+// The text values of the fields of a `DataRow`.
+fn data_row(body: &[u8]) -> Vec<Option<String>> {
+    let num_fields = i16::from_be_bytes([body[0], body[1]]);
+    let mut pos = 2;
+    (0..num_fields)
+        .map(|_| {
+            let len = i32::from_be_bytes(body[pos..pos + 4].try_into().unwrap());
+            pos += 4;
+            if len < 0 {
+                return None;
+            }
+            let value = String::from_utf8(body[pos..pos + len as usize].to_vec()).unwrap();
+            pos += len as usize;
+            Some(value)
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120, MySQLTlsConfig::default())?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut client = Client::connect(runnable_server.port(), "default").await?;
+
+    client
+        .send(b'Q', &cstr("SELECT 1, 'a', NULL, true;"))
+        .await?;
+    let messages = client.read_until_ready().await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, vec![b'T', b'D', b'C', b'Z']);
+    assert_eq!(data_row(&messages[1].1), vec![
+        Some("1".to_string()),
+        Some("a".to_string()),
+        None,
+        Some("true".to_string()),
+    ]);
+    assert_eq!(messages[2].1, cstr("SELECT 1"));
+    assert_eq!(messages[3].1, vec![b'I']);
+
+    // The error of a query doesn't break the connection.
+    client.send(b'Q', &cstr("SELECT * FROM t1")).await?;
+    let messages = client.read_until_ready().await?;
+    assert_eq!(messages[0].0, b'E');
+
+    client.send(b'Q', &cstr(" ; ")).await?;
+    let messages = client.read_until_ready().await?;
+    assert_eq!(messages[0].0, b'I');
+
+    client.send(b'Q', &cstr("BEGIN")).await?;
+    let messages = client.read_until_ready().await?;
+    assert_eq!(messages[0].1, cstr("BEGIN"));
+    assert_eq!(messages[1].1, vec![b'T']);
+
+    client.send(b'Q', &cstr("ROLLBACK")).await?;
+    let messages = client.read_until_ready().await?;
+    assert_eq!(messages[1].1, vec![b'I']);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120, MySQLTlsConfig::default())?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut client = Client::connect(runnable_server.port(), "default").await?;
+
+    // Parse: statement name, query, and the parameter types of BIGINT.
+    let mut parse = cstr("s1");
+    parse.extend(cstr(
+        "SELECT number FROM numbers(10) WHERE number > $1 ORDER BY number LIMIT $2",
+    ));
+    parse.extend_from_slice(&2i16.to_be_bytes());
+    parse.extend_from_slice(&20i32.to_be_bytes());
+    parse.extend_from_slice(&20i32.to_be_bytes());
+    client.send(b'P', &parse).await?;
+
+    // Bind: portal name, statement name, text parameters and text results.
+    let mut bind = cstr("");
+    bind.extend(cstr("s1"));
+    bind.extend_from_slice(&0i16.to_be_bytes());
+    bind.extend_from_slice(&2i16.to_be_bytes());
+    for param in ["6", "2"] {
+        bind.extend_from_slice(&(param.len() as i32).to_be_bytes());
+        bind.extend_from_slice(param.as_bytes());
+    }
+    bind.extend_from_slice(&0i16.to_be_bytes());
+    client.send(b'B', &bind).await?;
+
+    let mut describe = vec![b'P'];
+    describe.extend(cstr(""));
+    client.send(b'D', &describe).await?;
+
+    let mut execute = cstr("");
+    execute.extend_from_slice(&0i32.to_be_bytes());
+    client.send(b'E', &execute).await?;
+    client.send(b'S', &[]).await?;
+
+    let messages = client.read_until_ready().await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, vec![b'1', b'2', b'T', b'D', b'D', b'C', b'Z']);
+    assert_eq!(data_row(&messages[3].1), vec![Some("7".to_string())]);
+    assert_eq!(data_row(&messages[4].1), vec![Some("8".to_string())]);
+    assert_eq!(messages[5].1, cstr("SELECT 2"));
+
+    // The messages after an error are discarded until `Sync`.
+    let mut bind = cstr("");
+    bind.extend(cstr("unknown"));
+    bind.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+    client.send(b'B', &bind).await?;
+    client.send(b'E', &execute).await?;
+    client.send(b'S', &[]).await?;
+
+    let messages = client.read_until_ready().await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, vec![b'E', b'Z']);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_connect_with_tls() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tls_config = MySQLTlsConfig::new(TEST_SERVER_CERT.to_string(), TEST_SERVER_KEY.to_string());
+    let mut handler = PostgresHandler::create(120, tls_config)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+
+    let mut stream = TcpStream::connect(("127.0.0.1", runnable_server.port())).await?;
+    stream.write_all(&SSL_REQUEST).await?;
+    assert_eq!(stream.read_u8().await?, b'S');
+
+    let mut roots = RootCertStore::empty();
+    for cert in rustls_pemfile::certs(&mut BufReader::new(File::open(TEST_CA_CERT)?))? {
+        roots
+            .add(&Certificate(cert))
+            .map_err(|e| ErrorCode::TLSConfigurationFailure(e.to_string()))?;
+    }
+    let config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    let server_name = ServerName::try_from(TEST_CN_NAME)
+        .map_err(|e| ErrorCode::TLSConfigurationFailure(e.to_string()))?;
+    let stream = TlsConnector::from(Arc::new(config))
+        .connect(server_name, stream)
+        .await?;
+
+    let mut client = Client::startup(stream, "default").await?;
+    client.send(b'Q', &cstr("SELECT 1")).await?;
+    let messages = client.read_until_ready().await?;
+    assert_eq!(data_row(&messages[1].1), vec![Some("1".to_string())]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_reject_large_startup_message() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120, MySQLTlsConfig::default())?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+
+    // The connection is closed before the server reads the body.
+    let mut stream = TcpStream::connect(("127.0.0.1", runnable_server.port())).await?;
+    stream.write_all(&(1i32 << 20).to_be_bytes()).await?;
+    let mut buf = vec![];
+    assert_eq!(stream.read_to_end(&mut buf).await?, 0);
+
+    Ok(())
+}
@@ -36,6 +36,7 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::txn::TxnManagerRef;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -732,6 +733,10 @@ impl TableContext for CtxDelegation {
     fn has_bloom_runtime_filters(&self, _id: usize) -> bool {
         todo!()
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
     fn get_data_cache_metrics(&self) -> &DataCacheMetrics {
         todo!()
     }
@@ -0,0 +1,205 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::net::TcpListener;
+use databend_common_base::base::tokio::sync::mpsc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_query::tasks::TaskScheduler;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+// A webhook server which sends the bodies of the received requests to the channel.
+async fn start_webhook() -> Result<(String, mpsc::Receiver<String>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}/notify", listener.local_addr()?);
+    let (tx, rx) = mpsc::channel(16);
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut request = vec![];
+            let mut buf = [0; 4096];
+            let body = loop {
+                let Ok(n) = stream.read(&mut buf).await else {
+                    break None;
+                };
+                if n == 0 {
+                    break None;
+                }
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                let Some((headers, body)) = text.split_once("\r\n\r\n") else {
+                    continue;
+                };
+                let content_length = headers
+                    .lines()
+                    .filter_map(|line| line.split_once(':'))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                    .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                    .unwrap_or_default();
+                if body.len() >= content_length {
+                    break Some(body.to_string());
+                }
+            };
+            let _ = stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await;
+            if let Some(body) = body {
+                let _ = tx.send(body).await;
+            }
+        }
+    });
+    Ok((url, rx))
+}
+
+async fn query_count(fixture: &TestFixture, sql: &str) -> Result<u64> {
+    let blocks = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    match block.get_by_offset(0).value.index(0) {
+        Some(ScalarRef::Number(NumberScalar::UInt64(count))) => Ok(count),
+        other => Err(ErrorCode::Internal(format!("Unexpected count {:?}", other))),
+    }
+}
+
+async fn wait_until(fixture: &TestFixture, sql: &str, expected: u64) -> Result<()> {
+    let start = Instant::now();
+    loop {
+        if query_count(fixture, sql).await? >= expected {
+            return Ok(());
+        }
+        if start.elapsed() > Duration::from_secs(30) {
+            return Err(ErrorCode::Internal(format!(
+                "Timeout waiting for `{}` to reach {}",
+                sql, expected
+            )));
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_task_scheduler() -> Result<()> {
+    let (webhook_url, mut notifications) = start_webhook().await?;
+    let config = ConfigBuilder::create()
+        .add_notification_integration("webhook", &webhook_url)
+        .build();
+    let fixture = TestFixture::setup_with_config(&config).await?;
+    TaskScheduler::instance().start();
+
+    fixture
+        .execute_command("CREATE TABLE default.t_task(a INT)")
+        .await?;
+    fixture
+        .execute_command(
+            "CREATE TASK root_task SCHEDULE = 1 SECOND AS INSERT INTO default.t_task VALUES (1)",
+        )
+        .await?;
+    fixture
+        .execute_command(
+            "CREATE TASK child_task AFTER 'root_task' AS INSERT INTO default.t_task VALUES (2)",
+        )
+        .await?;
+    fixture
+        .execute_command(
+            "CREATE TASK failed_task SCHEDULE = 1 SECOND SUSPEND_TASK_AFTER_NUM_FAILURES = 1 \
+             ERROR_INTEGRATION = 'webhook' AS SELECT * FROM default.t_unknown",
+        )
+        .await?;
+    // The schedule has no more runs.
+    fixture
+        .execute_command(
+            "CREATE TASK past_task SCHEDULE = USING CRON '0 0 0 1 1 ? 2000' \
+             AS INSERT INTO default.t_task VALUES (3)",
+        )
+        .await?;
+
+    // The error integration must be configured.
+    let res = fixture
+        .execute_command(
+            "CREATE TASK unknown_integration_task SCHEDULE = 1 SECOND \
+             ERROR_INTEGRATION = 'unknown' AS SELECT 1",
+        )
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::BAD_ARGUMENTS);
+
+    // The suspended tasks are not scheduled.
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert_eq!(
+        query_count(&fixture, "SELECT count(*) FROM default.t_task").await?,
+        0
+    );
+
+    for task in ["child_task", "root_task", "failed_task", "past_task"] {
+        fixture
+            .execute_command(&format!("ALTER TASK {} RESUME", task))
+            .await?;
+    }
+
+    // The dependent runs after its predecessor.
+    wait_until(
+        &fixture,
+        "SELECT count(*) FROM default.t_task WHERE a = 2",
+        1,
+    )
+    .await?;
+    wait_until(
+        &fixture,
+        "SELECT count(*) FROM system.task_history WHERE name = 'root_task' AND state = 'SUCCEEDED'",
+        2,
+    )
+    .await?;
+
+    // The failure is notified, then the task is suspended.
+    let notification = tokio::time::timeout(Duration::from_secs(30), notifications.recv())
+        .await
+        .map_err(|_| ErrorCode::Internal("Timeout waiting for the notification"))?
+        .unwrap();
+    let notification: serde_json::Value = serde_json::from_str(&notification)?;
+    assert_eq!(notification["task_name"], "failed_task");
+    assert_eq!(notification["state"], "FAILED");
+    wait_until(
+        &fixture,
+        "SELECT count(*) FROM system.tasks WHERE name = 'failed_task' AND state = 'Suspended'",
+        1,
+    )
+    .await?;
+
+    assert_eq!(
+        query_count(
+            &fixture,
+            "SELECT count(*) FROM system.task_history WHERE name = 'past_task'"
+        )
+        .await?,
+        0
+    );
+    assert_eq!(
+        query_count(&fixture, "SELECT count(*) FROM default.t_task WHERE a = 3").await?,
+        0
+    );
+
+    Ok(())
+}
@@ -241,6 +241,10 @@ impl Settings {
         self.try_get_u64("max_inlist_to_or")
     }
 
+    pub fn get_max_cte_recursive_depth(&self) -> Result<u64> {
+        self.try_get_u64("max_cte_recursive_depth")
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         Ok(self.try_get_u64("unquoted_ident_case_sensitive")? != 0)
     }
@@ -441,6 +445,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
     pub fn get_enable_compact_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_compact_after_write")? != 0)
     }
@@ -561,4 +569,8 @@ impl Settings {
             u64::from(val),
         )
     }
+
+    pub fn get_enable_refresh_materialized_view_after_write(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_refresh_materialized_view_after_write")? != 0)
+    }
 }
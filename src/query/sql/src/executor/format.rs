@@ -15,6 +15,7 @@
 use databend_common_ast::ast::FormatTreeNode;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_exception::Result;
+use databend_common_expression::types::F64;
 use databend_common_expression::DataSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_profile::SharedProcessorProfiles;
@@ -44,6 +45,7 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::RangeJoinType;
 use crate::executor::physical_plans::ReclusterSink;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
 use crate::executor::physical_plans::TableScan;
@@ -228,6 +230,7 @@ fn to_format_tree(
             Ok(FormatTreeNode::new("MergeIntoAppendNotMatched".to_string()))
         }
         PhysicalPlan::CteScan(plan) => cte_scan_to_format_tree(plan),
+        PhysicalPlan::RecursiveCteScan(plan) => recursive_cte_scan_to_format_tree(plan, metadata),
         PhysicalPlan::MaterializedCte(plan) => {
             materialized_cte_to_format_tree(plan, metadata, profs)
         }
@@ -336,6 +339,20 @@ fn table_scan_to_format_tree(
         }
     };
     children.push(FormatTreeNode::new(push_downs));
+    // Table sample
+    if let Some(sample) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.sample.as_ref())
+    {
+        let probability = |p: Option<F64>| p.map_or("NONE".to_string(), |p| p.to_string());
+        children.push(FormatTreeNode::new(format!(
+            "sample: [block probability: {}, row probability: {}]",
+            probability(sample.block_probability),
+            probability(sample.row_probability)
+        )));
+    }
     // Aggregating index
     if let Some(agg_index) = agg_index {
         let (_, agg_index_sql, _) = metadata
@@ -393,6 +410,26 @@ fn cte_scan_to_format_tree(plan: &CteScan) -> Result<FormatTreeNode<String>> {
     ))
 }
 
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    metadata: &Metadata,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!("table: {}", plan.table_name)),
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+    ];
+    let items = plan_stats_info_to_format_tree(&plan.stat);
+    children.extend(items);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn constant_table_scan_to_format_tree(
     plan: &ConstantTableScan,
     metadata: &Metadata,
@@ -873,6 +910,7 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Asof => "AsofJoin".to_string(),
         },
         children,
     ))
@@ -972,6 +1010,13 @@ fn union_all_to_format_tree(
         format_output_columns(plan.output_schema()?, metadata, true)
     ))];
 
+    if !plan.cte_scan_names.is_empty() {
+        children.push(FormatTreeNode::new(format!(
+            "recursive cte: [{}]",
+            plan.cte_scan_names.join(", ")
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
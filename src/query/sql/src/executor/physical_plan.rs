@@ -44,6 +44,7 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::ReclusterSource;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
@@ -76,6 +77,7 @@ pub enum PhysicalPlan {
     Exchange(Exchange),
     UnionAll(UnionAll),
     CteScan(CteScan),
+    RecursiveCteScan(RecursiveCteScan),
     MaterializedCte(MaterializedCte),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
@@ -142,6 +144,7 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSource(v) => v.plan_id,
             PhysicalPlan::ExchangeSink(v) => v.plan_id,
             PhysicalPlan::CteScan(v) => v.plan_id,
+            PhysicalPlan::RecursiveCteScan(v) => v.plan_id,
             PhysicalPlan::MaterializedCte(v) => v.plan_id,
             PhysicalPlan::ConstantTableScan(v) => v.plan_id,
             PhysicalPlan::Udf(v) => v.plan_id,
@@ -184,6 +187,7 @@ impl PhysicalPlan {
             PhysicalPlan::RangeJoin(plan) => plan.output_schema(),
             PhysicalPlan::CopyIntoTable(plan) => plan.output_schema(),
             PhysicalPlan::CteScan(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
             PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
             PhysicalPlan::ConstantTableScan(plan) => plan.output_schema(),
             PhysicalPlan::Udf(plan) => plan.output_schema(),
@@ -236,6 +240,7 @@ impl PhysicalPlan {
             PhysicalPlan::MergeIntoSource(_) => "MergeIntoSource".to_string(),
             PhysicalPlan::MergeIntoAppendNotMatched(_) => "MergeIntoAppendNotMatched".to_string(),
             PhysicalPlan::CteScan(_) => "PhysicalCteScan".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "PhysicalRecursiveCteScan".to_string(),
             PhysicalPlan::MaterializedCte(_) => "PhysicalMaterializedCte".to_string(),
             PhysicalPlan::ConstantTableScan(_) => "PhysicalConstantTableScan".to_string(),
             PhysicalPlan::MergeIntoAddRowNumber(_) => "AddRowNumber".to_string(),
@@ -250,6 +255,7 @@ impl PhysicalPlan {
         match self {
             PhysicalPlan::TableScan(_)
             | PhysicalPlan::CteScan(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::ConstantTableScan(_)
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::CompactSource(_)
@@ -341,6 +347,7 @@ impl PhysicalPlan {
             | PhysicalPlan::MergeIntoSource(_)
             | PhysicalPlan::ConstantTableScan(_)
             | PhysicalPlan::CteScan(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::ReclusterSource(_)
             | PhysicalPlan::ReclusterSink(_)
             | PhysicalPlan::UpdateSource(_) => None,
@@ -380,6 +387,7 @@ impl PhysicalPlan {
             PhysicalPlan::RangeJoin(_)|
             PhysicalPlan::ConstantTableScan(_)
             |PhysicalPlan::CteScan(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::Udf(_)
             | PhysicalPlan::DeleteSource(_)
             | PhysicalPlan::CopyIntoTable(_)
@@ -104,6 +104,7 @@ impl PhysicalPlanBuilder {
                     .await
             }
             RelOperator::CteScan(cte_scan) => self.build_cte_scan(cte_scan, required).await,
+            RelOperator::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan).await,
             RelOperator::MaterializedCte(cte) => {
                 self.build_materialized_cte(s_expr, cte, required).await
             }
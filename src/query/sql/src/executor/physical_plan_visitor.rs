@@ -44,6 +44,7 @@ use crate::executor::physical_plans::QuerySource;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::ReclusterSource;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
@@ -60,6 +61,7 @@ pub trait PhysicalPlanReplacer {
         match plan {
             PhysicalPlan::TableScan(plan) => self.replace_table_scan(plan),
             PhysicalPlan::CteScan(plan) => self.replace_cte_scan(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
             PhysicalPlan::Filter(plan) => self.replace_filter(plan),
             PhysicalPlan::Project(plan) => self.replace_project(plan),
             PhysicalPlan::EvalScalar(plan) => self.replace_eval_scalar(plan),
@@ -124,6 +126,10 @@ pub trait PhysicalPlanReplacer {
         Ok(PhysicalPlan::ConstantTableScan(plan.clone()))
     }
 
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
     fn replace_filter(&mut self, plan: &Filter) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -353,6 +359,7 @@ pub trait PhysicalPlanReplacer {
             schema: plan.schema.clone(),
             pairs: plan.pairs.clone(),
             stat_info: plan.stat_info.clone(),
+            cte_scan_names: plan.cte_scan_names.clone(),
         }))
     }
 
@@ -508,6 +515,7 @@ impl PhysicalPlan {
                 | PhysicalPlan::ReplaceAsyncSourcer(_)
                 | PhysicalPlan::CteScan(_)
                 | PhysicalPlan::ConstantTableScan(_)
+                | PhysicalPlan::RecursiveCteScan(_)
                 | PhysicalPlan::ReclusterSource(_)
                 | PhysicalPlan::ExchangeSource(_)
                 | PhysicalPlan::CompactSource(_)
@@ -68,6 +68,8 @@ mod physical_recluster_sink;
 pub use physical_recluster_sink::ReclusterSink;
 mod physical_recluster_source;
 pub use physical_recluster_source::*;
+mod physical_recursive_cte_scan;
+pub use physical_recursive_cte_scan::RecursiveCteScan;
 mod physical_refresh_index;
 pub use physical_refresh_index::RefreshIndex;
 mod physical_replace_async_source;
@@ -111,6 +111,7 @@ impl PhysicalPlanBuilder {
                     build_expr.data_type().clone(),
                     &BUILTIN_FUNCTIONS.default_cast_rules,
                 )
+                .map(join_key_type)
                 .ok_or_else(|| {
                     ErrorCode::IllegalDataType(format!(
                         "Cannot find common type for probe key {:?} and build key {:?}",
@@ -244,6 +245,7 @@ impl PhysicalPlanBuilder {
                 right_type.clone(),
                 &BUILTIN_FUNCTIONS.default_cast_rules,
             )
+            .map(join_key_type)
             .ok_or_else(|| {
                 ErrorCode::IllegalDataType(format!(
                     "Cannot find common type for {:?} and {:?}",
@@ -408,6 +410,11 @@ impl PhysicalPlanBuilder {
                 ));
                 probe_fields
             }
+            JoinType::Asof | JoinType::LeftAsof => {
+                return Err(ErrorCode::Internal(
+                    "Asof join should be executed by range join",
+                ));
+            }
         };
         let mut projections = ColumnSet::new();
         let projected_schema = DataSchemaRefExt::create(merged_fields.clone());
@@ -465,3 +472,13 @@ impl PhysicalPlanBuilder {
         }))
     }
 }
+
+/// `TimestampTz` keys are joined by the instant whatever the offsets are,
+/// so they are hashed and compared as `Timestamp`.
+fn join_key_type(ty: DataType) -> DataType {
+    match ty.remove_nullable() {
+        DataType::TimestampTz if ty.is_nullable() => DataType::Timestamp.wrap_nullable(),
+        DataType::TimestampTz => DataType::Timestamp,
+        _ => ty,
+    }
+}
@@ -15,6 +15,7 @@
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
@@ -41,10 +42,11 @@ pub struct RangeJoin {
     pub right: Box<PhysicalPlan>,
     // The first two conditions: (>, >=, <, <=)
     // Condition's left/right side only contains one table's column
+    // For asof join, the first condition is the match condition and the rest are equi conditions(=)
     pub conditions: Vec<RangeJoinCondition>,
     // The other conditions
     pub other_conditions: Vec<RemoteExpr>,
-    // Now only support inner join, will support left/right join later
+    // Inner join or asof join, will support left/right join later
     pub join_type: JoinType,
     pub range_join_type: RangeJoinType,
 
@@ -55,7 +57,17 @@ pub struct RangeJoin {
 impl RangeJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.left.output_schema()?.fields().clone();
-        fields.extend(self.right.output_schema()?.fields().clone());
+        let right_fields = self.right.output_schema()?.fields().clone();
+        if self.join_type == JoinType::LeftAsof {
+            // Wrap nullable type for columns in right side.
+            fields.extend(
+                right_fields
+                    .iter()
+                    .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable())),
+            );
+        } else {
+            fields.extend(right_fields);
+        }
         Ok(DataSchemaRefExt::create(fields))
     }
 }
@@ -64,13 +76,14 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    Asof,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
     pub right_expr: RemoteExpr,
-    // "gt" | "lt" | "gte" | "lte"
+    // "gt" | "lt" | "gte" | "lte", or "eq" for the equi conditions of asof join
     pub operator: String,
 }
 
@@ -142,6 +155,91 @@ impl PhysicalPlanBuilder {
     }
 }
 
+impl PhysicalPlanBuilder {
+    pub async fn build_asof_join(
+        &mut self,
+        s_expr: &SExpr,
+        join: &crate::plans::Join,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        // Unlike the other range joins, the left child of asof join is kept as left side,
+        // because all of its rows are probed against the whole right side.
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        debug_assert!(join.non_equi_conditions.len() == 1);
+
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        let mut conditions = vec![resolve_range_condition(
+            &join.non_equi_conditions[0],
+            &left_schema,
+            &right_schema,
+            &left_prop,
+            &right_prop,
+        )?];
+        for (left, right) in join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+        {
+            conditions.push(resolve_equi_condition(
+                left,
+                right,
+                &left_schema,
+                &right_schema,
+            )?);
+        }
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: self.next_plan_id(),
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions,
+            other_conditions: vec![],
+            join_type: join.join_type.clone(),
+            range_join_type: RangeJoinType::Asof,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+}
+
+fn resolve_equi_condition(
+    left: &ScalarExpr,
+    right: &ScalarExpr,
+    left_schema: &DataSchemaRef,
+    right_schema: &DataSchemaRef,
+) -> Result<RangeJoinCondition> {
+    let mut left = left.clone();
+    let mut right = right.clone();
+    let left_data_type = left.data_type()?;
+    let right_data_type = right.data_type()?;
+    if left_data_type.ne(&right_data_type) {
+        let common_type = common_super_type(
+            left_data_type.clone(),
+            right_data_type.clone(),
+            &BUILTIN_FUNCTIONS.default_cast_rules,
+        )
+        .ok_or_else(|| {
+            ErrorCode::IllegalDataType(format!(
+                "Cannot find common type for {left_data_type} and {right_data_type}"
+            ))
+        })?;
+        left = wrap_cast(&left, &common_type);
+        right = wrap_cast(&right, &common_type);
+    }
+    Ok(RangeJoinCondition {
+        left_expr: resolve_scalar(&left, left_schema)?,
+        right_expr: resolve_scalar(&right, right_schema)?,
+        operator: "eq".to_string(),
+    })
+}
+
 fn resolve_range_condition(
     expr: &ScalarExpr,
     left_schema: &DataSchemaRef,
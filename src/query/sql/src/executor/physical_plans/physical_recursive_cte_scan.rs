@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub table_name: String,
+    pub output_schema: DataSchemaRef,
+    pub stat: PlanStatsInfo,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_recursive_cte_scan(
+        &mut self,
+        recursive_cte_scan: &crate::plans::RecursiveCteScan,
+    ) -> Result<PhysicalPlan> {
+        // The working table is read by position, so all the columns are kept.
+        let plan_stat = PlanStatsInfo {
+            estimated_rows: recursive_cte_scan.stat.cardinality,
+        };
+
+        Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+            plan_id: self.next_plan_id(),
+            table_name: recursive_cte_scan.table_name.clone(),
+            output_schema: DataSchemaRefExt::create(recursive_cte_scan.fields.clone()),
+            stat: plan_stat,
+        }))
+    }
+}
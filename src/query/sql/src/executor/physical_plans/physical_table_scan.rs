@@ -471,6 +471,20 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        // The top-k distance search is pushed down as the order by, so that it can be
+        // answered by the vector indexes of the table.
+        let order_by = match (order_by, &scan.vector_search) {
+            (None, Some(vector_search)) => {
+                let expr = vector_search
+                    .as_raw_expr()
+                    .type_check(&metadata)?
+                    .project_column_ref(|col| col.column_name.clone());
+                let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                Some(vec![(expr.as_remote_expr(), true, false)])
+            }
+            (order_by, _) => order_by,
+        };
+
         let virtual_columns = self.build_virtual_columns(&scan.columns);
 
         Ok(PushDownInfo {
@@ -485,6 +499,7 @@ impl PhysicalPlanBuilder {
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
             change_type: scan.change_type.clone(),
+            sample: scan.sample.clone(),
         })
     }
 
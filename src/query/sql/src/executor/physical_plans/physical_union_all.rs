@@ -43,6 +43,8 @@ pub struct UnionAll {
     pub right: Box<PhysicalPlan>,
     pub pairs: Vec<(String, String)>,
     pub schema: DataSchemaRef,
+    // Names of the recursive ctes scanned by the right child, see `plans::UnionAll`.
+    pub cte_scan_names: Vec<String>,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -185,6 +187,7 @@ impl PhysicalPlanBuilder {
             right: Box::new(right_plan),
             pairs,
             schema: DataSchemaRefExt::create(fields),
+            cte_scan_names: union_all.cte_scan_names.clone(),
 
             stat_info: Some(stat_info),
         }))
@@ -90,6 +90,17 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof)
         }
+        PhysicalPlan::RecursiveCteScan(scan) => {
+            let proc_prof = profs.get(&scan.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: scan.plan_id,
+                operator_type: OperatorType::RecursiveCteScan,
+                children: vec![],
+                execution_info: proc_prof.into(),
+                attribute: OperatorAttribute::Empty,
+            };
+            plan_node_profs.push(prof)
+        }
         PhysicalPlan::ConstantTableScan(scan) => {
             let proc_prof = profs.get(&scan.plan_id).copied().unwrap_or_default();
             let prof = OperatorProfile {
@@ -18,6 +18,7 @@ use std::hash::Hash;
 use std::sync::Arc;
 
 use dashmap::DashMap;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::TableAlias;
@@ -26,6 +27,7 @@ use databend_common_catalog::plan::InternalColumn;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::Span;
+use databend_common_expression::types::DataType;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
@@ -144,6 +146,10 @@ pub struct BindContext {
     pub planning_agg_index: bool,
 
     pub window_definitions: DashMap<String, WindowSpec>,
+
+    /// The arguments of the `match(column, query)` in the `WHERE` clause,
+    /// which is scored by `score()` in the select list and `ORDER BY`.
+    pub inverted_index_match: Option<(Expr, Expr)>,
 }
 
 #[derive(Clone, Debug)]
@@ -151,6 +157,8 @@ pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
     pub materialized: bool,
+    // If the cte is declared in `WITH RECURSIVE`, it can reference itself
+    pub recursive: bool,
     pub cte_idx: IndexType,
     // Record how many times this cte is used
     pub used_count: usize,
@@ -160,6 +168,17 @@ pub struct CteInfo {
     pub columns: Vec<ColumnBinding>,
 }
 
+/// The working table of a recursive cte whose body is being bound.
+#[derive(Clone, Debug, Default)]
+pub struct RecursiveCteInfo {
+    // Names and types of the working table columns.
+    // It's `None` while binding the anchor member, which can't reference the recursive cte.
+    pub columns: Option<Vec<(String, DataType)>>,
+    pub stat_info: Option<Arc<StatInfo>>,
+    // Whether the recursive member references the recursive cte
+    pub referenced: bool,
+}
+
 impl BindContext {
     pub fn new() -> Self {
         Self {
@@ -177,6 +196,7 @@ impl BindContext {
             expr_context: ExprContext::default(),
             planning_agg_index: false,
             window_definitions: DashMap::new(),
+            inverted_index_match: None,
         }
     }
 
@@ -196,6 +216,7 @@ impl BindContext {
             expr_context: ExprContext::default(),
             planning_agg_index: false,
             window_definitions: DashMap::new(),
+            inverted_index_match: None,
         }
     }
 
@@ -41,6 +41,7 @@ use super::Finder;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
+use crate::binder::RecursiveCteInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::CreateFileFormatPlan;
@@ -94,6 +95,8 @@ pub struct Binder {
     /// Use `IndexMap` because need to keep the insertion order
     /// Then wrap materialized ctes to main plan.
     pub ctes_map: Box<IndexMap<String, CteInfo>>,
+    /// The recursive ctes whose bodies are being bound, the key is cte name.
+    pub r_ctes_map: HashMap<String, RecursiveCteInfo>,
 }
 
 impl<'a> Binder {
@@ -114,6 +117,7 @@ impl<'a> Binder {
             eq_scalars: vec![],
             m_cte_bound_s_expr: Default::default(),
             ctes_map: Box::default(),
+            r_ctes_map: Default::default(),
         }
     }
 
@@ -133,6 +137,8 @@ impl<'a> Binder {
         let mut init_bind_context = BindContext::new();
         let plan = self.bind_statement(&mut init_bind_context, stmt).await?;
         self.bind_query_index(&mut init_bind_context, &plan).await?;
+        self.bind_query_materialized_views(&mut init_bind_context, &plan)
+            .await?;
         Ok(plan)
     }
 
@@ -313,11 +319,22 @@ impl<'a> Binder {
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
+            }
+            Statement::DropMaterializedView(stmt) => self.bind_drop_materialized_view(stmt).await?,
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
 
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
+            Statement::RefreshVectorIndex(stmt) => self.bind_refresh_vector_index(stmt).await?,
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
+            Statement::RefreshInvertedIndex(stmt) => self.bind_refresh_inverted_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -598,18 +615,15 @@ impl<'a> Binder {
             Statement::ShowStreams(stmt) => self.bind_show_streams(bind_context, stmt).await?,
             Statement::DescribeStream(stmt) => self.bind_describe_stream(bind_context, stmt).await?,
 
-            Statement::CreatePipe(_) => {
-                todo!()
-            }
-            Statement::DescribePipe(_) => {
-                todo!()
-            }
-            Statement::AlterPipe(_) => {
-                todo!()
-            }
-            Statement::DropPipe(_) => {
-                todo!()
-            }
+            // Pipes
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(stmt).await?,
+            Statement::DescribePipe(stmt) => self.bind_desc_pipe(stmt).await?,
+            Statement::AlterPipe(stmt) => self.bind_alter_pipe(bind_context, stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
+
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
         };
         Ok(plan)
     }
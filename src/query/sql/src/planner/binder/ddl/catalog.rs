@@ -34,6 +34,7 @@ use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
 
 use crate::binder::parse_uri_location;
@@ -160,7 +161,19 @@ impl Binder {
                 })
             }
             CatalogType::Iceberg => {
-                let sp = parse_catalog_url(ctx,options.clone()).await?.ok_or_else(|| {
+                let mut options = options.clone();
+
+                // Remove REST catalog fields to avoid unexpected field error in uri location.
+                let rest = options
+                    .remove("catalog_uri")
+                    .map(|uri| IcebergRestCatalogOption {
+                        uri,
+                        warehouse: options.remove("warehouse").unwrap_or_default(),
+                        credential: options.remove("catalog_credential").unwrap_or_default(),
+                        token: options.remove("catalog_token").unwrap_or_default(),
+                    });
+
+                let sp = parse_catalog_url(ctx, options).await?.ok_or_else(|| {
                     ErrorCode::InvalidArgument(
                         "expect storage connection but failed to find, seems the url is missing",
                     )
@@ -168,6 +181,7 @@ impl Binder {
 
                 let opt = IcebergCatalogOption {
                     storage_params: Box::new(sp),
+                    rest,
                 };
                 CatalogOption::Iceberg(opt)
             }
@@ -13,11 +13,15 @@
 // limitations under the License.
 
 use databend_common_ast::ast::CreateIndexStmt;
+use databend_common_ast::ast::CreateInvertedIndexStmt;
 use databend_common_ast::ast::DropIndexStmt;
+use databend_common_ast::ast::DropInvertedIndexStmt;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
+use databend_common_ast::ast::RefreshInvertedIndexStmt;
+use databend_common_ast::ast::RefreshVectorIndexStmt;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
@@ -28,6 +32,8 @@ use databend_common_ast::Visitor;
 use databend_common_ast::VisitorMut;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::TableDataType;
+use databend_common_io::tokenizer::Tokenizer;
 use databend_common_license::license::Feature::AggregateIndex;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::GetIndexReq;
@@ -36,12 +42,17 @@ use databend_common_meta_app::schema::IndexNameIdent;
 use databend_storages_common_table_meta::meta::Location;
 
 use crate::binder::Binder;
+use crate::normalize_identifier;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshInvertedIndexPlan;
+use crate::plans::RefreshVectorIndexPlan;
 use crate::AggregatingIndexChecker;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
@@ -333,6 +344,148 @@ impl Binder {
         Ok(plan)
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_vector_index(
+        &mut self,
+        stmt: &RefreshVectorIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshVectorIndexStmt {
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        Ok(Plan::RefreshVectorIndex(Box::new(RefreshVectorIndexPlan {
+            catalog,
+            database,
+            table,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+            index_options,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Inverted Index only support FUSE engine",
+            ));
+        }
+        let schema = table_info.schema();
+
+        let mut tokenizer = Tokenizer::default();
+        for (key, value) in index_options {
+            match key.as_str() {
+                "tokenizer" => tokenizer = value.parse()?,
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Unsupported inverted index option '{}', only 'tokenizer' is supported",
+                        key
+                    )));
+                }
+            }
+        }
+
+        let mut column_ids = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&column)?;
+            if field.data_type().remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Inverted index only supports STRING columns, but column '{}' is {}",
+                    column,
+                    field.data_type()
+                )));
+            }
+            if !column_ids.contains(&field.column_id()) {
+                column_ids.push(field.column_id());
+            }
+        }
+
+        Ok(Plan::CreateInvertedIndex(Box::new(
+            CreateInvertedIndexPlan {
+                if_not_exists: *if_not_exists,
+                catalog,
+                database,
+                table,
+                index_name,
+                column_ids,
+                tokenizer: tokenizer.to_string(),
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        Ok(Plan::DropInvertedIndex(Box::new(DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_inverted_index(
+        &mut self,
+        stmt: &RefreshInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshInvertedIndexStmt {
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        Ok(Plan::RefreshInvertedIndex(Box::new(
+            RefreshInvertedIndexPlan {
+                catalog,
+                database,
+                table,
+                index_name,
+            },
+        )))
+    }
+
     fn rewrite_query_with_database(query: &mut Query, name: &str) {
         if let SetExpr::Select(stmt) = &mut query.body {
             if let TableReference::Table { database, .. } = &mut stmt.from[0] {
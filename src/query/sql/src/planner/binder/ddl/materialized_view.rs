@@ -0,0 +1,367 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_ast::ast::CreateMaterializedViewStmt;
+use databend_common_ast::ast::DropMaterializedViewStmt;
+use databend_common_ast::ast::ExplainKind;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::RefreshMaterializedViewStmt;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::VisitorMut;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::infer_schema_type;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_storages_common_table_meta::table::MaterializedViews;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::binder::Binder;
+use crate::plans::CreateMaterializedViewPlan;
+use crate::plans::DropMaterializedViewPlan;
+use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
+use crate::MaterializedViewEntry;
+use crate::MetadataRef;
+use crate::TableEntry;
+use crate::ViewRewriter;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_query_materialized_views(
+        &mut self,
+        bind_context: &mut BindContext,
+        plan: &Plan,
+    ) -> Result<()> {
+        match plan {
+            Plan::Query { metadata, .. } => {
+                self.do_bind_query_materialized_views(bind_context, metadata)
+                    .await?;
+            }
+            Plan::Explain { kind, plan }
+                if matches!(kind, ExplainKind::Plan) && matches!(**plan, Plan::Query { .. }) =>
+            {
+                match **plan {
+                    Plan::Query { ref metadata, .. } => {
+                        self.do_bind_query_materialized_views(bind_context, metadata)
+                            .await?;
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Bind the materialized views which are up to date with the tables of the query,
+    /// the optimizer may rewrite the query to read from them.
+    #[async_backtrace::framed]
+    async fn do_bind_query_materialized_views(
+        &mut self,
+        bind_context: &mut BindContext,
+        metadata: &MetadataRef,
+    ) -> Result<()> {
+        if bind_context.planning_agg_index
+            || !self
+                .ctx
+                .get_settings()
+                .get_enable_materialized_view_rewrite()?
+        {
+            return Ok(());
+        }
+
+        let tables = metadata.read().tables().to_vec();
+        let mut visited = HashSet::new();
+        for table_entry in tables.iter() {
+            let table = table_entry.table();
+            if table_entry.is_source_of_index() || table.engine() != "FUSE" {
+                continue;
+            }
+            let full_table_name = format!(
+                "{}.{}.{}",
+                table_entry.catalog(),
+                table_entry.database(),
+                table_entry.name()
+            );
+            if !visited.insert(full_table_name.clone()) {
+                continue;
+            }
+            let views = MaterializedViews::from_options(table.options())?;
+            if views.is_empty() {
+                continue;
+            }
+
+            let snapshot_of = |entry: &TableEntry| {
+                entry
+                    .table()
+                    .options()
+                    .get(OPT_KEY_SNAPSHOT_LOCATION)
+                    .cloned()
+                    .unwrap_or_default()
+            };
+            let source_snapshot = snapshot_of(table_entry);
+            // The same table may be read at another snapshot in the query, e.g. by time travel.
+            if tables.iter().any(|entry| {
+                entry.catalog() == table_entry.catalog()
+                    && entry.database() == table_entry.database()
+                    && entry.name() == table_entry.name()
+                    && snapshot_of(entry) != source_snapshot
+            }) {
+                continue;
+            }
+
+            let mut entries = vec![];
+            for view in views.0.iter() {
+                let database = &view.database;
+                let view_table = match self
+                    .ctx
+                    .get_table(table_entry.catalog(), database, &view.name)
+                    .await
+                {
+                    Ok(view_table) => view_table,
+                    // The materialized view is dropped.
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => continue,
+                    Err(e) => return Err(e),
+                };
+
+                let options = view_table.options();
+                let source_table_id = table.get_id().to_string();
+                if options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID) != Some(&source_table_id)
+                    || options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT)
+                        != Some(&source_snapshot)
+                {
+                    // Not up to date.
+                    continue;
+                }
+                let Some(query) = options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+                    continue;
+                };
+
+                let tokens = tokenize_sql(query)?;
+                let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+                if let Statement::Query(query) = &stmt {
+                    let mut new_bind_context =
+                        BindContext::with_parent(Box::new(bind_context.clone()));
+                    new_bind_context.planning_agg_index = true;
+                    let (s_expr, view_bind_context) =
+                        self.bind_query(&mut new_bind_context, query).await?;
+                    let statistics = view_table.table_statistics(self.ctx.clone()).await?;
+                    entries.push(MaterializedViewEntry {
+                        catalog: table_entry.catalog().to_string(),
+                        database: database.clone(),
+                        table: view_table,
+                        statistics,
+                        s_expr,
+                        output_columns: view_bind_context
+                            .columns
+                            .iter()
+                            .map(|column| column.index)
+                            .collect(),
+                    });
+                }
+            }
+
+            if !entries.is_empty() {
+                metadata
+                    .write()
+                    .add_materialized_views(full_table_name, entries);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+
+        let mut query = *query.clone();
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let mut visitor = ViewRewriter {
+            current_database: database.clone(),
+        };
+        visitor.visit_query(&mut query);
+
+        let Some((source_catalog, source_database, source_table)) =
+            materialized_view_source(&query)
+        else {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view only supports simple query on a single table, like: \
+                SELECT ... FROM ... WHERE ... GROUP BY ...",
+            ));
+        };
+        let (source_catalog, source_database, source_table) =
+            self.normalize_object_identifier_triple(source_catalog, source_database, source_table);
+        if source_catalog != catalog {
+            return Err(ErrorCode::SemanticError(format!(
+                "Materialized view must be in the same catalog as table '{source_database}.{source_table}'"
+            )));
+        }
+
+        let mut init_bind_context = BindContext::new();
+        let (_, bind_context) = self.bind_query(&mut init_bind_context, &query).await?;
+
+        let tables = self.metadata.read().tables().to_vec();
+        if tables.len() != 1 {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view only supports simple query on a single table",
+            ));
+        }
+        let table = tables[0].table();
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Materialized view is not supported on table '{source_database}.{source_table}' of engine {}",
+                table.engine()
+            )));
+        }
+
+        let fields = bind_context
+            .columns
+            .iter()
+            .map(|column_binding| {
+                Ok(TableField::new(
+                    &column_binding.column_name,
+                    infer_schema_type(&column_binding.data_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = TableSchemaRefExt::create(fields);
+        Self::validate_create_table_schema(&schema)?;
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+            schema,
+            query: query.to_string(),
+            source_database,
+            source_table,
+            source_table_id: table.get_id(),
+        };
+        Ok(Plan::CreateMaterializedView(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = DropMaterializedViewPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::DropMaterializedView(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(plan.into()))
+    }
+}
+
+/// Returns the table which the query of a materialized view reads from.
+///
+/// Only a plain `SELECT ... FROM <table> [WHERE ...] [GROUP BY ...]` is supported,
+/// returns None for other queries.
+pub fn materialized_view_source(
+    query: &Query,
+) -> Option<(&Option<Identifier>, &Option<Identifier>, &Identifier)> {
+    if query.with.is_some()
+        || !query.order_by.is_empty()
+        || !query.limit.is_empty()
+        || query.offset.is_some()
+    {
+        return None;
+    }
+    let SetExpr::Select(select) = &query.body else {
+        return None;
+    };
+    if select.distinct
+        || select.having.is_some()
+        || select.window_list.is_some()
+        || select.qualify.is_some()
+        || select.from.len() != 1
+    {
+        return None;
+    }
+    match &select.from[0] {
+        TableReference::Table {
+            catalog,
+            database,
+            table,
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+            sample: None,
+            ..
+        } => Some((catalog, database, table)),
+        _ => None,
+    }
+}
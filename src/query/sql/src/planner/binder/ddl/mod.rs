@@ -19,8 +19,10 @@ mod connection;
 mod data_mask;
 mod database;
 mod index;
+mod materialized_view;
 mod network_policy;
 mod password_policy;
+mod pipe;
 mod role;
 mod share;
 mod stage;
@@ -29,3 +31,5 @@ mod table;
 mod task;
 mod view;
 mod virtual_column;
+
+pub use materialized_view::materialized_view_source;
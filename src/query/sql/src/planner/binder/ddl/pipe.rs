@@ -0,0 +1,293 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use chrono_tz::Tz;
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_ast::ast::AlterPipeStmt;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::CreatePipeStmt;
+use databend_common_ast::ast::DescribePipeStmt;
+use databend_common_ast::ast::DropPipeStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::timestamp::string_to_timestamp;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFilesInfo;
+use databend_common_users::UserApiProvider;
+
+use crate::binder::copy_into_table::check_transform_query;
+use crate::binder::resolve_file_location;
+use crate::binder::Binder;
+use crate::plans::AlterPipePlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+use crate::BindContext;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            comments,
+            copy_stmt,
+        } = stmt;
+
+        // The copied files are skipped by the following loads, which makes each file loaded only once.
+        if copy_stmt.force {
+            return Err(ErrorCode::SemanticError(
+                "FORCE is not allowed in the COPY statement of a pipe",
+            ));
+        }
+        if !copy_stmt.validation_mode.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "VALIDATION_MODE is not allowed in the COPY statement of a pipe",
+            ));
+        }
+        // The stage event notifications are not received, the new files are loaded by
+        // `ALTER PIPE ... REFRESH` only.
+        if *auto_ingest {
+            return Err(ErrorCode::Unimplemented(
+                "AUTO_INGEST = TRUE is not supported yet, use ALTER PIPE ... REFRESH to load the new files",
+            ));
+        }
+        let location = pipe_source_location(copy_stmt)?;
+        resolve_file_location(self.ctx.as_ref(), location).await?;
+
+        let (catalog_name, database_name, table_name) = self.normalize_object_identifier_triple(
+            &copy_stmt.dst.catalog,
+            &copy_stmt.dst.database,
+            &copy_stmt.dst.table,
+        );
+        self.ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+
+        // The statement is kept in the pipe as SQL, make sure it can be parsed back.
+        let copy_sql = copy_stmt.to_string();
+        parse_copy_stmt(&copy_sql)?;
+
+        let plan = CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            auto_ingest: *auto_ingest,
+            comment: comments.to_string(),
+            copy_stmt: copy_sql,
+        };
+        Ok(Plan::CreatePipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_pipe(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &AlterPipeStmt,
+    ) -> Result<Plan> {
+        let AlterPipeStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (execution_paused, comment) = match options {
+            AlterPipeOptions::Set {
+                execution_paused,
+                comments,
+            } => (*execution_paused, comments.clone()),
+            AlterPipeOptions::Refresh {
+                prefix,
+                modified_after,
+            } => {
+                let pipe = match UserApiProvider::instance().get_pipe(&tenant, name).await {
+                    Ok(pipe) => Some(pipe),
+                    Err(e) if *if_exists && e.code() == ErrorCode::UNKNOWN_PIPE => None,
+                    Err(e) => return Err(e),
+                };
+                match pipe {
+                    Some(pipe) => {
+                        if pipe.execution_paused {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Pipe '{}' is paused, set PIPE_EXECUTION_PAUSED = false to refresh it",
+                                name
+                            )));
+                        }
+                        let copy_stmt = parse_copy_stmt(&pipe.copy_stmt)?;
+                        let copy_stmt = self
+                            .bind_pipe_files(copy_stmt, prefix, modified_after)
+                            .await?;
+                        return self
+                            .bind_statement(bind_context, &Statement::CopyIntoTable(copy_stmt))
+                            .await;
+                    }
+                    // Nothing is altered as the pipe does not exist.
+                    None => (None, None),
+                }
+            }
+        };
+
+        let plan = AlterPipePlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            execution_paused,
+            comment,
+        };
+        Ok(Plan::AlterPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        let plan = DropPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+        };
+        Ok(Plan::DropPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_pipe(
+        &mut self,
+        stmt: &DescribePipeStmt,
+    ) -> Result<Plan> {
+        let DescribePipeStmt { name } = stmt;
+
+        let plan = DescPipePlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescPipe(Box::new(plan)))
+    }
+
+    /// Discover the files of the stage under `prefix` and modified after `modified_after`,
+    /// the files already loaded are filtered out later by the COPY statement.
+    #[async_backtrace::framed]
+    async fn bind_pipe_files(
+        &mut self,
+        mut copy_stmt: CopyIntoTableStmt,
+        prefix: &Option<String>,
+        modified_after: &Option<String>,
+    ) -> Result<CopyIntoTableStmt> {
+        if prefix.is_none() && modified_after.is_none() {
+            return Ok(copy_stmt);
+        }
+
+        let modified_after = match modified_after {
+            Some(modified_after) => {
+                let tz = self.ctx.get_settings().get_timezone()?;
+                let tz = tz
+                    .parse::<Tz>()
+                    .map_err(|_| ErrorCode::InvalidTimezone(format!("Invalid timezone: {}", tz)))?;
+                let ts = string_to_timestamp(modified_after, tz).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid MODIFIED_AFTER timestamp '{}'",
+                        modified_after
+                    ))
+                })?;
+                Some(ts.with_timezone(&Utc))
+            }
+            None => None,
+        };
+        let prefix = prefix
+            .as_ref()
+            .map(|prefix| prefix.trim_start_matches('/'))
+            .unwrap_or_default();
+
+        let location = pipe_source_location(&copy_stmt)?;
+        let (stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
+        let operator = init_stage_operator(&stage_info)?;
+        let dir = match path.as_str() {
+            "/" => "".to_string(),
+            path if path.ends_with('/') => path.to_string(),
+            path => format!("{path}/"),
+        };
+        let mut files_info = StageFilesInfo {
+            path: path.clone(),
+            files: copy_stmt.files.clone(),
+            pattern: copy_stmt.pattern.take(),
+        };
+        // Only the directory of the prefix is listed instead of the whole location, so the
+        // pattern is matched below against the path relative to the location as COPY does.
+        let mut pattern = None;
+        if files_info.files.is_none() {
+            pattern = files_info.get_pattern()?;
+            files_info.pattern = None;
+            if let Some(pos) = prefix.rfind('/') {
+                files_info.path = format!("{dir}{}", &prefix[..=pos]);
+            }
+        }
+        let files = files_info
+            .list(&operator, false, None)
+            .await?
+            .into_iter()
+            .filter(|file| modified_after.map_or(true, |t| file.last_modified > t))
+            .filter_map(|file| {
+                // The files of COPY are relative to the path of the location.
+                let name = file
+                    .path
+                    .strip_prefix(dir.as_str())
+                    .unwrap_or(&file.path)
+                    .trim_start_matches('/');
+                (name.starts_with(prefix) && pattern.as_ref().map_or(true, |p| p.is_match(name)))
+                    .then(|| name.to_string())
+            })
+            .collect::<Vec<_>>();
+
+        copy_stmt.files = Some(files);
+        Ok(copy_stmt)
+    }
+}
+
+fn pipe_source_location(copy_stmt: &CopyIntoTableStmt) -> Result<&FileLocation> {
+    let location = match &copy_stmt.src {
+        CopyIntoTableSource::Location(location) => location,
+        CopyIntoTableSource::Query(query) => check_transform_query(query)?.1,
+    };
+    match location {
+        FileLocation::Stage(_) => Ok(location),
+        FileLocation::Uri(_) => Err(ErrorCode::SemanticError(
+            "The COPY statement of a pipe must load files from a stage",
+        )),
+    }
+}
+
+fn parse_copy_stmt(sql: &str) -> Result<CopyIntoTableStmt> {
+    let tokens = tokenize_sql(sql)?;
+    match parse_sql(&tokens, Dialect::PostgreSQL)?.0 {
+        Statement::CopyIntoTable(stmt) => Ok(stmt),
+        _ => Err(ErrorCode::IllegalPipe(format!(
+            "Invalid COPY statement of pipe: {}",
+            sql
+        ))),
+    }
+}
@@ -32,6 +32,7 @@ use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::ExistsTableStmt;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::ModifyColumnAction;
 use databend_common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use databend_common_ast::ast::OptimizeTableStmt;
@@ -96,12 +97,14 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTablePartitionPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
@@ -983,9 +986,51 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::AddPartition {
+                if_not_exists,
+                partition,
+            } => Ok(Plan::AddTablePartition(Box::new(AddTablePartitionPlan {
+                catalog,
+                database,
+                table,
+                if_not_exists: *if_not_exists,
+                partition: self.bind_partition_spec(partition)?,
+            }))),
+            AlterTableAction::DropPartition {
+                if_exists,
+                partition,
+            } => Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                catalog,
+                database,
+                table,
+                if_exists: *if_exists,
+                partition: self.bind_partition_spec(partition)?,
+            }))),
         }
     }
 
+    fn bind_partition_spec(
+        &self,
+        partition: &[(Identifier, Literal)],
+    ) -> Result<Vec<(String, String)>> {
+        partition
+            .iter()
+            .map(|(key, value)| {
+                let key = normalize_identifier(key, &self.name_resolution_ctx).name;
+                let value = match value {
+                    Literal::String(s) => s.clone(),
+                    Literal::Null => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "partition value of '{key}' can not be NULL"
+                        )));
+                    }
+                    other => other.to_string(),
+                };
+                Ok((key, value))
+            })
+            .collect()
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_rename_table(
         &mut self,
@@ -1384,7 +1429,9 @@ impl Binder {
     }
 
     /// Validate the schema of the table to be created.
-    fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
+    pub(in crate::planner::binder) fn validate_create_table_schema(
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
         // Check if there are duplicated column names
         let mut name_set = HashSet::new();
         for field in schema.fields() {
@@ -60,6 +60,16 @@ fn verify_scheduler_option(schedule_opts: &Option<ScheduleOptions>) -> Result<()
         return Ok(());
     }
     let schedule_opts = schedule_opts.clone().unwrap();
+    // The interval is persisted as i32 seconds.
+    if let ScheduleOptions::IntervalSecs(secs) = schedule_opts {
+        if secs == 0 || secs > i32::MAX as u64 {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid schedule interval {} seconds, it must be between 1 and {}",
+                secs,
+                i32::MAX
+            )));
+        }
+    }
     if let ScheduleOptions::CronExpression(cron_expr, time_zone) = schedule_opts {
         if cron::Schedule::from_str(&cron_expr).is_err() {
             return Err(ErrorCode::SemanticError(format!(
@@ -89,6 +99,7 @@ impl Binder {
             warehouse_opts,
             schedule_opts,
             suspend_task_after_num_failures,
+            error_integration,
             comments,
             after,
             when_condition,
@@ -111,6 +122,7 @@ impl Binder {
             warehouse_opts: warehouse_opts.clone(),
             schedule_opts: schedule_opts.clone(),
             suspend_task_after_num_failures: *suspend_task_after_num_failures,
+            error_integration: error_integration.clone(),
             after: after.clone(),
             when_condition: when_condition.clone(),
             comment: comments.clone(),
@@ -134,12 +146,14 @@ impl Binder {
             warehouse,
             schedule,
             suspend_task_after_num_failures,
+            error_integration,
             comments,
         } = options
         {
             if warehouse.is_none()
                 && schedule.is_none()
                 && suspend_task_after_num_failures.is_none()
+                && error_integration.is_none()
                 && comments.is_none()
             {
                 return Err(ErrorCode::SyntaxException(
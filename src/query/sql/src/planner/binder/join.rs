@@ -86,6 +86,11 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::Asof | JoinOperator::LeftAsof if join.match_condition.is_none() => {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain a match condition".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -115,6 +120,17 @@ impl Binder {
             )
             .await?;
 
+        if let Some(match_condition) = &join.match_condition {
+            if !non_equi_conditions.is_empty() || !other_conditions.is_empty() {
+                return Err(ErrorCode::SemanticError(
+                    "asof join only supports equi conditions in ON clause".to_string(),
+                ));
+            }
+            join_condition_resolver
+                .resolve_match_condition(match_condition, &mut non_equi_conditions)
+                .await?;
+        }
+
         let join_conditions = JoinConditions {
             left_conditions: left_join_conditions,
             right_conditions: right_join_conditions,
@@ -174,6 +190,15 @@ impl Binder {
                     right_child,
                 )
             }
+            JoinOperator::Asof => {
+                self.bind_join_with_type(JoinType::Asof, join_conditions, left_child, right_child)
+            }
+            JoinOperator::LeftAsof => self.bind_join_with_type(
+                JoinType::LeftAsof,
+                join_conditions,
+                left_child,
+                right_child,
+            ),
         }?;
         Ok((s_expr, bind_context))
     }
@@ -280,7 +305,10 @@ impl Binder {
                         left_push_down.push(predicate.clone());
                         right_push_down.push(predicate.clone());
                     }
-                    JoinType::Left | JoinType::LeftSingle | JoinType::RightMark => {
+                    JoinType::Left
+                    | JoinType::LeftSingle
+                    | JoinType::RightMark
+                    | JoinType::LeftAsof => {
                         need_push_down = true;
                         right_push_down.push(predicate.clone());
                     }
@@ -288,7 +316,7 @@ impl Binder {
                         need_push_down = true;
                         left_push_down.push(predicate.clone());
                     }
-                    JoinType::Full => non_equi_conditions.push(predicate.clone()),
+                    JoinType::Full | JoinType::Asof => non_equi_conditions.push(predicate.clone()),
                 },
                 JoinPredicate::Left(_) => {
                     need_push_down = true;
@@ -344,7 +372,7 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
             for column in left_context.all_column_bindings() {
                 bind_context.add_column_binding(column.clone());
             }
@@ -638,6 +666,62 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(())
     }
 
+    // The match condition of asof join must be a comparison between the left and right table,
+    // such as `t1.ts >= t2.ts`, it's the only non-equi condition of the join.
+    #[async_backtrace::framed]
+    async fn resolve_match_condition(
+        &mut self,
+        match_condition: &Expr,
+        non_equi_conditions: &mut Vec<ScalarExpr>,
+    ) -> Result<()> {
+        let mut join_context = (*self.join_context).clone();
+        wrap_nullable_for_column(
+            &self.join_op,
+            self.left_context,
+            self.right_context,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, _) = scalar_binder.bind(match_condition).await?;
+
+        let (left_columns, right_columns) = self.left_right_columns()?;
+        let is_valid = match &predicate {
+            ScalarExpr::FunctionCall(func)
+                if matches!(func.func_name.as_str(), "gt" | "gte" | "lt" | "lte")
+                    && func.arguments.len() == 2 =>
+            {
+                let arg0_columns = func.arguments[0].used_columns();
+                let arg1_columns = func.arguments[1].used_columns();
+                !arg0_columns.is_empty()
+                    && !arg1_columns.is_empty()
+                    && ((arg0_columns.is_subset(&left_columns)
+                        && arg1_columns.is_subset(&right_columns))
+                        || (arg0_columns.is_subset(&right_columns)
+                            && arg1_columns.is_subset(&left_columns)))
+            }
+            _ => false,
+        };
+        if !is_valid {
+            return Err(ErrorCode::SemanticError(
+                "match condition of asof join must be a comparison(>, >=, <, <=) between the left and right table".to_string(),
+            )
+            .set_span(match_condition.span()));
+        }
+        self.check_join_allowed_scalar_expr(&vec![predicate.clone()])
+            .await?;
+
+        non_equi_conditions.push(predicate);
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn resolve_using(
         &mut self,
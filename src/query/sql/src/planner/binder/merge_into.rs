@@ -189,6 +189,7 @@ impl Binder {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         // get_source_table_reference
@@ -309,6 +310,7 @@ impl Binder {
         let join = Join {
             op: join_type,
             condition: JoinCondition::On(Box::new(join_expr.clone())),
+            match_condition: None,
             left: Box::new(target_table),
             // use source as build table
             right: Box::new(source_data.clone()),
@@ -61,6 +61,7 @@ pub use column_binding::ColumnBinding;
 pub use column_binding::ColumnBindingBuilder;
 pub use copy_into_table::resolve_file_location;
 pub use copy_into_table::resolve_stage_location;
+pub use ddl::materialized_view_source;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::get_storage_params_from_options;
 pub use location::parse_uri_location;
@@ -41,6 +41,7 @@ use databend_common_exception::Result;
 use databend_common_exception::Span;
 use databend_common_expression::type_check::common_super_type;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::ROW_ID_COLUMN_ID;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -60,6 +61,7 @@ use crate::optimizer::SExpr;
 use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::BindContext;
 use crate::planner::binder::Binder;
+use crate::planner::semantic::InvertedIndexMatchFinder;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
@@ -69,6 +71,11 @@ use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
 use crate::plans::Visitor as _;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::IndexType;
@@ -125,6 +132,7 @@ impl Binder {
                     join: Join {
                         op: JoinOperator::CrossJoin,
                         condition: JoinCondition::None,
+                        match_condition: None,
                         left: Box::new(left),
                         right: Box::new(right),
                     },
@@ -157,6 +165,13 @@ impl Binder {
             .bind_project_set(&mut from_context, &set_returning_functions, s_expr)
             .await?;
 
+        // Find the `match` in `WHERE` clause for `score()`, which is bound before `WHERE`.
+        if let Some(selection) = &stmt.selection {
+            let mut finder = InvertedIndexMatchFinder::default();
+            finder.visit_expr(selection);
+            from_context.inverted_index_match = finder.args;
+        }
+
         // Try put window definitions into bind context.
         // This operation should be before `normalize_select_list` because window functions can be used in select list.
         self.analyze_window_definition(&mut from_context, &stmt.window_list)?;
@@ -365,6 +380,7 @@ impl Binder {
                         .collect(),
                     query: *cte.query.clone(),
                     materialized: cte.materialized,
+                    recursive: with.recursive,
                     cte_idx: idx,
                     used_count: 0,
                     stat_info: None,
@@ -483,7 +499,7 @@ impl Binder {
         }
 
         match (op, all) {
-            (SetOperator::Intersect, false) => {
+            (SetOperator::Intersect, _) => {
                 // Transfer Intersect to Semi join
                 self.bind_intersect(
                     left.span(),
@@ -492,9 +508,10 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    !all,
                 )
             }
-            (SetOperator::Except, false) => {
+            (SetOperator::Except, _) => {
                 // Transfer Except to Anti join
                 self.bind_except(
                     left.span(),
@@ -503,6 +520,7 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    !all,
                 )
             }
             (SetOperator::Union, true) => self.bind_union(
@@ -523,9 +541,6 @@ impl Binder {
                 right_expr,
                 true,
             ),
-            _ => Err(ErrorCode::Unimplemented(
-                "Unsupported query type, currently, databend only support intersect distinct and except distinct",
-            )),
         }
     }
 
@@ -576,7 +591,10 @@ impl Binder {
             coercion_types,
         )?;
 
-        let union_plan = UnionAll { pairs };
+        let union_plan = UnionAll {
+            pairs,
+            cte_scan_names: vec![],
+        };
         let mut new_expr = SExpr::create_binary(
             Arc::new(union_plan.into()),
             Arc::new(left_expr),
@@ -596,6 +614,7 @@ impl Binder {
         Ok((new_expr, new_bind_context))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect(
         &mut self,
         left_span: Span,
@@ -604,6 +623,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -613,9 +633,11 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftSemi,
+            distinct,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_except(
         &mut self,
         left_span: Span,
@@ -624,6 +646,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -633,9 +656,18 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftAnti,
+            distinct,
         )
     }
 
+    // INTERSECT [DISTINCT] and EXCEPT [DISTINCT] are transferred to semi join and anti join
+    // on all the columns, with the left side deduplicated.
+    //
+    // INTERSECT ALL and EXCEPT ALL follow the multiset semantics, a row that appears m times
+    // in the left side and n times in the right side appears min(m, n) times in the result of
+    // INTERSECT ALL and max(m - n, 0) times in the result of EXCEPT ALL. Both sides are numbered
+    // by `row_number() OVER (PARTITION BY <all columns>)`, and the row number is joined as well,
+    // so the k-th duplicate of the left side can only be matched by the k-th duplicate of the right side.
     #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect_or_except(
         &mut self,
@@ -646,22 +678,33 @@ impl Binder {
         left_expr: SExpr,
         right_expr: SExpr,
         join_type: JoinType,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
-        let left_expr = self.bind_distinct(
-            left_span,
-            &left_context,
-            left_context.all_column_bindings(),
-            &mut HashMap::new(),
-            left_expr,
-        )?;
-        let mut left_conditions = Vec::with_capacity(left_context.columns.len());
-        let mut right_conditions = Vec::with_capacity(right_context.columns.len());
-        assert_eq!(left_context.columns.len(), right_context.columns.len());
-        for (left_column, right_column) in left_context
-            .columns
-            .iter()
-            .zip(right_context.columns.iter())
-        {
+        let mut left_columns = left_context.columns.clone();
+        let mut right_columns = right_context.columns.clone();
+        let (left_expr, right_expr) = if distinct {
+            let left_expr = self.bind_distinct(
+                left_span,
+                &left_context,
+                left_context.all_column_bindings(),
+                &mut HashMap::new(),
+                left_expr,
+            )?;
+            (left_expr, right_expr)
+        } else {
+            let (left_expr, left_row_number) =
+                self.bind_set_row_number(left_span, &left_context, left_expr)?;
+            let (right_expr, right_row_number) =
+                self.bind_set_row_number(right_span, &right_context, right_expr)?;
+            left_columns.push(left_row_number);
+            right_columns.push(right_row_number);
+            (left_expr, right_expr)
+        };
+
+        let mut left_conditions = Vec::with_capacity(left_columns.len());
+        let mut right_conditions = Vec::with_capacity(right_columns.len());
+        assert_eq!(left_columns.len(), right_columns.len());
+        for (left_column, right_column) in left_columns.iter().zip(right_columns.iter()) {
             left_conditions.push(
                 BoundColumnRef {
                     span: left_span,
@@ -687,6 +730,58 @@ impl Binder {
         Ok((s_expr, left_context))
     }
 
+    // Number the duplicated rows of the input by `row_number() OVER (PARTITION BY <all columns>)`.
+    fn bind_set_row_number(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        s_expr: SExpr,
+    ) -> Result<(SExpr, ColumnBinding)> {
+        let data_type = DataType::Number(NumberDataType::UInt64);
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column("row_number".to_string(), data_type.clone());
+        let row_number = ColumnBindingBuilder::new(
+            "row_number".to_string(),
+            index,
+            Box::new(data_type),
+            Visibility::InVisible,
+        )
+        .build();
+
+        let partition_by = bind_context
+            .columns
+            .iter()
+            .map(|column| ScalarItem {
+                index: column.index,
+                scalar: BoundColumnRef {
+                    span,
+                    column: column.clone(),
+                }
+                .into(),
+            })
+            .collect();
+        let window_plan = Window {
+            span,
+            index,
+            function: WindowFuncType::RowNumber,
+            arguments: vec![],
+            partition_by,
+            order_by: vec![],
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Rows,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::CurrentRow,
+            },
+        };
+
+        Ok((
+            SExpr::create_unary(Arc::new(window_plan.into()), Arc::new(s_expr)),
+            row_number,
+        ))
+    }
+
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
     fn coercion_union_type(
@@ -30,9 +30,14 @@ use databend_common_ast::ast::Indirection;
 use databend_common_ast::ast::Join;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Query;
+use databend_common_ast::ast::Sample;
+use databend_common_ast::ast::SampleConfig;
+use databend_common_ast::ast::SampleLevel;
 use databend_common_ast::ast::SelectStageOptions;
 use databend_common_ast::ast::SelectStmt;
 use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperator;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TableReference;
@@ -42,6 +47,7 @@ use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::plan::SampleDesc;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
@@ -52,8 +58,10 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::Span;
 use databend_common_expression::is_stream_column;
+use databend_common_expression::type_check::common_super_type;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::F64;
 use databend_common_expression::ColumnId;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
@@ -69,9 +77,12 @@ use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_types::MetaId;
+use databend_common_pipeline_sources::input_formats::read_arrow_schema_async;
+use databend_common_storage::init_stage_operator;
 use databend_common_storage::DataOperator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_orc::read_orc_schema_async;
 use databend_common_storages_parquet::Parquet2Table;
 use databend_common_storages_parquet::ParquetRSTable;
 use databend_common_storages_result_cache::ResultCacheMetaManager;
@@ -94,6 +105,7 @@ use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
+use crate::binder::RecursiveCteInfo;
 use crate::binder::Visibility;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
@@ -102,10 +114,12 @@ use crate::planner::semantic::TypeChecker;
 use crate::plans::CteScan;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::RecursiveCteScan;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
+use crate::plans::UnionAll;
 use crate::BaseTableColumn;
 use crate::BindContext;
 use crate::ColumnEntry;
@@ -188,6 +202,7 @@ impl Binder {
         alias: &Option<TableAlias>,
         travel_point: &Option<TimeTravelPoint>,
     ) -> Result<(SExpr, BindContext)> {
+        let qualified = catalog.is_some() || database.is_some();
         let (catalog, database, table_name) =
             self.normalize_object_identifier_triple(catalog, database, table);
         let table_alias_name = if let Some(table_alias) = alias {
@@ -195,6 +210,10 @@ impl Binder {
         } else {
             None
         };
+        // Check and bind the self reference of a recursive common table expression
+        if !qualified && self.r_ctes_map.contains_key(&table_name) {
+            return self.bind_r_cte_scan(*span, bind_context, &table_name, alias);
+        }
         let mut bind_cte = true;
         if let Some(cte_name) = &bind_context.cte_name {
             // If table name equals to cte name, then skip bind cte and find table from catalog
@@ -207,11 +226,14 @@ impl Binder {
         let ctes_map = self.ctes_map.clone();
         if let Some(cte_info) = ctes_map.get(&table_name) {
             if bind_cte {
-                return if !cte_info.materialized {
-                    self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
+                return if cte_info.materialized {
+                    self.bind_m_cte(bind_context, cte_info, &table_name, alias, span)
+                        .await
+                } else if cte_info.recursive {
+                    self.bind_r_cte(*span, bind_context, &table_name, alias, cte_info)
                         .await
                 } else {
-                    self.bind_m_cte(bind_context, cte_info, &table_name, alias, span)
+                    self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
                         .await
                 };
             }
@@ -245,11 +267,14 @@ impl Binder {
                     let bind_context = parent.unwrap().as_mut();
                     let ctes_map = self.ctes_map.clone();
                     if let Some(cte_info) = ctes_map.get(&table_name) {
-                        return if !cte_info.materialized {
-                            self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
+                        return if cte_info.materialized {
+                            self.bind_m_cte(bind_context, cte_info, &table_name, alias, span)
+                                .await
+                        } else if cte_info.recursive {
+                            self.bind_r_cte(*span, bind_context, &table_name, alias, cte_info)
                                 .await
                         } else {
-                            self.bind_m_cte(bind_context, cte_info, &table_name, alias, span)
+                            self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
                                 .await
                         };
                     }
@@ -916,17 +941,26 @@ impl Binder {
                 travel_point,
                 pivot: _,
                 unpivot: _,
+                sample,
             } => {
-                self.bind_table(
-                    bind_context,
-                    span,
-                    catalog,
-                    database,
-                    table,
-                    alias,
-                    travel_point,
-                )
-                .await
+                let (s_expr, bind_context) = self
+                    .bind_table(
+                        bind_context,
+                        span,
+                        catalog,
+                        database,
+                        table,
+                        alias,
+                        travel_point,
+                    )
+                    .await?;
+                match sample {
+                    Some(sample) => {
+                        let s_expr = self.bind_table_sample(span, s_expr, sample)?;
+                        Ok((s_expr, bind_context))
+                    }
+                    None => Ok((s_expr, bind_context)),
+                }
             }
             TableReference::TableFunction {
                 span,
@@ -975,6 +1009,63 @@ impl Binder {
         }
     }
 
+    /// Attach the sample of a fuse table to its scan. If the seed is not specified,
+    /// a random one is generated here so that all the partitions use the same seed.
+    fn bind_table_sample(&self, span: &Span, s_expr: SExpr, sample: &Sample) -> Result<SExpr> {
+        let mut scan = match s_expr.plan() {
+            RelOperator::Scan(scan) => scan.clone(),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "SAMPLE is only supported for base tables".to_string(),
+                )
+                .set_span(*span));
+            }
+        };
+        let table = self.metadata.read().table(scan.table_index).table();
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(format!(
+                "SAMPLE is not supported for table engine {}",
+                table.engine()
+            ))
+            .set_span(*span));
+        }
+
+        let probability = match sample.sample_conf {
+            SampleConfig::Probability(percent) => {
+                if !(0.0..=100.0).contains(&percent) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Sample probability must be between 0 and 100, but got {percent}"
+                    ))
+                    .set_span(*span));
+                }
+                percent / 100.0
+            }
+            SampleConfig::RowsNum(rows) => {
+                // The fixed-size sample is converted to a probability by the table statistics,
+                // so the number of returned rows is approximate.
+                match scan.statistics.statistics.and_then(|stat| stat.num_rows) {
+                    Some(num_rows) if num_rows > 0 => (rows / num_rows as f64).min(1.0),
+                    _ => 1.0,
+                }
+            }
+        };
+        let probability = Some(F64::from(probability));
+        let seed = sample.seed.unwrap_or_else(rand::random);
+        scan.sample = Some(match sample.sample_level {
+            SampleLevel::Row => SampleDesc {
+                block_probability: None,
+                row_probability: probability,
+                seed,
+            },
+            SampleLevel::Block => SampleDesc {
+                block_probability: probability,
+                row_probability: None,
+                seed,
+            },
+        });
+        Ok(SExpr::create_leaf(Arc::new(scan.into())))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_stage_table(
         &mut self,
@@ -1027,7 +1118,10 @@ impl Binder {
                     .await?
                 }
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::NdJson(..)
+            | FileFormatParams::Json(..)
+            | FileFormatParams::Xml(..)
+            | FileFormatParams::Avro(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -1042,6 +1136,40 @@ impl Binder {
                 };
                 StageTable::try_create(info)?
             }
+            FileFormatParams::Orc(..) => {
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match files_to_copy.as_ref().and_then(|files| files.first()) {
+                    Some(file) => file.path.clone(),
+                    None => files_info.first_file(&operator).await?.path,
+                };
+                let schema = Arc::new(read_orc_schema_async(&operator, &first_file).await?);
+                let info = StageTableInfo {
+                    schema,
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: true,
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
+            FileFormatParams::Arrow(..) => {
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match files_to_copy.as_ref().and_then(|files| files.first()) {
+                    Some(file) => file.path.clone(),
+                    None => files_info.first_file(&operator).await?.path,
+                };
+                let schema = Arc::new(read_arrow_schema_async(&operator, &first_file).await?);
+                let info = StageTableInfo {
+                    schema,
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: true,
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
             FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) => {
                 let max_column_position = self.metadata.read().get_max_column_position();
                 if max_column_position == 0 {
@@ -1078,7 +1206,7 @@ impl Binder {
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
-                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, and TSV. Provided format: '{}'.",
+                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, ORC, Arrow, NDJson, JSON, XML, Avro, CSV, and TSV. Provided format: '{}'.",
                     stage_info.file_format_params
                 )));
             }
@@ -1224,16 +1352,9 @@ impl Binder {
         Ok(cte_scan)
     }
 
-    #[async_backtrace::framed]
-    pub(crate) async fn bind_cte(
-        &mut self,
-        span: Span,
-        bind_context: &mut BindContext,
-        table_name: &str,
-        alias: &Option<TableAlias>,
-        cte_info: &CteInfo,
-    ) -> Result<(SExpr, BindContext)> {
-        let mut new_bind_context = BindContext {
+    // Create the `BindContext` to bind the query of a cte
+    fn new_cte_bind_context(bind_context: &BindContext, table_name: &str) -> BindContext {
+        BindContext {
             parent: Some(Box::new(bind_context.clone())),
             bound_internal_columns: BTreeMap::new(),
             columns: vec![],
@@ -1248,7 +1369,20 @@ impl Binder {
             planning_agg_index: false,
             allow_internal_columns: true,
             window_definitions: DashMap::new(),
-        };
+            inverted_index_match: None,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut new_bind_context = Self::new_cte_bind_context(bind_context, table_name);
 
         let (s_expr, mut res_bind_context) = self
             .bind_query(&mut new_bind_context, &cte_info.query)
@@ -1339,6 +1473,321 @@ impl Binder {
         Ok((s_expr, new_bind_context))
     }
 
+    // Bind recursive cte
+    //
+    // A recursive cte is in the form of `anchor UNION ALL recursive`, the recursive member
+    // references the cte itself, which is bound to a `RecursiveCteScan` reading the rows
+    // produced by the previous iteration. The cte is bound to a `UnionAll` whose
+    // `cte_scan_names` is the name of the cte, and it's evaluated until no new rows produced.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_r_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation)
+                if set_operation.op == SetOperator::Union
+                    && query.with.is_none()
+                    && query.order_by.is_empty()
+                    && query.limit.is_empty()
+                    && query.offset.is_none() =>
+            {
+                set_operation
+            }
+            _ => {
+                // Not in the form of `anchor UNION [ALL] recursive`, bind it as a normal cte
+                // but disallow the self reference.
+                self.r_ctes_map
+                    .insert(table_name.to_string(), RecursiveCteInfo::default());
+                let res = self
+                    .bind_cte(span, bind_context, table_name, alias, cte_info)
+                    .await;
+                self.r_ctes_map.remove(table_name);
+                return res;
+            }
+        };
+
+        // 1. Bind the anchor member, it can't reference the recursive cte.
+        self.r_ctes_map
+            .insert(table_name.to_string(), RecursiveCteInfo::default());
+        let mut anchor_bind_context = Self::new_cte_bind_context(bind_context, table_name);
+        let anchor = self
+            .bind_set_expr(&mut anchor_bind_context, &set_operation.left, &[], 0)
+            .await;
+        let (anchor_expr, mut anchor_bind_context) = match anchor {
+            Ok(anchor) => anchor,
+            Err(e) => {
+                self.r_ctes_map.remove(table_name);
+                return Err(e);
+            }
+        };
+        if cte_info.columns_alias.len() > anchor_bind_context.columns.len() {
+            self.r_ctes_map.remove(table_name);
+            return Err(ErrorCode::SemanticError(format!(
+                "The CTE '{}' has {} columns, but {} aliases were provided. Ensure the number of aliases matches the number of columns in the CTE.",
+                table_name,
+                anchor_bind_context.columns.len(),
+                cte_info.columns_alias.len()
+            ))
+            .set_span(span));
+        }
+        for (column, column_alias) in anchor_bind_context
+            .columns
+            .iter_mut()
+            .zip(cte_info.columns_alias.iter())
+        {
+            column.column_name = column_alias.clone();
+        }
+
+        // 2. Bind the recursive member. The types of the working table start from the types
+        // of the anchor member, and are widened until they can hold the rows produced by
+        // the recursive member, e.g. the type of `n` in `SELECT n + 1 FROM t`.
+        let res = self
+            .bind_r_cte_recursive_member(
+                span,
+                bind_context,
+                table_name,
+                &set_operation.right,
+                &anchor_expr,
+                &anchor_bind_context,
+            )
+            .await;
+        let referenced = self
+            .r_ctes_map
+            .remove(table_name)
+            .map_or(false, |r_cte_info| r_cte_info.referenced);
+        let (recursive_expr, recursive_bind_context) = res?;
+
+        // 3. Union the anchor member and the recursive member.
+        let (s_expr, mut res_bind_context) = if !referenced {
+            // The cte doesn't reference itself, bind it as a normal union.
+            self.bind_union(
+                set_operation.left.span(),
+                set_operation.right.span(),
+                anchor_bind_context,
+                recursive_bind_context,
+                anchor_expr,
+                recursive_expr,
+                !set_operation.all,
+            )?
+        } else {
+            if !set_operation.all {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Recursive CTE '{table_name}' only supports UNION ALL between the anchor member and the recursive member"
+                ))
+                .set_span(span));
+            }
+            let (s_expr, res_bind_context) = self.bind_union(
+                set_operation.left.span(),
+                set_operation.right.span(),
+                anchor_bind_context,
+                recursive_bind_context,
+                anchor_expr,
+                recursive_expr,
+                false,
+            )?;
+            let mut union_all: UnionAll = s_expr.plan().clone().try_into()?;
+            union_all.cte_scan_names = vec![table_name.to_string()];
+            (
+                s_expr.replace_plan(Arc::new(union_all.into())),
+                res_bind_context,
+            )
+        };
+
+        // 4. Resolve the alias of the cte reference.
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        if let Some(alias) = alias {
+            if alias.columns.len() > res_bind_context.columns.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The CTE '{}' has {} columns, but {} aliases were provided. Ensure the number of aliases matches the number of columns in the CTE.",
+                    table_name,
+                    res_bind_context.columns.len(),
+                    alias.columns.len()
+                ))
+                .set_span(span));
+            }
+            for (column, column_alias) in res_bind_context
+                .columns
+                .iter_mut()
+                .zip(alias.columns.iter())
+            {
+                column.column_name =
+                    normalize_identifier(column_alias, &self.name_resolution_ctx).name;
+            }
+        }
+        for column in res_bind_context.columns.iter_mut() {
+            column.database_name = None;
+            column.table_name = Some(alias_table_name.clone());
+        }
+        Ok((s_expr, res_bind_context))
+    }
+
+    // Bind the recursive member of a recursive cte, the working table of the cte
+    // must have been registered in `r_ctes_map`.
+    #[async_backtrace::framed]
+    async fn bind_r_cte_recursive_member(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        table_name: &str,
+        recursive: &SetExpr,
+        anchor_expr: &SExpr,
+        anchor_bind_context: &BindContext,
+    ) -> Result<(SExpr, BindContext)> {
+        // Each round the types of the working table are widened, e.g. UInt8 -> UInt16 -> ... -> UInt64,
+        // so the types should be stable after a few rounds.
+        const MAX_TYPE_RESOLUTION_ROUNDS: usize = 8;
+
+        let stat_info = RelExpr::with_s_expr(anchor_expr).derive_cardinality()?;
+        let column_names = anchor_bind_context
+            .columns
+            .iter()
+            .map(|column| column.column_name.clone())
+            .collect::<Vec<_>>();
+        let mut data_types = anchor_bind_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect::<Vec<_>>();
+
+        for _ in 0..MAX_TYPE_RESOLUTION_ROUNDS {
+            self.r_ctes_map
+                .insert(table_name.to_string(), RecursiveCteInfo {
+                    columns: Some(
+                        column_names
+                            .iter()
+                            .cloned()
+                            .zip(data_types.iter().cloned())
+                            .collect(),
+                    ),
+                    stat_info: Some(stat_info.clone()),
+                    referenced: false,
+                });
+            let mut recursive_bind_context = Self::new_cte_bind_context(bind_context, table_name);
+            let (recursive_expr, recursive_bind_context) = self
+                .bind_set_expr(&mut recursive_bind_context, recursive, &[], 0)
+                .await?;
+            if !self.r_ctes_map[table_name].referenced {
+                return Ok((recursive_expr, recursive_bind_context));
+            }
+
+            if recursive_bind_context.columns.len() != data_types.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The anchor member and the recursive member of recursive CTE '{table_name}' must have the same number of columns"
+                ))
+                .set_span(span));
+            }
+            let mut common_types = Vec::with_capacity(data_types.len());
+            for (column, data_type) in recursive_bind_context.columns.iter().zip(data_types.iter())
+            {
+                let common_type = common_super_type(
+                    data_type.clone(),
+                    *column.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "The type of column '{}' in the recursive member of recursive CTE '{}' is {}, which can't be matched with the anchor member's {}",
+                        column.column_name, table_name, column.data_type, data_type
+                    ))
+                    .set_span(span)
+                })?;
+                common_types.push(common_type);
+            }
+            if common_types == data_types {
+                return Ok((recursive_expr, recursive_bind_context));
+            }
+            data_types = common_types;
+        }
+
+        Err(ErrorCode::SemanticError(format!(
+            "Cannot resolve the column types of recursive CTE '{table_name}', please cast the columns of the anchor member to the expected types"
+        ))
+        .set_span(span))
+    }
+
+    // Bind the self reference of a recursive cte in its recursive member
+    fn bind_r_cte_scan(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let r_cte_info = self.r_ctes_map.get_mut(table_name).unwrap();
+        let (columns, stat_info) = match (&r_cte_info.columns, &r_cte_info.stat_info) {
+            (Some(columns), Some(stat_info)) => (columns.clone(), stat_info.clone()),
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Recursive CTE '{table_name}' can only be referenced in the recursive member of `anchor UNION ALL recursive`"
+                ))
+                .set_span(span));
+            }
+        };
+        r_cte_info.referenced = true;
+
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        let columns_alias = alias
+            .as_ref()
+            .map(|alias| alias.columns.as_slice())
+            .unwrap_or_default();
+        if columns_alias.len() > columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "The CTE '{}' has {} columns, but {} aliases were provided. Ensure the number of aliases matches the number of columns in the CTE.",
+                table_name,
+                columns.len(),
+                columns_alias.len()
+            ))
+            .set_span(span));
+        }
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut fields = Vec::with_capacity(columns.len());
+        for (idx, (column_name, data_type)) in columns.into_iter().enumerate() {
+            let column_name = match columns_alias.get(idx) {
+                Some(column_alias) => {
+                    normalize_identifier(column_alias, &self.name_resolution_ctx).name
+                }
+                None => column_name,
+            };
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            let column_binding = ColumnBindingBuilder::new(
+                column_name,
+                index,
+                Box::new(data_type.clone()),
+                Visibility::Visible,
+            )
+            .table_name(Some(alias_table_name.clone()))
+            .build();
+            new_bind_context.add_column_binding(column_binding);
+            fields.push(DataField::new(&index.to_string(), data_type));
+        }
+
+        let s_expr = SExpr::create_leaf(Arc::new(
+            RecursiveCteScan {
+                table_name: table_name.to_string(),
+                fields,
+                stat: stat_info,
+            }
+            .into(),
+        ));
+        Ok((s_expr, new_bind_context))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_base_table(
         &mut self,
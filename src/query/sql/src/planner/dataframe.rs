@@ -65,6 +65,7 @@ impl Dataframe {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         let settings = query_ctx.get_settings();
@@ -442,6 +443,22 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            true,
+        )?;
+        self.s_expr = s_expr;
+        self.bind_context = bind_context;
+        Ok(self)
+    }
+
+    pub async fn except_all(mut self, dataframe: Dataframe) -> Result<Self> {
+        let (s_expr, bind_context) = self.binder.bind_except(
+            None,
+            None,
+            self.bind_context,
+            dataframe.bind_context,
+            self.s_expr,
+            dataframe.s_expr,
+            false,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -456,6 +473,22 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            true,
+        )?;
+        self.s_expr = s_expr;
+        self.bind_context = bind_context;
+        Ok(self)
+    }
+
+    pub async fn intersect_all(mut self, dataframe: Dataframe) -> Result<Self> {
+        let (s_expr, bind_context) = self.binder.bind_intersect(
+            None,
+            None,
+            self.bind_context,
+            dataframe.bind_context,
+            self.s_expr,
+            dataframe.s_expr,
+            false,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -479,6 +512,7 @@ impl Dataframe {
                 travel_point: None,
                 pivot: None,
                 unpivot: None,
+                sample: None,
             };
             table_ref.push(table);
         }
@@ -490,6 +524,7 @@ impl Dataframe {
                 join: Join {
                     op: op.clone(),
                     condition: condition.clone(),
+                    match_condition: None,
                     left: Box::new(left),
                     right: Box::new(right),
                 },
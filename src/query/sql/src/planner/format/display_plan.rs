@@ -77,6 +77,8 @@ impl Plan {
             Plan::DescribeTable(_) => Ok("DescribeTable".to_string()),
             Plan::RenameTable(_) => Ok("RenameTable".to_string()),
             Plan::SetOptions(_) => Ok("SetOptions".to_string()),
+            Plan::AddTablePartition(_) => Ok("AddTablePartition".to_string()),
+            Plan::DropTablePartition(_) => Ok("DropTablePartition".to_string()),
             Plan::RenameTableColumn(_) => Ok("RenameTableColumn".to_string()),
             Plan::AddTableColumn(_) => Ok("AddTableColumn".to_string()),
             Plan::ModifyTableColumn(_) => Ok("ModifyTableColumn".to_string()),
@@ -96,6 +98,11 @@ impl Plan {
             Plan::AlterView(_) => Ok("AlterView".to_string()),
             Plan::DropView(_) => Ok("DropView".to_string()),
 
+            // Materialized views
+            Plan::CreateMaterializedView(_) => Ok("CreateMaterializedView".to_string()),
+            Plan::DropMaterializedView(_) => Ok("DropMaterializedView".to_string()),
+            Plan::RefreshMaterializedView(_) => Ok("RefreshMaterializedView".to_string()),
+
             // Streams
             Plan::CreateStream(_) => Ok("CreateStream".to_string()),
             Plan::DropStream(_) => Ok("DropStream".to_string()),
@@ -104,6 +111,10 @@ impl Plan {
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
             Plan::DropIndex(_) => Ok("DropIndex".to_string()),
             Plan::RefreshIndex(_) => Ok("RefreshIndex".to_string()),
+            Plan::RefreshVectorIndex(_) => Ok("RefreshVectorIndex".to_string()),
+            Plan::CreateInvertedIndex(_) => Ok("CreateInvertedIndex".to_string()),
+            Plan::DropInvertedIndex(_) => Ok("DropInvertedIndex".to_string()),
+            Plan::RefreshInvertedIndex(_) => Ok("RefreshInvertedIndex".to_string()),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(_) => Ok("CreateVirtualColumn".to_string()),
@@ -151,6 +162,10 @@ impl Plan {
             Plan::UseDatabase(_) => Ok("UseDatabase".to_string()),
             Plan::Kill(_) => Ok("Kill".to_string()),
 
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
+
             Plan::CreateShareEndpoint(_) => Ok("CreateShareEndpoint".to_string()),
             Plan::ShowShareEndpoint(_) => Ok("ShowShareEndpoint".to_string()),
             Plan::DropShareEndpoint(_) => Ok("DropShareEndpoint".to_string()),
@@ -184,6 +199,12 @@ impl Plan {
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
 
+            // pipe
+            Plan::CreatePipe(_) => Ok("CreatePipe".to_string()),
+            Plan::AlterPipe(_) => Ok("AlterPipe".to_string()),
+            Plan::DropPipe(_) => Ok("DropPipe".to_string()),
+            Plan::DescPipe(_) => Ok("DescPipe".to_string()),
+
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
             Plan::DropTask(_) => Ok("DropTask".to_string()),
@@ -243,6 +264,8 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             prewhere: None,
             agg_index: None,
             change_type: None,
+            sample: None,
+            vector_search: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
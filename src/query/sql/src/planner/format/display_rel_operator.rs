@@ -76,6 +76,7 @@ impl Display for FormatContext {
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
                 RelOperator::CteScan(_) => write!(f, "CteScan"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
                 RelOperator::MaterializedCte(_) => write!(f, "MaterializedCte"),
                 RelOperator::ConstantTableScan(_) => write!(f, "ConstantTableScan"),
                 RelOperator::AddRowNumber(_) => write!(f, "AddRowNumber"),
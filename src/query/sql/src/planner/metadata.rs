@@ -23,6 +23,7 @@ use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Literal;
 use databend_common_catalog::plan::InternalColumn;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableStatistics;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::Scalar;
@@ -61,6 +62,7 @@ pub struct Metadata {
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    materialized_views: HashMap<String, Vec<MaterializedViewEntry>>,
     max_column_position: usize, // for CSV
 }
 
@@ -274,6 +276,17 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_materialized_views(&mut self, table: String, views: Vec<MaterializedViewEntry>) {
+        self.materialized_views
+            .entry(table)
+            .and_modify(|entries| entries.extend_from_slice(&views))
+            .or_insert(views);
+    }
+
+    pub fn get_materialized_views(&self, table: &str) -> Option<&[MaterializedViewEntry]> {
+        self.materialized_views.get(table).map(|v| v.as_slice())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
@@ -492,6 +505,30 @@ impl TableEntry {
     }
 }
 
+/// A materialized view which is up to date with its source table,
+/// so queries on the source table can be answered by it.
+#[derive(Clone)]
+pub struct MaterializedViewEntry {
+    pub catalog: String,
+    pub database: String,
+    pub table: Arc<dyn Table>,
+    pub statistics: Option<TableStatistics>,
+    /// The bound plan of the view query.
+    pub s_expr: SExpr,
+    /// The column indexes of the view query outputs, in the order of the view schema.
+    pub output_columns: Vec<IndexType>,
+}
+
+impl Debug for MaterializedViewEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaterializedViewEntry")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("name", &self.table.name())
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct BaseTableColumn {
     pub table_index: IndexType,
@@ -26,6 +26,7 @@ pub mod optimizer;
 pub mod plans;
 mod stream_column;
 mod udf_validator;
+mod vector_index;
 
 pub use binder::parse_result_scan_args;
 pub use binder::BindContext;
@@ -48,3 +49,4 @@ pub use plans::INSERT_NAME;
 pub use plans::UPDATE_NAME;
 pub use semantic::*;
 pub use stream_column::*;
+pub use vector_index::VectorIndexColumns;
@@ -78,6 +78,7 @@ impl DefaultCostModel {
             RelOperator::Scan(plan) => self.compute_cost_scan(memo, m_expr, plan),
             RelOperator::DummyTableScan(_)
             | RelOperator::CteScan(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::ConstantTableScan(_) => Ok(Cost(0.0)),
             RelOperator::Join(plan) => self.compute_cost_join(memo, m_expr, plan),
             RelOperator::UnionAll(_) => self.compute_cost_union_all(memo, m_expr),
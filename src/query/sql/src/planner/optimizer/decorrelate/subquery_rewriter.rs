@@ -173,6 +173,7 @@ impl SubqueryRewriter {
             RelOperator::DummyTableScan(_)
             | RelOperator::Scan(_)
             | RelOperator::CteScan(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::ConstantTableScan(_) => Ok(s_expr.clone()),
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
@@ -65,6 +65,7 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::ProjectSet(_) => "ProjectSet".to_string(),
         RelOperator::Window(_) => "WindowFunc".to_string(),
         RelOperator::CteScan(_) => "CteScan".to_string(),
+        RelOperator::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
         RelOperator::MaterializedCte(_) => "MaterializedCte".to_string(),
         RelOperator::ConstantTableScan(_) => "ConstantTableScan".to_string(),
         RelOperator::AddRowNumber(_) => "AddRowNumber".to_string(),
@@ -255,6 +255,7 @@ impl DPhpy {
             RelOperator::DummyTableScan(_)
             | RelOperator::ConstantTableScan(_)
             | RelOperator::CteScan(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::MaterializedCte(_) => Ok((s_expr, true)),
         }
     }
@@ -0,0 +1,478 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use log::info;
+
+use crate::optimizer::agg_index::check_predicates_equal;
+use crate::optimizer::agg_index::check_predicates_other;
+use crate::optimizer::agg_index::check_predicates_range;
+use crate::optimizer::agg_index::collect_information;
+use crate::optimizer::agg_index::distinguish_predicates;
+use crate::optimizer::agg_index::format_col_name;
+use crate::optimizer::agg_index::rewrite_by_selection;
+use crate::optimizer::agg_index::rewrite_index_plan;
+use crate::optimizer::agg_index::try_create_column_binding;
+use crate::optimizer::agg_index::RewriteInfomartion;
+use crate::optimizer::agg_index::SelectionMap;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::plans::Statistics;
+use crate::plans::VisitorMut;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Try to answer the query by one of the materialized views of its table.
+///
+/// Only the whole query is rewritten, which should be in the shape of:
+///
+/// ```text
+/// [Limit] -> EvalScalar -> [Aggregate -> EvalScalar] -> [Filter] -> Scan
+/// ```
+///
+/// so the outputs of the rewritten plan are exactly the outputs of the query.
+pub fn try_rewrite(metadata: &MetadataRef, s_expr: &SExpr) -> Result<Option<SExpr>> {
+    if let RelOperator::Limit(_) = s_expr.plan() {
+        let child = try_rewrite(metadata, s_expr.child(0)?)?;
+        return Ok(child.map(|child| s_expr.replace_children(vec![Arc::new(child)])));
+    }
+    if !matches!(s_expr.plan(), RelOperator::EvalScalar(_)) {
+        return Ok(None);
+    }
+    let Some(scan) = find_scan(s_expr.child(0)?) else {
+        return Ok(None);
+    };
+
+    let (views, base_columns) = {
+        let metadata = metadata.read();
+        let table = metadata.table(scan.table_index);
+        let table_name = format!("{}.{}.{}", table.catalog(), table.database(), table.name());
+        match metadata.get_materialized_views(&table_name) {
+            Some(views) => (
+                views.to_vec(),
+                metadata.columns_by_table_index(scan.table_index),
+            ),
+            None => return Ok(None),
+        }
+    };
+
+    let query_info = collect_information(s_expr)?;
+    if !query_info.can_apply_index() || !is_supported_information(&query_info) {
+        return Ok(None);
+    }
+
+    let col_index_map = base_columns
+        .iter()
+        .map(|col| (col.name(), col.index()))
+        .collect::<HashMap<_, _>>();
+
+    for view in views.iter() {
+        let plan = rewrite_index_plan(scan.table_index, &col_index_map, &view.s_expr);
+        let view_info = collect_information(&plan)?;
+        if !view_info.can_apply_index()
+            || !is_supported_information(&view_info)
+            || view_info.sort_items.is_some()
+        {
+            continue;
+        }
+
+        // The view outputs, the index in the selection is the position in the view schema.
+        let mut view_selection = SelectionMap::new();
+        let mut output_bound_cols = ColumnSet::new();
+        for (position, column) in view.output_columns.iter().enumerate() {
+            let Some(item) = view_info
+                .selection
+                .items
+                .iter()
+                .find(|item| item.index == *column)
+            else {
+                continue;
+            };
+            if let ScalarExpr::BoundColumnRef(col) = &item.scalar {
+                output_bound_cols.insert(col.column.index);
+            }
+            view_selection
+                .entry(view_info.format_scalar(&item.scalar))
+                .or_insert((position, item.scalar.data_type()?));
+        }
+
+        let rewritten = match (&query_info.aggregation, &view_info.aggregation) {
+            (None, None) => {
+                let Some(predicates) = residual_predicates(
+                    &query_info,
+                    &view_info,
+                    &view_selection,
+                    &output_bound_cols,
+                ) else {
+                    continue;
+                };
+                let Some(items) = query_info
+                    .selection
+                    .items
+                    .iter()
+                    .map(|item| {
+                        Some(ScalarItem {
+                            index: item.index,
+                            scalar: rewrite_by_selection(
+                                &query_info,
+                                &item.scalar,
+                                &view_selection,
+                            )?,
+                        })
+                    })
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+                // EvalScalar -> [Filter] -> Scan(view)
+                match create_view_input(metadata, view, items, predicates)? {
+                    Some(input) => input,
+                    None => continue,
+                }
+            }
+            (Some((agg, inputs)), None) => {
+                let Some(predicates) = residual_predicates(
+                    &query_info,
+                    &view_info,
+                    &view_selection,
+                    &output_bound_cols,
+                ) else {
+                    continue;
+                };
+                // Rewrite the input items of the aggregation,
+                // the columns used by the aggregation directly should be outputs of the view too.
+                let mut used_columns = ColumnSet::new();
+                for item in agg.group_items.iter().chain(agg.aggregate_functions.iter()) {
+                    used_columns.extend(item.scalar.used_columns());
+                }
+                let Some(mut items) = inputs
+                    .iter()
+                    .map(|(index, scalar)| {
+                        Some(ScalarItem {
+                            index: *index,
+                            scalar: rewrite_by_selection(&query_info, scalar, &view_selection)?,
+                        })
+                    })
+                    .chain(
+                        used_columns
+                            .iter()
+                            .filter(|column| !inputs.contains_key(*column))
+                            .map(|column| {
+                                let col = try_create_column_binding(
+                                    &view_selection,
+                                    &format_col_name(*column),
+                                )?;
+                                Some(ScalarItem {
+                                    index: *column,
+                                    scalar: col.into(),
+                                })
+                            }),
+                    )
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+                items.sort_by_key(|item| item.index);
+                let Some(input) = create_view_input(metadata, view, items, predicates)? else {
+                    continue;
+                };
+                // EvalScalar -> Aggregate -> EvalScalar -> [Filter] -> Scan(view)
+                let agg_expr = s_expr.child(0)?;
+                s_expr.replace_children(vec![Arc::new(
+                    agg_expr.replace_children(vec![Arc::new(input)]),
+                )])
+            }
+            (Some((query_agg, _)), Some(_)) => {
+                // The rows of the view are the results of the aggregation,
+                // so the query should have the same groups and the same predicates.
+                if query_info.formatted_group_items() != view_info.formatted_group_items()
+                    || formatted_predicates(&query_info) != formatted_predicates(&view_info)
+                {
+                    continue;
+                }
+                let Some(items) = query_agg
+                    .group_items
+                    .iter()
+                    .chain(query_agg.aggregate_functions.iter())
+                    .map(|item| {
+                        let col = try_create_column_binding(
+                            &view_selection,
+                            &query_info.format_scalar(&item.scalar),
+                        )?;
+                        Some(ScalarItem {
+                            index: item.index,
+                            scalar: col.into(),
+                        })
+                    })
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+                let Some(input) = create_view_input(metadata, view, items, vec![])? else {
+                    continue;
+                };
+                // EvalScalar -> EvalScalar -> Scan(view)
+                s_expr.replace_children(vec![Arc::new(input)])
+            }
+            (None, Some(_)) => continue,
+        };
+
+        info!(
+            "Use materialized view: {}.{}",
+            view.database,
+            view.table.name()
+        );
+        return Ok(Some(rewritten));
+    }
+
+    Ok(None)
+}
+
+/// Find the scan of the query which is in the shape of `[Aggregate -> EvalScalar] -> [Filter] -> Scan`.
+fn find_scan(s_expr: &SExpr) -> Option<&Scan> {
+    let mut s_expr = s_expr;
+    if let RelOperator::Aggregate(_) = s_expr.plan() {
+        s_expr = s_expr.child(0).ok()?;
+        if !matches!(s_expr.plan(), RelOperator::EvalScalar(_)) {
+            return None;
+        }
+        s_expr = s_expr.child(0).ok()?;
+    }
+    if let RelOperator::Filter(_) = s_expr.plan() {
+        s_expr = s_expr.child(0).ok()?;
+    }
+    match s_expr.plan() {
+        RelOperator::Scan(scan)
+            if scan.change_type.is_none()
+                && scan.sample.is_none()
+                && scan.agg_index.is_none()
+                && scan.prewhere.is_none()
+                && scan.push_down_predicates.is_none() =>
+        {
+            Some(scan)
+        }
+        _ => None,
+    }
+}
+
+/// Only the scalars which can be formatted by [`RewriteInfomartion`] are supported.
+fn is_supported_information(info: &RewriteInfomartion<'_>) -> bool {
+    fn is_supported(scalar: &ScalarExpr) -> bool {
+        match scalar {
+            ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => true,
+            ScalarExpr::FunctionCall(func) => func.arguments.iter().all(is_supported),
+            ScalarExpr::CastExpr(cast) => is_supported(&cast.argument),
+            ScalarExpr::UDFServerCall(udf) => udf.arguments.iter().all(is_supported),
+            // Distinct is not a part of the formatted aggregate function.
+            ScalarExpr::AggregateFunction(agg) => {
+                !agg.distinct && agg.args.iter().all(is_supported)
+            }
+            _ => false,
+        }
+    }
+
+    info.selection
+        .items
+        .iter()
+        .all(|item| is_supported(&item.scalar))
+        && info
+            .predicates
+            .map_or(true, |predicates| predicates.iter().all(is_supported))
+        && info.aggregation.as_ref().map_or(true, |(agg, inputs)| {
+            agg.group_items
+                .iter()
+                .chain(agg.aggregate_functions.iter())
+                .all(|item| is_supported(&item.scalar))
+                && inputs.values().all(|scalar| is_supported(scalar))
+        })
+}
+
+fn formatted_predicates(info: &RewriteInfomartion<'_>) -> Vec<String> {
+    let mut predicates = info
+        .predicates
+        .unwrap_or_default()
+        .iter()
+        .map(|pred| info.format_scalar(pred))
+        .collect::<Vec<_>>();
+    predicates.sort();
+    predicates
+}
+
+/// Check if the predicates of the view fit the query,
+/// returns the predicates need to be applied on the view data.
+fn residual_predicates(
+    query_info: &RewriteInfomartion<'_>,
+    view_info: &RewriteInfomartion<'_>,
+    view_selection: &SelectionMap<'_>,
+    view_output_bound_cols: &ColumnSet,
+) -> Option<Vec<ScalarExpr>> {
+    let (qe, qr, qo) = distinguish_predicates(query_info.predicates.unwrap_or_default());
+    let (ve, vr, vo) = distinguish_predicates(view_info.predicates.unwrap_or_default());
+    if !check_predicates_equal(&qe, &ve)
+        || !check_predicates_other(&qo, &vo)
+        // The view data is filtered by a column which is not filtered in the query.
+        || vr.keys().any(|col| !qr.contains_key(col))
+    {
+        return None;
+    }
+
+    let mut predicates = check_predicates_range(&qr, &vr, view_output_bound_cols, view_selection)?;
+    for (left, right) in qe.iter() {
+        if ve
+            .iter()
+            .any(|(l, r)| (l == left && r == right) || (l == right && r == left))
+        {
+            continue;
+        }
+        let pred = ScalarExpr::FunctionCall(FunctionCall {
+            span: None,
+            func_name: "eq".to_string(),
+            params: vec![],
+            arguments: vec![(*left).clone(), (*right).clone()],
+        });
+        predicates.push(rewrite_by_selection(query_info, &pred, view_selection)?);
+    }
+    for pred in qo.iter() {
+        if vo.contains(pred) {
+            continue;
+        }
+        predicates.push(rewrite_by_selection(query_info, pred, view_selection)?);
+    }
+    Some(predicates)
+}
+
+/// Create `EvalScalar -> [Filter] -> Scan` reading from the view.
+///
+/// The columns in `items` and `predicates` are the positions of the view outputs,
+/// which will be replaced by the columns of the view table.
+fn create_view_input(
+    metadata: &MetadataRef,
+    view: &MaterializedViewEntry,
+    mut items: Vec<ScalarItem>,
+    mut predicates: Vec<ScalarExpr>,
+) -> Result<Option<SExpr>> {
+    let mut positions = ColumnSet::new();
+    for scalar in items
+        .iter()
+        .map(|item| &item.scalar)
+        .chain(predicates.iter())
+    {
+        positions.extend(scalar.used_columns());
+    }
+    if positions.is_empty() {
+        return Ok(None);
+    }
+
+    let mut metadata = metadata.write();
+    let table_index = metadata.add_table(
+        view.catalog.clone(),
+        view.database.clone(),
+        view.table.clone(),
+        None,
+        true,
+        false,
+        false,
+    );
+    let columns = metadata
+        .columns_by_table_index(table_index)
+        .into_iter()
+        .filter_map(|column| match column {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                column_index,
+                column_name,
+                path_indices: None,
+                ..
+            }) => Some((column_name, column_index)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let schema = view.table.schema();
+    let mut column_indexes = Vec::with_capacity(schema.fields().len());
+    for field in schema.fields().iter() {
+        match columns.get(field.name()) {
+            Some(index) => column_indexes.push((field.name().clone(), *index)),
+            None => return Ok(None),
+        }
+    }
+
+    struct RewriteVisitor<'a> {
+        table_index: IndexType,
+        database: &'a str,
+        table: &'a str,
+        columns: &'a [(String, IndexType)],
+    }
+
+    impl<'a> VisitorMut<'a> for RewriteVisitor<'a> {
+        fn visit_bound_column_ref(&mut self, col: &'a mut BoundColumnRef) -> Result<()> {
+            let (name, index) = &self.columns[col.column.index];
+            col.column.index = *index;
+            col.column.column_name = name.clone();
+            col.column.table_index = Some(self.table_index);
+            col.column.database_name = Some(self.database.to_string());
+            col.column.table_name = Some(self.table.to_string());
+            Ok(())
+        }
+    }
+
+    for scalar in items
+        .iter_mut()
+        .map(|item| &mut item.scalar)
+        .chain(predicates.iter_mut())
+    {
+        let mut visitor = RewriteVisitor {
+            table_index,
+            database: &view.database,
+            table: view.table.name(),
+            columns: &column_indexes,
+        };
+        visitor.visit(scalar)?;
+    }
+
+    let mut s_expr = SExpr::create_leaf(Arc::new(
+        Scan {
+            table_index,
+            columns: positions
+                .iter()
+                .map(|position| column_indexes[*position].1)
+                .collect(),
+            statistics: Statistics {
+                statistics: view.statistics,
+                col_stats: HashMap::new(),
+            },
+            ..Default::default()
+        }
+        .into(),
+    ));
+    if !predicates.is_empty() {
+        s_expr = SExpr::create_unary(Arc::new(Filter { predicates }.into()), Arc::new(s_expr));
+    }
+    Ok(Some(SExpr::create_unary(
+        Arc::new(EvalScalar { items }.into()),
+        Arc::new(s_expr),
+    )))
+}
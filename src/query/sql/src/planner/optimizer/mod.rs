@@ -20,6 +20,7 @@ mod format;
 mod group;
 mod hyper_dp;
 mod m_expr;
+mod materialized_view;
 mod memo;
 #[allow(clippy::module_inception)]
 mod optimizer;
@@ -33,8 +33,10 @@ use crate::optimizer::decorrelate::decorrelate_subquery;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::distributed::SortAndLimitPushDownOptimizer;
 use crate::optimizer::hyper_dp::DPhpy;
+use crate::optimizer::materialized_view;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::util::contains_recursive_cte;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -215,13 +217,19 @@ pub fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
 
 pub fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<SExpr> {
     let enable_distributed_query = opt_ctx.enable_distributed_optimization
-        && !contains_local_table_scan(&s_expr, &opt_ctx.metadata);
+        && !contains_local_table_scan(&s_expr, &opt_ctx.metadata)
+        && !contains_recursive_cte(&s_expr);
 
     // Decorrelate subqueries, after this step, there should be no subquery in the expression.
     if s_expr.contain_subquery() {
         s_expr = decorrelate_subquery(opt_ctx.metadata.clone(), s_expr.clone())?;
     }
 
+    // Answer the query by a materialized view if possible.
+    if let Some(rewritten) = materialized_view::try_rewrite(&opt_ctx.metadata, &s_expr)? {
+        s_expr = rewritten;
+    }
+
     // Run default rewrite rules
     s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, &opt_ctx).run(&s_expr)?;
 
@@ -281,7 +289,8 @@ pub fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<SE
 // TODO(leiysky): reuse the optimization logic with `optimize_query`
 fn get_optimized_memo(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<Memo> {
     let enable_distributed_query = opt_ctx.enable_distributed_optimization
-        && !contains_local_table_scan(&s_expr, &opt_ctx.metadata);
+        && !contains_local_table_scan(&s_expr, &opt_ctx.metadata)
+        && !contains_recursive_cte(&s_expr);
 
     // Decorrelate subqueries, after this step, there should be no subquery in the expression.
     if s_expr.contain_subquery() {
@@ -291,7 +291,7 @@ pub fn try_rewrite(
 }
 
 /// Rewrite base column index in the original index plan by `columns`.
-fn rewrite_index_plan(
+pub(crate) fn rewrite_index_plan(
     table_index: IndexType,
     columns: &HashMap<String, IndexType>,
     s_expr: &SExpr,
@@ -386,7 +386,7 @@ fn rewrite_scalar_index(
 ///
 /// Notes that only conjunctions will be parsed, and disjunctions will be ignored.
 #[derive(Default, Debug)]
-struct Range<'a> {
+pub(crate) struct Range<'a> {
     min: Option<&'a Scalar>,
     min_close: bool,
     max: Option<&'a Scalar>,
@@ -678,14 +678,14 @@ impl<'a> Range<'a> {
 }
 
 /// Each element is the operands of each equal predicate.
-type EqualPredicates<'a> = Vec<(&'a ScalarExpr, &'a ScalarExpr)>;
+pub(crate) type EqualPredicates<'a> = Vec<(&'a ScalarExpr, &'a ScalarExpr)>;
 /// Each element is the operands and the operator of each range predicate.
 /// Currently, range predicates should have one column and one constant.
-type RangePredicates<'a> = HashMap<IndexType, Range<'a>>;
+pub(crate) type RangePredicates<'a> = HashMap<IndexType, Range<'a>>;
 /// Each element is the full expression of each other predicate .
-type OtherPredicates<'a> = Vec<&'a ScalarExpr>;
+pub(crate) type OtherPredicates<'a> = Vec<&'a ScalarExpr>;
 
-type Predicates<'a> = (
+pub(crate) type Predicates<'a> = (
     EqualPredicates<'a>,
     RangePredicates<'a>,
     OtherPredicates<'a>,
@@ -697,7 +697,7 @@ type AggregationInfo<'a> = (&'a Aggregate, HashMap<IndexType, &'a ScalarExpr>);
 ///
 /// - Key: the formatted expression.
 /// - Value: (index, data type) of the expression
-type SelectionMap<'a> = HashMap<String, (IndexType, DataType)>;
+pub(crate) type SelectionMap<'a> = HashMap<String, (IndexType, DataType)>;
 
 // Record information helping to rewrite the query plan.
 pub struct RewriteInfomartion<'a> {
@@ -720,7 +720,7 @@ impl RewriteInfomartion<'_> {
         cols
     }
 
-    fn can_apply_index(&self) -> bool {
+    pub(crate) fn can_apply_index(&self) -> bool {
         if let Some((agg, _)) = self.aggregation {
             if agg.grouping_sets.is_some() {
                 // Grouping sets is not supported.
@@ -731,7 +731,7 @@ impl RewriteInfomartion<'_> {
         true
     }
 
-    fn formatted_group_items(&self) -> Vec<String> {
+    pub(crate) fn formatted_group_items(&self) -> Vec<String> {
         if let Some((agg, _)) = self.aggregation {
             let mut cols = Vec::with_capacity(agg.group_items.len());
             for item in agg.group_items.iter() {
@@ -786,7 +786,7 @@ impl RewriteInfomartion<'_> {
         col
     }
 
-    fn format_scalar(&self, scalar: &ScalarExpr) -> String {
+    pub(crate) fn format_scalar(&self, scalar: &ScalarExpr) -> String {
         match scalar {
             ScalarExpr::BoundColumnRef(_) => match self.actual_column_ref(scalar) {
                 ScalarExpr::BoundColumnRef(col) => format_col_name(col.column.index),
@@ -837,7 +837,7 @@ impl RewriteInfomartion<'_> {
     }
 }
 
-fn collect_information(s_expr: &SExpr) -> Result<RewriteInfomartion<'_>> {
+pub(crate) fn collect_information(s_expr: &SExpr) -> Result<RewriteInfomartion<'_>> {
     // The plan tree should be started with [`EvalScalar`].
     if let RelOperator::EvalScalar(eval) = s_expr.plan() {
         let mut info = RewriteInfomartion {
@@ -899,7 +899,7 @@ fn collect_information_impl<'a>(
 /// 1. `Equal`. Such as `column = constant`.
 /// 2. `Range`. Such as `column op constant`m `op` should be `gt`, `gte`, `lt` or `lte`.
 /// 3. `Other`. Predicates except `Equal` and `Range`.
-fn distinguish_predicates(predicates: &[ScalarExpr]) -> Predicates<'_> {
+pub(crate) fn distinguish_predicates(predicates: &[ScalarExpr]) -> Predicates<'_> {
     let mut equal_predicates = vec![];
     let mut range_predicates = HashMap::new();
     let mut other_predicates = vec![];
@@ -945,7 +945,7 @@ fn distinguish_predicates(predicates: &[ScalarExpr]) -> Predicates<'_> {
 }
 
 #[inline(always)]
-fn format_col_name(index: IndexType) -> String {
+pub(crate) fn format_col_name(index: IndexType) -> String {
     format!("col_{index}")
 }
 
@@ -963,7 +963,7 @@ fn reverse_op(op: &str) -> String {
 /// Check if equal predicates of the index fit the query.
 ///
 /// For each predicate of index, it should be in the query.
-fn check_predicates_equal(query: &EqualPredicates, index: &EqualPredicates) -> bool {
+pub(crate) fn check_predicates_equal(query: &EqualPredicates, index: &EqualPredicates) -> bool {
     // TBD: if there is a better way.
     for (left, right) in index {
         if !query
@@ -990,7 +990,7 @@ fn check_predicates_equal(query: &EqualPredicates, index: &EqualPredicates) -> b
 ///
 /// - If not matched, returns [None].
 /// - If matched, returns columns need to be filtered.
-fn check_predicates_range(
+pub(crate) fn check_predicates_range(
     query: &RangePredicates,
     index: &RangePredicates,
     index_output_bound_cols: &ColumnSet,
@@ -1035,7 +1035,7 @@ fn check_predicates_range(
 /// Check if other predicates of the index fit the query.
 ///
 /// For each predicate of index, it should be in the query.
-fn check_predicates_other(query: &OtherPredicates, index: &OtherPredicates) -> bool {
+pub(crate) fn check_predicates_other(query: &OtherPredicates, index: &OtherPredicates) -> bool {
     // TBD: if there is a better way.
     for pred in index {
         if !query.iter().any(|p| p == pred) {
@@ -1045,7 +1045,7 @@ fn check_predicates_other(query: &OtherPredicates, index: &OtherPredicates) -> b
     true
 }
 
-fn try_create_column_binding(
+pub(crate) fn try_create_column_binding(
     index_selection: &SelectionMap<'_>,
     formatted_scalar: &str,
 ) -> Option<BoundColumnRef> {
@@ -1065,7 +1065,7 @@ fn try_create_column_binding(
     }
 }
 
-fn rewrite_by_selection(
+pub(crate) fn rewrite_by_selection(
     query_info: &RewriteInfomartion<'_>,
     scalar: &ScalarExpr,
     index_selection: &SelectionMap<'_>,
@@ -41,6 +41,7 @@ mod rule_push_down_limit_sort;
 mod rule_push_down_limit_union;
 mod rule_push_down_prewhere;
 mod rule_push_down_sort_scan;
+mod rule_push_down_vector_search_scan;
 mod rule_semi_to_inner_join;
 mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
@@ -73,6 +74,7 @@ pub use rule_push_down_limit_sort::RulePushDownLimitSort;
 pub use rule_push_down_limit_union::RulePushDownLimitUnion;
 pub use rule_push_down_prewhere::RulePushDownPrewhere;
 pub use rule_push_down_sort_scan::RulePushDownSortScan;
+pub use rule_push_down_vector_search_scan::RulePushDownVectorSearchScan;
 pub use rule_semi_to_inner_join::RuleSemiToInnerJoin;
 pub use rule_split_aggregate::RuleSplitAggregate;
 pub use rule_try_apply_agg_index::RuleTryApplyAggIndex;
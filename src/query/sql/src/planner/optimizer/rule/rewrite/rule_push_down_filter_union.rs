@@ -93,6 +93,11 @@ impl Rule for RulePushDownFilterUnion {
         let filter: Filter = s_expr.plan().clone().try_into()?;
         let union_s_expr = s_expr.child(0)?;
         let union: UnionAll = union_s_expr.plan().clone().try_into()?;
+        if union.is_recursive() {
+            // The rows of the recursive member are fed back into the next iteration,
+            // filtering them would change the result of the recursive cte.
+            return Ok(());
+        }
 
         // Create a filter which matches union's right child.
         let index_pairs: HashMap<IndexType, IndexType> =
@@ -95,7 +95,7 @@ impl Rule for RulePushDownLimitOuterJoin {
             let child = s_expr.child(0)?;
             let join: Join = child.plan().clone().try_into()?;
             match join.join_type {
-                JoinType::Left => {
+                JoinType::Left | JoinType::LeftAsof => {
                     let child = child.replace_children(vec![
                         Arc::new(SExpr::create_unary(
                             Arc::new(RelOperator::Limit(limit.clone())),
@@ -82,7 +82,7 @@ impl Rule for RulePushDownLimitUnion {
         let union_s_expr = s_expr.child(0)?;
         let union: UnionAll = union_s_expr.plan().clone().try_into()?;
 
-        if limit.limit.is_none() {
+        if limit.limit.is_none() || union.is_recursive() {
             return Ok(());
         }
         // Create limit which will be pushed down
@@ -75,7 +75,7 @@ impl Rule for RulePushDownSortScan {
         if get.order_by.is_none() {
             get.order_by = Some(sort.items);
         }
-        if let Some(limit) = sort.limit {
+        if let (Some(limit), None) = (sort.limit, &get.sample) {
             get.limit = Some(get.limit.map_or(limit, |c| cmp::max(c, limit)));
         }
         let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
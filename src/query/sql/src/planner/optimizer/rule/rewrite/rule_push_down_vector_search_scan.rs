@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+use crate::plans::Sort;
+
+/// Input:  Sort(limit)
+///           \
+///          EvalScalar(distance(col, const))
+///             \
+///            LogicalGet
+///
+/// Output:
+///         Sort(limit)
+///           \
+///          EvalScalar(distance(col, const))
+///             \
+///            LogicalGet(padding vector_search and limit)
+pub struct RulePushDownVectorSearchScan {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+}
+
+impl RulePushDownVectorSearchScan {
+    pub fn new() -> Self {
+        Self {
+            id: RuleID::PushDownVectorSearchScan,
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Sort,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
+                )),
+            )],
+        }
+    }
+
+    /// Returns the distance function call if it is `distance(col, const)` and `col` is a
+    /// column of the scan.
+    fn vector_search(scalar: &ScalarExpr, scan: &Scan) -> Option<ScalarExpr> {
+        let ScalarExpr::FunctionCall(func) = scalar else {
+            return None;
+        };
+        if !matches!(func.func_name.as_str(), "cosine_distance" | "l2_distance")
+            || func.arguments.len() != 2
+        {
+            return None;
+        }
+
+        let column = match &func.arguments[0] {
+            ScalarExpr::BoundColumnRef(column) => column,
+            ScalarExpr::CastExpr(cast) => match cast.argument.as_ref() {
+                ScalarExpr::BoundColumnRef(column) => column,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if !scan.columns.contains(&column.column.index)
+            || !matches!(func.arguments[1], ScalarExpr::ConstantExpr(_))
+        {
+            return None;
+        }
+        Some(scalar.clone())
+    }
+}
+
+impl Rule for RulePushDownVectorSearchScan {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut get: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+
+        // Only `ORDER BY distance ASC LIMIT n` can be answered by the nearest neighbors.
+        let (Some(limit), [item]) = (sort.limit, sort.items.as_slice()) else {
+            return Ok(());
+        };
+        if !item.asc || item.nulls_first || get.vector_search.is_some() || get.sample.is_some() {
+            return Ok(());
+        }
+        let Some(vector_search) = eval_scalar
+            .items
+            .iter()
+            .find(|scalar_item| scalar_item.index == item.index)
+            .and_then(|scalar_item| Self::vector_search(&scalar_item.scalar, &get))
+        else {
+            return Ok(());
+        };
+
+        get.vector_search = Some(vector_search);
+        get.limit = Some(get.limit.map_or(limit, |c| c.max(limit)));
+        let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
+
+        let mut result = s_expr.replace_children(vec![Arc::new(
+            eval_scalar_expr.replace_children(vec![Arc::new(get)]),
+        )]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
@@ -145,6 +145,7 @@ fn find_group_by_keys(child: &SExpr, group_by_keys: &mut HashSet<IndexType>) ->
         | RelOperator::Udf(_)
         | RelOperator::Scan(_)
         | RelOperator::CteScan(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::Join(_)
         | RelOperator::Pattern(_) => {}
     }
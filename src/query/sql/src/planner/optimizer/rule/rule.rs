@@ -42,6 +42,7 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownLimitAggregate,
         RuleID::PushDownLimitOuterJoin,
         RuleID::PushDownLimitScan,
+        RuleID::PushDownVectorSearchScan,
         RuleID::PushDownFilterSort,
         RuleID::PushDownFilterEvalScalar,
         RuleID::PushDownFilterJoin,
@@ -94,6 +95,7 @@ pub enum RuleID {
     PushDownLimitAggregate,
     PushDownLimitScan,
     PushDownSortScan,
+    PushDownVectorSearchScan,
     SemiToInnerJoin,
     EliminateEvalScalar,
     EliminateFilter,
@@ -129,6 +131,7 @@ impl Display for RuleID {
             RuleID::PushDownFilterAggregate => write!(f, "PushDownFilterAggregate"),
             RuleID::PushDownLimitScan => write!(f, "PushDownLimitScan"),
             RuleID::PushDownSortScan => write!(f, "PushDownSortScan"),
+            RuleID::PushDownVectorSearchScan => write!(f, "PushDownVectorSearchScan"),
             RuleID::PushDownFilterWindow => write!(f, "PushDownFilterWindow"),
             RuleID::EliminateEvalScalar => write!(f, "EliminateEvalScalar"),
             RuleID::EliminateFilter => write!(f, "EliminateFilter"),
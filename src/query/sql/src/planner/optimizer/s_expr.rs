@@ -361,6 +361,7 @@ impl SExpr {
             | RelOperator::Sort(_)
             | RelOperator::DummyTableScan(_)
             | RelOperator::CteScan(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::AddRowNumber(_)
             | RelOperator::Pattern(_)
             | RelOperator::MaterializedCte(_)
@@ -427,6 +428,7 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::CteScan(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::AddRowNumber(_)
         | RelOperator::Pattern(_)
         | RelOperator::MaterializedCte(_)
@@ -28,3 +28,13 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
             false
         }
 }
+
+/// Check if a query contains a recursive cte, which can only be executed on the local node.
+pub fn contains_recursive_cte(s_expr: &SExpr) -> bool {
+    s_expr.children().iter().any(contains_recursive_cte)
+        || match s_expr.plan() {
+            RelOperator::UnionAll(union_all) => union_all.is_recursive(),
+            RelOperator::RecursiveCteScan(_) => true,
+            _ => false,
+        }
+}
@@ -63,6 +63,28 @@ impl Planner {
     #[async_backtrace::framed]
     #[minitrace::trace]
     pub async fn plan_sql(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
+        let res = self.try_plan_sql(sql).await;
+        self.fail_txn_on_error(res)
+    }
+
+    /// Plan a parsed statement, e.g. a prepared statement with the parameters bound.
+    #[async_backtrace::framed]
+    pub async fn plan_stmt(&mut self, stmt: Statement) -> Result<(Plan, PlanExtras)> {
+        let res = self.try_plan_stmt(stmt).await;
+        self.fail_txn_on_error(res)
+    }
+
+    /// A statement failing to be bound or planned in an explicit transaction fails the
+    /// transaction, as if it failed to execute.
+    fn fail_txn_on_error<T>(&self, res: Result<T>) -> Result<T> {
+        if res.is_err() {
+            self.ctx.txn_mgr().lock().set_fail();
+        }
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn try_plan_sql(&mut self, sql: &str) -> Result<(Plan, PlanExtras)> {
         let settings = self.ctx.get_settings();
         let sql_dialect = settings.get_sql_dialect()?;
         // Step 1: Tokenize the SQL.
@@ -91,41 +113,10 @@ impl Planner {
         loop {
             let res = async {
                 // Step 2: Parse the SQL.
-                let (mut stmt, format) = parse_sql(&tokens, sql_dialect)?;
-
-                if matches!(stmt, Statement::CopyIntoLocation(_)) {
-                    // Indicate binder there is no need to collect column statistics for the binding table.
-                    self.ctx
-                        .attach_query_str(QueryKind::CopyIntoTable, String::new());
-                }
-
-                self.replace_stmt(&mut stmt, sql_dialect);
-
-                // Step 3: Bind AST with catalog, and generate a pure logical SExpr
-                let metadata = Arc::new(RwLock::new(Metadata::default()));
-                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-                let binder = Binder::new(
-                    self.ctx.clone(),
-                    CatalogManager::instance(),
-                    name_resolution_ctx,
-                    metadata.clone(),
-                );
-                let plan = binder.bind(&stmt).await?;
-
-                // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
-                let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
-                    .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
-                    .with_enable_join_reorder(unsafe {
-                        !self.ctx.get_settings().get_disable_join_reorder()?
-                    })
-                    .with_enable_dphyp(self.ctx.get_settings().get_enable_dphyp()?);
-
-                let optimized_plan = optimize(opt_ctx, plan)?;
-                Ok((optimized_plan, PlanExtras {
-                    metadata,
-                    format,
-                    statement: stmt,
-                }))
+                let (stmt, format) = parse_sql(&tokens, sql_dialect)?;
+                let (plan, mut extras) = self.try_plan_stmt(stmt).await?;
+                extras.format = format;
+                Ok((plan, extras))
             }
             .await;
 
@@ -169,6 +160,43 @@ impl Planner {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn try_plan_stmt(&mut self, mut stmt: Statement) -> Result<(Plan, PlanExtras)> {
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+        if matches!(stmt, Statement::CopyIntoLocation(_)) {
+            // Indicate binder there is no need to collect column statistics for the binding table.
+            self.ctx
+                .attach_query_str(QueryKind::CopyIntoTable, String::new());
+        }
+
+        self.replace_stmt(&mut stmt, sql_dialect);
+
+        // Step 3: Bind AST with catalog, and generate a pure logical SExpr
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        );
+        let plan = binder.bind(&stmt).await?;
+
+        // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
+        let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
+            .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
+            .with_enable_join_reorder(unsafe { !settings.get_disable_join_reorder()? })
+            .with_enable_dphyp(settings.get_enable_dphyp()?);
+
+        let optimized_plan = optimize(opt_ctx, plan)?;
+        Ok((optimized_plan, PlanExtras {
+            metadata,
+            format: None,
+            statement: stmt,
+        }))
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_ast::ast::TableIndexType;
+use databend_common_expression::ColumnId;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_types::MetaId;
@@ -49,3 +50,38 @@ pub struct RefreshIndexPlan {
     pub segment_locs: Option<Vec<Location>>,
     pub user_defined_block_name: bool,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshVectorIndexPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateInvertedIndexPlan {
+    pub if_not_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+    pub column_ids: Vec<ColumnId>,
+    pub tokenizer: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropInvertedIndexPlan {
+    pub if_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshInvertedIndexPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+}
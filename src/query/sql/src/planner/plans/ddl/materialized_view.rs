@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::TableSchemaRef;
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub schema: TableSchemaRef,
+    /// The defining query, with all table references qualified.
+    pub query: String,
+    pub source_database: String,
+    pub source_table: String,
+    pub source_table_id: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropMaterializedViewPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
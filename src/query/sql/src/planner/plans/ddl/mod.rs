@@ -18,6 +18,8 @@ mod connection;
 mod database;
 mod file_format;
 mod index;
+mod materialized_view;
+mod pipe;
 mod stage;
 mod stream;
 mod table;
@@ -32,6 +34,8 @@ pub use connection::*;
 pub use database::*;
 pub use file_format::*;
 pub use index::*;
+pub use materialized_view::*;
+pub use pipe::*;
 pub use stage::*;
 pub use stream::*;
 pub use table::*;
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatePipePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub auto_ingest: bool,
+    pub comment: String,
+    pub copy_stmt: String,
+}
+
+/// `ALTER PIPE ... REFRESH` is bound to the `COPY INTO <table>` plan of the pipe,
+/// only the options of the pipe are altered by this plan.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterPipePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub execution_paused: Option<bool>,
+    pub comment: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropPipePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescPipePlan {
+    pub name: String,
+}
+
+impl DescPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("auto_ingest", DataType::Boolean),
+            DataField::new("execution_paused", DataType::Boolean),
+            DataField::new("comment", DataType::String),
+            DataField::new("definition", DataType::String),
+            DataField::new("created_on", DataType::Timestamp),
+            DataField::new(
+                "updated_on",
+                DataType::Nullable(Box::new(DataType::Timestamp)),
+            ),
+        ])
+    }
+}
@@ -220,6 +220,40 @@ impl SetOptionsPlan {
     }
 }
 
+/// Add partition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddTablePartitionPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub if_not_exists: bool,
+    /// Partition column names and values.
+    pub partition: Vec<(String, String)>,
+}
+
+impl AddTablePartitionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Drop partition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTablePartitionPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub if_exists: bool,
+    /// Partition column names and values.
+    pub partition: Vec<(String, String)>,
+}
+
+impl DropTablePartitionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // Table add column
 #[derive(Clone, Debug, PartialEq)]
 pub struct AddTableColumnPlan {
@@ -82,6 +82,7 @@ pub struct CreateTaskPlan {
     pub after: Vec<String>,
     pub when_condition: Option<String>,
     pub suspend_task_after_num_failures: Option<u64>,
+    pub error_integration: Option<String>,
     pub sql: String,
     pub comment: String,
 }
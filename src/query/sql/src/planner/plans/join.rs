@@ -61,6 +61,10 @@ pub enum JoinType {
     /// Single Join is a special kind of join that is used to process correlated scalar subquery.
     LeftSingle,
     RightSingle,
+    /// Asof Join matches each row of the left side with the closest row of the right side
+    /// according to the match condition, it's executed by the range join.
+    Asof,
+    LeftAsof,
 }
 
 impl JoinType {
@@ -87,6 +91,10 @@ impl JoinType {
     pub fn is_mark_join(&self) -> bool {
         matches!(self, JoinType::LeftMark | JoinType::RightMark)
     }
+
+    pub fn is_asof_join(&self) -> bool {
+        matches!(self, JoinType::Asof | JoinType::LeftAsof)
+    }
 }
 
 impl Display for JoinType {
@@ -131,6 +139,12 @@ impl Display for JoinType {
             JoinType::RightSingle => {
                 write!(f, "RIGHT SINGLE")
             }
+            JoinType::Asof => {
+                write!(f, "ASOF")
+            }
+            JoinType::LeftAsof => {
+                write!(f, "LEFT ASOF")
+            }
         }
     }
 }
@@ -472,9 +486,14 @@ impl Operator for Join {
                     + f64::max(right_cardinality, inner_join_cardinality)
                     - inner_join_cardinality
             }
-            JoinType::LeftSemi => f64::min(left_cardinality, inner_join_cardinality),
+            JoinType::LeftSemi | JoinType::Asof => {
+                f64::min(left_cardinality, inner_join_cardinality)
+            }
             JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
-            JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
+            JoinType::LeftSingle
+            | JoinType::RightMark
+            | JoinType::LeftAnti
+            | JoinType::LeftAsof => left_cardinality,
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
@@ -507,12 +526,14 @@ impl Operator for Join {
         let probe_physical_prop = rel_expr.derive_physical_prop_child(0)?;
         let build_physical_prop = rel_expr.derive_physical_prop_child(1)?;
 
-        // if join/probe side is Serial or this is a non-equi join, we use Serial distribution
+        // if join/probe side is Serial or this is a non-equi join, we use Serial distribution.
+        // Asof join is executed by range join, which needs all rows of both sides.
         if probe_physical_prop.distribution == Distribution::Serial
             || build_physical_prop.distribution == Distribution::Serial
             || (self.left_conditions.is_empty()
                 && self.right_conditions.is_empty()
                 && !self.non_equi_conditions.is_empty())
+            || self.join_type.is_asof_join()
         {
             // TODO(leiysky): we can enforce redistribution here
             required.distribution = Distribution::Serial;
@@ -568,7 +589,10 @@ impl Operator for Join {
     ) -> Result<Vec<Vec<RequiredProperty>>> {
         let mut children_required = vec![];
 
-        if self.join_type != JoinType::Cross && !ctx.get_settings().get_enforce_broadcast_join()? {
+        if self.join_type != JoinType::Cross
+            && !self.join_type.is_asof_join()
+            && !ctx.get_settings().get_enforce_broadcast_join()?
+        {
             // (Hash, Hash)
             children_required.extend(
                 self.left_conditions
@@ -595,6 +619,8 @@ impl Operator for Join {
                 | JoinType::RightSemi
                 | JoinType::RightMark
                 | JoinType::RightSingle
+                | JoinType::Asof
+                | JoinType::LeftAsof
         ) {
             // (Any, Broadcast)
             let left_distribution = Distribution::Any;
@@ -40,6 +40,7 @@ mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte_scan;
 mod replace;
 mod revert_table;
 mod scalar_expr;
@@ -84,6 +85,7 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte_scan::RecursiveCteScan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
 pub use scalar_expr::*;
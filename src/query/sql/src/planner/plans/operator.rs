@@ -39,6 +39,7 @@ use crate::plans::ConstantTableScan;
 use crate::plans::CteScan;
 use crate::plans::Exchange;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCteScan;
 use crate::plans::Udf;
 use crate::plans::Window;
 
@@ -103,6 +104,7 @@ pub enum RelOp {
     ConstantTableScan,
     AddRowNumber,
     Udf,
+    RecursiveCteScan,
 
     // Pattern
     Pattern,
@@ -128,6 +130,7 @@ pub enum RelOperator {
     MaterializedCte(MaterializedCte),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
+    RecursiveCteScan(RecursiveCteScan),
     Pattern(PatternPlan),
 }
 
@@ -152,6 +155,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::AddRowNumber(rel_op) => rel_op.rel_op(),
             RelOperator::Udf(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -174,6 +178,7 @@ impl Operator for RelOperator {
             RelOperator::MaterializedCte(rel_op) => rel_op.arity(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.arity(),
             RelOperator::Udf(rel_op) => rel_op.arity(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.arity(),
             RelOperator::Pattern(rel_op) => rel_op.arity(),
         }
     }
@@ -198,6 +203,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -221,6 +227,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -244,6 +251,7 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_stats(rel_expr),
         }
     }
 
@@ -309,6 +317,9 @@ impl Operator for RelOperator {
             RelOperator::Udf(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 
@@ -370,6 +381,9 @@ impl Operator for RelOperator {
             RelOperator::Udf(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
             RelOperator::Pattern(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
@@ -695,3 +709,23 @@ impl TryFrom<RelOperator> for Udf {
         }
     }
 }
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
@@ -28,8 +28,10 @@ use super::SetSecondaryRolesPlan;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTablePartitionPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterPipePlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
@@ -46,8 +48,11 @@ use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateDatamaskPolicyPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::CreatePipePlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
@@ -64,6 +69,7 @@ use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
 use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescPipePlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -73,8 +79,11 @@ use crate::plans::DropDatabasePlan;
 use crate::plans::DropDatamaskPolicyPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
+use crate::plans::DropMaterializedViewPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::DropPipePlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
@@ -82,6 +91,7 @@ use crate::plans::DropStagePlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
@@ -101,6 +111,9 @@ use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshInvertedIndexPlan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::plans::RefreshVectorIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
@@ -206,6 +219,8 @@ pub enum Plan {
     AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
     SetOptions(Box<SetOptionsPlan>),
+    AddTablePartition(Box<AddTablePartitionPlan>),
+    DropTablePartition(Box<DropTablePartitionPlan>),
 
     // Insert
     Insert(Box<Insert>),
@@ -218,6 +233,11 @@ pub enum Plan {
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Materialized views
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    DropMaterializedView(Box<DropMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
     // Streams
     CreateStream(Box<CreateStreamPlan>),
     DropStream(Box<DropStreamPlan>),
@@ -226,6 +246,10 @@ pub enum Plan {
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
+    RefreshVectorIndex(Box<RefreshVectorIndexPlan>),
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
+    RefreshInvertedIndex(Box<RefreshInvertedIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumn(Box<CreateVirtualColumnPlan>),
@@ -279,6 +303,11 @@ pub enum Plan {
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Share
     CreateShareEndpoint(Box<CreateShareEndpointPlan>),
     ShowShareEndpoint(Box<ShowShareEndpointPlan>),
@@ -311,6 +340,12 @@ pub enum Plan {
     DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
     DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
 
+    // Pipe
+    CreatePipe(Box<CreatePipePlan>),
+    AlterPipe(Box<AlterPipePlan>),
+    DropPipe(Box<DropPipePlan>),
+    DescPipe(Box<DescPipePlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -421,6 +456,7 @@ impl Plan {
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
             Plan::DescPasswordPolicy(plan) => plan.schema(),
+            Plan::DescPipe(plan) => plan.schema(),
             Plan::CopyIntoTable(plan) => plan.schema(),
             Plan::CopyIntoLocation(plan) => plan.schema(),
             Plan::MergeInto(plan) => plan.schema(),
@@ -466,6 +502,7 @@ impl Plan {
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
                 | Plan::DescPasswordPolicy(_)
+                | Plan::DescPipe(_)
                 | Plan::CopyIntoTable(_)
                 | Plan::CopyIntoLocation(_)
                 | Plan::ShowTasks(_)
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::SampleDesc;
 use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_context::TableContext;
@@ -97,6 +98,10 @@ pub struct Scan {
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
     pub change_type: Option<ChangeType>,
+    pub sample: Option<SampleDesc>,
+    // top-k distance search, e.g. `cosine_distance(col, const)`, which can be answered by
+    // the vector indexes of the table
+    pub vector_search: Option<ScalarExpr>,
 
     pub statistics: Statistics,
 }
@@ -124,6 +129,8 @@ impl Scan {
             prewhere,
             agg_index: self.agg_index.clone(),
             change_type: self.change_type.clone(),
+            sample: self.sample.clone(),
+            vector_search: self.vector_search.clone(),
         }
     }
 
@@ -253,9 +260,17 @@ impl Operator for Scan {
             (Some(precise_cardinality), None) => precise_cardinality as f64,
             (_, _) => 0.0,
         };
+        let cardinality = match &self.sample {
+            Some(sample) => {
+                let block_probability = sample.block_probability.map_or(1.0, |p| p.0);
+                let row_probability = sample.row_probability.map_or(1.0, |p| p.0);
+                cardinality * block_probability * row_probability
+            }
+            None => cardinality,
+        };
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        // If prewhere or sample is not none, we can't get precise cardinality
+        let precise_cardinality = if self.prewhere.is_none() && self.sample.is_none() {
             precise_cardinality
         } else {
             None
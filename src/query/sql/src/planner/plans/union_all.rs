@@ -33,6 +33,10 @@ use crate::IndexType;
 pub struct UnionAll {
     // Pairs of unioned columns
     pub pairs: Vec<(IndexType, IndexType)>,
+    // Names of the recursive ctes scanned by the right child.
+    // If it's not empty, the union is the body of a recursive cte: the left child is
+    // the anchor member and the right child is the recursive member.
+    pub cte_scan_names: Vec<String>,
 }
 
 impl UnionAll {
@@ -44,6 +48,10 @@ impl UnionAll {
         }
         Ok(used_columns)
     }
+
+    pub fn is_recursive(&self) -> bool {
+        !self.cte_scan_names.is_empty()
+    }
 }
 
 impl Operator for UnionAll {
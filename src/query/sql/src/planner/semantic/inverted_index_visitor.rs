@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Lambda;
+use databend_common_ast::ast::Window;
+use databend_common_ast::walk_expr;
+use databend_common_ast::Visitor;
+use databend_common_exception::Span;
+
+/// Find the arguments of the first `match(column, query)` in the `WHERE` clause,
+/// so that `score()` can be rewritten to score the same column and query.
+#[derive(Default)]
+pub(crate) struct InvertedIndexMatchFinder {
+    pub(crate) args: Option<(Expr, Expr)>,
+}
+
+impl<'ast> Visitor<'ast> for InvertedIndexMatchFinder {
+    fn visit_function_call(
+        &mut self,
+        _span: Span,
+        _distinct: bool,
+        name: &'ast Identifier,
+        args: &'ast [Expr],
+        params: &'ast [Expr],
+        _over: &'ast Option<Window>,
+        _lambda: &'ast Option<Lambda>,
+    ) {
+        if let [column, query] = args {
+            if self.args.is_none() && name.name.eq_ignore_ascii_case("match") {
+                self.args = Some((column.clone(), query.clone()));
+            }
+        }
+
+        for arg in args {
+            walk_expr(self, arg);
+        }
+        for param in params {
+            walk_expr(self, param);
+        }
+    }
+}
@@ -16,6 +16,7 @@ mod aggregate_rewriter;
 mod aggregating_index_visitor;
 mod distinct_to_groupby;
 mod grouping_check;
+mod inverted_index_visitor;
 mod lowering;
 mod name_resolution;
 mod type_check;
@@ -30,6 +31,7 @@ pub use aggregating_index_visitor::AggregatingIndexRewriter;
 pub use aggregating_index_visitor::RefreshAggregatingIndexRewriter;
 pub use distinct_to_groupby::DistinctToGroupBy;
 pub use grouping_check::GroupingChecker;
+pub(crate) use inverted_index_visitor::InvertedIndexMatchFinder;
 pub use lowering::*;
 pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
@@ -74,6 +74,7 @@ use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFServer;
 use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::InvertedIndexes;
 use indexmap::IndexMap;
 use itertools::Itertools;
 use jsonb::keypath::KeyPath;
@@ -1008,12 +1009,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit).await?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -2248,6 +2244,35 @@ impl<'a> TypeChecker<'a> {
         self.resolve_scalar_function_call(span, &func_name, vec![], args)
     }
 
+    /// Resolve `INTERVAL expr unit` to an interval value, e.g. `INTERVAL 3 DAY`
+    /// is resolved to `to_interval(concat(to_string(3), ' day'))`.
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    pub async fn resolve_interval(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        unit: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if matches!(unit, ASTIntervalKind::Doy | ASTIntervalKind::Dow) {
+            return Err(
+                ErrorCode::SemanticError(format!("Unsupported interval unit {unit}"))
+                    .set_span(span),
+            );
+        }
+        let (value, _) = *self.resolve(expr).await?;
+        let (value, _) =
+            *self.resolve_scalar_function_call(span, "to_string", vec![], vec![value])?;
+        let unit = ConstantExpr {
+            span,
+            value: Scalar::String(format!(" {}", unit.to_string().to_lowercase())),
+        }
+        .into();
+        let (value, _) =
+            *self.resolve_scalar_function_call(span, "concat", vec![], vec![value, unit])?;
+        self.resolve_scalar_function_call(span, "to_interval", vec![], vec![value])
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve_date_trunc(
@@ -2759,10 +2784,70 @@ impl<'a> TypeChecker<'a> {
                     .ok()?;
                 Some(self.resolve_scalar_function_call(span, "array_min", vec![], vec![array]))
             }
+            ("match", &[column, query]) => Some(
+                self.resolve_inverted_index_function(span, "match", column, query)
+                    .await,
+            ),
+            ("score", &[]) => match self.bind_context.inverted_index_match.clone() {
+                Some((column, query)) => Some(
+                    self.resolve_inverted_index_function(span, "score", &column, &query)
+                        .await,
+                ),
+                None => Some(Err(ErrorCode::SemanticError(
+                    "score() must be used with match() in the WHERE clause",
+                )
+                .set_span(span))),
+            },
             _ => None,
         }
     }
 
+    /// Rewrite `match(column, query)` and `score()` to the functions taking the tokenizer
+    /// of the inverted index of the column, e.g. `match(column, query, 'english')`.
+    #[async_backtrace::framed]
+    async fn resolve_inverted_index_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        column: &Expr,
+        query: &Expr,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let box (scalar, _) = self.resolve(column).await?;
+        let Some(tokenizer) = self.inverted_index_tokenizer(&scalar) else {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name}() requires a column with inverted index, but got {column}"
+            ))
+            .set_span(span));
+        };
+        let tokenizer = Expr::Literal {
+            span,
+            lit: Literal::String(tokenizer),
+        };
+        self.resolve_function(span, func_name, vec![], &[column, query, &tokenizer])
+            .await
+    }
+
+    fn inverted_index_tokenizer(&self, scalar: &ScalarExpr) -> Option<String> {
+        let ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }) = scalar else {
+            return None;
+        };
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn(BaseTableColumn {
+            table_index,
+            column_name,
+            ..
+        }) = metadata.column(column.index)
+        else {
+            return None;
+        };
+        let table = metadata.table(*table_index).table();
+        let field = table.schema().field_with_name(column_name).ok()?.clone();
+        let inverted_indexes = InvertedIndexes::from_options(table.options()).ok()?;
+        inverted_indexes
+            .get_by_column(field.column_id())
+            .map(|index| index.tokenizer.clone())
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_trim_function(
@@ -3837,6 +3922,8 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         TypeName::String => TableDataType::String,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
+        TypeName::Interval => TableDataType::Interval,
+        TypeName::TimestampTz => TableDataType::TimestampTz,
         TypeName::Array(item_type) => {
             TableDataType::Array(Box::new(resolve_type_name(item_type, not_null)?))
         }
@@ -3865,6 +3952,7 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
             }
         }
         TypeName::Bitmap => TableDataType::Bitmap,
+        TypeName::Geometry => TableDataType::Geometry,
         TypeName::Tuple {
             fields_type,
             fields_name,
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use databend_common_ast::parser::parse_comma_separated_idents;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ComputedExpr;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_settings::Settings;
+
+use crate::normalize_identifier;
+use crate::planner::semantic::NameResolutionContext;
+
+/// The columns of vector index, specified by the table option `vector_index_columns`.
+/// Unlike bloom index, no column has vector index by default.
+#[derive(Clone, Default)]
+pub struct VectorIndexColumns(pub Vec<String>);
+
+impl FromStr for VectorIndexColumns {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(VectorIndexColumns::default());
+        }
+
+        let sql_dialect = Dialect::default();
+        let tokens = tokenize_sql(s)?;
+        let idents = parse_comma_separated_idents(&tokens, sql_dialect)?;
+
+        let settings = Settings::create("".to_string());
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+
+        let cols = idents
+            .iter()
+            .map(|ident| normalize_identifier(ident, &name_resolution_ctx).name)
+            .collect();
+        Ok(VectorIndexColumns(cols))
+    }
+}
+
+impl VectorIndexColumns {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Verify the definition based on schema.
+    pub fn verify_definition<F>(
+        definition: &str,
+        schema: TableSchemaRef,
+        verify_type: F,
+    ) -> Result<()>
+    where
+        F: Fn(&TableDataType) -> bool,
+    {
+        let cols = definition.parse::<VectorIndexColumns>()?;
+        for name in cols.0.iter() {
+            let field = schema.field_with_name(name)?;
+
+            if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "The value specified for computed column '{}' is not allowed for vector index",
+                    name
+                )));
+            }
+
+            let data_type = field.data_type();
+            if !verify_type(data_type) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Unsupported data type '{}' for vector index",
+                    data_type
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get table field based on the VectorIndexColumns and schema.
+    pub fn vector_index_fields<F>(
+        &self,
+        schema: TableSchemaRef,
+        verify_type: F,
+    ) -> Result<BTreeMap<FieldIndex, TableField>>
+    where
+        F: Fn(&TableDataType) -> bool,
+    {
+        let source_schema = schema.remove_virtual_computed_fields();
+        let mut fields_map = BTreeMap::new();
+        for col in self.0.iter() {
+            let field_index = source_schema.index_of(col)?;
+            let field = source_schema.fields[field_index].clone();
+            let data_type = field.data_type();
+            if !verify_type(data_type) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Unsupported data type for vector index: {:?}",
+                    data_type
+                )));
+            }
+            fields_map.insert(field_index, field);
+        }
+        Ok(fields_map)
+    }
+}
@@ -25,6 +25,7 @@ pub trait Index {
                 | DataType::Timestamp
                 | DataType::String
                 | DataType::Decimal(_)
+                | DataType::Geometry
         )
     }
 }
@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::tokenizer::Tokenizer;
+
+use crate::utils::read_u32;
+use crate::FilterEvalResult;
+use crate::Index;
+
+/// The posting lists of the terms of the text columns of a block, stored in a file
+/// under the version directory of the inverted index.
+///
+/// Layout: `[num_columns: u32] ([column_id: u32] [num_terms: u32]
+/// ([term_len: u32] [term: term_len bytes] [num_rows: u32] [row: u32]*)*)*`,
+/// all the integers are little-endian.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InvertedIndex {
+    /// The rows of the block containing each term, in ascending order.
+    pub columns: BTreeMap<ColumnId, BTreeMap<String, Vec<u32>>>,
+}
+
+impl InvertedIndex {
+    /// Build the posting lists of the text columns of the block, the NULL rows are not indexed.
+    pub fn try_create(
+        tokenizer: Tokenizer,
+        block: &DataBlock,
+        text_columns_map: &BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        let mut columns = BTreeMap::new();
+        for (index, field) in text_columns_map {
+            if !Self::supported_type(field.data_type()) {
+                continue;
+            }
+            let entry = block.get_by_offset(*index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+
+            let mut postings: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+            for row in 0..column.len() {
+                let Some(ScalarRef::String(text)) = column.index(row) else {
+                    continue;
+                };
+                for term in tokenizer.tokenize(text) {
+                    let rows = postings.entry(term).or_default();
+                    if rows.last() != Some(&(row as u32)) {
+                        rows.push(row as u32);
+                    }
+                }
+            }
+            columns.insert(field.column_id(), postings);
+        }
+
+        if columns.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self { columns }))
+    }
+
+    /// Only the `STRING` columns are supported.
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
+    /// The rows of the column containing any term of the query, in ascending order.
+    ///
+    /// Returns `None` if the column is not indexed.
+    pub fn search(
+        &self,
+        column_id: ColumnId,
+        query: &str,
+        tokenizer: Tokenizer,
+    ) -> Option<Vec<u32>> {
+        let postings = self.columns.get(&column_id)?;
+        let rows = tokenizer
+            .tokenize(query)
+            .filter_map(|term| postings.get(&term))
+            .flatten()
+            .copied()
+            .collect::<BTreeSet<_>>();
+        Some(rows.into_iter().collect())
+    }
+
+    /// Apply the predicate expression, return `MustFalse` if it must be false in the block.
+    ///
+    /// `match(column, 'query', 'tokenizer')` is rewritten to `false` if the column is indexed
+    /// with the same tokenizer and no row of the block contains any term of the query.
+    pub fn apply(
+        &self,
+        mut expr: Expr<String>,
+        tokenizer: Tokenizer,
+        data_schema: TableSchemaRef,
+        func_ctx: &FunctionContext,
+    ) -> Result<FilterEvalResult> {
+        self.rewrite_match(&mut expr, tokenizer, &data_schema)?;
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    fn rewrite_match(
+        &self,
+        expr: &mut Expr<String>,
+        tokenizer: Tokenizer,
+        data_schema: &TableSchemaRef,
+    ) -> Result<()> {
+        match expr {
+            Expr::FunctionCall {
+                span,
+                function,
+                args,
+                return_type,
+                ..
+            } if function.signature.name == "match" => {
+                if let [Expr::ColumnRef { id, .. }, query, call_tokenizer] = args.as_slice() {
+                    if let (Some(Scalar::String(query)), Some(Scalar::String(call_tokenizer))) =
+                        (constant_scalar(query), constant_scalar(call_tokenizer))
+                    {
+                        let column_id = data_schema.field_with_name(id)?.column_id();
+                        let rows = match call_tokenizer.parse::<Tokenizer>() {
+                            Ok(call_tokenizer) if call_tokenizer == tokenizer => {
+                                self.search(column_id, query, tokenizer)
+                            }
+                            _ => None,
+                        };
+                        if rows.is_some_and(|rows| rows.is_empty()) {
+                            *expr = Expr::Constant {
+                                span: *span,
+                                scalar: Scalar::Boolean(false),
+                                data_type: return_type.clone(),
+                            };
+                        }
+                    }
+                }
+            }
+            Expr::Cast { expr, .. } => self.rewrite_match(expr, tokenizer, data_schema)?,
+            Expr::FunctionCall { args, .. } => {
+                for arg in args.iter_mut() {
+                    self.rewrite_match(arg, tokenizer, data_schema)?;
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&(self.columns.len() as u32).to_le_bytes());
+        for (column_id, postings) in &self.columns {
+            bytes.extend_from_slice(&column_id.to_le_bytes());
+            bytes.extend_from_slice(&(postings.len() as u32).to_le_bytes());
+            for (term, rows) in postings {
+                bytes.extend_from_slice(&(term.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(term.as_bytes());
+                bytes.extend_from_slice(&(rows.len() as u32).to_le_bytes());
+                for row in rows {
+                    bytes.extend_from_slice(&row.to_le_bytes());
+                }
+            }
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        let num_columns = read_u32(&mut reader).ok_or_else(invalid_format)?;
+        let mut columns = BTreeMap::new();
+        for _ in 0..num_columns {
+            let column_id = read_u32(&mut reader).ok_or_else(invalid_format)?;
+            let num_terms = read_u32(&mut reader).ok_or_else(invalid_format)?;
+            let mut postings = BTreeMap::new();
+            for _ in 0..num_terms {
+                let len = read_u32(&mut reader).ok_or_else(invalid_format)? as usize;
+                if reader.len() < len {
+                    return Err(invalid_format());
+                }
+                let (term, rest) = reader.split_at(len);
+                let term = String::from_utf8(term.to_vec()).map_err(|_| invalid_format())?;
+                reader = rest;
+
+                let num_rows = read_u32(&mut reader).ok_or_else(invalid_format)?;
+                let rows = (0..num_rows)
+                    .map(|_| read_u32(&mut reader).ok_or_else(invalid_format))
+                    .collect::<Result<Vec<_>>>()?;
+                postings.insert(term, rows);
+            }
+            columns.insert(column_id, postings);
+        }
+        if !reader.is_empty() {
+            return Err(invalid_format());
+        }
+        Ok(Self { columns })
+    }
+}
+
+/// The constant of the expression, the constant arguments may be cast to nullable.
+fn constant_scalar(expr: &Expr<String>) -> Option<&Scalar> {
+    match expr {
+        Expr::Constant { scalar, .. } => Some(scalar),
+        Expr::Cast { expr, .. } => constant_scalar(expr),
+        _ => None,
+    }
+}
+
+fn invalid_format() -> ErrorCode {
+    ErrorCode::StorageOther("Invalid inverted index: malformed data")
+}
+
+impl Index for InvertedIndex {}
@@ -21,6 +21,7 @@ use databend_common_expression::types::decimal::Decimal128Type;
 use databend_common_expression::types::decimal::Decimal256Type;
 use databend_common_expression::types::decimal::DecimalDataType;
 use databend_common_expression::types::decimal::DecimalDomain;
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::SimpleDomain;
 use databend_common_expression::types::string::StringDomain;
@@ -246,6 +247,10 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                         *sz,
                     )),
                 },
+                DataType::Geometry => match GeometryDomain::from_stats(&min, &max) {
+                    Some(domain) => Domain::Geometry(domain),
+                    None => Domain::full(data_type),
+                },
                 // Unsupported data type
                 _ => Domain::full(data_type),
             })
@@ -0,0 +1,33 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Read a little-endian `u32` and advance the reader, `None` if there are not enough bytes.
+pub(crate) fn read_u32(reader: &mut &[u8]) -> Option<u32> {
+    if reader.len() < 4 {
+        return None;
+    }
+    let (bytes, rest) = reader.split_at(4);
+    *reader = rest;
+    Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// Read a little-endian `u64` and advance the reader, `None` if there are not enough bytes.
+pub(crate) fn read_u64(reader: &mut &[u8]) -> Option<u64> {
+    if reader.len() < 8 {
+        return None;
+    }
+    let (bytes, rest) = reader.split_at(8);
+    *reader = rest;
+    Some(u64::from_le_bytes(bytes.try_into().unwrap()))
+}
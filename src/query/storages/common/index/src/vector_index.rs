@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnId;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_vector::HnswIndex;
+use databend_common_vector::VectorDistance;
+
+use crate::utils::read_u32;
+use crate::utils::read_u64;
+use crate::Index;
+
+/// The ANN indexes of the vector columns of a block, stored in a file next to the block.
+///
+/// Layout: `[num_columns: u32] ([column_id: u32] [len: u64] [hnsw index: len bytes])*`,
+/// all the integers are little-endian.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorIndex {
+    pub indexes: BTreeMap<ColumnId, HnswIndex>,
+}
+
+impl VectorIndex {
+    /// Build the indexes of the vector columns of the block.
+    ///
+    /// The dimension of a column is the dimension of its first vector, the rows of NULL,
+    /// empty arrays or of other dimensions are not indexed.
+    pub fn try_create(
+        distance: VectorDistance,
+        block: &DataBlock,
+        vector_columns_map: &BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        let mut indexes = BTreeMap::new();
+        for (index, field) in vector_columns_map {
+            if !Self::supported_type(field.data_type()) {
+                continue;
+            }
+            let entry = block.get_by_offset(*index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+
+            let vectors = (0..column.len())
+                .filter_map(|row| {
+                    let vector = column.index(row).and_then(scalar_to_vector)?;
+                    (!vector.is_empty()).then_some((row as u32, vector))
+                })
+                .collect::<Vec<_>>();
+            let Some(dimension) = vectors.first().map(|(_, v)| v.len()) else {
+                continue;
+            };
+            let hnsw = HnswIndex::build(
+                distance,
+                dimension,
+                vectors
+                    .iter()
+                    .filter(|(_, v)| v.len() == dimension)
+                    .map(|(row, v)| (*row, v.as_slice())),
+            )?;
+            indexes.insert(field.column_id(), hnsw);
+        }
+
+        if indexes.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self { indexes }))
+    }
+
+    /// Only the arrays of floats are supported, e.g. `ARRAY(FLOAT)` and `ARRAY(DOUBLE)`.
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        match data_type.remove_nullable() {
+            TableDataType::Array(box inner_ty) => matches!(
+                inner_ty.remove_nullable(),
+                TableDataType::Number(NumberDataType::Float32)
+                    | TableDataType::Number(NumberDataType::Float64)
+            ),
+            _ => false,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&(self.indexes.len() as u32).to_le_bytes());
+        for (column_id, index) in &self.indexes {
+            let index_bytes = index.to_bytes();
+            bytes.extend_from_slice(&column_id.to_le_bytes());
+            bytes.extend_from_slice(&(index_bytes.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&index_bytes);
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        let num_columns = read_u32(&mut reader).ok_or_else(invalid_format)?;
+        let mut indexes = BTreeMap::new();
+        for _ in 0..num_columns {
+            let column_id = read_u32(&mut reader).ok_or_else(invalid_format)?;
+            let len = read_u64(&mut reader).ok_or_else(invalid_format)? as usize;
+            if reader.len() < len {
+                return Err(invalid_format());
+            }
+            let (index_bytes, rest) = reader.split_at(len);
+            indexes.insert(column_id, HnswIndex::from_bytes(index_bytes)?);
+            reader = rest;
+        }
+        if !reader.is_empty() {
+            return Err(invalid_format());
+        }
+        Ok(Self { indexes })
+    }
+}
+
+/// Convert an array of floats to a vector, `None` if it is NULL or contains NULL.
+pub fn scalar_to_vector(scalar: ScalarRef) -> Option<Vec<f32>> {
+    match scalar {
+        ScalarRef::Array(column) => column_to_vector(&column),
+        _ => None,
+    }
+}
+
+fn column_to_vector(column: &Column) -> Option<Vec<f32>> {
+    match column {
+        Column::Number(NumberColumn::Float32(values)) => Some(values.iter().map(|v| v.0).collect()),
+        Column::Number(NumberColumn::Float64(values)) => {
+            Some(values.iter().map(|v| v.0 as f32).collect())
+        }
+        Column::Nullable(box column) if column.validity.unset_bits() == 0 => {
+            column_to_vector(&column.column)
+        }
+        _ => None,
+    }
+}
+
+fn invalid_format() -> ErrorCode {
+    ErrorCode::StorageOther("Invalid vector index: malformed data")
+}
+
+impl Index for VectorIndex {}
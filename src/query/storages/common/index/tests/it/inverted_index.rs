@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::number::UInt8Type;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::tokenizer::Tokenizer;
+use databend_storages_common_index::FilterEvalResult;
+use databend_storages_common_index::InvertedIndex;
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("id", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("log", TableDataType::String),
+    ]));
+    let block = DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec![
+            "Connection refused",
+            "The disk is full",
+            "connection reset by peer, connection closed",
+        ]),
+    ]);
+    let text_columns = BTreeMap::from([(0, schema.field(0).clone()), (1, schema.field(1).clone())]);
+
+    let index = InvertedIndex::try_create(Tokenizer::English, &block, &text_columns)?.unwrap();
+    // The column of numbers is not indexed.
+    assert_eq!(index.columns.len(), 1);
+    assert_eq!(InvertedIndex::from_bytes(&index.to_bytes())?, index);
+    assert!(InvertedIndex::from_bytes(&index.to_bytes()[1..]).is_err());
+
+    let column_id = schema.field(1).column_id();
+    assert_eq!(
+        index.search(column_id, "CONNECTION", Tokenizer::English),
+        Some(vec![0, 2])
+    );
+    assert_eq!(
+        index.search(column_id, "disk timeout", Tokenizer::English),
+        Some(vec![1])
+    );
+    assert_eq!(
+        index.search(column_id, "the", Tokenizer::English),
+        Some(vec![])
+    );
+    assert_eq!(
+        index.search(schema.field(0).column_id(), "1", Tokenizer::English),
+        None
+    );
+
+    let func_ctx = FunctionContext::default();
+    let eval = |query: &str, tokenizer: &str| {
+        index.apply(
+            match_expr("log", query, tokenizer),
+            Tokenizer::English,
+            schema.clone(),
+            &func_ctx,
+        )
+    };
+    assert_eq!(eval("timeout", "english")?, FilterEvalResult::MustFalse);
+    assert_eq!(
+        eval("refused timeout", "english")?,
+        FilterEvalResult::Uncertain
+    );
+    // The index of another tokenizer can not prune the block.
+    assert_eq!(eval("timeout", "simple")?, FilterEvalResult::Uncertain);
+
+    let expr = check_function(
+        None,
+        "or",
+        &[],
+        &[
+            match_expr("log", "timeout", "english"),
+            match_expr("log", "full", "english"),
+        ],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    assert_eq!(
+        index.apply(expr, Tokenizer::English, schema.clone(), &func_ctx)?,
+        FilterEvalResult::Uncertain
+    );
+    let expr = check_function(
+        None,
+        "and",
+        &[],
+        &[
+            match_expr("log", "timeout", "english"),
+            match_expr("log", "full", "english"),
+        ],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    assert_eq!(
+        index.apply(expr, Tokenizer::English, schema, &func_ctx)?,
+        FilterEvalResult::MustFalse
+    );
+
+    Ok(())
+}
+
+fn match_expr(column: &str, query: &str, tokenizer: &str) -> Expr<String> {
+    check_function(
+        None,
+        "match",
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: column.to_string(),
+                data_type: DataType::String,
+                display_name: column.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(query.to_string()),
+                data_type: DataType::String,
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(tokenizer.to_string()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap()
+}
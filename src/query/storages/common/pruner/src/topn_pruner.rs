@@ -77,10 +77,13 @@ impl TopNPrunner {
         };
 
         // String Type min/max is truncated
-        if matches!(
-            self.schema.field_with_name(column)?.data_type(),
-            TableDataType::String
-        ) {
+        let data_type = self.schema.field_with_name(column)?.data_type();
+        if matches!(data_type, TableDataType::String) {
+            return Ok(metas);
+        }
+
+        // Geometry Type min/max are the corners of the bounding box, not an order
+        if data_type.remove_nullable() == TableDataType::Geometry {
             return Ok(metas);
         }
 
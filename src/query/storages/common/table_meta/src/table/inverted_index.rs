@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_common_io::tokenizer::Tokenizer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_INVERTED_INDEXES;
+
+/// An inverted (full-text) index of a table, created by `CREATE INVERTED INDEX`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InvertedIndexDefinition {
+    pub name: String,
+    /// The ids of the indexed columns, so that renaming a column keeps its index.
+    pub column_ids: Vec<ColumnId>,
+    pub tokenizer: String,
+    /// Generated when the index is created, the index files of a block are stored under it,
+    /// so that the files of a dropped index are never read by a new index of the same name.
+    pub version: String,
+}
+
+impl InvertedIndexDefinition {
+    pub fn tokenizer(&self) -> Result<Tokenizer> {
+        self.tokenizer.parse()
+    }
+}
+
+/// The inverted indexes of a table, stored as JSON in the table option `inverted_indexes`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InvertedIndexes(pub Vec<InvertedIndexDefinition>);
+
+impl InvertedIndexes {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        match options.get(OPT_KEY_INVERTED_INDEXES) {
+            Some(value) => serde_json::from_str(value)
+                .map(InvertedIndexes)
+                .map_err(|e| {
+                    ErrorCode::TableOptionInvalid(format!("Invalid inverted indexes: {}", e))
+                }),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// The value of the table option, `None` if there is no index left.
+    pub fn to_option_value(&self) -> Result<Option<String>> {
+        if self.0.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::to_string(&self.0)?))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&InvertedIndexDefinition> {
+        self.0.iter().find(|index| index.name == name)
+    }
+
+    /// The first index of the column.
+    pub fn get_by_column(&self, column_id: ColumnId) -> Option<&InvertedIndexDefinition> {
+        self.0
+            .iter()
+            .find(|index| index.column_ids.contains(&column_id))
+    }
+}
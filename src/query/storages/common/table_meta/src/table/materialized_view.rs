@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_MATERIALIZED_VIEWS;
+
+/// A materialized view defined on a table.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MaterializedViewName {
+    pub database: String,
+    pub name: String,
+}
+
+/// The materialized views of a table, stored as JSON in the table option `materialized_views`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MaterializedViews(pub Vec<MaterializedViewName>);
+
+impl MaterializedViews {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        match options.get(OPT_KEY_MATERIALIZED_VIEWS) {
+            Some(value) => serde_json::from_str(value)
+                .map(MaterializedViews)
+                .map_err(|e| {
+                    ErrorCode::TableOptionInvalid(format!("Invalid materialized views: {}", e))
+                }),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// The value of the table option, `None` if there is no view left.
+    pub fn to_option_value(&self) -> Result<Option<String>> {
+        if self.0.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::to_string(&self.0)?))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, database: &str, name: &str) -> bool {
+        self.0
+            .iter()
+            .any(|view| view.database == database && view.name == name)
+    }
+
+    pub fn add(&mut self, database: &str, name: &str) {
+        if !self.contains(database, name) {
+            self.0.push(MaterializedViewName {
+                database: database.to_string(),
+                name: name.to_string(),
+            });
+        }
+    }
+
+    pub fn remove(&mut self, database: &str, name: &str) {
+        self.0
+            .retain(|view| view.database != database || view.name != name);
+    }
+}
@@ -12,11 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod inverted_index;
+mod materialized_view;
 mod stream_keys;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
 
+pub use inverted_index::InvertedIndexDefinition;
+pub use inverted_index::InvertedIndexes;
+pub use materialized_view::MaterializedViewName;
+pub use materialized_view::MaterializedViews;
 pub use stream_keys::*;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
@@ -22,7 +22,22 @@ pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+pub const OPT_KEY_VECTOR_INDEX_COLUMNS: &str = "vector_index_columns";
+pub const OPT_KEY_VECTOR_INDEX_DISTANCE: &str = "vector_index_distance";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
+// The inverted indexes of the table, maintained by `CREATE/DROP INVERTED INDEX`.
+pub const OPT_KEY_INVERTED_INDEXES: &str = "inverted_indexes";
+
+// Materialized view options.
+// The defining query of a materialized view.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+// The id of the table a materialized view is defined on.
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID: &str = "materialized_view_source_table_id";
+// The snapshot location of the source table at the last refresh, empty if the source table had no
+// snapshot then. Absent if the materialized view has never been refreshed.
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT: &str = "materialized_view_source_snapshot";
+// Set on the source table, its materialized views as a JSON array, see `MaterializedViews`.
+pub const OPT_KEY_MATERIALIZED_VIEWS: &str = "materialized_views";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -53,6 +68,11 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
+    r.insert(OPT_KEY_INVERTED_INDEXES);
     r
 });
 
@@ -62,6 +82,11 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_ENGINE_META);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
+    r.insert(OPT_KEY_INVERTED_INDEXES);
     r
 });
 
@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read deletion vectors of delta tables.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vector-format>
+
+use std::io::Cursor;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use deltalake::kernel::DeletionVectorDescriptor;
+use deltalake::kernel::StorageType;
+use opendal::Operator;
+use roaring::RoaringTreemap;
+
+/// Magic number at the beginning of a serialized deletion vector.
+const DV_MAGIC: u32 = 1681511377;
+
+/// Length of z85 encoded uuid in the path of a deletion vector file.
+const ENCODED_UUID_LEN: usize = 20;
+
+const Z85_CHARS: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Serializable copy of [`DeletionVectorDescriptor`] carried by partitions.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub enum DeletionVector {
+    /// Serialized bitmap stored inline.
+    Inline(Vec<u8>),
+    /// Bitmap stored in the file relative to the table root.
+    File {
+        path: String,
+        offset: u64,
+        size: u64,
+    },
+}
+
+impl DeletionVector {
+    pub fn try_create(dv: &DeletionVectorDescriptor) -> Result<Self> {
+        match dv.storage_type {
+            StorageType::Inline => {
+                // Encoded bytes are padded to multiple of 4.
+                let mut data = z85_decode(&dv.path_or_inline_dv)?;
+                data.truncate(dv.size_in_bytes as usize);
+                Ok(DeletionVector::Inline(data))
+            }
+            StorageType::UuidRelativePath => {
+                let encoded = &dv.path_or_inline_dv;
+                if encoded.len() < ENCODED_UUID_LEN {
+                    return Err(ErrorCode::ReadTableDataError(format!(
+                        "Invalid deletion vector path: {encoded}"
+                    )));
+                }
+                let (prefix, uuid) = encoded.split_at(encoded.len() - ENCODED_UUID_LEN);
+                let uuid = uuid::Uuid::from_slice(&z85_decode(uuid)?).map_err(|e| {
+                    ErrorCode::ReadTableDataError(format!(
+                        "Invalid deletion vector path {encoded}: {e}"
+                    ))
+                })?;
+                let file_name = format!("deletion_vector_{uuid}.bin");
+                let path = if prefix.is_empty() {
+                    file_name
+                } else {
+                    format!("{prefix}/{file_name}")
+                };
+                Ok(DeletionVector::File {
+                    path,
+                    offset: dv.offset.unwrap_or(1) as u64,
+                    size: dv.size_in_bytes as u64,
+                })
+            }
+            StorageType::AbsolutePath => Err(ErrorCode::Unimplemented(format!(
+                "Deletion vector with absolute path is not supported: {}",
+                dv.path_or_inline_dv
+            ))),
+        }
+    }
+
+    /// Read positions of deleted rows in the data file.
+    pub async fn read(&self, op: &Operator) -> Result<RoaringTreemap> {
+        match self {
+            DeletionVector::Inline(data) => deserialize_bitmap(data),
+            DeletionVector::File { path, offset, size } => {
+                // Each bitmap in the file is stored as `<size: u32 BE><data><checksum: u32 BE>`.
+                let start = offset + 4;
+                let data = op.read_with(path).range(start..start + size).await?;
+                deserialize_bitmap(&data)
+            }
+        }
+    }
+}
+
+fn deserialize_bitmap(data: &[u8]) -> Result<RoaringTreemap> {
+    if data.len() < 4 || u32::from_le_bytes(data[..4].try_into().unwrap()) != DV_MAGIC {
+        return Err(ErrorCode::ReadTableDataError(
+            "Invalid deletion vector: magic number mismatch",
+        ));
+    }
+    RoaringTreemap::deserialize_from(Cursor::new(&data[4..]))
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("Invalid deletion vector: {e}")))
+}
+
+/// Decode z85 string, the length of input must be multiple of 5.
+fn z85_decode(input: &str) -> Result<Vec<u8>> {
+    let input = input.as_bytes();
+    if input.len() % 5 != 0 {
+        return Err(ErrorCode::ReadTableDataError(format!(
+            "Invalid z85 string length {}",
+            input.len()
+        )));
+    }
+    let mut output = Vec::with_capacity(input.len() / 5 * 4);
+    for chunk in input.chunks(5) {
+        let mut value: u32 = 0;
+        for c in chunk {
+            let digit = Z85_CHARS.iter().position(|v| v == c).ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!("Invalid z85 character {}", *c as char))
+            })?;
+            value = value
+                .checked_mul(85)
+                .and_then(|v| v.checked_add(digit as u32))
+                .ok_or_else(|| ErrorCode::ReadTableDataError("Invalid z85 string"))?;
+        }
+        output.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use roaring::RoaringTreemap;
+
+    use super::deserialize_bitmap;
+    use super::z85_decode;
+    use super::DV_MAGIC;
+
+    #[test]
+    fn test_z85_decode() {
+        assert_eq!(z85_decode("HelloWorld").unwrap(), vec![
+            0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B
+        ]);
+        assert!(z85_decode("Hello").is_ok());
+        assert!(z85_decode("Hell").is_err());
+        assert!(z85_decode("Hell~").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_bitmap() {
+        let mut bitmap = RoaringTreemap::new();
+        bitmap.insert(1);
+        bitmap.insert(3);
+        bitmap.insert(u32::MAX as u64 + 2);
+
+        let mut data = DV_MAGIC.to_le_bytes().to_vec();
+        bitmap.serialize_into(&mut data).unwrap();
+        assert_eq!(deserialize_bitmap(&data).unwrap(), bitmap);
+
+        data[0] = 0;
+        assert!(deserialize_bitmap(&data).is_err());
+    }
+}
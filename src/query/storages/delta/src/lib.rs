@@ -17,9 +17,13 @@
 #![allow(clippy::diverging_sub_expression)]
 
 mod dal;
+mod deletion_vector;
+mod history_table;
 mod partition;
 mod partition_columns;
+mod sink;
 mod table;
 mod table_source;
 
+pub use history_table::DeltaHistoryTable;
 pub use table::DeltaTable;
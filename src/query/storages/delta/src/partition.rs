@@ -21,11 +21,14 @@ use databend_common_exception::Result;
 use databend_common_expression::Scalar;
 use databend_common_storages_parquet::ParquetPart;
 
+use crate::deletion_vector::DeletionVector;
+
 /// only support parquet for now: https://github.com/delta-io/delta/issues/87
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
 pub struct DeltaPartInfo {
     pub data: ParquetPart,
     pub partition_values: Vec<Scalar>,
+    pub deletion_vector: Option<DeletionVector>,
 }
 
 impl DeltaPartInfo {
@@ -104,7 +104,13 @@ pub fn str_to_scalar(value: &str, data_type: &DataType) -> Result<Scalar> {
     }
 }
 
-pub fn get_partition_values(add: &Add, fields: &[&TableField]) -> Result<Vec<Scalar>> {
+/// `keys` are the names of partition columns in `add.partition_values`,
+/// which are physical names if column mapping is enabled.
+pub fn get_partition_values(
+    add: &Add,
+    fields: &[&TableField],
+    keys: &[&str],
+) -> Result<Vec<Scalar>> {
     match &add.partition_values_parsed {
         Some(row) => row
             .get_column_iter()
@@ -112,8 +118,8 @@ pub fn get_partition_values(add: &Add, fields: &[&TableField]) -> Result<Vec<Sca
             .collect(),
         None => {
             let mut values = Vec::with_capacity(fields.len());
-            for f in fields {
-                match add.partition_values.get(&f.name) {
+            for (f, key) in fields.iter().zip(keys) {
+                match add.partition_values.get(*key) {
                     Some(Some(v)) => values.push(str_to_scalar(v, &f.data_type().into())?),
                     Some(None) => values.push(Scalar::Null),
                     None => {
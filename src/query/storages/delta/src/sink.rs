@@ -0,0 +1,411 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_base::base::escape_for_key;
+use databend_common_base::base::Progress;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_storages_parquet::lake_sink::split_by_partition;
+use databend_common_storages_parquet::lake_sink::to_record_batch;
+use databend_common_storages_parquet::lake_sink::write_parquet;
+use databend_common_storages_parquet::lake_sink::MAX_COMMIT_RETRIES;
+use databend_common_storages_parquet::lake_sink::MAX_FILE_BYTES;
+use databend_common_users::UserApiProvider;
+use deltalake::kernel::DataType;
+use deltalake::kernel::MetadataValue;
+use deltalake::kernel::WriterFeatures;
+use opendal::Operator;
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::DeltaTable;
+
+/// Max writer version of the delta protocol supported by the sink.
+const MAX_WRITER_VERSION: i32 = 7;
+
+const INVARIANTS: &str = "delta.invariants";
+const GENERATION_EXPRESSION: &str = "delta.generationExpression";
+const IDENTITY_PREFIX: &str = "delta.identity.";
+const CONSTRAINTS_PREFIX: &str = "delta.constraints.";
+const COLUMN_MAPPING_ID: &str = "delta.columnMapping.id";
+
+/// Key prefix in meta service to claim the versions of delta tables.
+const COMMIT_KEY_PREFIX: &str = "__fd_delta_commit";
+/// Claims are only used during the commit, expire them in case the writer crashed.
+const COMMIT_CLAIM_TTL: Duration = Duration::from_secs(60);
+
+/// Write data blocks as parquet data files of the delta table,
+/// and commit the add actions as a new version when all the data are written.
+///
+/// Data files are written in the root of table, partition values are only
+/// recorded in the add actions, which is allowed by the delta protocol.
+pub struct DeltaTableSink {
+    sp: StorageParams,
+    op: Operator,
+    write_progress: Arc<Progress>,
+
+    /// Schema of data files, partition columns are excluded and
+    /// columns are named by physical names.
+    arrow_schema: Option<ArrowSchemaRef>,
+    /// Schema of the table when the sink is started, the commit fails if it's changed.
+    table_schema: ArrowSchema,
+    /// Indexes of partition columns in the block, and the keys of partition values.
+    partition_columns: Vec<(FieldIndex, String)>,
+    partition_by: Vec<String>,
+
+    blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    adds: Vec<JsonValue>,
+}
+
+impl DeltaTableSink {
+    pub fn create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        sp: StorageParams,
+        op: Operator,
+    ) -> Box<dyn Processor> {
+        AsyncSinker::create(input, ctx.clone(), DeltaTableSink {
+            sp,
+            op,
+            write_progress: ctx.get_write_progress(),
+            arrow_schema: None,
+            table_schema: ArrowSchema::empty(),
+            partition_columns: vec![],
+            partition_by: vec![],
+            blocks: vec![],
+            buffered_bytes: 0,
+            adds: vec![],
+        })
+    }
+
+    /// Write buffered blocks as data files, one for each partition.
+    async fn flush(&mut self) -> Result<()> {
+        if self.blocks.is_empty() {
+            return Ok(());
+        }
+        let block = DataBlock::concat(&std::mem::take(&mut self.blocks))?;
+        self.buffered_bytes = 0;
+
+        let partition_indexes = self
+            .partition_columns
+            .iter()
+            .map(|(index, _)| *index)
+            .collect::<Vec<_>>();
+        for (values, block) in
+            split_by_partition(block, &partition_indexes, partition_value_to_string)?
+        {
+            let partition_values = self
+                .partition_columns
+                .iter()
+                .map(|(_, key)| key.clone())
+                .zip(values)
+                .collect::<BTreeMap<_, _>>();
+            let num_rows = block.num_rows();
+            let batch = to_record_batch(block, self.arrow_schema.as_ref().unwrap())?;
+            let data = write_parquet(batch)?;
+            let size = data.len();
+
+            let path = format!(
+                "part-{:05}-{}-c000.snappy.parquet",
+                self.adds.len(),
+                uuid::Uuid::new_v4()
+            );
+            self.op.write(&path, data).await?;
+
+            self.adds.push(json!({
+                "add": {
+                    "path": path,
+                    "partitionValues": partition_values,
+                    "size": size,
+                    "modificationTime": chrono::Utc::now().timestamp_millis(),
+                    "dataChange": true,
+                    "stats": json!({ "numRecords": num_rows }).to_string(),
+                }
+            }));
+        }
+        Ok(())
+    }
+
+    /// Commit the add actions as the next version of the table.
+    ///
+    /// Storages have no put-if-absent write, the version is claimed in the meta service
+    /// before writing the commit file, so databend writers never overwrite each other,
+    /// and the commit file is checked to detect the versions committed by other engines.
+    async fn commit(&self) -> Result<()> {
+        let commit_info = json!({
+            "commitInfo": {
+                "timestamp": chrono::Utc::now().timestamp_millis(),
+                "operation": "WRITE",
+                "operationParameters": {
+                    "mode": "Append",
+                    "partitionBy": serde_json::to_string(&self.partition_by)?,
+                },
+                "isBlindAppend": true,
+                "engineInfo": "Databend",
+            }
+        });
+        let entries = std::iter::once(&commit_info)
+            .chain(self.adds.iter())
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut retries = 0;
+        loop {
+            // Appends only conflict with the changes of protocol and metadata,
+            // otherwise just commit to the latest version.
+            let table = DeltaTable::load(&self.sp).await?;
+            check_writable(&table)?;
+            if DeltaTable::get_arrow_schema(&table)? != self.table_schema
+                || DeltaTable::build_meta(&table)?.partition_columns != self.partition_by
+            {
+                return Err(ErrorCode::StorageOther(format!(
+                    "Delta commit failed, the metadata is changed at version {}",
+                    table.version()
+                )));
+            }
+
+            let version = table.version() + 1;
+            let path = format!("_delta_log/{version:020}.json");
+            if self.claim_version(version).await? && !self.op.is_exist(&path).await? {
+                self.op.write(&path, entries).await?;
+                return Ok(());
+            }
+            if retries >= MAX_COMMIT_RETRIES {
+                return Err(ErrorCode::StorageOther(format!(
+                    "Delta commit failed after {retries} retries, version conflicts at {path}"
+                )));
+            }
+            retries += 1;
+            log::info!(
+                "Delta commit conflicts at {path}, retry {}/{}",
+                retries,
+                MAX_COMMIT_RETRIES
+            );
+        }
+    }
+
+    /// Claim the version of the table, returns false if it's claimed by other writers.
+    async fn claim_version(&self, version: i64) -> Result<bool> {
+        let key = format!(
+            "{COMMIT_KEY_PREFIX}/{}/{version}",
+            escape_for_key(&self.sp.to_string())?
+        );
+        let kv_api = UserApiProvider::instance().get_meta_store_client();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(vec![]),
+                Some(MetaSpec::new_ttl(COMMIT_CLAIM_TTL)),
+            ))
+            .await?;
+        Ok(res.added_seq_or_else(|_| ()).is_ok())
+    }
+}
+
+#[async_trait]
+impl AsyncSink for DeltaTableSink {
+    const NAME: &'static str = "DeltaTableSink";
+
+    #[async_backtrace::framed]
+    async fn on_start(&mut self) -> Result<()> {
+        let table = DeltaTable::load(&self.sp).await?;
+        check_writable(&table)?;
+        let arrow_schema = DeltaTable::get_arrow_schema(&table)?;
+        let meta = DeltaTable::build_meta(&table)?;
+        let field_ids = column_mapping_ids(&table)?;
+
+        self.partition_columns = meta
+            .partition_columns
+            .iter()
+            .map(|name| {
+                let index = arrow_schema.index_of(name)?;
+                Ok((index, meta.physical_name(name).to_string()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let fields = arrow_schema
+            .fields()
+            .iter()
+            .filter(|f| !meta.partition_columns.contains(f.name()))
+            .map(|f| {
+                let field = f.as_ref().clone().with_name(meta.physical_name(f.name()));
+                match field_ids.get(f.name()) {
+                    Some(id) => field.with_metadata(HashMap::from([(
+                        PARQUET_FIELD_ID_META_KEY.to_string(),
+                        id.to_string(),
+                    )])),
+                    None => field,
+                }
+            })
+            .collect::<Vec<_>>();
+        self.arrow_schema = Some(Arc::new(ArrowSchema::new(fields)));
+        self.table_schema = arrow_schema;
+        self.partition_by = meta.partition_columns;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        self.flush().await?;
+        if self.adds.is_empty() {
+            return Ok(());
+        }
+        self.commit().await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if data_block.is_empty() {
+            return Ok(false);
+        }
+        let progress_values = ProgressValues {
+            rows: data_block.num_rows(),
+            bytes: data_block.memory_size(),
+        };
+        self.buffered_bytes += data_block.memory_size();
+        self.blocks.push(data_block);
+        if self.buffered_bytes >= MAX_FILE_BYTES {
+            self.flush().await?;
+        }
+        self.write_progress.incr(&progress_values);
+        Ok(false)
+    }
+}
+
+/// Check whether the table can be written by the sink.
+///
+/// The sink only appends data files, so `appendOnly` tables are writable, but features
+/// requiring to validate or generate values of the written rows are refused if used.
+fn check_writable(table: &deltalake::table::DeltaTable) -> Result<()> {
+    let unsupported = |feature: String| {
+        ErrorCode::Unimplemented(format!(
+            "writing delta table with {feature} is not supported"
+        ))
+    };
+
+    let min_writer_version = table.get_min_writer_version();
+    if min_writer_version > MAX_WRITER_VERSION {
+        return Err(unsupported(format!("writer version {min_writer_version}")));
+    }
+    for feature in table.get_writer_features().into_iter().flatten() {
+        match feature {
+            // Usages of the following features are checked below.
+            WriterFeatures::AppendOnly
+            | WriterFeatures::Invariants
+            | WriterFeatures::CheckConstraints
+            | WriterFeatures::GeneratedColumns
+            | WriterFeatures::IdentityColumns
+            | WriterFeatures::ColumnMapping
+            | WriterFeatures::ChangeDataFeed
+            | WriterFeatures::DeletionVectors
+            | WriterFeatures::TimestampWithoutTimezone => {}
+            other => return Err(unsupported(format!("writer feature {other:?}"))),
+        }
+    }
+
+    // Features of legacy writer versions are enabled by the usages.
+    let schema = table.get_schema().map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+    })?;
+    for field in schema.fields() {
+        let metadata = field.metadata();
+        if metadata.contains_key(INVARIANTS) {
+            return Err(unsupported(format!(
+                "invariants on column {}",
+                field.name()
+            )));
+        }
+        if metadata.contains_key(GENERATION_EXPRESSION) {
+            return Err(unsupported(format!("generated column {}", field.name())));
+        }
+        if metadata.keys().any(|k| k.starts_with(IDENTITY_PREFIX)) {
+            return Err(unsupported(format!("identity column {}", field.name())));
+        }
+    }
+    let state = table.state.current_metadata().ok_or_else(|| {
+        ErrorCode::ReadTableDataError("bug: Delta table current_metadata is None.")
+    })?;
+    if let Some(key) = state
+        .configuration
+        .keys()
+        .find(|k| k.starts_with(CONSTRAINTS_PREFIX))
+    {
+        return Err(unsupported(format!("CHECK constraint {key}")));
+    }
+    Ok(())
+}
+
+/// Column mapping ids keyed by column names, empty if column mapping is disabled.
+///
+/// The ids are written as parquet field ids, which are used to resolve columns in `id` mode.
+fn column_mapping_ids(table: &deltalake::table::DeltaTable) -> Result<HashMap<String, i32>> {
+    let schema = table.get_schema().map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+    })?;
+    let mut ids = HashMap::new();
+    for field in schema.fields() {
+        let Some(MetadataValue::Number(id)) = field.metadata().get(COLUMN_MAPPING_ID) else {
+            continue;
+        };
+        // Nested fields have their own ids, which are not written yet.
+        if !matches!(field.data_type(), DataType::Primitive(_)) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "writing nested column {} of delta table with column mapping is not supported",
+                field.name()
+            )));
+        }
+        ids.insert(field.name().to_string(), *id);
+    }
+    Ok(ids)
+}
+
+fn partition_value_to_string(value: ScalarRef) -> Result<Option<String>> {
+    match value {
+        ScalarRef::Null => Ok(None),
+        ScalarRef::Number(v) => Ok(Some(v.to_string())),
+        ScalarRef::String(v) => Ok(Some(v.to_string())),
+        ScalarRef::Boolean(v) => Ok(Some(v.to_string())),
+        v => Err(ErrorCode::Unimplemented(format!(
+            "can not use value {v} as delta partition"
+        ))),
+    }
+}
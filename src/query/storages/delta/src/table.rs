@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -26,6 +27,8 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -36,15 +39,22 @@ use databend_common_expression::FieldIndex;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::init_operator;
+use databend_common_storages_parquet::lake_sink::add_commit_sink;
+use databend_common_storages_parquet::lake_sink::overwrite_unsupported;
 use databend_common_storages_parquet::ParquetFilesPart;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
 use deltalake::kernel::Add;
+use deltalake::kernel::MetadataValue;
 use deltalake::logstore::default_logstore::DefaultLogStore;
 use deltalake::logstore::LogStoreConfig;
 use deltalake::DeltaTableConfig;
@@ -55,13 +65,18 @@ use url::Url;
 
 // use object_store_opendal::OpendalStore;
 use crate::dal::OpendalStore;
+use crate::deletion_vector::DeletionVector;
 use crate::partition::DeltaPartInfo;
 use crate::partition_columns::get_partition_values;
 use crate::partition_columns::get_pushdown_without_partition_columns;
+use crate::sink::DeltaTableSink;
 use crate::table_source::DeltaTableSource;
 
 pub const DELTA_ENGINE: &str = "DELTA";
 
+const COLUMN_MAPPING_MODE: &str = "delta.columnMapping.mode";
+const COLUMN_MAPPING_PHYSICAL_NAME: &str = "delta.columnMapping.physicalName";
+
 pub struct DeltaTable {
     info: TableInfo,
     table: OnceCell<deltalake::table::DeltaTable>,
@@ -70,7 +85,21 @@ pub struct DeltaTable {
 
 #[derive(Serialize, Deserialize)]
 pub struct DeltaTableMeta {
-    partition_columns: Vec<String>,
+    pub(crate) partition_columns: Vec<String>,
+    /// Physical names of columns keyed by column names, empty if column mapping is disabled.
+    ///
+    /// Physical names are used in data files and partition values of add actions.
+    #[serde(default)]
+    pub(crate) column_mapping: BTreeMap<String, String>,
+}
+
+impl DeltaTableMeta {
+    pub(crate) fn physical_name<'a>(&'a self, name: &'a str) -> &'a str {
+        self.column_mapping
+            .get(name)
+            .map(|v| v.as_str())
+            .unwrap_or(name)
+    }
 }
 
 /// In a delta table, partition columns are not stored in parquet file.
@@ -132,31 +161,57 @@ impl DeltaTable {
 
     #[async_backtrace::framed]
     pub async fn get_meta(table: &deltalake::table::DeltaTable) -> Result<(TableSchema, String)> {
+        let arrow_schema = Self::get_arrow_schema(table)?;
+        let meta = Self::build_meta(table)?;
+        let meta = serde_json::to_string(&meta).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
+        })?;
+
+        let schema = TableSchema::try_from(&arrow_schema)?;
+        Ok((schema, meta))
+    }
+
+    /// Build arrow schema from delta metadata.
+    pub(crate) fn get_arrow_schema(table: &deltalake::table::DeltaTable) -> Result<ArrowSchema> {
         let delta_meta = table.get_schema().map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
         })?;
 
-        // Build arrow schema from delta metadata.
-        let arrow_schema: ArrowSchema = delta_meta.try_into().map_err(|e| {
+        delta_meta.try_into().map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
-        })?;
+        })
+    }
 
+    pub(crate) fn build_meta(table: &deltalake::table::DeltaTable) -> Result<DeltaTableMeta> {
         let state = table.state.current_metadata().ok_or_else(|| {
             ErrorCode::ReadTableDataError("bug: Delta table current_metadata is None.")
         })?;
-        let meta = DeltaTableMeta {
-            partition_columns: state.partition_columns.clone(),
+
+        let column_mapping = match state.configuration.get(COLUMN_MAPPING_MODE) {
+            Some(Some(mode)) if mode != "none" => {
+                let delta_meta = table.get_schema().map_err(|e| {
+                    ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+                })?;
+                delta_meta
+                    .fields()
+                    .iter()
+                    .filter_map(|f| match f.metadata().get(COLUMN_MAPPING_PHYSICAL_NAME) {
+                        Some(MetadataValue::String(v)) => Some((f.name().to_string(), v.clone())),
+                        _ => None,
+                    })
+                    .collect()
+            }
+            _ => BTreeMap::new(),
         };
-        let meta = serde_json::to_string(&meta).map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
-        })?;
 
-        let schema = TableSchema::try_from(&arrow_schema)?;
-        Ok((schema, meta))
+        Ok(DeltaTableMeta {
+            partition_columns: state.partition_columns.clone(),
+            column_mapping,
+        })
     }
 
-    #[async_backtrace::framed]
-    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+    /// Create a delta table without loading any version.
+    fn new_delta_table(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
         let opendal_store = Arc::new(OpendalStore::new(op));
         let config = DeltaTableConfig::default();
@@ -164,15 +219,47 @@ impl DeltaTable {
             location: Url::from_directory_path("/").unwrap(),
             options: HashMap::new().into(),
         }));
-        let mut table = deltalake::table::DeltaTable::new(log_store, config);
+        Ok(deltalake::table::DeltaTable::new(log_store, config))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::new_delta_table(sp)?;
         table.load().await.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
         })?;
         Ok(table)
     }
 
+    /// Load the version of the given navigation point.
+    ///
+    /// The snapshot id is the delta version, and for a time point,
+    /// the latest version committed before it is used.
+    #[async_backtrace::framed]
+    pub async fn load_at(
+        sp: &StorageParams,
+        point: &NavigationPoint,
+    ) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::new_delta_table(sp)?;
+        let res = match point {
+            NavigationPoint::SnapshotID(version) => {
+                let version = version.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!("Invalid delta table version: {version}"))
+                })?;
+                table.load_version(version).await
+            }
+            NavigationPoint::TimePoint(time_point) => table.load_with_datetime(*time_point).await,
+        };
+        res.map_err(|err| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at given point {point:?}: {err:?}"
+            ))
+        })?;
+        Ok(table)
+    }
+
     #[async_backtrace::framed]
-    async fn table(&self) -> Result<&deltalake::table::DeltaTable> {
+    pub(crate) async fn table(&self) -> Result<&deltalake::table::DeltaTable> {
         self.table
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
@@ -244,7 +331,7 @@ impl DeltaTable {
             None
         };
         let mut builder =
-            ParquetRSReaderBuilder::create(ctx.clone(), op, table_schema, &arrow_schema)?
+            ParquetRSReaderBuilder::create(ctx.clone(), op.clone(), table_schema, &arrow_schema)?
                 .with_options(read_options)
                 .with_push_downs(push_downs.as_ref())
                 .with_pruner(Some(pruner))
@@ -260,6 +347,7 @@ impl DeltaTable {
                     output,
                     output_schema.clone(),
                     parquet_reader.clone(),
+                    op.clone(),
                     self.get_partition_fields()?.into_iter().cloned().collect(),
                 )
             },
@@ -280,6 +368,10 @@ impl DeltaTable {
         let mut read_bytes = 0;
 
         let partition_fields = self.get_partition_fields()?;
+        let partition_keys = partition_fields
+            .iter()
+            .map(|f| self.meta.physical_name(&f.name))
+            .collect::<Vec<_>>();
         let adds = table.get_state().files();
         let total_files = adds.len();
         let parts = adds.iter()
@@ -293,12 +385,21 @@ impl DeltaTable {
                             add.path
                         ))
                     })?;
-                read_rows += stats.num_records as usize;
+                // Rows in the deletion vector are not counted.
+                let deleted_rows = add.deletion_vector.as_ref().map_or(0, |dv| dv.cardinality);
+                read_rows += (stats.num_records - deleted_rows).max(0) as usize;
                 read_bytes += add.size as usize;
-                let partition_values = get_partition_values(add, &partition_fields[..])?;
+                let deletion_vector = add
+                    .deletion_vector
+                    .as_ref()
+                    .map(DeletionVector::try_create)
+                    .transpose()?;
+                let partition_values =
+                    get_partition_values(add, &partition_fields[..], &partition_keys[..])?;
                 Ok(Arc::new(
                     Box::new(DeltaPartInfo{
                         partition_values,
+                        deletion_vector,
                         data: ParquetPart::ParquetFiles(
                             ParquetFilesPart {
                             files: vec![(add.path.clone(), add.size as u64)],
@@ -366,4 +467,64 @@ impl Table for DeltaTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let sp = self.get_storage_params()?;
+        let table = Self::load_at(sp, point).await?;
+
+        // Schema and partition columns may be changed since the version.
+        let (schema, meta_string) = Self::get_meta(&table).await?;
+        let meta = serde_json::from_str(&meta_string).map_err(|e| {
+            ErrorCode::Internal(format!(
+                "fail to deserialize DeltaTableMeta({meta_string}): {e:?}"
+            ))
+        })?;
+        let mut info = self.info.clone();
+        info.meta.schema = Arc::new(schema);
+        info.meta
+            .engine_options
+            .insert(OPT_KEY_ENGINE_META.to_string(), meta_string);
+
+        Ok(Arc::new(DeltaTable {
+            info,
+            table: OnceCell::new_with(Some(table)),
+            meta,
+        }))
+    }
+
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _pipeline: &mut Pipeline,
+        _: AppendMode,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        if overwrite {
+            return Err(overwrite_unsupported("delta", self.name()));
+        }
+
+        let sp = self.get_storage_params()?.clone();
+        let op = init_operator(&sp)?;
+        add_commit_sink(pipeline, |input| {
+            Ok(ProcessorPtr::create(DeltaTableSink::create(
+                input,
+                ctx.clone(),
+                sp.clone(),
+                op.clone(),
+            )))
+        })
+    }
 }
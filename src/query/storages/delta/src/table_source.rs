@@ -33,6 +33,7 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSFullReader;
+use opendal::Operator;
 use opendal::Reader;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
 
@@ -51,6 +52,8 @@ pub struct DeltaTableSource {
 
     // Used to read parquet file.
     parquet_reader: Arc<ParquetRSFullReader>,
+    // Used to read deletion vector file.
+    op: Operator,
 
     // Used to insert partition_block_entries to data block
     // FieldIndex is the index in the output_schema
@@ -72,6 +75,7 @@ impl DeltaTableSource {
         output: Arc<OutputPort>,
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetRSFullReader>,
+        op: Operator,
         partition_fields: Vec<TableField>,
     ) -> Result<ProcessorPtr> {
         let output_partition_columns = output_schema
@@ -91,6 +95,7 @@ impl DeltaTableSource {
             scan_progress,
             ctx,
             parquet_reader,
+            op,
             output_schema,
             partition_fields,
             output_partition_columns,
@@ -180,9 +185,18 @@ impl Processor for DeltaTableSource {
                             BlockEntry::new(f.data_type().into(), Value::Scalar(v.clone()))
                         })
                         .collect::<Vec<_>>();
+                    // Rows in the deletion vector should be skipped.
+                    let deleted_rows = match &part.deletion_vector {
+                        Some(dv) => Some(dv.read(&self.op).await?.iter().collect::<Vec<_>>()),
+                        None => None,
+                    };
                     let stream = self
                         .parquet_reader
-                        .prepare_data_stream(&files.files[0].0, Some(&partition_fields))
+                        .prepare_data_stream(
+                            &files.files[0].0,
+                            Some(&partition_fields),
+                            deleted_rows.as_deref(),
+                        )
                         .await?;
                     self.stream = Some(stream);
                 }
@@ -50,11 +50,13 @@ use databend_common_sharing::create_share_table_operator;
 use databend_common_sql::binder::STREAM_COLUMN_FACTORY;
 use databend_common_sql::parse_exprs;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_sql::VectorIndexColumns;
 use databend_common_storage::init_operator;
 use databend_common_storage::DataOperator;
 use databend_common_storage::ShareTableConfig;
 use databend_common_storage::StorageMetrics;
 use databend_common_storage::StorageMetricsLayer;
+use databend_common_vector::VectorDistance;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::SnapshotId;
@@ -63,6 +65,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::table_storage_prefix;
+use databend_storages_common_table_meta::table::InvertedIndexes;
 use databend_storages_common_table_meta::table::TableCompression;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
@@ -74,6 +77,8 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_DISTANCE;
 use log::error;
 use log::warn;
 use opendal::Operator;
@@ -107,6 +112,9 @@ pub struct FuseTable {
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) vector_index_cols: VectorIndexColumns,
+    pub(crate) vector_index_distance: VectorDistance,
+    pub(crate) inverted_indexes: InvertedIndexes,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -211,6 +219,20 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        let vector_index_cols = table_info
+            .options()
+            .get(OPT_KEY_VECTOR_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<VectorIndexColumns>().ok())
+            .unwrap_or_default();
+
+        let vector_index_distance = table_info
+            .options()
+            .get(OPT_KEY_VECTOR_INDEX_DISTANCE)
+            .and_then(|s| s.parse::<VectorDistance>().ok())
+            .unwrap_or_default();
+
+        let inverted_indexes = InvertedIndexes::from_options(table_info.options())?;
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
         let meta_location_generator =
@@ -221,6 +243,9 @@ impl FuseTable {
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            vector_index_cols,
+            vector_index_distance,
+            inverted_indexes,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
@@ -435,6 +460,18 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn vector_index_cols(&self) -> VectorIndexColumns {
+        self.vector_index_cols.clone()
+    }
+
+    pub fn vector_index_distance(&self) -> VectorDistance {
+        self.vector_index_distance
+    }
+
+    pub fn inverted_indexes(&self) -> InvertedIndexes {
+        self.inverted_indexes.clone()
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -30,7 +30,9 @@ use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -154,6 +156,36 @@ impl TableMetaLocationGenerator {
         let block_name = splits[len - 1];
         format!("{prefix}/{FUSE_TBL_AGG_INDEX_PREFIX}/{index_id}/{block_name}")
     }
+
+    pub fn gen_vector_index_location_from_block_location(loc: &str) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = splits[len - 1];
+        let block_stem = block_name
+            .rsplit_once('.')
+            .map_or(block_name, |(stem, _)| stem);
+        format!("{prefix}/{FUSE_TBL_VECTOR_INDEX_PREFIX}/{block_stem}.hnsw")
+    }
+
+    pub fn gen_inverted_index_location_from_block_location(loc: &str, version: &str) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = splits[len - 1];
+        let block_stem = block_name
+            .rsplit_once('.')
+            .map_or(block_name, |(stem, _)| stem);
+        format!("{prefix}/{FUSE_TBL_INVERTED_INDEX_PREFIX}/{version}/{block_stem}.idx")
+    }
+
+    /// The directory of the files of an inverted index, removed when the index is dropped.
+    pub fn gen_inverted_index_prefix(&self, version: &str) -> String {
+        format!(
+            "{}/{}/{}/",
+            &self.prefix, FUSE_TBL_INVERTED_INDEX_PREFIX, version
+        )
+    }
 }
 
 trait SnapshotLocationCreator {
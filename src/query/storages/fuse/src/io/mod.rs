@@ -44,6 +44,9 @@ pub use write::write_data;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
 pub use write::CachedMetaWriter;
+pub use write::InvertedIndexBuilder;
+pub use write::InvertedIndexState;
 pub use write::MetaWriter;
 pub use write::SegmentWriter;
+pub use write::VectorIndexState;
 pub use write::WriteSettings;
@@ -28,12 +28,18 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use databend_common_io::tokenizer::Tokenizer;
+use databend_common_vector::VectorDistance;
 use databend_storages_common_blocks::blocks_to_parquet;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::InvertedIndex;
+use databend_storages_common_index::VectorIndex;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::ColumnMeta;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::table::InvertedIndexDefinition;
+use databend_storages_common_table_meta::table::InvertedIndexes;
 use databend_storages_common_table_meta::table::TableCompression;
 use opendal::Operator;
 
@@ -150,11 +156,95 @@ impl BloomIndexState {
     }
 }
 
+pub struct VectorIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl VectorIndexState {
+    pub fn try_create(
+        block: &DataBlock,
+        location: String,
+        distance: VectorDistance,
+        vector_columns_map: &BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        let maybe_vector_index = VectorIndex::try_create(distance, block, vector_columns_map)?;
+        Ok(maybe_vector_index.map(|vector_index| Self {
+            data: vector_index.to_bytes(),
+            location,
+        }))
+    }
+}
+
+/// The text columns of an inverted index of the table, indexed when a block is written.
+#[derive(Clone)]
+pub struct InvertedIndexBuilder {
+    pub version: String,
+    pub tokenizer: Tokenizer,
+    pub text_columns_map: BTreeMap<FieldIndex, TableField>,
+}
+
+impl InvertedIndexBuilder {
+    pub fn try_create(index: &InvertedIndexDefinition, schema: &TableSchemaRef) -> Result<Self> {
+        let text_columns_map = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| {
+                index.column_ids.contains(&field.column_id())
+                    && InvertedIndex::supported_type(field.data_type())
+            })
+            .map(|(i, field)| (i, field.clone()))
+            .collect();
+        Ok(Self {
+            version: index.version.clone(),
+            tokenizer: index.tokenizer()?,
+            text_columns_map,
+        })
+    }
+
+    pub fn try_create_builders(
+        inverted_indexes: &InvertedIndexes,
+        schema: &TableSchemaRef,
+    ) -> Result<Vec<Self>> {
+        inverted_indexes
+            .0
+            .iter()
+            .map(|index| Self::try_create(index, schema))
+            .collect()
+    }
+}
+
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        block: &DataBlock,
+        block_location: &str,
+        builder: &InvertedIndexBuilder,
+    ) -> Result<Option<Self>> {
+        let maybe_inverted_index =
+            InvertedIndex::try_create(builder.tokenizer, block, &builder.text_columns_map)?;
+        Ok(maybe_inverted_index.map(|inverted_index| Self {
+            data: inverted_index.to_bytes(),
+            location: TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                block_location,
+                &builder.version,
+            ),
+        }))
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub vector_index_state: Option<VectorIndexState>,
+    pub inverted_index_states: Vec<InvertedIndexState>,
 }
 
 #[derive(Clone)]
@@ -165,6 +255,9 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub vector_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub vector_index_distance: VectorDistance,
+    pub inverted_index_builders: Vec<InvertedIndexBuilder>,
 }
 
 impl BlockBuilder {
@@ -185,6 +278,26 @@ impl BlockBuilder {
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let vector_index_location =
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                &block_location.0,
+            );
+        let vector_index_state = VectorIndexState::try_create(
+            &data_block,
+            vector_index_location,
+            self.vector_index_distance,
+            &self.vector_columns_map,
+        )?;
+
+        let mut inverted_index_states = Vec::with_capacity(self.inverted_index_builders.len());
+        for builder in &self.inverted_index_builders {
+            if let Some(state) =
+                InvertedIndexState::try_create(&data_block, &block_location.0, builder)?
+            {
+                inverted_index_states.push(state);
+            }
+        }
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -220,6 +333,8 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            vector_index_state,
+            inverted_index_states,
         };
         Ok(serialized)
     }
@@ -223,7 +223,18 @@ impl FuseTable {
             update_stream_meta: update_stream_meta.to_vec(),
         };
 
-        // 3. let's roll
+        // 3. in an explicit transaction, buffer the request and commit it with the transaction
+        {
+            let txn_mgr = ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.update_table_meta(table_info.catalog(), req, table_info)?;
+                TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+                return Ok(());
+            }
+        }
+
+        // 4. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
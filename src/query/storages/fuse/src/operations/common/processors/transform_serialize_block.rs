@@ -34,11 +34,13 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::VectorIndex;
 use opendal::Operator;
 
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
+use crate::io::InvertedIndexBuilder;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
@@ -102,6 +104,11 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let vector_columns_map = table
+            .vector_index_cols
+            .vector_index_fields(source_schema.clone(), VectorIndex::supported_type)?;
+        let inverted_index_builders =
+            InvertedIndexBuilder::try_create_builders(&table.inverted_indexes, &source_schema)?;
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -109,6 +116,9 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            vector_columns_map,
+            vector_index_distance: table.vector_index_distance,
+            inverted_index_builders,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -306,6 +316,22 @@ impl Processor for TransformSerializeBlock {
                         );
                     }
                 }
+                if let Some(vector_index_state) = serialized.vector_index_state {
+                    write_data(
+                        vector_index_state.data,
+                        &self.dal,
+                        &vector_index_state.location,
+                    )
+                    .await?;
+                }
+                for inverted_index_state in serialized.inverted_index_states {
+                    write_data(
+                        inverted_index_state.data,
+                        &self.dal,
+                        &inverted_index_state.location,
+                    )
+                    .await?;
+                }
 
                 let data_block = if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::ReplacedBlock {
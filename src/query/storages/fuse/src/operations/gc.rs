@@ -342,6 +342,14 @@ impl FuseTable {
                         ),
                     )
                 }
+                if !self.vector_index_cols.is_empty() {
+                    purge_files.push(
+                        TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                            loc,
+                        ),
+                    )
+                }
+                purge_files.extend(self.inverted_index_locations(loc));
             }
 
             for loc in &locations.bloom_location {
@@ -382,6 +390,8 @@ impl FuseTable {
 
             let mut blocks_to_be_purged = HashSet::new();
             let mut agg_indexes_to_be_purged = HashSet::new();
+            let mut vector_indexes_to_be_purged = HashSet::new();
+            let mut inverted_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
                 if locations_referenced_by_root.block_location.contains(loc) {
                     continue;
@@ -394,6 +404,14 @@ impl FuseTable {
                         ),
                     );
                 }
+                if !self.vector_index_cols.is_empty() {
+                    vector_indexes_to_be_purged.insert(
+                        TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                            loc,
+                        ),
+                    );
+                }
+                inverted_indexes_to_be_purged.extend(self.inverted_index_locations(loc));
             }
 
             let mut blooms_to_be_purged = HashSet::new();
@@ -428,6 +446,8 @@ impl FuseTable {
                 counter,
                 blocks_to_be_purged,
                 agg_indexes_to_be_purged,
+                vector_indexes_to_be_purged,
+                inverted_indexes_to_be_purged,
                 blooms_to_be_purged,
                 segment_locations_to_be_purged,
             )
@@ -464,11 +484,28 @@ impl FuseTable {
             }));
         }
 
+        let mut vector_indexes_to_be_purged = HashSet::new();
+        if !self.vector_index_cols.is_empty() {
+            vector_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(
+                |loc| {
+                    TableMetaLocationGenerator::gen_vector_index_location_from_block_location(loc)
+                },
+            ));
+        }
+
+        let inverted_indexes_to_be_purged = root_location_tuple
+            .block_location
+            .iter()
+            .flat_map(|loc| self.inverted_index_locations(loc))
+            .collect::<HashSet<_>>();
+
         self.purge_block_segments(
             ctx,
             counter,
             root_location_tuple.block_location,
             agg_indexes_to_be_purged,
+            vector_indexes_to_be_purged,
+            inverted_indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
         )
@@ -493,6 +530,8 @@ impl FuseTable {
         counter: &mut PurgeCounter,
         blocks_to_be_purged: HashSet<String>,
         agg_indexes_to_be_purged: HashSet<String>,
+        vector_indexes_to_be_purged: HashSet<String>,
+        inverted_indexes_to_be_purged: HashSet<String>,
         blooms_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
@@ -511,6 +550,20 @@ impl FuseTable {
                 .await?;
         }
 
+        let vector_index_count = vector_indexes_to_be_purged.len();
+        if vector_index_count > 0 {
+            counter.vector_indexes += vector_index_count;
+            self.try_purge_location_files(ctx.clone(), vector_indexes_to_be_purged)
+                .await?;
+        }
+
+        let inverted_index_count = inverted_indexes_to_be_purged.len();
+        if inverted_index_count > 0 {
+            counter.inverted_indexes += inverted_index_count;
+            self.try_purge_location_files(ctx.clone(), inverted_indexes_to_be_purged)
+                .await?;
+        }
+
         // 2. Try to purge bloom index file chunks.
         let blooms_count = blooms_to_be_purged.len();
         if blooms_count > 0 {
@@ -663,6 +716,19 @@ impl FuseTable {
         );
         SnapshotsIO::list_files(self.get_operator(), &prefix, None).await
     }
+
+    // The locations of the inverted index files of the block, one for each inverted index.
+    fn inverted_index_locations<'a>(
+        &'a self,
+        block_location: &'a str,
+    ) -> impl Iterator<Item = String> + 'a {
+        self.inverted_indexes.0.iter().map(move |index| {
+            TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                block_location,
+                &index.version,
+            )
+        })
+    }
 }
 
 struct RootSnapshotInfo {
@@ -700,6 +766,8 @@ struct PurgeCounter {
     start: Instant,
     blocks: usize,
     agg_indexes: usize,
+    vector_indexes: usize,
+    inverted_indexes: usize,
     blooms: usize,
     segments: usize,
     table_statistics: usize,
@@ -712,6 +780,8 @@ impl PurgeCounter {
             start: Instant::now(),
             blocks: 0,
             agg_indexes: 0,
+            vector_indexes: 0,
+            inverted_indexes: 0,
             blooms: 0,
             segments: 0,
             table_statistics: 0,
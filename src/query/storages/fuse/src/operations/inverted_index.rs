@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::table::InvertedIndexDefinition;
+
+use crate::io::write_data;
+use crate::io::InvertedIndexBuilder;
+use crate::io::InvertedIndexState;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Build the files of the inverted index for the blocks that do not have one, e.g. the
+    /// blocks written before the index is created.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_inverted_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index: &InvertedIndexDefinition,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            // no snapshot
+            return Ok(());
+        };
+
+        let table_schema = self.schema();
+        let mut builder = InvertedIndexBuilder::try_create(index, &table_schema)?;
+        if builder.text_columns_map.is_empty() {
+            return Ok(());
+        }
+
+        // Only read the text columns, which are the leading columns of the projected block.
+        let field_indices = builder.text_columns_map.keys().copied().collect::<Vec<_>>();
+        builder.text_columns_map = builder
+            .text_columns_map
+            .into_values()
+            .enumerate()
+            .collect::<BTreeMap<_, _>>();
+        let projection = Projection::Columns(field_indices);
+        let block_reader =
+            self.create_block_reader(ctx.clone(), projection, false, false, false)?;
+
+        let segment_reader = MetaReaders::segment_info_reader(self.get_operator(), table_schema);
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let storage_format = self.get_write_settings().storage_format;
+        let operator = self.get_operator_ref();
+
+        for (location, ver) in snapshot.segments.iter() {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: location.to_string(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                let index_location =
+                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                        &block_meta.location.0,
+                        &builder.version,
+                    );
+                if operator.is_exist(&index_location).await? {
+                    continue;
+                }
+
+                let block = block_reader
+                    .read_by_meta(&settings, &block_meta, &storage_format)
+                    .await?;
+                let inverted_index_state =
+                    InvertedIndexState::try_create(&block, &block_meta.location.0, &builder)?;
+                if let Some(state) = inverted_index_state {
+                    write_data(state.data, operator, &state.location).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
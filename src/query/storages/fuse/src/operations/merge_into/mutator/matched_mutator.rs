@@ -427,6 +427,12 @@ impl AggregationContext {
         let new_block_raw_data = serialized.block_raw_data;
         let data_accessor = self.data_accessor.clone();
         write_data(new_block_raw_data, &data_accessor, &new_block_location).await?;
+        if let Some(index_state) = serialized.vector_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location).await?;
+        }
+        for index_state in serialized.inverted_index_states {
+            write_data(index_state.data, &data_accessor, &index_state.location).await?;
+        }
 
         metrics_inc_merge_into_replace_blocks_counter(1);
         metrics_inc_merge_into_replace_blocks_rows_counter(origin_num_rows as u32);
@@ -22,6 +22,7 @@ mod parquet_data_source;
 mod parquet_data_source_deserializer;
 mod parquet_data_source_reader;
 mod parquet_rows_fetcher;
+mod row_sample_transform;
 mod runtime_filter_prunner;
 
 mod data_source_with_meta;
@@ -32,4 +33,5 @@ pub use native_data_source_deserializer::NativeDeserializeDataTransform;
 pub use native_data_source_reader::ReadNativeDataSource;
 pub use parquet_data_source_deserializer::DeserializeDataTransform;
 pub use parquet_data_source_reader::ReadParquetDataSource;
+pub use row_sample_transform::TransformRowSample;
 pub use util::need_reserve_block_info;
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+/// Bernoulli row sampling, each row is kept with the given probability.
+///
+/// Every processor has its own random generator seeded by the sample seed,
+/// so the sampled rows are reproducible as long as the blocks are read in
+/// the same order.
+pub struct TransformRowSample {
+    probability: f64,
+    rng: StdRng,
+}
+
+impl TransformRowSample {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        probability: f64,
+        seed: u64,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            TransformRowSample {
+                probability: probability.clamp(0.0, 1.0),
+                rng: StdRng::seed_from_u64(seed),
+            },
+        )))
+    }
+}
+
+impl Transform for TransformRowSample {
+    const NAME: &'static str = "TransformRowSample";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        for _ in 0..num_rows {
+            bitmap.push(self.rng.gen_bool(self.probability));
+        }
+        data.filter_with_bitmap(&bitmap.into())
+    }
+}
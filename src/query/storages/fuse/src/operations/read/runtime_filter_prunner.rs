@@ -139,7 +139,8 @@ pub(crate) fn update_bitmap_with_bloom_filter(
             match key_state {
                 KeysState::Column(Column::Binary(col))
                 | KeysState::Column(Column::Variant(col))
-                | KeysState::Column(Column::Bitmap(col)) => col.iter().for_each(|key| {
+                | KeysState::Column(Column::Bitmap(col))
+                | KeysState::Column(Column::Geometry(col)) => col.iter().for_each(|key| {
                     let hash = key.fast_hash();
                     if filter.contains(&hash) {
                         bitmap.set(idx, true);
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_base::runtime::Runtime;
@@ -34,6 +36,7 @@ use crate::io::BlockReader;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::build_fuse_parquet_source_pipeline;
 use crate::operations::read::fuse_source::build_fuse_native_source_pipeline;
+use crate::operations::read::TransformRowSample;
 use crate::pruning::SegmentLocation;
 use crate::FuseLazyPartInfo;
 use crate::FuseStorageFormat;
@@ -139,6 +142,27 @@ impl FuseTable {
         Ok(())
     }
 
+    fn apply_row_sample_if_needed(
+        &self,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let sample = plan.push_downs.as_ref().and_then(|p| p.sample.as_ref());
+        if let Some(sample) = sample {
+            if let Some(probability) = sample.row_probability {
+                // Seed the processors differently, or they sample the same rows of their blocks.
+                let seed = sample.seed;
+                let processor_id = AtomicU64::new(0);
+                pipeline.add_transform(|input, output| {
+                    let id = processor_id.fetch_add(1, Ordering::Relaxed);
+                    TransformRowSample::create(input, output, probability.0, seed.wrapping_add(id))
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
     #[inline]
     pub fn do_read_data(
         &self,
@@ -250,6 +274,9 @@ impl FuseTable {
             virtual_reader,
         )?;
 
+        // filter the rows by the row sample if needed
+        self.apply_row_sample_if_needed(plan, pipeline)?;
+
         // replace the column which has data mask if needed
         self.apply_data_mask_policy_if_needed(ctx, plan, pipeline)?;
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::hash::Hasher;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -41,6 +42,7 @@ use log::info;
 use opendal::Operator;
 use sha2::Digest;
 use sha2::Sha256;
+use siphasher::sip::SipHasher24;
 
 use crate::fuse_part::FusePartInfo;
 use crate::pruning::FusePruner;
@@ -184,6 +186,8 @@ impl FuseTable {
                 self.bloom_index_cols(),
             )?
         };
+        pruner.set_vector_index_columns(self.vector_index_cols());
+        pruner.set_inverted_indexes(self.inverted_indexes());
 
         let block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
@@ -194,8 +198,15 @@ impl FuseTable {
             start.elapsed().as_secs()
         );
 
+        let sample = push_downs.as_ref().and_then(|p| p.sample.as_ref());
         let block_metas = block_metas
             .into_iter()
+            .filter(|(_, block_meta)| match sample {
+                Some(sample) => sample.block_probability.map_or(true, |probability| {
+                    Self::sample_block(&block_meta.location.0, sample.seed, probability.0)
+                }),
+                None => true,
+            })
             .map(|(block_meta_index, block_meta)| (Some(block_meta_index), block_meta))
             .collect::<Vec<_>>();
 
@@ -217,6 +228,17 @@ impl FuseTable {
         Ok(result)
     }
 
+    // A block is sampled if the hash of its location is under the probability,
+    // so the same seed always selects the same blocks of a snapshot.
+    fn sample_block(location: &str, seed: u64, probability: f64) -> bool {
+        if probability >= 1.0 {
+            return true;
+        }
+        let mut hasher = SipHasher24::new_with_keys(seed, 0);
+        hasher.write(location.as_bytes());
+        (hasher.finish() as f64 / u64::MAX as f64) < probability
+    }
+
     pub fn read_partitions_with_metas(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -70,8 +70,11 @@ pub fn row_hash_of_columns(
                 NumberScalar::Float64(v) => sip.write_u64(v.to_bits()),
             },
             ScalarRef::Timestamp(v) => sip.write_i64(v),
+            // Values of the same instant are the same key whatever the offset is.
+            ScalarRef::TimestampTz(v) => sip.write_i64(v.timestamp()),
             ScalarRef::String(v) => sip.write(v.as_bytes()),
             ScalarRef::Bitmap(v) => sip.write(v),
+            ScalarRef::Geometry(v) => sip.write(v),
             ScalarRef::Decimal(v) => match v {
                 DecimalScalar::Decimal128(i, DecimalSize { precision, scale }) => {
                     sip.write_i128(i);
@@ -69,19 +69,30 @@ impl FuseTable {
             let table_version = self.table_info.ident.seq;
             let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
 
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            };
+
+            // in an explicit transaction, the new snapshot is committed with the transaction,
+            // and the copied files are purged after the commit.
+            {
+                let txn_mgr = ctx.txn_mgr();
+                let mut txn_mgr = txn_mgr.lock();
+                if txn_mgr.is_active() {
+                    txn_mgr.truncate_table(self.table_info.catalog(), req, &self.table_info)?;
+                    return Ok(());
+                }
+            }
+
             // commit table meta to meta server.
             // `truncate_table` is not supposed to be retry-able, thus we use
             // `update_data_table_meta` directly.
-            catalog
-                .update_table_meta(&self.table_info, UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                    copied_files: None,
-                    deduplicated_label: None,
-                    update_stream_meta: vec![],
-                })
-                .await?;
+            catalog.update_table_meta(&self.table_info, req).await?;
 
             catalog
                 .truncate_table(&self.table_info, TruncateTableReq {
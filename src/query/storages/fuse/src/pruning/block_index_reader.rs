@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+
+use databend_common_base::base::tokio::sync::OwnedSemaphorePermit;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use futures_util::future;
+use log::debug;
+use opendal::Operator;
+
+use crate::pruning::PruningContext;
+
+/// Read and load the index file of a block, e.g. the inverted index or the vector index.
+///
+/// Returns `None` if the file does not exist or fails to load, the block can't be pruned
+/// by the index then.
+pub(crate) async fn read_block_index<T, F>(
+    dal: &Operator,
+    location: &str,
+    index_name: &str,
+    load: F,
+) -> Option<T>
+where
+    F: FnOnce(&[u8]) -> Result<T>,
+{
+    let data = dal
+        .read(location)
+        .await
+        .inspect_err(|e| {
+            if e.kind() == opendal::ErrorKind::NotFound {
+                debug!("{index_name} `{location}` not found.")
+            } else {
+                debug!("Read {index_name} `{location}` failed: {e}");
+            }
+        })
+        .ok()?;
+    load(&data)
+        .inspect_err(|e| debug!("Load {index_name} `{location}` failed: {e}"))
+        .ok()
+}
+
+/// Run the pruning task of each block in the pruning runtime, the number of running tasks
+/// is limited by the pruning semaphore. The results are in the order of the tasks.
+pub(crate) async fn spawn_block_tasks<F, Fut>(
+    pruning_ctx: &PruningContext,
+    index_name: &str,
+    tasks: impl IntoIterator<Item = F>,
+) -> Result<Vec<Fut::Output>>
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    let tasks = tasks.into_iter().map(|task| {
+        move |permit: OwnedSemaphorePermit| async move {
+            let _permit = permit;
+            task().await
+        }
+    });
+    let join_handlers = pruning_ctx
+        .pruning_runtime
+        .try_spawn_batch_with_owned_semaphore(pruning_ctx.pruning_semaphore.clone(), tasks)
+        .await?;
+    future::try_join_all(join_handlers)
+        .await
+        .map_err(|e| ErrorCode::StorageOther(format!("{index_name} pruning failure, {}", e)))
+}
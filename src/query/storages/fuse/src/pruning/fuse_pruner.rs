@@ -27,6 +27,7 @@ use databend_common_expression::SEGMENT_NAME_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::field_default_value;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_sql::VectorIndexColumns;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_pruner::BlockMetaIndex;
 use databend_storages_common_pruner::InternalColumnPruner;
@@ -41,6 +42,7 @@ use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use databend_storages_common_table_meta::table::InvertedIndexes;
 use log::warn;
 use opendal::Operator;
 
@@ -50,7 +52,9 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -179,6 +183,8 @@ pub struct FusePruner {
     pub push_down: Option<PushDownInfo>,
     pub inverse_range_index: Option<RangeIndex>,
     pub deleted_segments: Vec<DeletedSegmentInfo>,
+    pub vector_index_cols: VectorIndexColumns,
+    pub inverted_indexes: InvertedIndexes,
 }
 
 impl FusePruner {
@@ -242,6 +248,8 @@ impl FusePruner {
             pruning_ctx,
             inverse_range_index: None,
             deleted_segments: vec![],
+            vector_index_cols: VectorIndexColumns::default(),
+            inverted_indexes: InvertedIndexes::default(),
         })
     }
 
@@ -261,7 +269,7 @@ impl FusePruner {
         self.pruning(segment_locs, true).await
     }
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> inverted index pruner -> topn pruner -> vector pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -375,8 +383,12 @@ impl FusePruner {
                 } else {
                     // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                     // will get here, we can prevent other mutations like update and so on.
+                    // Inverted index pruner.
+                    let metas = self.inverted_index_pruning(metas).await?;
                     // TopN pruner.
-                    self.topn_pruning(metas)
+                    let metas = self.topn_pruning(metas)?;
+                    // Vector pruner.
+                    self.vector_pruning(metas).await
                 }
             }
         }
@@ -404,6 +416,48 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // inverted index pruner:
+    // if there are filters of `match` on the columns of inverted indexes, use inverted index pruner
+    #[async_backtrace::framed]
+    async fn inverted_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        if self.inverted_indexes.is_empty() {
+            return Ok(metas);
+        }
+        match InvertedIndexPruner::try_create(
+            self.pruning_ctx.clone(),
+            &self.table_schema,
+            &self.push_down,
+            &self.inverted_indexes,
+        )? {
+            Some(inverted_index_pruner) => inverted_index_pruner.prune(metas).await,
+            None => Ok(metas),
+        }
+    }
+
+    // vector pruner:
+    // if there are ordering by a vector distance + limit clause and no filters, use vector pruner
+    #[async_backtrace::framed]
+    async fn vector_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        if self.vector_index_cols.is_empty() {
+            return Ok(metas);
+        }
+        match VectorPruner::try_create(
+            self.pruning_ctx.clone(),
+            &self.table_schema,
+            &self.push_down,
+            &self.vector_index_cols,
+        )? {
+            Some(vector_pruner) => vector_pruner.prune(metas).await,
+            None => Ok(metas),
+        }
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> databend_common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
@@ -434,4 +488,12 @@ impl FusePruner {
     pub fn get_inverse_range_index(&self) -> Option<RangeIndex> {
         self.inverse_range_index.clone()
     }
+
+    pub fn set_vector_index_columns(&mut self, vector_index_cols: VectorIndexColumns) {
+        self.vector_index_cols = vector_index_cols;
+    }
+
+    pub fn set_inverted_indexes(&mut self, inverted_indexes: InvertedIndexes) {
+        self.inverted_indexes = inverted_indexes;
+    }
 }
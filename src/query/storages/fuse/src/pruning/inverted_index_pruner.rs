@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::tokenizer::Tokenizer;
+use databend_storages_common_index::FilterEvalResult;
+use databend_storages_common_index::InvertedIndex;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::table::InvertedIndexes;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::block_index_reader::read_block_index;
+use crate::pruning::block_index_reader::spawn_block_tasks;
+use crate::pruning::PruningContext;
+
+/// Inverted index pruner.
+/// Pruning for the filters of `match(col, 'query')` with the inverted indexes of the blocks.
+///
+/// The terms of the query are looked up in the inverted index files of each block, the blocks
+/// in which the filter must be false are removed. The blocks without an inverted index are
+/// always kept.
+pub struct InvertedIndexPruner {
+    pruning_ctx: Arc<PruningContext>,
+    func_ctx: FunctionContext,
+    schema: TableSchemaRef,
+    filter_expr: Arc<Expr<String>>,
+    /// The version and the tokenizer of each inverted index.
+    indexes: Arc<Vec<(String, Tokenizer)>>,
+}
+
+impl InvertedIndexPruner {
+    /// Returns `None` if the filters do not call `match`.
+    pub fn try_create(
+        pruning_ctx: Arc<PruningContext>,
+        schema: &TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+        inverted_indexes: &InvertedIndexes,
+    ) -> Result<Option<Self>> {
+        let Some(filters) = push_down.as_ref().and_then(|p| p.filters.as_ref()) else {
+            return Ok(None);
+        };
+        let filter_expr = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+        if !contains_match(&filter_expr) {
+            return Ok(None);
+        }
+
+        let indexes = inverted_indexes
+            .0
+            .iter()
+            .map(|index| Ok((index.version.clone(), index.tokenizer()?)))
+            .collect::<Result<Vec<_>>>()?;
+        let func_ctx = pruning_ctx.ctx.get_function_context()?;
+
+        Ok(Some(Self {
+            pruning_ctx,
+            func_ctx,
+            schema: schema.clone(),
+            filter_expr: Arc::new(filter_expr),
+            indexes: Arc::new(indexes),
+        }))
+    }
+
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let pruning_tasks = metas.iter().map(|(_, block_meta)| {
+            let dal = self.pruning_ctx.dal.clone();
+            let block_location = block_meta.location.0.clone();
+            let func_ctx = self.func_ctx.clone();
+            let schema = self.schema.clone();
+            let filter_expr = self.filter_expr.clone();
+            let indexes = self.indexes.clone();
+            move || async move {
+                for (version, tokenizer) in indexes.iter() {
+                    let location =
+                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                            &block_location,
+                            version,
+                        );
+                    let Some(inverted_index) = read_block_index(
+                        &dal,
+                        &location,
+                        "inverted index",
+                        InvertedIndex::from_bytes,
+                    )
+                    .await
+                    else {
+                        continue;
+                    };
+                    let result = inverted_index.apply(
+                        filter_expr.as_ref().clone(),
+                        *tokenizer,
+                        schema.clone(),
+                        &func_ctx,
+                    )?;
+                    if result == FilterEvalResult::MustFalse {
+                        return Ok(false);
+                    }
+                }
+                Result::<_, ErrorCode>::Ok(true)
+            }
+        });
+
+        let results = spawn_block_tasks(&self.pruning_ctx, "inverted index", pruning_tasks).await?;
+
+        let mut pruned = Vec::with_capacity(metas.len());
+        for (meta, keep) in metas.into_iter().zip(results) {
+            if keep? {
+                pruned.push(meta);
+            }
+        }
+        Ok(pruned)
+    }
+}
+
+fn contains_match(expr: &Expr<String>) -> bool {
+    match expr {
+        Expr::FunctionCall { function, args, .. } => {
+            function.signature.name == "match" || args.iter().any(contains_match)
+        }
+        Expr::Cast { expr, .. } => contains_match(expr),
+        _ => false,
+    }
+}
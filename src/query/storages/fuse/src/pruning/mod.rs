@@ -12,19 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod block_index_reader;
 mod block_pruner;
 mod bloom_pruner;
 mod fuse_pruner;
+mod inverted_index_pruner;
 mod pruner_location;
 mod pruning_statistics;
 mod segment_pruner;
+mod vector_pruner;
 
 pub use block_pruner::BlockPruner;
 pub use bloom_pruner::BloomPruner;
 pub use bloom_pruner::BloomPrunerCreator;
 pub use fuse_pruner::FusePruner;
 pub use fuse_pruner::PruningContext;
+pub use inverted_index_pruner::InvertedIndexPruner;
 pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
 pub use segment_pruner::SegmentPruner;
+pub use vector_pruner::VectorPruner;
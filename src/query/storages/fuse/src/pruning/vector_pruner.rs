@@ -0,0 +1,181 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::TableSchemaRef;
+use databend_common_sql::VectorIndexColumns;
+use databend_common_vector::VectorDistance;
+use databend_storages_common_index::scalar_to_vector;
+use databend_storages_common_index::VectorIndex;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use opendal::Operator;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::block_index_reader::read_block_index;
+use crate::pruning::block_index_reader::spawn_block_tasks;
+use crate::pruning::PruningContext;
+
+/// Vector pruner.
+/// Pruning for `ORDER BY distance(col, const) LIMIT N` with the vector indexes of the blocks.
+///
+/// The approximate nearest rows of each block are looked up in its vector index, only the
+/// blocks holding the `N` nearest of them are kept. The blocks without a vector index are
+/// always kept.
+pub struct VectorPruner {
+    pruning_ctx: Arc<PruningContext>,
+    column_id: ColumnId,
+    distance: VectorDistance,
+    query: Arc<Vec<f32>>,
+    limit: usize,
+}
+
+impl VectorPruner {
+    /// Returns `None` if the push down is not a top-k distance search on a vector index column.
+    pub fn try_create(
+        pruning_ctx: Arc<PruningContext>,
+        schema: &TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+        vector_index_cols: &VectorIndexColumns,
+    ) -> Result<Option<Self>> {
+        let Some(push_down) = push_down else {
+            return Ok(None);
+        };
+        let (Some(limit), None, [(expr, true, false)]) = (
+            push_down.limit,
+            &push_down.filters,
+            push_down.order_by.as_slice(),
+        ) else {
+            return Ok(None);
+        };
+        let RemoteExpr::FunctionCall { id, args, .. } = expr else {
+            return Ok(None);
+        };
+        let Some(distance) = [VectorDistance::Cosine, VectorDistance::L2]
+            .into_iter()
+            .find(|distance| distance.function_name() == id.name())
+        else {
+            return Ok(None);
+        };
+
+        let [column, RemoteExpr::Constant { scalar, .. }] = args.as_slice() else {
+            return Ok(None);
+        };
+        let column = match column {
+            RemoteExpr::Cast { expr, .. } => expr.as_ref(),
+            column => column,
+        };
+        let RemoteExpr::ColumnRef { id: column, .. } = column else {
+            return Ok(None);
+        };
+        let Some(query) = scalar_to_vector(scalar.as_ref()) else {
+            return Ok(None);
+        };
+
+        let vector_columns_map =
+            vector_index_cols.vector_index_fields(schema.clone(), VectorIndex::supported_type)?;
+        let Some(field) = vector_columns_map
+            .values()
+            .find(|field| field.name() == column)
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            pruning_ctx,
+            column_id: field.column_id(),
+            distance,
+            query: Arc::new(query),
+            limit,
+        }))
+    }
+
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        if metas.len() <= 1 {
+            return Ok(metas);
+        }
+
+        let pruning_tasks =
+            metas.iter().map(|(_, block_meta)| {
+                let dal = self.pruning_ctx.dal.clone();
+                let location =
+                    TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                        &block_meta.location.0,
+                    );
+                let column_id = self.column_id;
+                let distance = self.distance;
+                let query = self.query.clone();
+                let limit = self.limit;
+                move || async move {
+                    Self::search(dal, location, column_id, distance, &query, limit).await
+                }
+            });
+        let results = spawn_block_tasks(&self.pruning_ctx, "vector index", pruning_tasks).await?;
+
+        // The nearest rows of all the indexed blocks, and the block they belong to.
+        let mut candidates = results
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, distances)| distances.as_ref().map(|d| (idx, d)))
+            .flat_map(|(idx, distances)| distances.iter().map(move |d| (*d, idx)))
+            .collect::<Vec<_>>();
+        if candidates.len() < self.limit {
+            // Some rows are not indexed, e.g. the zero vectors of the cosine distance.
+            return Ok(metas);
+        }
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let nearest_blocks = candidates
+            .iter()
+            .take(self.limit)
+            .map(|(_, idx)| *idx)
+            .collect::<HashSet<_>>();
+
+        Ok(metas
+            .into_iter()
+            .zip(results)
+            .enumerate()
+            .filter(|(idx, (_, distances))| distances.is_none() || nearest_blocks.contains(idx))
+            .map(|(_, (meta, _))| meta)
+            .collect())
+    }
+
+    /// Search the nearest rows of the block, returns their distances.
+    /// Returns `None` if the block has no usable vector index.
+    async fn search(
+        dal: Operator,
+        location: String,
+        column_id: ColumnId,
+        distance: VectorDistance,
+        query: &[f32],
+        limit: usize,
+    ) -> Option<Vec<f32>> {
+        let vector_index =
+            read_block_index(&dal, &location, "vector index", VectorIndex::from_bytes).await?;
+        let index = vector_index.indexes.get(&column_id)?;
+        if index.distance() != distance {
+            return None;
+        }
+        let nearest = index.search(query, limit).ok()?;
+        Some(nearest.into_iter().map(|(_, d)| d).collect())
+    }
+}
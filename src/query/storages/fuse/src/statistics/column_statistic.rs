@@ -15,11 +15,13 @@
 use std::collections::HashMap;
 
 use databend_common_exception::Result;
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
+use databend_common_expression::Domain;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
@@ -66,30 +68,35 @@ pub fn gen_columns_statistics(
         let mut min = Scalar::Null;
         let mut max = Scalar::Null;
 
-        let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
-        let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
+        if data_type.remove_nullable() == DataType::Geometry {
+            // Geometries have no order, the min and max are the corners of their bounding box.
+            (min, max) = geometry_min_max(col);
+        } else {
+            let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
+            let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
 
-        if mins.len() > 0 {
-            min = if let Some(v) = mins.index(0) {
-                if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
-                    v
+            if mins.len() > 0 {
+                min = if let Some(v) = mins.index(0) {
+                    if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
-        }
 
-        if maxs.len() > 0 {
-            max = if let Some(v) = maxs.index(0) {
-                if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
-                    v
+            if maxs.len() > 0 {
+                max = if let Some(v) = maxs.index(0) {
+                    if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
         }
 
@@ -131,7 +138,21 @@ pub fn gen_columns_statistics(
     Ok(statistics)
 }
 
+fn geometry_min_max(col: &Column) -> (Scalar, Scalar) {
+    let (is_all_null, validity) = col.validity();
+    if is_all_null || validity.is_some_and(|v| v.unset_bits() == v.len()) {
+        return (Scalar::Null, Scalar::Null);
+    }
+    match col.remove_nullable().domain() {
+        Domain::Geometry(domain) => domain.to_stats(),
+        _ => unreachable!(),
+    }
+}
+
 pub fn scalar_min_max(data_type: &DataType, scalar: Scalar) -> Option<(Scalar, Scalar)> {
+    if let Scalar::Geometry(wkb) = &scalar {
+        return Some(GeometryDomain::from_wkb(wkb).to_stats());
+    }
     if RangeIndex::supported_type(data_type) {
         if let Some((min, Some(max))) = scalar
             .clone()
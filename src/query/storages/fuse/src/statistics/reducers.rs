@@ -15,6 +15,7 @@
 use std::borrow::Borrow;
 use std::collections::HashMap;
 
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
@@ -62,6 +63,24 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
+            if matches!(
+                min_stats.iter().find(|s| !s.is_null()),
+                Some(Scalar::Geometry(_))
+            ) {
+                // The bounding boxes of geometries are merged instead of ordering the corners.
+                let (min, max) = min_stats
+                    .iter()
+                    .zip(max_stats.iter())
+                    .filter_map(|(min, max)| GeometryDomain::from_stats(min, max))
+                    .reduce(|a, b| a.merge(&b))
+                    .map_or((Scalar::Null, Scalar::Null), |domain| domain.to_stats());
+                acc.insert(
+                    *id,
+                    ColumnStatistics::new(min, max, null_count, in_memory_size, None),
+                );
+                return acc;
+            }
+
             let min = min_stats
                 .into_iter()
                 .filter(|s| !s.is_null())
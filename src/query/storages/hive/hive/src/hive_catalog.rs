@@ -223,12 +223,52 @@ impl HiveCatalog {
             .collect())
     }
 
+    /// Create a partition with the default location, the partition name likes `k1=v1/k2=v2`.
+    #[async_backtrace::framed]
+    pub async fn append_partition_by_name(
+        &self,
+        db: String,
+        table: String,
+        partition_name: String,
+    ) -> Result<Partition> {
+        self.client
+            .append_partition_by_name(
+                FastStr::new(db),
+                FastStr::new(table),
+                FastStr::new(partition_name),
+            )
+            .await
+            .map_err(from_thrift_error)
+    }
+
+    /// Drop a partition, data of the partition is deleted by the metastore for managed tables.
+    #[async_backtrace::framed]
+    pub async fn drop_partition_by_name(
+        &self,
+        db: String,
+        table: String,
+        partition_name: String,
+        delete_data: bool,
+    ) -> Result<bool> {
+        self.client
+            .drop_partition_by_name(
+                FastStr::new(db),
+                FastStr::new(table),
+                FastStr::new(partition_name),
+                delete_data,
+            )
+            .await
+            .map_err(from_thrift_error)
+    }
+
     fn handle_table_meta(table_meta: &hive_metastore::Table) -> Result<()> {
         if let Some(sd) = table_meta.sd.as_ref() {
             if let Some(input_format) = sd.input_format.as_ref() {
-                if input_format != "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat" {
+                if input_format != "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat"
+                    && input_format != "org.apache.hadoop.hive.ql.io.orc.OrcInputFormat"
+                {
                     return Err(ErrorCode::Unimplemented(format!(
-                        "only support parquet, {} not support",
+                        "only support parquet and orc, {} not support",
                         input_format
                     )));
                 }
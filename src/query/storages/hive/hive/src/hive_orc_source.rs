@@ -0,0 +1,147 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storages_orc::read_orc_metadata_async;
+use databend_common_storages_orc::stripe_range;
+use databend_common_storages_orc::OrcPruner;
+use databend_common_storages_orc::OrcReader;
+use opendal::Operator;
+
+use crate::HivePartInfo;
+use crate::HivePartitionFiller;
+
+/// Reads the hive partitions stored as ORC files.
+///
+/// Only the stripes starting in the range of a [`HivePartInfo`] are read, so that a file split
+/// into several parts is read exactly once.
+pub struct HiveOrcSource {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    func_ctx: FunctionContext,
+    reader: Arc<OrcReader>,
+    pruner: Arc<OrcPruner>,
+    partition_filler: Option<HivePartitionFiller>,
+    prewhere_filter: Arc<Option<Expr>>,
+    source_schema: DataSchemaRef,
+    output_schema: DataSchemaRef,
+    blocks: VecDeque<DataBlock>,
+}
+
+impl HiveOrcSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        output: Arc<OutputPort>,
+        reader: Arc<OrcReader>,
+        pruner: Arc<OrcPruner>,
+        partition_filler: Option<HivePartitionFiller>,
+        prewhere_filter: Arc<Option<Expr>>,
+        source_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        let func_ctx = ctx.get_function_context()?;
+        AsyncSourcer::create(ctx.clone(), output, HiveOrcSource {
+            ctx,
+            dal,
+            func_ctx,
+            reader,
+            pruner,
+            partition_filler,
+            prewhere_filter,
+            source_schema,
+            output_schema,
+            blocks: VecDeque::new(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_part(&mut self, part: &HivePartInfo) -> Result<()> {
+        let meta =
+            read_orc_metadata_async(self.dal.clone(), &part.filename, Some(part.filesize)).await?;
+        let stripes = &meta.metadata.footer.stripes;
+        for i in self.pruner.prune_stripes(&meta.metadata) {
+            let range = stripe_range(&stripes[i]);
+            if !part.range.contains(&range.start) {
+                continue;
+            }
+            let blocks = self
+                .reader
+                .read(&part.filename, part.filesize, range)
+                .await?;
+            for block in blocks {
+                let num_rows = block.num_rows();
+                let block = match &self.partition_filler {
+                    Some(filler) => filler.fill_data(block, part, num_rows)?,
+                    None => block,
+                };
+                let block = self.filter(block)?;
+                if !block.is_empty() {
+                    let block = block.resort(&self.source_schema, &self.output_schema)?;
+                    self.blocks.push_back(block);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn filter(&self, block: DataBlock) -> Result<DataBlock> {
+        let Some(filter) = self.prewhere_filter.as_ref() else {
+            return Ok(block);
+        };
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let predicates = evaluator
+            .run(filter)
+            .map_err(|e| e.add_message("eval prewhere filter failed:"))?
+            .try_downcast::<BooleanType>()
+            .unwrap();
+        block.filter_boolean_value(&predicates)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for HiveOrcSource {
+    const NAME: &'static str = "HiveOrcSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            let Some(part) = self.ctx.get_partition() else {
+                return Ok(None);
+            };
+            let part = HivePartInfo::from_part(&part)?;
+            self.read_part(part).await?;
+        }
+    }
+}
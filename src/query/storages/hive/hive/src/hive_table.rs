@@ -16,6 +16,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
+use arrow_schema::Schema as ArrowSchema;
 use async_recursion::async_recursion;
 use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::Semaphore;
@@ -26,6 +27,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableStatistics;
@@ -34,10 +36,12 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -52,6 +56,10 @@ use databend_common_pipeline_sources::SyncSource;
 use databend_common_pipeline_sources::SyncSourcer;
 use databend_common_storage::init_operator;
 use databend_common_storage::DataOperator;
+use databend_common_storages_orc::OrcPruner;
+use databend_common_storages_orc::OrcReader;
+use databend_common_storages_parquet::lake_sink::add_commit_sink;
+use databend_common_storages_parquet::lake_sink::overwrite_unsupported;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
@@ -64,12 +72,17 @@ use opendal::Operator;
 
 use super::hive_catalog::HiveCatalog;
 use super::hive_partition_pruner::HivePartitionPruner;
+use super::hive_table_options::HiveFileFormat;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
+use crate::hive_orc_source::HiveOrcSource;
 use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::hive_table_sink::check_partition_value;
+use crate::hive_table_sink::HiveTableSink;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
 use crate::HiveFileSplitter;
+use crate::HivePartitionFiller;
 
 pub const HIVE_TABLE_ENGINE: &str = "hive";
 pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
@@ -179,6 +192,10 @@ impl HiveTable {
         plan: &DataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        if self.table_options.file_format == HiveFileFormat::Orc {
+            return self.do_read_orc(ctx, plan, pipeline);
+        }
+
         let push_downs = &plan.push_downs;
         let chunk_size = ctx.get_settings().get_hive_parquet_chunk_size()? as usize;
 
@@ -237,6 +254,85 @@ impl HiveTable {
         Ok(())
     }
 
+    fn do_read_orc(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let settings = ctx.get_settings();
+        let max_threads = std::cmp::min(plan.parts.len(), settings.get_max_threads()? as usize);
+        let func_ctx = ctx.get_function_context()?;
+        let schema = self.table_info.schema();
+
+        // The columns needed by the prewhere filter may not be in the output.
+        let mut projection = self.get_projections(&plan.push_downs)?;
+        let prewhere = PushDownInfo::prewhere_of_push_downs(plan.push_downs.as_ref());
+        if let Some(Projection::Columns(prewhere_columns)) =
+            prewhere.as_ref().map(|v| &v.prewhere_columns)
+        {
+            for i in prewhere_columns {
+                if !projection.contains(i) {
+                    projection.push(*i);
+                }
+            }
+        }
+        let (data_columns, partition_fields) = filter_hive_partition_from_partition_keys(
+            schema.clone(),
+            projection,
+            &self.table_options.partition_keys,
+        );
+        let data_schema = Arc::new(schema.project(&data_columns));
+        let mut source_fields = DataSchema::from(data_schema.as_ref()).fields().clone();
+        source_fields.extend(partition_fields.iter().map(DataField::from));
+        let source_schema = DataSchemaRefExt::create(source_fields);
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+
+        // Columns added after the files of a partition were written are read as their default
+        // values, which are NULL as the columns of Hive tables are nullable.
+        let default_values = data_schema
+            .fields()
+            .iter()
+            .map(|f| Scalar::default_value(&f.data_type().into()))
+            .collect();
+        let reader = Arc::new(OrcReader::create(
+            self.dal.clone(),
+            func_ctx.clone(),
+            data_schema,
+            Some(default_values),
+            settings.get_max_block_size()? as usize,
+        ));
+        let pruner = if settings.get_enable_hive_parquet_predict_pushdown()? == 0 {
+            OrcPruner::try_create(func_ctx, schema.clone(), &None)?
+        } else {
+            OrcPruner::try_create(func_ctx, schema.clone(), &plan.push_downs)?
+        };
+        let pruner = Arc::new(pruner);
+        let partition_filler = if partition_fields.is_empty() {
+            None
+        } else {
+            Some(HivePartitionFiller::create(schema, partition_fields))
+        };
+        let prewhere_filter = self.build_prewhere_filter_executor(plan, source_schema.clone())?;
+
+        pipeline.add_source(
+            |output| {
+                HiveOrcSource::create(
+                    ctx.clone(),
+                    self.dal.clone(),
+                    output,
+                    reader.clone(),
+                    pruner.clone(),
+                    partition_filler.clone(),
+                    prewhere_filter.clone(),
+                    source_schema.clone(),
+                    output_schema.clone(),
+                )
+            },
+            std::cmp::max(1, max_threads),
+        )
+    }
+
     // simple select query is the sql likes `select * from xx limit 10` or
     // `select * from xx where p_date = '20220201' limit 10` where p_date is a partition column;
     // we just need to read a few data from table
@@ -387,6 +483,70 @@ impl HiveTable {
         Ok(Arc::new(TableSchema::new(fields)))
     }
 
+    /// Database and table names in the metastore.
+    fn hive_table_name(&self) -> (String, String) {
+        let names = self.table_info.desc.split('.').collect::<Vec<&str>>();
+        (names[0].to_string(), names[1].to_string())
+    }
+
+    fn partition_keys(&self) -> &[String] {
+        self.table_options
+            .partition_keys
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    /// Build the partition name like `k1=v1/k2=v2` from a full partition spec,
+    /// keys are ordered as the partition keys of the table.
+    fn partition_name(&self, partition: &[(String, String)]) -> Result<String> {
+        let partition_keys = self.partition_keys();
+        if partition_keys.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "hive table {} is not partitioned",
+                self.name()
+            )));
+        }
+        if let Some((key, _)) = partition
+            .iter()
+            .find(|(key, _)| !partition_keys.contains(key))
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "'{}' is not a partition key of hive table {}",
+                key,
+                self.name()
+            )));
+        }
+
+        partition_keys
+            .iter()
+            .map(|partition_key| {
+                let (_, value) = partition
+                    .iter()
+                    .find(|(key, _)| key == partition_key)
+                    .ok_or_else(|| {
+                        ErrorCode::BadArguments(format!(
+                            "value of partition key '{}' is missing",
+                            partition_key
+                        ))
+                    })?;
+                check_partition_value(value)?;
+                Ok(format!("{partition_key}={value}"))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(|parts| parts.join("/"))
+    }
+
+    #[async_backtrace::framed]
+    async fn partition_exists(
+        &self,
+        hive_catalog: &HiveCatalog,
+        partition_name: &str,
+    ) -> Result<bool> {
+        let (db, table) = self.hive_table_name();
+        let partition_names = hive_catalog.get_partition_names(db, table, -1).await?;
+        Ok(partition_names.iter().any(|name| name == partition_name))
+    }
+
     #[async_backtrace::framed]
     async fn get_query_locations_from_partition_table(
         &self,
@@ -583,21 +743,125 @@ impl Table for HiveTable {
         self.do_read2(ctx, plan, pipeline)
     }
 
-    fn commit_insertion(
+    fn append_data(
         &self,
         _ctx: Arc<dyn TableContext>,
         _pipeline: &mut Pipeline,
+        _: AppendMode,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
         _copied_files: Option<UpsertTableCopiedFileReq>,
         _update_stream_meta: Vec<UpdateStreamMetaReq>,
-        _overwrite: bool,
+        overwrite: bool,
         _prev_snapshot_id: Option<SnapshotId>,
         _deduplicated_label: Option<String>,
     ) -> Result<()> {
-        Err(ErrorCode::Unimplemented(format!(
-            "commit_insertion operation for table {} is not implemented, table engine is {}",
-            self.name(),
-            self.get_table_info().meta.engine
-        )))
+        if overwrite {
+            return Err(overwrite_unsupported("hive", self.name()));
+        }
+        if self.table_options.file_format != HiveFileFormat::Parquet {
+            return Err(ErrorCode::Unimplemented(format!(
+                "only parquet is supported when inserting into hive table {}",
+                self.name()
+            )));
+        }
+        let location = self.table_options.location.as_ref().ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("{}, table location is empty", self.name()))
+        })?;
+        let location = convert_hdfs_path(location, true);
+
+        let schema = self.table_info.schema();
+        let partition_keys = self.partition_keys();
+        let partition_columns = partition_keys
+            .iter()
+            .map(|key| Ok((schema.index_of(key)?, key.clone())))
+            .collect::<Result<Vec<_>>>()?;
+        let data_fields = schema
+            .fields()
+            .iter()
+            .filter(|f| !partition_keys.contains(f.name()))
+            .cloned()
+            .collect::<Vec<_>>();
+        let arrow_schema = Arc::new(ArrowSchema::from(&TableSchema::new(data_fields)));
+
+        let (database, table) = self.hive_table_name();
+        // New partitions are registered by the sink when finished.
+        add_commit_sink(pipeline, |input| {
+            Ok(ProcessorPtr::create(HiveTableSink::create(
+                input,
+                ctx.clone(),
+                self.dal.clone(),
+                database.clone(),
+                table.clone(),
+                location.clone(),
+                arrow_schema.clone(),
+                partition_columns.clone(),
+            )))
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn add_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition: Vec<(String, String)>,
+        if_not_exists: bool,
+    ) -> Result<()> {
+        let partition_name = self.partition_name(&partition)?;
+        let hive_catalog = ctx.get_catalog(CATALOG_HIVE).await?;
+        let hive_catalog = hive_catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
+
+        if self.partition_exists(hive_catalog, &partition_name).await? {
+            if if_not_exists {
+                return Ok(());
+            }
+            return Err(ErrorCode::BadArguments(format!(
+                "partition {} of hive table {} already exists",
+                partition_name,
+                self.name()
+            )));
+        }
+
+        let (db, table) = self.hive_table_name();
+        hive_catalog
+            .append_partition_by_name(db, table, partition_name)
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition: Vec<(String, String)>,
+        if_exists: bool,
+    ) -> Result<()> {
+        let partition_name = self.partition_name(&partition)?;
+        let hive_catalog = ctx.get_catalog(CATALOG_HIVE).await?;
+        let hive_catalog = hive_catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
+
+        if !self.partition_exists(hive_catalog, &partition_name).await? {
+            if if_exists {
+                return Ok(());
+            }
+            return Err(ErrorCode::BadArguments(format!(
+                "partition {} of hive table {} does not exist",
+                partition_name,
+                self.name()
+            )));
+        }
+
+        let (db, table) = self.hive_table_name();
+        hive_catalog
+            .drop_partition_by_name(db, table, partition_name, true)
+            .await?;
+        Ok(())
     }
 
     #[async_backtrace::framed]
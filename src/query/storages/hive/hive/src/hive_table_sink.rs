@@ -0,0 +1,251 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_base::base::Progress;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::catalog_kind::CATALOG_HIVE;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::ScalarRef;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_storages_parquet::lake_sink::split_by_partition;
+use databend_common_storages_parquet::lake_sink::to_record_batch;
+use databend_common_storages_parquet::lake_sink::write_parquet;
+use databend_common_storages_parquet::lake_sink::MAX_FILE_BYTES;
+use opendal::Operator;
+
+use crate::hive_catalog::HiveCatalog;
+use crate::hive_table::HIVE_DEFAULT_PARTITION;
+
+/// Write data blocks as parquet files into a staging directory of the table, the files are
+/// moved into the directories of hive partitions and the new partitions are registered to
+/// the metastore when all the data are written.
+///
+/// The staging directory starts with '.', so the files are invisible to readers until committed.
+pub struct HiveTableSink {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    write_progress: Arc<Progress>,
+
+    database: String,
+    table: String,
+    /// Directory of the table, ends with '/'.
+    location: String,
+    /// Directory of the data files not committed yet, ends with '/'.
+    staging_location: String,
+    /// Schema of data files, partition columns are excluded.
+    arrow_schema: ArrowSchemaRef,
+    /// Indexes of partition columns in the block, and the partition keys.
+    partition_columns: Vec<(FieldIndex, String)>,
+
+    blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    num_files: usize,
+    /// Paths of written data files relative to the staging directory.
+    files: Vec<String>,
+    /// Names of written partitions, like `k1=v1/k2=v2`.
+    partitions: BTreeSet<String>,
+}
+
+impl HiveTableSink {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        database: String,
+        table: String,
+        location: String,
+        arrow_schema: ArrowSchemaRef,
+        partition_columns: Vec<(FieldIndex, String)>,
+    ) -> Box<dyn Processor> {
+        AsyncSinker::create(input, ctx.clone(), HiveTableSink {
+            ctx: ctx.clone(),
+            dal,
+            write_progress: ctx.get_write_progress(),
+            database,
+            table,
+            staging_location: format!("{location}.databend-staging-{}/", uuid::Uuid::new_v4()),
+            location,
+            arrow_schema,
+            partition_columns,
+            blocks: vec![],
+            buffered_bytes: 0,
+            num_files: 0,
+            files: vec![],
+            partitions: BTreeSet::new(),
+        })
+    }
+
+    /// Write buffered blocks as data files, one for each partition.
+    async fn flush(&mut self) -> Result<()> {
+        if self.blocks.is_empty() {
+            return Ok(());
+        }
+        let block = DataBlock::concat(&std::mem::take(&mut self.blocks))?;
+        self.buffered_bytes = 0;
+
+        let partition_indexes = self
+            .partition_columns
+            .iter()
+            .map(|(index, _)| *index)
+            .collect::<Vec<_>>();
+        for (values, block) in
+            split_by_partition(block, &partition_indexes, partition_value_to_string)?
+        {
+            let partition_name = (!values.is_empty()).then(|| {
+                self.partition_columns
+                    .iter()
+                    .zip(values)
+                    .map(|((_, key), value)| format!("{key}={value}"))
+                    .collect::<Vec<_>>()
+                    .join("/")
+            });
+            let batch = to_record_batch(block, &self.arrow_schema)?;
+            let data = write_parquet(batch)?;
+
+            let dir = match &partition_name {
+                Some(name) => format!("{name}/"),
+                None => String::new(),
+            };
+            let path = format!(
+                "{dir}part-{:05}-{}.snappy.parquet",
+                self.num_files,
+                uuid::Uuid::new_v4()
+            );
+            self.dal
+                .write(&format!("{}{}", self.staging_location, path), data)
+                .await?;
+            self.num_files += 1;
+            self.files.push(path);
+
+            if let Some(name) = partition_name {
+                self.partitions.insert(name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Move the written data files from the staging directory into the table directory.
+    async fn commit_files(&self) -> Result<()> {
+        let can_rename = self.dal.info().full_capability().rename;
+        for path in &self.files {
+            let from = format!("{}{}", self.staging_location, path);
+            let to = format!("{}{}", self.location, path);
+            if can_rename {
+                self.dal.rename(&from, &to).await?;
+            } else {
+                self.dal.copy(&from, &to).await?;
+                self.dal.delete(&from).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Register the written partitions which are not in the metastore yet,
+    /// the partitions are created with the default locations under the table directory.
+    async fn register_partitions(&self) -> Result<()> {
+        let catalog = self.ctx.get_catalog(CATALOG_HIVE).await?;
+        let catalog = catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
+
+        let existing = catalog
+            .get_partition_names(self.database.clone(), self.table.clone(), -1)
+            .await?
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        for name in self.partitions.difference(&existing) {
+            catalog
+                .append_partition_by_name(self.database.clone(), self.table.clone(), name.clone())
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncSink for HiveTableSink {
+    const NAME: &'static str = "HiveTableSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        self.flush().await?;
+        if self.files.is_empty() {
+            return Ok(());
+        }
+        self.commit_files().await?;
+        self.dal.remove_all(&self.staging_location).await?;
+        if self.partitions.is_empty() {
+            return Ok(());
+        }
+        self.register_partitions().await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if data_block.is_empty() {
+            return Ok(false);
+        }
+        let progress_values = ProgressValues {
+            rows: data_block.num_rows(),
+            bytes: data_block.memory_size(),
+        };
+        self.buffered_bytes += data_block.memory_size();
+        self.blocks.push(data_block);
+        if self.buffered_bytes >= MAX_FILE_BYTES {
+            self.flush().await?;
+        }
+        self.write_progress.incr(&progress_values);
+        Ok(false)
+    }
+}
+
+/// Format the value as the hive partition value, NULL is the default partition.
+pub(crate) fn partition_value_to_string(value: ScalarRef) -> Result<String> {
+    let value = match value {
+        ScalarRef::Null => return Ok(HIVE_DEFAULT_PARTITION.to_string()),
+        ScalarRef::Number(v) => v.to_string(),
+        ScalarRef::String(v) => v.to_string(),
+        ScalarRef::Boolean(v) => v.to_string(),
+        v => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "can not use value {v} as hive partition"
+            )));
+        }
+    };
+    check_partition_value(&value)?;
+    Ok(value)
+}
+
+/// Partition values are part of the directory path, escaping is not supported yet.
+pub(crate) fn check_partition_value(value: &str) -> Result<()> {
+    if value.is_empty() || value.contains(['/', '=']) {
+        return Err(ErrorCode::BadArguments(format!(
+            "invalid hive partition value '{value}', it can not be empty or contain '/' and '='"
+        )));
+    }
+    Ok(())
+}
@@ -23,12 +23,14 @@ mod hive_catalog;
 mod hive_database;
 mod hive_file_splitter;
 mod hive_meta_data_reader;
+mod hive_orc_source;
 mod hive_parquet_block_reader;
 mod hive_partition;
 mod hive_partition_filler;
 mod hive_partition_pruner;
 mod hive_table;
 mod hive_table_options;
+mod hive_table_sink;
 mod hive_table_source;
 mod utils;
 
@@ -91,6 +91,7 @@ use futures::TryStreamExt;
 use opendal::Metakey;
 
 use crate::database::IcebergDatabase;
+use crate::rest::RestCatalogClient;
 use crate::IcebergTable;
 
 pub const ICEBERG_CATALOG: &str = "iceberg";
@@ -119,7 +120,7 @@ impl CatalogCreator for IcebergCreator {
 ///
 /// - Metadata of databases are saved in meta store
 /// - Instances of `Database` are created from reading subdirectories of
-///    Iceberg table
+///    Iceberg table, or from namespaces of the REST catalog if configured
 /// - Table metadata are saved in external Iceberg storage
 #[derive(Clone, Debug)]
 pub struct IcebergCatalog {
@@ -128,6 +129,9 @@ pub struct IcebergCatalog {
 
     /// underlying storage access operator
     operator: DataOperator,
+
+    /// client of the REST catalog, `None` for a storage based catalog
+    rest: Option<Arc<RestCatalogClient>>,
 }
 
 impl IcebergCatalog {
@@ -147,13 +151,33 @@ impl IcebergCatalog {
     /// a `default` database will be generated directly
     #[minitrace::trace]
     pub fn try_create(info: CatalogInfo, operator: DataOperator) -> Result<Self> {
-        Ok(Self { info, operator })
+        let rest = match &info.meta.catalog_option {
+            CatalogOption::Iceberg(opt) => opt
+                .rest
+                .clone()
+                .map(|v| Arc::new(RestCatalogClient::new(v))),
+            _ => None,
+        };
+        Ok(Self {
+            info,
+            operator,
+            rest,
+        })
     }
 
     /// list read databases
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn list_database_from_read(&self) -> Result<Vec<Arc<dyn Database>>> {
+        if let Some(rest) = &self.rest {
+            let mut dbs = vec![];
+            for db_name in rest.list_namespaces().await? {
+                let db: Arc<dyn Database> = self.get_database("", &db_name).await?;
+                dbs.push(db);
+            }
+            return Ok(dbs);
+        }
+
         let op = self.operator.operator();
         let mut dbs = vec![];
         let mut ls = op.lister_with("/").metakey(Metakey::Mode).await?;
@@ -187,6 +211,20 @@ impl Catalog for IcebergCatalog {
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn get_database(&self, _tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        if let Some(rest) = &self.rest {
+            if !rest.namespace_exists(db_name).await? {
+                return Err(ErrorCode::UnknownDatabase(format!(
+                    "Database {db_name} does not exist"
+                )));
+            }
+            return Ok(Arc::new(IcebergDatabase::create_with_rest(
+                &self.name(),
+                db_name,
+                self.operator.clone(),
+                rest.clone(),
+            )));
+        }
+
         let rel_path = format!("{db_name}/");
 
         let operator = self.operator.operator();
@@ -25,11 +25,13 @@ use databend_common_meta_app::schema::DatabaseIdent;
 use databend_common_meta_app::schema::DatabaseInfo;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::schema::DatabaseNameIdent;
+use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use futures::StreamExt;
 use opendal::EntryMode;
 use opendal::Metakey;
 
+use crate::rest::RestCatalogClient;
 use crate::table::IcebergTable;
 
 #[derive(Clone, Debug)]
@@ -37,9 +39,13 @@ pub struct IcebergDatabase {
     /// catalog this database belongs to
     ctl_name: String,
     /// operator pointing to the directory holding iceberg tables
+    ///
+    /// For databases from REST catalog, it points to the root of catalog storage.
     db_root: DataOperator,
     /// database information
     info: DatabaseInfo,
+    /// client of the REST catalog this database belongs to
+    rest: Option<Arc<RestCatalogClient>>,
 }
 
 impl IcebergDatabase {
@@ -62,8 +68,86 @@ impl IcebergDatabase {
             ctl_name: ctl_name.to_string(),
             db_root,
             info,
+            rest: None,
         }
     }
+
+    /// create a new database mapped to a namespace of REST catalog
+    pub fn create_with_rest(
+        ctl_name: &str,
+        db_name: &str,
+        ctl_root: DataOperator,
+        rest: Arc<RestCatalogClient>,
+    ) -> Self {
+        Self {
+            rest: Some(rest),
+            ..Self::create(ctl_name, db_name, ctl_root)
+        }
+    }
+
+    /// Build the operator of table location returned by REST catalog.
+    ///
+    /// Location like `s3://bucket/path/to/table` is accessed with the credentials
+    /// of catalog storage, with bucket replaced by `bucket` and root by `/path/to/table/`.
+    async fn table_root_from_location(&self, location: &str) -> Result<DataOperator> {
+        let (scheme, bucket, path) = match location.split_once("://") {
+            Some((scheme, rest)) => match rest.find('/') {
+                Some(idx) => (scheme, &rest[..idx], &rest[idx..]),
+                None => (scheme, rest, "/"),
+            },
+            None => ("", "", location),
+        };
+        let root = format!("{}/", path.trim_end_matches('/'));
+
+        let table_sp = match (scheme, self.db_root.params()) {
+            ("s3" | "s3a" | "s3n", StorageParams::S3(mut cfg)) => {
+                cfg.bucket = bucket.to_string();
+                cfg.root = root;
+                StorageParams::S3(cfg)
+            }
+            ("gs" | "gcs", StorageParams::Gcs(mut cfg)) => {
+                cfg.bucket = bucket.to_string();
+                cfg.root = root;
+                StorageParams::Gcs(cfg)
+            }
+            ("oss", StorageParams::Oss(mut cfg)) => {
+                cfg.bucket = bucket.to_string();
+                cfg.root = root;
+                StorageParams::Oss(cfg)
+            }
+            ("obs", StorageParams::Obs(mut cfg)) => {
+                cfg.bucket = bucket.to_string();
+                cfg.root = root;
+                StorageParams::Obs(cfg)
+            }
+            ("cos", StorageParams::Cos(mut cfg)) => {
+                cfg.bucket = bucket.to_string();
+                cfg.root = root;
+                StorageParams::Cos(cfg)
+            }
+            ("azblob", StorageParams::Azblob(mut cfg)) => {
+                cfg.container = bucket.to_string();
+                cfg.root = root;
+                StorageParams::Azblob(cfg)
+            }
+            ("hdfs", StorageParams::Hdfs(mut cfg)) => {
+                cfg.name_node = format!("hdfs://{bucket}");
+                cfg.root = root;
+                StorageParams::Hdfs(cfg)
+            }
+            ("" | "file" | "fs", StorageParams::Fs(mut cfg)) => {
+                cfg.root = root;
+                StorageParams::Fs(cfg)
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Iceberg table location {location} is not supported by the storage of catalog {}",
+                    self.ctl_name
+                )));
+            }
+        };
+        DataOperator::try_create(&table_sp).await
+    }
 }
 
 #[async_trait]
@@ -78,6 +162,35 @@ impl Database for IcebergDatabase {
 
     #[async_backtrace::framed]
     async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+        if let Some(rest) = &self.rest {
+            let location = rest.load_table_location(self.name(), table_name).await?;
+            let tbl_root = self.table_root_from_location(&location.location).await?;
+            // Metadata files of REST catalog are not versioned like the storage catalog,
+            // load the current one tracked by the catalog.
+            let metadata_path = location
+                .metadata_location
+                .map(|v| {
+                    v.strip_prefix(location.location.trim_end_matches('/'))
+                        .map(|v| v.trim_start_matches('/').to_string())
+                        .ok_or_else(|| {
+                            ErrorCode::BadArguments(format!(
+                                "Iceberg metadata location {v} is not under the table location {}",
+                                location.location
+                            ))
+                        })
+                })
+                .transpose()?;
+            let tbl = IcebergTable::try_create_from_iceberg_catalog(
+                &self.ctl_name,
+                &self.info.name_ident.db_name,
+                table_name,
+                tbl_root,
+                metadata_path,
+            )
+            .await?;
+            return Ok(Arc::new(tbl));
+        }
+
         let path = format!("{table_name}/");
         let op = self.db_root.operator();
         // check existence first
@@ -96,6 +209,7 @@ impl Database for IcebergDatabase {
             &self.info.name_ident.db_name,
             table_name,
             tbl_root,
+            None,
         )
         .await?;
         let tbl = Arc::new(tbl) as Arc<dyn Table>;
@@ -106,6 +220,13 @@ impl Database for IcebergDatabase {
     #[async_backtrace::framed]
     async fn list_tables(&self) -> Result<Vec<Arc<dyn Table>>> {
         let mut tables = vec![];
+        if let Some(rest) = &self.rest {
+            for tbl_name in rest.list_tables(self.name()).await? {
+                tables.push(self.get_table(&tbl_name).await?);
+            }
+            return Ok(tables);
+        }
+
         let op = self.db_root.operator();
         let mut lister = op.lister_with("/").metakey(Metakey::Mode).await?;
         while let Some(entry) = lister.next().await.transpose()? {
@@ -86,6 +86,22 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.default.icbg_tbl_0;
 //! ```
+//!
+//! ## REST Catalogs
+//!
+//! Tables registered in an iceberg REST catalog can be accessed by setting `CATALOG_URI`.
+//! Namespaces of the REST catalog are mapped to databases, and table data are read
+//! from the location returned by the REST catalog, with the storage connection of `URL`.
+//!
+//! ```sql
+//! CREATE CATALOG icb_ctl TYPE=ICEBERG CONNECTION=(
+//! URL='s3://bkt/'
+//! CATALOG_URI='http://127.0.0.1:8181'
+//! WAREHOUSE='demo'             -- optional
+//! CATALOG_TOKEN='<token>'      -- optional, or CATALOG_CREDENTIAL='<client_id>:<client_secret>'
+//! ... -- credentials and other options
+//! )
+//! ```
 
 #![feature(lazy_cell)]
 #![feature(impl_trait_in_assoc_type)]
@@ -94,6 +110,9 @@
 mod catalog;
 mod database;
 mod partition;
+mod rest;
+mod sink;
+mod snapshot_table;
 mod stats;
 mod table;
 mod table_source;
@@ -101,4 +120,5 @@ mod table_source;
 pub use catalog::IcebergCatalog;
 pub use catalog::IcebergCreator;
 pub use catalog::ICEBERG_CATALOG;
+pub use snapshot_table::IcebergSnapshotTable;
 pub use table::IcebergTable;
@@ -0,0 +1,316 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client of the iceberg REST catalog.
+//!
+//! Only the endpoints needed for reading are implemented:
+//! - `GET /v1/config`
+//! - `POST /v1/oauth/tokens`
+//! - `GET /v1/{prefix}/namespaces?parent={namespace}`
+//! - `GET /v1/{prefix}/namespaces/{namespace}`
+//! - `GET /v1/{prefix}/namespaces/{namespace}/tables`
+//! - `GET /v1/{prefix}/namespaces/{namespace}/tables/{table}`
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
+use reqwest::Client;
+use reqwest::Method;
+use reqwest::RequestBuilder;
+use reqwest::StatusCode;
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+/// Separator of multi-level namespace in url path, defined by the REST spec.
+const NAMESPACE_SEPARATOR: &str = "\u{1f}";
+
+#[derive(Debug)]
+pub struct RestCatalogClient {
+    option: IcebergRestCatalogOption,
+    client: Client,
+    /// Base url with prefix and access token, resolved on first use.
+    session: OnceCell<RestSession>,
+}
+
+#[derive(Debug)]
+struct RestSession {
+    base: Url,
+    token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ConfigResponse {
+    #[serde(default)]
+    defaults: HashMap<String, String>,
+    #[serde(default)]
+    overrides: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+struct ListNamespacesResponse {
+    namespaces: Vec<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct TableIdentifier {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct ListTablesResponse {
+    identifiers: Vec<TableIdentifier>,
+}
+
+#[derive(Deserialize)]
+struct TableMetadata {
+    location: String,
+}
+
+#[derive(Deserialize)]
+struct LoadTableResponse {
+    #[serde(rename = "metadata-location")]
+    metadata_location: Option<String>,
+    metadata: TableMetadata,
+}
+
+/// Locations of the table loaded from REST catalog.
+pub struct RestTableLocation {
+    /// Location of the table, like `s3://bucket/path/to/table`.
+    pub location: String,
+    /// Location of the current metadata file, `None` if the table is staged.
+    pub metadata_location: Option<String>,
+}
+
+impl RestCatalogClient {
+    pub fn new(option: IcebergRestCatalogOption) -> Self {
+        Self {
+            option,
+            client: Client::new(),
+            session: OnceCell::new(),
+        }
+    }
+
+    /// List namespaces of all levels, multi-level namespaces are joined with `.`.
+    #[async_backtrace::framed]
+    pub async fn list_namespaces(&self) -> Result<Vec<String>> {
+        let mut namespaces = BTreeSet::new();
+        let mut parents: Vec<Vec<String>> = vec![vec![]];
+        while let Some(parent) = parents.pop() {
+            let parent_param = parent.join(NAMESPACE_SEPARATOR);
+            let query = if parent.is_empty() {
+                vec![]
+            } else {
+                vec![("parent", parent_param.as_str())]
+            };
+            let resp: ListNamespacesResponse =
+                self.get(&["namespaces"], &query).await?.ok_or_else(|| {
+                    ErrorCode::Internal("Iceberg REST catalog returns not found for namespaces")
+                })?;
+            // Only take the children of the parent, in case the `parent` is ignored by server.
+            for ns in resp.namespaces {
+                if ns.len() == parent.len() + 1
+                    && ns.starts_with(&parent)
+                    && namespaces.insert(ns.join("."))
+                {
+                    parents.push(ns);
+                }
+            }
+        }
+        Ok(namespaces.into_iter().collect())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn namespace_exists(&self, namespace: &str) -> Result<bool> {
+        let ns = encode_namespace(namespace);
+        let resp: Option<serde_json::Value> = self.get(&["namespaces", ns.as_str()], &[]).await?;
+        Ok(resp.is_some())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn list_tables(&self, namespace: &str) -> Result<Vec<String>> {
+        let ns = encode_namespace(namespace);
+        let resp: ListTablesResponse = self
+            .get(&["namespaces", ns.as_str(), "tables"], &[])
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::UnknownDatabase(format!("Database {namespace} does not exist"))
+            })?;
+        Ok(resp.identifiers.into_iter().map(|v| v.name).collect())
+    }
+
+    /// Load the table and return its locations.
+    #[async_backtrace::framed]
+    pub async fn load_table_location(
+        &self,
+        namespace: &str,
+        table: &str,
+    ) -> Result<RestTableLocation> {
+        let ns = encode_namespace(namespace);
+        let resp: LoadTableResponse = self
+            .get(&["namespaces", ns.as_str(), "tables", table], &[])
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::UnknownTable(format!(
+                    "table {namespace}.{table} does not exist or is not a valid table"
+                ))
+            })?;
+        Ok(RestTableLocation {
+            location: resp.metadata.location,
+            metadata_location: resp.metadata_location,
+        })
+    }
+
+    /// Send a GET request to the path under prefix, returns `None` if not found.
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &[&str],
+        query: &[(&str, &str)],
+    ) -> Result<Option<T>> {
+        let session = self.session().await?;
+        let mut url = session.base.clone();
+        url.path_segments_mut()
+            .map_err(|_| ErrorCode::BadArguments("Invalid iceberg REST catalog uri"))?
+            .extend(path);
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+
+        let resp = self
+            .authorize(self.client.request(Method::GET, url), &session.token)
+            .send()
+            .await
+            .map_err(rest_error)?;
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        parse_response(resp).await.map(Some)
+    }
+
+    async fn session(&self) -> Result<&RestSession> {
+        self.session
+            .get_or_try_init(|| async {
+                let root = Url::parse(self.option.uri.trim_end_matches('/')).map_err(|e| {
+                    ErrorCode::BadArguments(format!("Invalid iceberg REST catalog uri: {e}"))
+                })?;
+                let token = self.fetch_token(&root).await?;
+
+                let mut url = join_url(&root, &["v1", "config"])?;
+                if !self.option.warehouse.is_empty() {
+                    url.query_pairs_mut()
+                        .append_pair("warehouse", &self.option.warehouse);
+                }
+                let resp = self
+                    .authorize(self.client.request(Method::GET, url), &token)
+                    .send()
+                    .await
+                    .map_err(rest_error)?;
+                let config: ConfigResponse = parse_response(resp).await?;
+
+                // Overrides take precedence over the defaults.
+                let base = match config
+                    .overrides
+                    .get("prefix")
+                    .or_else(|| config.defaults.get("prefix"))
+                {
+                    Some(prefix) if !prefix.is_empty() => join_url(&root, &["v1", prefix])?,
+                    _ => join_url(&root, &["v1"])?,
+                };
+
+                Ok(RestSession { base, token })
+            })
+            .await
+    }
+
+    /// Use the token directly, or exchange one with the client credential.
+    async fn fetch_token(&self, root: &Url) -> Result<Option<String>> {
+        if !self.option.token.is_empty() {
+            return Ok(Some(self.option.token.clone()));
+        }
+        if self.option.credential.is_empty() {
+            return Ok(None);
+        }
+
+        let (client_id, client_secret) = match self.option.credential.split_once(':') {
+            Some((id, secret)) => (id, secret),
+            None => ("", self.option.credential.as_str()),
+        };
+        let url = join_url(root, &["v1", "oauth", "tokens"])?;
+        let resp = self
+            .client
+            .post(url)
+            .form(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", client_id),
+                ("client_secret", client_secret),
+                ("scope", "catalog"),
+            ])
+            .send()
+            .await
+            .map_err(rest_error)?;
+        let token: TokenResponse = parse_response(resp).await?;
+        Ok(Some(token.access_token))
+    }
+
+    fn authorize(&self, req: RequestBuilder, token: &Option<String>) -> RequestBuilder {
+        match token {
+            Some(token) => req.bearer_auth(token),
+            None => req,
+        }
+    }
+}
+
+fn encode_namespace(namespace: &str) -> String {
+    namespace
+        .split('.')
+        .collect::<Vec<_>>()
+        .join(NAMESPACE_SEPARATOR)
+}
+
+fn join_url(root: &Url, path: &[&str]) -> Result<Url> {
+    let mut url = root.clone();
+    url.path_segments_mut()
+        .map_err(|_| ErrorCode::BadArguments("Invalid iceberg REST catalog uri"))?
+        .pop_if_empty()
+        .extend(path);
+    Ok(url)
+}
+
+async fn parse_response<T: DeserializeOwned>(resp: reqwest::Response) -> Result<T> {
+    let status = resp.status();
+    let body = resp.text().await.map_err(rest_error)?;
+    if !status.is_success() {
+        return Err(ErrorCode::Internal(format!(
+            "Iceberg REST catalog request failed with status {status}: {body}"
+        )));
+    }
+    serde_json::from_str(&body).map_err(|e| {
+        ErrorCode::Internal(format!(
+            "Failed to parse iceberg REST catalog response: {e}, body: {body}"
+        ))
+    })
+}
+
+fn rest_error(e: reqwest::Error) -> ErrorCode {
+    ErrorCode::Internal(format!("Iceberg REST catalog request failed: {e}"))
+}
@@ -0,0 +1,350 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_base::base::escape_for_key;
+use databend_common_base::base::Progress;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_storage::DataOperator;
+use databend_common_storages_parquet::lake_sink::to_record_batch;
+use databend_common_storages_parquet::lake_sink::MAX_COMMIT_RETRIES;
+use databend_common_users::UserApiProvider;
+use icelake::catalog::Catalog;
+use icelake::catalog::CatalogRef;
+use icelake::catalog::MetadataUpdate;
+use icelake::catalog::UpdateTable;
+use icelake::io::task_writer::TaskWriter;
+use icelake::transaction::Transaction;
+use icelake::types::DataFile;
+use icelake::TableIdentifier;
+
+use crate::IcebergTable;
+
+/// Prefix of the meta keys to claim the metadata versions of iceberg tables.
+const COMMIT_KEY_PREFIX: &str = "__fd_iceberg_commit";
+/// The claim is expired if the writer is gone before committing.
+const COMMIT_CLAIM_TTL: Duration = Duration::from_secs(60);
+
+/// Write data blocks as parquet data files of the iceberg table,
+/// and commit them as a new snapshot when all the data are written.
+pub struct IcebergTableSink {
+    dop: DataOperator,
+    write_progress: Arc<Progress>,
+    /// Replace all the data of the table instead of appending to it.
+    overwrite: bool,
+
+    table: Option<icelake::Table>,
+    arrow_schema: Option<ArrowSchemaRef>,
+    writer: Option<TaskWriter>,
+}
+
+impl IcebergTableSink {
+    pub fn create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        dop: DataOperator,
+        overwrite: bool,
+    ) -> Box<dyn Processor> {
+        AsyncSinker::create(input, ctx.clone(), IcebergTableSink {
+            dop,
+            write_progress: ctx.get_write_progress(),
+            overwrite,
+            table: None,
+            arrow_schema: None,
+            writer: None,
+        })
+    }
+
+    async fn commit(&mut self, data_files: Vec<DataFile>) -> Result<()> {
+        let mut table = self.table.take().unwrap();
+        let mut retries = 0;
+        loop {
+            // The storage catalog overwrites the metadata file of the next version,
+            // claim the version first so that only one writer commits it.
+            let version = table.current_table_version() + 1;
+            let path = format!("metadata/v{version}.metadata.json");
+            let err = if self.claim_version(version).await?
+                && !self.dop.operator().is_exist(&path).await?
+            {
+                let mut commit_table = self.commit_table(&table, version)?;
+                let mut tx = Transaction::new(&mut commit_table);
+                tx.append_data_file(data_files.clone());
+                let Err(e) = tx.commit().await else {
+                    return Ok(());
+                };
+
+                // Writers other than databend don't claim the version, reload the table
+                // and apply the data files on the latest snapshot again if they have committed.
+                // Other errors are not caused by the concurrent writers, retrying won't help.
+                table = IcebergTable::load_iceberg_table(self.dop.clone()).await?;
+                if table.current_table_version() < version {
+                    return Err(ErrorCode::StorageOther(format!(
+                        "Iceberg commit failed: {e:?}"
+                    )));
+                }
+                format!("{e:?}")
+            } else {
+                table = IcebergTable::load_iceberg_table(self.dop.clone()).await?;
+                format!("version conflicts at {path}")
+            };
+            if retries >= MAX_COMMIT_RETRIES {
+                return Err(ErrorCode::StorageOther(format!(
+                    "Iceberg commit failed after {retries} retries: {err}"
+                )));
+            }
+            retries += 1;
+            log::info!(
+                "Iceberg commit conflicted with other writers, retry {}/{}: {err}",
+                retries,
+                MAX_COMMIT_RETRIES
+            );
+        }
+    }
+
+    /// Claim the metadata version of the table, returns false if it's claimed by other writers.
+    async fn claim_version(&self, version: i64) -> Result<bool> {
+        let key = format!(
+            "{COMMIT_KEY_PREFIX}/{}/{version}",
+            escape_for_key(&self.dop.params().to_string())?
+        );
+        let kv_api = UserApiProvider::instance().get_meta_store_client();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(vec![]),
+                Some(MetaSpec::new_ttl(COMMIT_CLAIM_TTL)),
+            ))
+            .await?;
+        Ok(res.added_seq_or_else(|_| ()).is_ok())
+    }
+
+    /// Build the table to commit the transaction on, the new snapshot is committed
+    /// through [`SinkCatalog`].
+    ///
+    /// For overwrite, the current snapshot is hidden from the transaction,
+    /// so the manifest list of the new snapshot only contains the new data files.
+    fn commit_table(&self, table: &icelake::Table, version: i64) -> Result<icelake::Table> {
+        let mut metadata = table.current_table_metadata().clone();
+        let base_snapshot_id = current_snapshot_id(table);
+        if self.overwrite {
+            metadata.current_snapshot_id = None;
+        }
+        let catalog: CatalogRef = Arc::new(SinkCatalog {
+            inner: IcebergTable::iceberg_catalog(self.dop.clone()),
+            version,
+            base_snapshot_id,
+            overwrite: self.overwrite,
+        });
+        icelake::Table::builder_from_catalog(
+            self.dop.operator(),
+            catalog,
+            metadata,
+            table.table_name().clone(),
+        )
+        .build()
+        .map_err(|e| ErrorCode::StorageOther(format!("Cannot build iceberg table: {e:?}")))
+    }
+}
+
+fn current_snapshot_id(table: &icelake::Table) -> Option<i64> {
+    table
+        .current_table_metadata()
+        .current_snapshot_id
+        .filter(|id| *id != -1)
+}
+
+/// Catalog to commit the snapshot of [`IcebergTableSink`].
+///
+/// The storage catalog applies the updates on the latest metadata of the table,
+/// the commit is rejected if the latest metadata is not the one before the claimed version,
+/// otherwise the snapshot committed by other writers would be lost.
+struct SinkCatalog {
+    inner: CatalogRef,
+    /// The metadata version claimed by [`IcebergTableSink`].
+    version: i64,
+    base_snapshot_id: Option<i64>,
+    overwrite: bool,
+}
+
+impl SinkCatalog {
+    /// The transaction of overwrite is built without the current snapshot,
+    /// take the snapshot as a child of the current one, which replaces all the data files.
+    fn overwrite_updates(
+        updates: &[MetadataUpdate],
+        latest: &icelake::Table,
+        base_snapshot_id: Option<i64>,
+    ) -> Vec<MetadataUpdate> {
+        let metadata = latest.current_table_metadata();
+        let snapshot_id = metadata
+            .snapshots
+            .iter()
+            .flatten()
+            .map(|s| s.snapshot_id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        updates
+            .iter()
+            .cloned()
+            .map(|update| match update {
+                MetadataUpdate::AddSnapshot { mut snapshot } => {
+                    snapshot.snapshot_id = snapshot_id;
+                    snapshot.parent_snapshot_id = base_snapshot_id;
+                    snapshot
+                        .summary
+                        .insert("operation".to_string(), "overwrite".to_string());
+                    MetadataUpdate::AddSnapshot { snapshot }
+                }
+                MetadataUpdate::SetSnapshotRef {
+                    ref_name,
+                    typ,
+                    min_snapshots_to_keep,
+                    max_snapshot_ages,
+                    max_ref_ages,
+                    ..
+                } => MetadataUpdate::SetSnapshotRef {
+                    ref_name,
+                    snapshot_id,
+                    typ,
+                    min_snapshots_to_keep,
+                    max_snapshot_ages,
+                    max_ref_ages,
+                },
+                other => other,
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Catalog for SinkCatalog {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn load_table(
+        self: Arc<Self>,
+        table_name: &TableIdentifier,
+    ) -> icelake::Result<icelake::Table> {
+        self.inner.clone().load_table(table_name).await
+    }
+
+    async fn update_table(
+        self: Arc<Self>,
+        table_update: &UpdateTable,
+    ) -> icelake::Result<icelake::Table> {
+        let latest = self
+            .inner
+            .clone()
+            .load_table(table_update.table_name())
+            .await?;
+        if latest.current_table_version() + 1 != self.version {
+            return Err(icelake::Error::new(
+                icelake::ErrorKind::IcebergDataInvalid,
+                format!(
+                    "Metadata version {} is committed by other writers",
+                    self.version
+                ),
+            ));
+        }
+
+        if !self.overwrite {
+            return self.inner.clone().update_table(table_update).await;
+        }
+        let mut builder = UpdateTable::builder(table_update.table_name().clone());
+        builder.add_requirements(table_update.requirements().to_vec());
+        builder.add_updates(Self::overwrite_updates(
+            table_update.updates(),
+            &latest,
+            self.base_snapshot_id,
+        ));
+        self.inner.clone().update_table(&builder.build()).await
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergTableSink {
+    const NAME: &'static str = "IcebergTableSink";
+
+    #[async_backtrace::framed]
+    async fn on_start(&mut self) -> Result<()> {
+        let table = IcebergTable::load_iceberg_table(self.dop.clone()).await?;
+        let arrow_schema = IcebergTable::get_arrow_schema(&table)?;
+        // Data files are partitioned by the partition spec of the table.
+        let writer = table.task_writer().await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot create iceberg task writer: {e:?}"))
+        })?;
+        self.arrow_schema = Some(Arc::new(arrow_schema));
+        self.writer = Some(writer);
+        self.table = Some(table);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let Some(writer) = self.writer.take() else {
+            return Ok(());
+        };
+        let data_files = writer.close().await.map_err(|e| {
+            ErrorCode::StorageOther(format!("Cannot close iceberg task writer: {e:?}"))
+        })?;
+        // Overwrite with empty data still commits an empty snapshot to clear the table.
+        if data_files.is_empty() && !self.overwrite {
+            return Ok(());
+        }
+        self.commit(data_files).await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if data_block.is_empty() {
+            return Ok(false);
+        }
+        let progress_values = ProgressValues {
+            rows: data_block.num_rows(),
+            bytes: data_block.memory_size(),
+        };
+        let batch = to_record_batch(data_block, self.arrow_schema.as_ref().unwrap())?;
+        self.writer
+            .as_mut()
+            .unwrap()
+            .write(&batch)
+            .await
+            .map_err(|e| {
+                ErrorCode::StorageOther(format!("Cannot write iceberg data file: {e:?}"))
+            })?;
+        self.write_progress.incr(&progress_values);
+        Ok(false)
+    }
+}
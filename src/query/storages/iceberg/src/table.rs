@@ -28,6 +28,8 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -39,31 +41,47 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::init_operator;
 use databend_common_storage::DataOperator;
+use databend_common_storages_parquet::lake_sink::add_commit_sink;
 use databend_common_storages_parquet::ParquetFilesPart;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use icelake::catalog::Catalog;
+use icelake::types::parse_manifest_file;
+use icelake::types::parse_manifest_list;
+use icelake::types::parse_table_metadata;
+use icelake::types::ManifestStatus;
 use opendal::Operator;
 use tokio::sync::OnceCell;
 
 use crate::partition::IcebergPartInfo;
+use crate::sink::IcebergTableSink;
 use crate::stats::get_stats_of_data_file;
 use crate::table_source::IcebergTableSource;
 
 pub const ICEBERG_ENGINE: &str = "ICEBERG";
 
+/// Engine option of the metadata file path relative to the table root,
+/// set if the table is tracked by REST catalog.
+const OPT_KEY_METADATA_PATH: &str = "metadata_path";
+
 /// accessor wrapper as a table
 ///
 /// TODO: we should use icelake Table instead.
 pub struct IcebergTable {
     info: TableInfo,
     table: OnceCell<icelake::Table>,
+    /// The snapshot to read, `None` means the current snapshot.
+    snapshot_id: Option<i64>,
 }
 
 impl IcebergTable {
@@ -73,6 +91,7 @@ impl IcebergTable {
         Ok(Box::new(Self {
             info,
             table: OnceCell::new(),
+            snapshot_id: None,
         }))
     }
 
@@ -93,12 +112,17 @@ impl IcebergTable {
         })
     }
 
-    pub async fn load_iceberg_table(dop: DataOperator) -> Result<icelake::Table> {
+    /// The storage catalog of the iceberg table at the root of the operator.
+    pub(crate) fn iceberg_catalog(dop: DataOperator) -> icelake::catalog::CatalogRef {
         // FIXME: we should implement catalog for icelake.
-        let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
+        Arc::new(icelake::catalog::StorageCatalog::new(
             "databend",
-            OperatorCreatorWrapper(dop.clone()),
-        ));
+            OperatorCreatorWrapper(dop),
+        ))
+    }
+
+    pub async fn load_iceberg_table(dop: DataOperator) -> Result<icelake::Table> {
+        let icelake_catalog = Self::iceberg_catalog(dop);
 
         let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
         icelake_catalog.load_table(&table_id).await.map_err(|err| {
@@ -106,22 +130,67 @@ impl IcebergTable {
         })
     }
 
-    pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
+    /// Load the table from the given metadata file if any, e.g. the one tracked by REST catalog,
+    /// whose name doesn't follow the versions of the storage catalog.
+    async fn load_iceberg_table_from(
+        dop: DataOperator,
+        metadata_path: Option<&str>,
+    ) -> Result<icelake::Table> {
+        let Some(metadata_path) = metadata_path else {
+            return Self::load_iceberg_table(dop).await;
+        };
+
+        let content = dop.operator().read(metadata_path).await?;
+        let metadata = parse_table_metadata(&content).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot parse table metadata: {e:?}"))
+        })?;
+        let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
+        icelake::Table::builder_from_catalog(
+            dop.operator(),
+            Self::iceberg_catalog(dop),
+            metadata,
+            table_id,
+        )
+        .build()
+        .map_err(|err| ErrorCode::ReadTableDataError(format!("Iceberg table load failed: {err:?}")))
+    }
+
+    /// Build arrow schema from iceberg metadata.
+    pub fn get_arrow_schema(table: &icelake::Table) -> Result<ArrowSchema> {
         let meta = table.current_table_metadata();
 
-        // Build arrow schema from iceberg metadata.
-        let arrow_schema: ArrowSchema = meta
+        let schema = meta.schemas.last().ok_or_else(|| {
+            ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
+        })?;
+        Self::to_arrow_schema(schema)
+    }
+
+    /// Build arrow schema of the given schema id, snapshots may be written with old schemas.
+    pub fn get_arrow_schema_of(table: &icelake::Table, schema_id: i32) -> Result<ArrowSchema> {
+        let meta = table.current_table_metadata();
+
+        let schema = meta
             .schemas
-            .last()
+            .iter()
+            .find(|s| s.schema_id == schema_id)
             .ok_or_else(|| {
-                ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
-            })?
-            .clone()
-            .try_into()
-            .map_err(|e| {
-                ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+                ErrorCode::ReadTableDataError(format!("Iceberg table schema {schema_id} not found"))
             })?;
+        Self::to_arrow_schema(schema)
+    }
+
+    fn to_arrow_schema(schema: &icelake::types::Schema) -> Result<ArrowSchema> {
+        schema.clone().try_into().map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+        })
+    }
 
+    pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
+        let arrow_schema = Self::get_arrow_schema(table)?;
+        Self::to_table_schema(&arrow_schema)
+    }
+
+    fn to_table_schema(arrow_schema: &ArrowSchema) -> Result<TableSchema> {
         // Build arrow2 schema from arrow schema.
         let fields: Vec<Arrow2Field> = arrow_schema
             .fields()
@@ -140,9 +209,14 @@ impl IcebergTable {
         database: &str,
         table_name: &str,
         dop: DataOperator,
+        metadata_path: Option<String>,
     ) -> Result<IcebergTable> {
-        let table = Self::load_iceberg_table(dop.clone()).await?;
+        let table = Self::load_iceberg_table_from(dop.clone(), metadata_path.as_deref()).await?;
         let table_schema = Self::get_schema(&table).await?;
+        let engine_options = metadata_path
+            .into_iter()
+            .map(|path| (OPT_KEY_METADATA_PATH.to_string(), path))
+            .collect();
 
         // construct table info
         let info = TableInfo {
@@ -155,6 +229,7 @@ impl IcebergTable {
                 engine: "iceberg".to_string(),
                 created_on: Utc::now(),
                 storage_params: Some(dop.params()),
+                engine_options,
                 ..Default::default()
             },
             ..Default::default()
@@ -163,28 +238,95 @@ impl IcebergTable {
         Ok(Self {
             info,
             table: OnceCell::new_with(Some(table)),
+            snapshot_id: None,
         })
     }
 
-    async fn table(&self) -> Result<&icelake::Table> {
+    pub(crate) async fn table(&self) -> Result<&icelake::Table> {
         self.table
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
                 let op = DataOperator::try_new(sp)?;
-                // FIXME: we should implement catalog for icelake.
-                let icelake_catalog = Arc::new(icelake::catalog::StorageCatalog::new(
-                    "databend",
-                    OperatorCreatorWrapper(op),
-                ));
-
-                let table_id = icelake::TableIdentifier::new(vec![""]).unwrap();
-                let table = icelake_catalog.load_table(&table_id).await.map_err(|err| {
-                    ErrorCode::ReadTableDataError(format!("Iceberg catalog load failed: {err:?}"))
+                let metadata_path = self.info.meta.engine_options.get(OPT_KEY_METADATA_PATH);
+                Self::load_iceberg_table_from(op, metadata_path.map(|v| v.as_str())).await
+            })
+            .await
+    }
+
+    /// Find the snapshot of the given navigation point.
+    ///
+    /// For a time point, the latest snapshot committed before it is used.
+    fn resolve_snapshot(table: &icelake::Table, point: &NavigationPoint) -> Result<i64> {
+        let meta = table.current_table_metadata();
+        let snapshots = meta.snapshots.as_deref().unwrap_or_default();
+        let snapshot = match point {
+            NavigationPoint::SnapshotID(id) => {
+                let id = id.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!("Invalid iceberg snapshot id: {id}"))
                 })?;
+                snapshots.iter().find(|s| s.snapshot_id == id)
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                let ts = time_point.timestamp_millis();
+                snapshots
+                    .iter()
+                    .filter(|s| s.timestamp_ms <= ts)
+                    .max_by_key(|s| s.timestamp_ms)
+            }
+        };
+        snapshot.map(|s| s.snapshot_id).ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at given point {point:?}"
+            ))
+        })
+    }
+
+    /// List the live data files of the given snapshot from its manifest list.
+    #[async_backtrace::framed]
+    async fn data_files_of_snapshot(
+        &self,
+        table: &icelake::Table,
+        snapshot_id: i64,
+    ) -> Result<Vec<icelake::types::DataFile>> {
+        let meta = table.current_table_metadata();
+        let snapshot = meta
+            .snapshots
+            .iter()
+            .flatten()
+            .find(|s| s.snapshot_id == snapshot_id)
+            .ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "Iceberg snapshot {snapshot_id} not found"
+                ))
+            })?;
 
-                Ok(table)
+        let op = init_operator(self.get_storage_params()?)?;
+        let rel_path = |path: &str| {
+            table.rel_path(path).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("Invalid iceberg file path {path}: {e:?}"))
             })
-            .await
+        };
+
+        let content = op.read(&rel_path(&snapshot.manifest_list)?).await?;
+        let manifest_list = parse_manifest_list(&content).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot parse manifest list: {e:?}"))
+        })?;
+
+        let mut data_files = vec![];
+        for entry in manifest_list.entries {
+            let content = op.read(&rel_path(&entry.manifest_path)?).await?;
+            let manifest = parse_manifest_file(&content).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("Cannot parse manifest file: {e:?}"))
+            })?;
+            data_files.extend(
+                manifest
+                    .entries
+                    .into_iter()
+                    .filter(|e| !matches!(e.status, ManifestStatus::Deleted))
+                    .map(|e| e.data_file),
+            );
+        }
+        Ok(data_files)
     }
 
     pub fn do_read_data(
@@ -258,9 +400,12 @@ impl IcebergTable {
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.table().await?;
 
-        let data_files = table.current_data_files().await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
-        })?;
+        let data_files = match self.snapshot_id {
+            Some(snapshot_id) => self.data_files_of_snapshot(table, snapshot_id).await?,
+            None => table.current_data_files().await.map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
+            })?,
+        };
 
         let filter = push_downs.as_ref().and_then(|extra| {
             extra
@@ -370,6 +515,75 @@ impl Table for IcebergTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let table = self.table().await?;
+        let snapshot_id = Self::resolve_snapshot(table, point)?;
+
+        // Schema may be changed since the snapshot, snapshots without schema id
+        // are read with the current schema.
+        let mut info = self.info.clone();
+        let schema_id = table
+            .current_table_metadata()
+            .snapshots
+            .iter()
+            .flatten()
+            .find(|s| s.snapshot_id == snapshot_id)
+            .and_then(|s| s.schema_id);
+        if let Some(schema_id) = schema_id {
+            let arrow_schema = Self::get_arrow_schema_of(table, schema_id as i32)?;
+            info.meta.schema = Arc::new(Self::to_table_schema(&arrow_schema)?);
+        }
+        Ok(Arc::new(IcebergTable {
+            info,
+            table: OnceCell::new(),
+            snapshot_id: Some(snapshot_id),
+        }))
+    }
+
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _pipeline: &mut Pipeline,
+        _: AppendMode,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        // Snapshots committed by the storage catalog are not visible to the REST catalog.
+        if self
+            .info
+            .meta
+            .engine_options
+            .contains_key(OPT_KEY_METADATA_PATH)
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "INSERT is not supported for iceberg table {} of REST catalog yet",
+                self.name()
+            )));
+        }
+
+        let dop = DataOperator::try_new(self.get_storage_params()?)?;
+        add_commit_sink(pipeline, |input| {
+            Ok(ProcessorPtr::create(IcebergTableSink::create(
+                input,
+                ctx.clone(),
+                dop.clone(),
+                overwrite,
+            )))
+        })
+    }
 }
 
 struct OperatorCreatorWrapper(DataOperator);
@@ -130,7 +130,7 @@ impl Processor for IcebergTableSource {
                     assert_eq!(files.files.len(), 1);
                     let stream = self
                         .parquet_reader
-                        .prepare_data_stream(&files.files[0].0, None)
+                        .prepare_data_stream(&files.files[0].0, None, None)
                         .await?;
                     self.stream = Some(stream);
                 }
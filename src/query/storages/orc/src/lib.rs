@@ -0,0 +1,33 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::uninlined_format_args)]
+
+mod meta;
+mod orc_part;
+mod pruning;
+mod read;
+mod source;
+mod statistics;
+mod table;
+
+pub use meta::read_orc_metadata_async;
+pub use meta::read_orc_schema_async;
+pub use meta::OrcFileMeta;
+pub use orc_part::OrcStripePart;
+pub use pruning::stripe_range;
+pub use pruning::OrcPruner;
+pub use read::OrcReader;
+pub use source::OrcSource;
+pub use table::OrcTable;
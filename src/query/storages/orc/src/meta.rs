@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableSchema;
+use opendal::Operator;
+use orc_rust::proto::r#type::Kind;
+use orc_rust::reader::metadata::read_metadata_async;
+use orc_rust::reader::metadata::FileMetadata;
+use orc_rust::reader::schema::create_field;
+use orc_rust::reader::schema::create_schema;
+use orc_rust::reader::schema::TypeDescription;
+
+pub struct OrcFileMeta {
+    pub location: String,
+    pub size: u64,
+    pub schema: ArrowSchemaRef,
+    pub metadata: Arc<FileMetadata>,
+}
+
+/// Names and types of the columns of an orc file.
+pub(crate) type OrcColumns = Vec<(String, Arc<TypeDescription>)>;
+
+pub(crate) fn orc_error(location: &str, e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to read orc file {location}: {e}"))
+}
+
+/// Return the top level columns of the file, in the order of the file.
+///
+/// Only the primitive types which can be decoded by `orc-rust` are supported.
+pub(crate) fn orc_columns(location: &str, metadata: &FileMetadata) -> Result<OrcColumns> {
+    let root = create_schema(&metadata.footer.types, 0).map_err(|e| orc_error(location, e))?;
+    metadata.footer.types[0]
+        .field_names
+        .iter()
+        .map(|name| {
+            let typ = root
+                .field(name)
+                .ok_or_else(|| orc_error(location, format!("column {name} not found")))?;
+            match typ.kind() {
+                Kind::Boolean
+                | Kind::Short
+                | Kind::Int
+                | Kind::Long
+                | Kind::Float
+                | Kind::Double
+                | Kind::String
+                | Kind::Varchar
+                | Kind::Char
+                | Kind::Binary
+                | Kind::Date
+                | Kind::Timestamp => Ok((name.clone(), typ)),
+                kind => Err(ErrorCode::Unimplemented(format!(
+                    "unsupported type {kind:?} of column {name} in orc file {location}"
+                ))),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn orc_arrow_schema(columns: &OrcColumns) -> ArrowSchemaRef {
+    let fields = columns
+        .iter()
+        .map(|(name, typ)| create_field((name, typ)))
+        .collect::<Vec<_>>();
+    Arc::new(ArrowSchema::new(fields))
+}
+
+#[async_backtrace::framed]
+pub async fn read_orc_metadata_async(
+    operator: Operator,
+    location: &str,
+    size: Option<u64>,
+) -> Result<OrcFileMeta> {
+    let size = match size {
+        Some(size) => size,
+        None => operator.stat(location).await?.content_length(),
+    };
+    // The range of the whole file, so that seeking from the end needs no stat.
+    let mut reader = operator.reader_with(location).range(0..size).await?;
+    let metadata = read_metadata_async(&mut reader)
+        .await
+        .map_err(|e| orc_error(location, e))?;
+    let schema = orc_arrow_schema(&orc_columns(location, &metadata)?);
+    Ok(OrcFileMeta {
+        location: location.to_string(),
+        size,
+        schema,
+        metadata: Arc::new(metadata),
+    })
+}
+
+#[async_backtrace::framed]
+pub async fn read_orc_schema_async(operator: &Operator, location: &str) -> Result<TableSchema> {
+    let meta = read_orc_metadata_async(operator.clone(), location, None).await?;
+    TableSchema::try_from(meta.schema.as_ref())
+}
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::Result;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_pruner::RangePruner;
+use databend_storages_common_pruner::RangePrunerCreator;
+use orc_rust::proto::StripeInformation;
+use orc_rust::reader::metadata::FileMetadata;
+
+use crate::statistics::collect_stripe_statistics;
+
+/// Prunes the stripes of ORC files by the column statistics stored in the file.
+#[derive(Clone)]
+pub struct OrcPruner {
+    schema: TableSchemaRef,
+    range_pruner: Option<Arc<dyn RangePruner + Send + Sync>>,
+}
+
+impl OrcPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+    ) -> Result<Self> {
+        let range_pruner = match push_down.as_ref().and_then(|p| p.filters.as_ref()) {
+            Some(filters) => {
+                let filter_expr = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+                Some(RangePrunerCreator::try_create(
+                    func_ctx,
+                    &schema,
+                    Some(&filter_expr),
+                )?)
+            }
+            None => None,
+        };
+        Ok(Self {
+            schema,
+            range_pruner,
+        })
+    }
+
+    /// Return the indices of the stripes in `meta` which can not be pruned.
+    pub fn prune_stripes(&self, meta: &FileMetadata) -> Vec<usize> {
+        let stripes = &meta.footer.stripes;
+        let (Some(pruner), Some(root)) = (&self.range_pruner, meta.footer.types.first()) else {
+            return (0..stripes.len()).collect();
+        };
+        let column_indices = self
+            .schema
+            .fields()
+            .iter()
+            .map(|field| {
+                root.field_names
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(field.name()))
+                    .and_then(|i| root.subtypes.get(i))
+                    .map(|column| *column as usize)
+            })
+            .collect::<Vec<_>>();
+        stripes
+            .iter()
+            .enumerate()
+            .filter(|(i, stripe)| {
+                // Stripes without statistics can't be pruned.
+                let Some(stripe_stats) = meta.metadata.stripe_stats.get(*i) else {
+                    return true;
+                };
+                let stats = collect_stripe_statistics(
+                    &stripe_stats.col_stats,
+                    &self.schema,
+                    &column_indices,
+                    stripe.number_of_rows(),
+                );
+                pruner.should_keep(&stats, None)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// The byte range of a stripe in the file, including its indexes and footer.
+pub fn stripe_range(stripe: &StripeInformation) -> Range<u64> {
+    let start = stripe.offset();
+    let len = stripe.index_length() + stripe.data_length() + stripe.footer_length();
+    start..start + len
+}
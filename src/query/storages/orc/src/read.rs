@@ -0,0 +1,227 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use opendal::Operator;
+use orc_rust::arrow_reader::NaiveStripeDecoder;
+use orc_rust::arrow_reader::Stripe;
+use orc_rust::Reader;
+
+use crate::meta::orc_arrow_schema;
+use crate::meta::orc_columns;
+use crate::meta::orc_error;
+
+/// Reads stripes of ORC files into blocks of `output_schema`.
+///
+/// Columns are matched by name. The ones missing in a file are filled with `default_values`,
+/// or rejected if `default_values` is [None].
+pub struct OrcReader {
+    operator: Operator,
+    func_ctx: FunctionContext,
+    output_schema: TableSchemaRef,
+    default_values: Option<Vec<Scalar>>,
+    batch_size: usize,
+}
+
+impl OrcReader {
+    pub fn create(
+        operator: Operator,
+        func_ctx: FunctionContext,
+        output_schema: TableSchemaRef,
+        default_values: Option<Vec<Scalar>>,
+        batch_size: usize,
+    ) -> Self {
+        Self {
+            operator,
+            func_ctx,
+            output_schema,
+            default_values,
+            batch_size,
+        }
+    }
+
+    /// Read the stripes starting in `range` of the file.
+    #[async_backtrace::framed]
+    pub async fn read(
+        &self,
+        location: &str,
+        file_size: u64,
+        range: Range<u64>,
+    ) -> Result<Vec<DataBlock>> {
+        let reader = self
+            .operator
+            .reader_with(location)
+            .range(0..file_size)
+            .await?;
+        let mut reader = Reader::new_async(reader)
+            .await
+            .map_err(|e| orc_error(location, e))?;
+        let file_columns = orc_columns(location, reader.metadata())?;
+        let file_schema = TableSchema::try_from(orc_arrow_schema(&file_columns).as_ref())?;
+        let (projection, output_exprs) = self.build_projection(location, &file_schema)?;
+
+        let read_columns = Arc::new(
+            projection
+                .iter()
+                .map(|i| file_columns[*i].clone())
+                .collect::<Vec<_>>(),
+        );
+        let read_arrow_schema = orc_arrow_schema(&read_columns);
+        let read_schema = DataSchema::from(&file_schema.project(&projection));
+        let mut blocks = vec![];
+        for index in 0..reader.metadata().footer.stripes.len() {
+            let Some(info) = reader.stripe(index) else {
+                break;
+            };
+            if !range.contains(&info.offset()) {
+                continue;
+            }
+            let stripe = Stripe::new_async(&mut reader, read_columns.clone(), index, info)
+                .await
+                .map_err(|e| orc_error(location, e))?;
+            let decoder =
+                NaiveStripeDecoder::new(stripe, read_arrow_schema.clone(), self.batch_size)
+                    .map_err(|e| orc_error(location, e))?;
+            for batch in decoder {
+                let batch = batch.map_err(|e| orc_error(location, e))?;
+                let batch = Self::cast_batch(batch, &read_arrow_schema)?;
+                let (block, _) = DataBlock::from_record_batch(&read_schema, &batch)?;
+                let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                let columns = output_exprs
+                    .iter()
+                    .map(|expr| {
+                        let value = evaluator.run(expr)?;
+                        Ok(BlockEntry::new(expr.data_type().clone(), value))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                blocks.push(DataBlock::new(columns, block.num_rows()));
+            }
+        }
+        Ok(blocks)
+    }
+
+    /// Cast the decoded columns to the types of the schema, e.g. the dictionary encoded
+    /// strings to `Utf8` and the binaries to `LargeBinary`.
+    fn cast_batch(batch: RecordBatch, schema: &ArrowSchemaRef) -> Result<RecordBatch> {
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(schema.fields())
+            .map(|(array, field)| {
+                if array.data_type() == field.data_type() {
+                    Ok(array.clone())
+                } else {
+                    Ok(arrow_cast::cast(array, field.data_type())?)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(RecordBatch::try_new(schema.clone(), columns)?)
+    }
+
+    /// Return the file columns to read, in the order of the file, and the expressions
+    /// to build the output columns from them.
+    fn build_projection(
+        &self,
+        location: &str,
+        file_schema: &TableSchema,
+    ) -> Result<(Vec<usize>, Vec<Expr>)> {
+        let positions = self
+            .output_schema
+            .fields()
+            .iter()
+            .map(|to_field| {
+                file_schema
+                    .fields()
+                    .iter()
+                    .position(|f| f.name().eq_ignore_ascii_case(to_field.name()))
+            })
+            .collect::<Vec<_>>();
+        let mut projection = positions.iter().flatten().cloned().collect::<Vec<_>>();
+        if projection.is_empty() && !self.output_schema.fields().is_empty() {
+            return Err(ErrorCode::BadBytes(format!(
+                "no column name match in orc file {location}",
+            )));
+        }
+        if projection.is_empty() && !file_schema.fields().is_empty() {
+            // Still need to read a column to know the number of rows.
+            projection.push(0);
+        }
+        projection.sort();
+        projection.dedup();
+
+        let mut output_exprs = Vec::with_capacity(positions.len());
+        for (i, (to_field, pos)) in self
+            .output_schema
+            .fields()
+            .iter()
+            .zip(positions)
+            .enumerate()
+        {
+            let expr = match pos {
+                Some(pos) => {
+                    let from_field = file_schema.field(pos);
+                    let expr = Expr::ColumnRef {
+                        span: None,
+                        id: projection.iter().position(|p| *p == pos).unwrap(),
+                        data_type: from_field.data_type().into(),
+                        display_name: from_field.name().clone(),
+                    };
+                    if from_field.data_type() == to_field.data_type() {
+                        expr
+                    } else {
+                        check_cast(
+                            None,
+                            false,
+                            expr,
+                            &to_field.data_type().into(),
+                            &BUILTIN_FUNCTIONS,
+                        )?
+                    }
+                }
+                None => match &self.default_values {
+                    Some(default_values) => Expr::Constant {
+                        span: None,
+                        scalar: default_values[i].clone(),
+                        data_type: to_field.data_type().into(),
+                    },
+                    None => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "column {} not found in orc file {location}",
+                            to_field.name()
+                        )));
+                    }
+                },
+            };
+            output_exprs.push(expr);
+        }
+        Ok((projection, output_exprs))
+    }
+}
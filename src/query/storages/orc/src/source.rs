@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+
+use crate::OrcReader;
+use crate::OrcStripePart;
+
+/// Reads the [`OrcStripePart`]s of the query one by one.
+pub struct OrcSource {
+    ctx: Arc<dyn TableContext>,
+    reader: Arc<OrcReader>,
+    blocks: VecDeque<DataBlock>,
+}
+
+impl OrcSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        reader: Arc<OrcReader>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, OrcSource {
+            ctx,
+            reader,
+            blocks: VecDeque::new(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for OrcSource {
+    const NAME: &'static str = "OrcSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            let Some(part) = self.ctx.get_partition() else {
+                return Ok(None);
+            };
+            let part = part
+                .as_any()
+                .downcast_ref::<OrcStripePart>()
+                .ok_or_else(|| ErrorCode::Internal("OrcSource must read OrcStripePart"))?;
+            let blocks = self
+                .reader
+                .read(&part.location, part.file_size, part.range.clone())
+                .await?;
+            self.blocks = blocks.into();
+        }
+    }
+}
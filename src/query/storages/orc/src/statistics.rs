@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use orc_rust::proto::ColumnStatistics as OrcColumnStatistics;
+
+/// Collect the statistics of the top-level columns of `schema` from the statistics of a stripe.
+///
+/// `column_indices` are the positions of the fields of `schema` in the ORC type tree,
+/// [None] if the field is not stored in the file.
+pub fn collect_stripe_statistics(
+    stripe_statistics: &[OrcColumnStatistics],
+    schema: &TableSchema,
+    column_indices: &[Option<usize>],
+    num_rows: u64,
+) -> StatisticsOfColumns {
+    let mut stats = StatisticsOfColumns::new();
+    for (field, index) in schema.fields().iter().zip(column_indices) {
+        let Some(s) = index.and_then(|i| stripe_statistics.get(i)) else {
+            continue;
+        };
+        if let Some(col_stats) = convert_column_statistics(s, field.data_type(), num_rows) {
+            stats.insert(field.column_id(), col_stats);
+        }
+    }
+    stats
+}
+
+/// according to https://orc.apache.org/specification/ORCv1/#column-statistics
+fn convert_column_statistics(
+    s: &OrcColumnStatistics,
+    typ: &TableDataType,
+    num_rows: u64,
+) -> Option<ColumnStatistics> {
+    let (min, max) = match typ.remove_nullable() {
+        TableDataType::Number(number) => match (&s.int_statistics, &s.double_statistics) {
+            (Some(stats), _) => {
+                let (min, max) = (stats.minimum?, stats.maximum?);
+                match number {
+                    NumberDataType::Int8 => (Scalar::from(min as i8), Scalar::from(max as i8)),
+                    NumberDataType::Int16 => (Scalar::from(min as i16), Scalar::from(max as i16)),
+                    NumberDataType::Int32 => (Scalar::from(min as i32), Scalar::from(max as i32)),
+                    NumberDataType::Int64 => (Scalar::from(min), Scalar::from(max)),
+                    _ => return None,
+                }
+            }
+            (_, Some(stats)) => {
+                let (min, max) = (stats.minimum?, stats.maximum?);
+                match number {
+                    NumberDataType::Float32 => (Scalar::from(min as f32), Scalar::from(max as f32)),
+                    NumberDataType::Float64 => (Scalar::from(min), Scalar::from(max)),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        },
+        TableDataType::String => {
+            let stats = s.string_statistics.as_ref()?;
+            (
+                Scalar::String(stats.minimum.clone()?),
+                Scalar::String(stats.maximum.clone()?),
+            )
+        }
+        TableDataType::Date => {
+            let stats = s.date_statistics.as_ref()?;
+            (Scalar::Date(stats.minimum?), Scalar::Date(stats.maximum?))
+        }
+        TableDataType::Boolean => {
+            let true_count = *s.bucket_statistics.as_ref()?.count.first()?;
+            (
+                Scalar::Boolean(true_count == s.number_of_values()),
+                Scalar::Boolean(true_count > 0),
+            )
+        }
+        _ => return None,
+    };
+    Some(ColumnStatistics::new(
+        min,
+        max,
+        num_rows.saturating_sub(s.number_of_values()),
+        0, // this field is not used.
+        None,
+    ))
+}
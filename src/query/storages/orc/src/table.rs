@@ -0,0 +1,181 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourceInfo;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Scalar;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::FileStatus;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::read_orc_metadata_async;
+use crate::stripe_range;
+use crate::OrcPruner;
+use crate::OrcReader;
+use crate::OrcSource;
+use crate::OrcStripePart;
+
+/// Reads ORC files of a stage, for both `COPY INTO` and `SELECT FROM @stage`.
+pub struct OrcTable {}
+
+impl OrcTable {
+    #[async_backtrace::framed]
+    pub async fn do_read_partitions(
+        stage_table_info: &StageTableInfo,
+        ctx: Arc<dyn TableContext>,
+        push_down: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let operator = init_stage_operator(&stage_table_info.stage_info)?;
+        let files = match &stage_table_info.files_to_copy {
+            Some(files) => files.clone(),
+            None => {
+                stage_table_info
+                    .files_info
+                    .list(&operator, false, None)
+                    .await?
+            }
+        };
+        let metas =
+            futures::stream::iter(files.iter().map(|file| {
+                read_orc_metadata_async(operator.clone(), &file.path, Some(file.size))
+            }))
+            .buffered(max_threads.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let pruner = OrcPruner::try_create(
+            ctx.get_function_context()?,
+            stage_table_info.schema.clone(),
+            &push_down,
+        )?;
+        let copy_status = ctx.get_copy_status();
+        let mut stats = PartStatistics::default();
+        let mut parts = vec![];
+        for meta in metas.iter() {
+            let stripes = &meta.metadata.footer.stripes;
+            let num_rows = stripes
+                .iter()
+                .map(|s| s.number_of_rows() as usize)
+                .sum::<usize>();
+            if !stage_table_info.is_select {
+                copy_status.add_chunk(meta.location.as_str(), FileStatus {
+                    num_rows_loaded: num_rows,
+                    error: None,
+                });
+            }
+            stats.read_rows += num_rows;
+            stats.read_bytes += meta.size as usize;
+            stats.partitions_total += stripes.len();
+            for i in pruner.prune_stripes(&meta.metadata) {
+                let part = OrcStripePart {
+                    location: meta.location.clone(),
+                    file_size: meta.size,
+                    range: stripe_range(&stripes[i]),
+                    num_rows: stripes[i].number_of_rows(),
+                };
+                parts.push(Arc::new(Box::new(part) as Box<dyn PartInfo>));
+            }
+        }
+        stats.partitions_scanned = parts.len();
+
+        Ok((
+            stats,
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    pub fn do_read_data(
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let settings = ctx.get_settings();
+        let max_threads = settings.get_max_threads()? as usize;
+        let batch_size = settings.get_max_block_size()? as usize;
+        let stage_table_info =
+            if let DataSourceInfo::StageSource(stage_table_info) = &plan.source_info {
+                stage_table_info
+            } else {
+                return Err(ErrorCode::Internal(
+                    "bug: OrcTable::read_data must be called with StageSource",
+                ));
+            };
+
+        let operator = init_stage_operator(&stage_table_info.stage_info)?;
+        let output_schema = plan.schema();
+        let default_values = Self::default_values_of_missing_fields(stage_table_info, plan)?;
+        let reader = Arc::new(OrcReader::create(
+            operator,
+            ctx.get_function_context()?,
+            output_schema,
+            default_values,
+            batch_size,
+        ));
+
+        ctx.set_partitions(plan.parts.clone())?;
+        pipeline.add_source(
+            |output| OrcSource::create(ctx.clone(), output, reader.clone()),
+            max_threads,
+        )
+    }
+
+    /// Values of the output columns missing in a file according to `MISSING_FIELD_AS`.
+    ///
+    /// Missing columns are only allowed when loading with `MISSING_FIELD_AS` other than `ERROR`.
+    fn default_values_of_missing_fields(
+        stage_table_info: &StageTableInfo,
+        plan: &DataSourcePlan,
+    ) -> Result<Option<Vec<Scalar>>> {
+        let (FileFormatParams::Orc(params), Some(default_values)) = (
+            &stage_table_info.stage_info.file_format_params,
+            &stage_table_info.default_values,
+        ) else {
+            return Ok(None);
+        };
+        if params.missing_field_as == NullAs::Error {
+            return Ok(None);
+        }
+        plan.schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                let value = match params.missing_field_as {
+                    NullAs::Null if field.is_nullable() => Scalar::Null,
+                    _ => {
+                        let i = stage_table_info.schema.index_of(field.name())?;
+                        default_values[i].clone()
+                    }
+                };
+                Ok(value)
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Some)
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utilities shared by the sinks writing parquet data files of lake tables,
+//! e.g. iceberg, delta and hive tables.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::ScalarRef;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+/// Max times to retry the commit when the table is changed by other writers.
+pub const MAX_COMMIT_RETRIES: usize = 5;
+
+/// Buffered bytes of blocks to write a data file.
+pub const MAX_FILE_BYTES: usize = 128 * 1024 * 1024;
+
+/// Add the sink committing the written data files to the pipeline.
+///
+/// Data files are committed once when the sink is finished, so there should be only one sink.
+pub fn add_commit_sink<F>(pipeline: &mut Pipeline, f: F) -> Result<()>
+where F: Fn(Arc<InputPort>) -> Result<ProcessorPtr> {
+    pipeline.try_resize(1)?;
+    pipeline.add_sink(f)
+}
+
+pub fn overwrite_unsupported(engine: &str, table: &str) -> ErrorCode {
+    ErrorCode::Unimplemented(format!(
+        "INSERT OVERWRITE is not supported for {engine} table {table} yet"
+    ))
+}
+
+/// Convert the block to the arrow schema of data files,
+/// columns are casted if the types are different, e.g. `LargeUtf8` to `Utf8`.
+pub fn to_record_batch(block: DataBlock, arrow_schema: &ArrowSchemaRef) -> Result<RecordBatch> {
+    let block = block.convert_to_full();
+    if block.num_columns() != arrow_schema.fields().len() {
+        return Err(ErrorCode::Internal(format!(
+            "Data file expects {} columns, but got {}",
+            arrow_schema.fields().len(),
+            block.num_columns()
+        )));
+    }
+    let columns = block
+        .columns()
+        .iter()
+        .zip(arrow_schema.fields())
+        .map(|(entry, field)| {
+            let array = entry
+                .value
+                .to_owned()
+                .into_column()
+                .unwrap()
+                .into_arrow_rs();
+            if array.data_type() == field.data_type() {
+                Ok(array)
+            } else {
+                Ok(arrow_cast::cast(&array, field.data_type())?)
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(RecordBatch::try_new(arrow_schema.clone(), columns)?)
+}
+
+/// Write the batch as a snappy compressed parquet file.
+pub fn write_parquet(batch: RecordBatch) -> Result<Vec<u8>> {
+    let props = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut data = vec![];
+    let mut writer = ArrowWriter::try_new(&mut data, batch.schema(), Some(props))?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(data)
+}
+
+/// Split the block by values of partition columns, partition columns are removed.
+///
+/// Values are converted by `to_value`, the whole block is one partition
+/// without values if there are no partition columns.
+pub fn split_by_partition<V, F>(
+    block: DataBlock,
+    partition_columns: &[FieldIndex],
+    to_value: F,
+) -> Result<Vec<(Vec<V>, DataBlock)>>
+where
+    V: Ord,
+    F: Fn(ScalarRef) -> Result<V>,
+{
+    let block = block.convert_to_full();
+    if partition_columns.is_empty() {
+        return Ok(vec![(vec![], block)]);
+    }
+
+    let mut groups: BTreeMap<Vec<V>, Vec<u32>> = BTreeMap::new();
+    for row in 0..block.num_rows() {
+        let values = partition_columns
+            .iter()
+            .map(|index| to_value(block.get_by_offset(*index).value.index(row).unwrap()))
+            .collect::<Result<Vec<_>>>()?;
+        groups.entry(values).or_default().push(row as u32);
+    }
+
+    let data_columns = (0..block.num_columns())
+        .filter(|i| !partition_columns.contains(i))
+        .collect::<Vec<_>>();
+    groups
+        .into_iter()
+        .map(|(values, rows)| {
+            let taken = block.take(&rows, &mut None)?;
+            let columns = data_columns
+                .iter()
+                .map(|i| taken.get_by_offset(*i).clone())
+                .collect();
+            Ok((values, DataBlock::new(columns, rows.len())))
+        })
+        .collect()
+}
@@ -21,6 +21,7 @@
 #![feature(box_patterns)]
 #![allow(clippy::diverging_sub_expression)]
 
+pub mod lake_sink;
 mod parquet2;
 mod parquet_part;
 mod parquet_rs;
@@ -32,9 +32,12 @@ use parquet::arrow::arrow_reader::ArrowPredicateFn;
 use parquet::arrow::arrow_reader::ArrowReaderOptions;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use parquet::arrow::arrow_reader::RowFilter;
+use parquet::arrow::arrow_reader::RowSelection;
+use parquet::arrow::arrow_reader::RowSelector;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
 use parquet::arrow::ParquetRecordBatchStreamBuilder;
 use parquet::arrow::ProjectionMask;
+use parquet::file::metadata::ParquetMetaData;
 
 use crate::parquet_rs::parquet_reader::predicate::ParquetPredicate;
 use crate::parquet_rs::parquet_reader::utils::transform_record_batch;
@@ -68,11 +71,14 @@ pub struct ParquetRSFullReader {
 }
 
 impl ParquetRSFullReader {
-    // partition_fields is only used for delta table engine.
+    // partition_fields and deleted_rows are only used for delta table engine.
+    //
+    // deleted_rows are sorted positions of rows in the file which should be skipped.
     pub async fn prepare_data_stream(
         &self,
         loc: &str,
         partition_fields: Option<&[(TableField, Scalar)]>,
+        deleted_rows: Option<&[u64]>,
     ) -> Result<ParquetRecordBatchStream<Reader>> {
         let partition_values_map = partition_fields.map(|arr| {
             arr.iter()
@@ -93,30 +99,43 @@ impl ParquetRSFullReader {
         let mut all_pruned = false;
 
         let file_meta = builder.metadata().clone();
+        let mut selected_row_groups = (0..file_meta.num_row_groups()).collect::<Vec<_>>();
+        let mut row_selection = None;
 
         // Prune row groups.
         if let Some(pruner) = &self.pruner {
-            let (selected_row_groups, omits) =
+            let (row_groups, omits) =
                 pruner.prune_row_groups(&file_meta, None, partition_values_map.as_ref())?;
             all_pruned = omits.iter().all(|x| *x);
-            builder = builder.with_row_groups(selected_row_groups.clone());
+            builder = builder.with_row_groups(row_groups.clone());
+            selected_row_groups = row_groups;
 
             if !all_pruned {
-                let row_selection = pruner.prune_pages(
+                row_selection = pruner.prune_pages(
                     &file_meta,
                     &selected_row_groups,
                     partition_values_map.as_ref(),
                 )?;
-
-                if let Some(row_selection) = row_selection {
-                    builder = builder.with_row_selection(row_selection);
-                }
             } else {
                 metrics_inc_omit_filter_rowgroups(file_meta.num_row_groups() as u64);
                 metrics_inc_omit_filter_rows(file_meta.file_metadata().num_rows() as u64);
             }
         }
 
+        if !all_pruned {
+            if let Some(deleted_rows) = deleted_rows {
+                let selection =
+                    deleted_rows_to_selection(&file_meta, &selected_row_groups, deleted_rows);
+                row_selection = Some(match row_selection {
+                    Some(s) => s.intersection(&selection),
+                    None => selection,
+                });
+            }
+            if let Some(row_selection) = row_selection {
+                builder = builder.with_row_selection(row_selection);
+            }
+        }
+
         if !all_pruned {
             if let Some(predicate) = self.predicate.as_ref() {
                 let projection = predicate.projection().clone();
@@ -231,3 +250,36 @@ impl ParquetRSFullReader {
         }
     }
 }
+
+/// Build the [`RowSelection`] of selected row groups which skips the deleted rows.
+fn deleted_rows_to_selection(
+    file_meta: &ParquetMetaData,
+    selected_row_groups: &[usize],
+    deleted_rows: &[u64],
+) -> RowSelection {
+    let mut row_group_offsets = Vec::with_capacity(file_meta.num_row_groups());
+    let mut offset = 0;
+    for rg in file_meta.row_groups() {
+        row_group_offsets.push(offset);
+        offset += rg.num_rows() as u64;
+    }
+
+    let mut selectors = vec![];
+    for rg in selected_row_groups {
+        let start = row_group_offsets[*rg];
+        let end = start + file_meta.row_group(*rg).num_rows() as u64;
+        let first = deleted_rows.partition_point(|v| *v < start);
+        let mut pos = start;
+        for row in deleted_rows[first..].iter().take_while(|v| **v < end) {
+            if *row > pos {
+                selectors.push(RowSelector::select((*row - pos) as usize));
+            }
+            selectors.push(RowSelector::skip(1));
+            pos = *row + 1;
+        }
+        if end > pos {
+            selectors.push(RowSelector::select((end - pos) as usize));
+        }
+    }
+    RowSelection::from(selectors)
+}
@@ -43,6 +43,7 @@ use databend_common_pipeline_sources::input_formats::SplitInfo;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::STDIN_FD;
+use databend_common_storages_orc::OrcTable;
 use databend_common_storages_parquet::ParquetTableForCopy;
 use log::debug;
 use opendal::Operator;
@@ -127,6 +128,12 @@ impl Table for StageTable {
         ) {
             return ParquetTableForCopy::do_read_partitions(stage_info, ctx, _push_downs).await;
         }
+        if matches!(
+            stage_info.stage_info.file_format_params,
+            FileFormatParams::Orc(_)
+        ) {
+            return OrcTable::do_read_partitions(stage_info, ctx, _push_downs).await;
+        }
         // User set the files.
         let files = if let Some(files) = &stage_info.files_to_copy {
             files.clone()
@@ -177,6 +184,12 @@ impl Table for StageTable {
         ) {
             return ParquetTableForCopy::do_read_data(ctx, plan, pipeline, _put_cache);
         }
+        if matches!(
+            stage_table_info.stage_info.file_format_params,
+            FileFormatParams::Orc(_)
+        ) {
+            return OrcTable::do_read_data(ctx, plan, pipeline);
+        }
 
         let projection = if let Some(PushDownInfo {
             projection: Some(Projection::Columns(columns)),
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PushDownInfo;
@@ -23,7 +24,6 @@ use databend_common_cloud_control::pb::ShowTaskRunsRequest;
 use databend_common_cloud_control::pb::TaskRun;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::Int32Type;
@@ -33,13 +33,41 @@ use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_run_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
+use crate::tasks_table::make_pb_schedule_options;
+use crate::tasks_table::make_pb_warehouse_options;
+
+/// Converts a task run of the built-in scheduler into the message of cloud control.
+pub fn make_pb_task_run(run: principal::TaskRun) -> TaskRun {
+    let task = run.task;
+    TaskRun {
+        task_id: task.task_id,
+        task_name: task.task_name,
+        query_text: task.query_text,
+        comment: task.comment,
+        owner: task.owner,
+        schedule_options: task.schedule_options.map(make_pb_schedule_options),
+        run_id: run.run_id,
+        attempt_number: run.attempt_number,
+        warehouse_options: task.warehouse_options.map(make_pb_warehouse_options),
+        state: run.state as i32,
+        error_code: run.error_code,
+        error_message: run.error_message,
+        scheduled_time: run.scheduled_at.to_rfc3339(),
+        completed_time: run.completed_at.map(|t| t.to_rfc3339()),
+        query_id: run.query_id,
+        condition_text: task.when_condition.unwrap_or_default(),
+        root_task_id: run.root_task_id.to_string(),
+    }
+}
 
 pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock> {
     let mut name: Vec<String> = Vec::with_capacity(task_runs.len());
@@ -119,17 +147,28 @@ impl AsyncSystemTable for TaskHistoryTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_available_roles().await?;
+
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.task_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Task runs are recorded by the built-in scheduler.
+            let owners = available_roles
+                .iter()
+                .map(|x| x.identity())
+                .collect::<HashSet<_>>();
+            let mut runs = UserApiProvider::instance()
+                .get_task_runs(&tenant, None)
+                .await?
+                .into_iter()
+                .filter(|run| owners.contains(run.task.owner.as_str()))
+                .collect::<Vec<_>>();
+            runs.sort_by(|a, b| b.scheduled_at.cmp(&a.scheduled_at));
+            return parse_task_runs_to_datablock(runs.into_iter().map(make_pb_task_run).collect());
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().to_string();
-        let available_roles = ctx.get_available_roles().await?;
         let req = ShowTaskRunsRequest {
             tenant_id: tenant.clone(),
             scheduled_time_start: "".to_string(),
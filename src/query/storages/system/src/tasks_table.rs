@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PushDownInfo;
@@ -19,11 +20,12 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
+use databend_common_cloud_control::pb::ScheduleOptions;
 use databend_common_cloud_control::pb::ShowTasksRequest;
 use databend_common_cloud_control::pb::Task;
+use databend_common_cloud_control::pb::WarehouseOptions;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::StringType;
@@ -31,14 +33,54 @@ use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 
+/// Converts a task of the built-in scheduler into the message of cloud control,
+/// so that tasks from both sources are rendered in the same way.
+pub fn make_pb_task(task: principal::Task) -> Task {
+    Task {
+        task_id: task.task_id,
+        task_name: task.task_name,
+        query_text: task.query_text,
+        comment: task.comment,
+        owner: task.owner,
+        schedule_options: task.schedule_options.map(make_pb_schedule_options),
+        warehouse_options: task.warehouse_options.map(make_pb_warehouse_options),
+        next_scheduled_at: task.next_scheduled_at.map(|t| t.to_rfc3339()),
+        suspend_task_after_num_failures: task.suspend_task_after_num_failures.map(|v| v as i32),
+        status: task.status as i32,
+        created_at: task.created_at.to_rfc3339(),
+        updated_at: task.updated_at.to_rfc3339(),
+        last_suspended_at: task.last_suspended_at.map(|t| t.to_rfc3339()),
+        after: task.after,
+        when_condition: task.when_condition,
+    }
+}
+
+pub fn make_pb_schedule_options(opts: principal::ScheduleOptions) -> ScheduleOptions {
+    ScheduleOptions {
+        interval: opts.interval,
+        cron: opts.cron,
+        time_zone: opts.time_zone,
+        schedule_type: opts.schedule_type as i32,
+    }
+}
+
+pub fn make_pb_warehouse_options(opts: principal::WarehouseOptions) -> WarehouseOptions {
+    WarehouseOptions {
+        warehouse: opts.warehouse,
+        using_warehouse_size: opts.using_warehouse_size,
+    }
+}
+
 pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
     let mut created_on: Vec<i64> = Vec::with_capacity(tasks.len());
     let mut name: Vec<String> = Vec::with_capacity(tasks.len());
@@ -113,17 +155,29 @@ impl AsyncSystemTable for TasksTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_available_roles().await?;
+
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.tasks table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Tasks are managed by the built-in scheduler.
+            let owners = available_roles
+                .iter()
+                .map(|x| x.identity())
+                .collect::<HashSet<_>>();
+            let mut tasks = UserApiProvider::instance()
+                .get_tasks(&tenant)
+                .await?
+                .into_iter()
+                .map(|v| v.data)
+                .filter(|task| owners.contains(task.owner.as_str()))
+                .collect::<Vec<_>>();
+            tasks.sort_by(|a, b| a.task_name.cmp(&b.task_name));
+            return parse_tasks_to_datablock(tasks.into_iter().map(make_pb_task).collect());
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().to_string();
-        let available_roles = ctx.get_available_roles().await?;
         let req = ShowTasksRequest {
             tenant_id: tenant.clone(),
             name_like: "".to_string(),
@@ -21,7 +21,9 @@ extern crate core;
 mod jwt;
 mod network_policy;
 mod password_policy;
+mod pipe;
 mod role_mgr;
+mod task;
 mod user;
 mod user_api;
 mod user_mgr;
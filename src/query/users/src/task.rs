@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::TaskApi;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+/// task operations.
+impl UserApiProvider {
+    // Add a new task.
+    #[async_backtrace::framed]
+    pub async fn add_task(&self, tenant: &str, task: Task, if_not_exists: bool) -> Result<u64> {
+        let client = self.get_task_api_client(tenant)?;
+        match client.add_task(task).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::TASK_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back(" (while add task)"))
+                }
+            }
+        }
+    }
+
+    // Update a task if its seq matches.
+    #[async_backtrace::framed]
+    pub async fn update_task(&self, tenant: &str, task: Task, seq: MatchSeq) -> Result<u64> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .update_task(task, seq)
+            .await
+            .map_err(|e| e.add_message_back(" (while update task)"))
+    }
+
+    // Drop a task by name.
+    #[async_backtrace::framed]
+    pub async fn drop_task(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.get_task_api_client(tenant)?;
+        match client.drop_task(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_TASK {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop task)"))
+                }
+            }
+        }
+    }
+
+    // Get a task by name, together with its seq.
+    #[async_backtrace::framed]
+    pub async fn get_task(&self, tenant: &str, name: &str) -> Result<SeqV<Task>> {
+        let client = self.get_task_api_client(tenant)?;
+        client.get_task(name, MatchSeq::GE(0)).await
+    }
+
+    // Get all tasks by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_tasks(&self, tenant: &str) -> Result<Vec<SeqV<Task>>> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .get_tasks()
+            .await
+            .map_err(|e| e.add_message_back(" (while get tasks)."))
+    }
+
+    // Save the state of a task run.
+    #[async_backtrace::framed]
+    pub async fn upsert_task_run(&self, tenant: &str, run: TaskRun) -> Result<u64> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .upsert_task_run(run)
+            .await
+            .map_err(|e| e.add_message_back(" (while save task run)."))
+    }
+
+    // Get the run history of a task, or of all tasks if `task_name` is None.
+    #[async_backtrace::framed]
+    pub async fn get_task_runs(
+        &self,
+        tenant: &str,
+        task_name: Option<&str>,
+    ) -> Result<Vec<TaskRun>> {
+        let client = self.get_task_api_client(tenant)?;
+        client
+            .get_task_runs(task_name)
+            .await
+            .map_err(|e| e.add_message_back(" (while get task runs)."))
+    }
+}
@@ -26,6 +26,8 @@ use databend_common_management::NetworkPolicyApi;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyApi;
 use databend_common_management::PasswordPolicyMgr;
+use databend_common_management::PipeApi;
+use databend_common_management::PipeMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
 use databend_common_management::RoleApi;
@@ -34,6 +36,8 @@ use databend_common_management::SettingApi;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TaskApi;
+use databend_common_management::TaskMgr;
 use databend_common_management::UdfApi;
 use databend_common_management::UdfMgr;
 use databend_common_management::UserApi;
@@ -189,6 +193,14 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_pipe_api_client(&self, tenant: &str) -> Result<Arc<impl PipeApi>> {
+        Ok(Arc::new(PipeMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_task_api_client(&self, tenant: &str) -> Result<Arc<impl TaskApi>> {
+        Ok(Arc::new(TaskMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -15,8 +15,10 @@
 mod jwt;
 mod network_policy;
 mod password_policy;
+mod pipe;
 mod role_cache_mgr;
 mod role_mgr;
 mod role_util;
+mod task;
 mod user_mgr;
 mod user_udf;
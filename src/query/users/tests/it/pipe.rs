@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_grpc::RpcClientConf;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_users::UserApiProvider;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pipe() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let tenant = "test";
+
+    let user_mgr = UserApiProvider::try_create_simple(conf, tenant).await?;
+    let pipe_name = "test_pipe";
+
+    // add pipe
+    let pipe = PipeInfo {
+        name: pipe_name.to_string(),
+        auto_ingest: true,
+        execution_paused: false,
+        comment: "".to_string(),
+        copy_stmt: "COPY INTO t1 FROM @s1".to_string(),
+        created_on: Utc.with_ymd_and_hms(2024, 1, 29, 12, 0, 9).unwrap(),
+        updated_on: None,
+    };
+    user_mgr.add_pipe(tenant, pipe.clone(), false).await?;
+
+    let res = user_mgr.add_pipe(tenant, pipe.clone(), false).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::PIPE_ALREADY_EXISTS);
+    user_mgr.add_pipe(tenant, pipe.clone(), true).await?;
+
+    // update pipe
+    let res = user_mgr
+        .update_pipe(
+            tenant,
+            pipe_name,
+            Some(true),
+            Some("paused".to_string()),
+            false,
+        )
+        .await?;
+    assert!(res.is_some());
+
+    let new_pipe = user_mgr.get_pipe(tenant, pipe_name).await?;
+    assert!(new_pipe.execution_paused);
+    assert_eq!(new_pipe.comment, "paused");
+    assert_eq!(new_pipe.copy_stmt, pipe.copy_stmt);
+    assert!(new_pipe.updated_on.is_some());
+
+    let res = user_mgr
+        .update_pipe(tenant, "unknown_pipe", Some(false), None, true)
+        .await?;
+    assert!(res.is_none());
+
+    let pipes = user_mgr.get_pipes(tenant).await?;
+    assert_eq!(pipes.len(), 1);
+
+    // drop pipe
+    user_mgr.drop_pipe(tenant, pipe_name, false).await?;
+    let res = user_mgr.get_pipe(tenant, pipe_name).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_PIPE);
+
+    let res = user_mgr.drop_pipe(tenant, pipe_name, false).await;
+    assert!(res.is_err());
+    user_mgr.drop_pipe(tenant, pipe_name, true).await?;
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_grpc::RpcClientConf;
+use databend_common_meta_app::principal::ScheduleOptions;
+use databend_common_meta_app::principal::ScheduleType;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::TaskRunState;
+use databend_common_meta_app::principal::TaskStatus;
+use databend_common_meta_types::MatchSeq;
+use databend_common_users::UserApiProvider;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let tenant = "test";
+
+    let user_mgr = UserApiProvider::try_create_simple(conf, tenant).await?;
+    let task_name = "test_task";
+
+    // add task
+    let now = Utc.with_ymd_and_hms(2024, 2, 1, 12, 0, 9).unwrap();
+    let task = Task {
+        task_name: task_name.to_string(),
+        query_text: "INSERT INTO t1 SELECT 1".to_string(),
+        owner: "account_admin".to_string(),
+        schedule_options: Some(ScheduleOptions {
+            interval: Some(60),
+            cron: None,
+            time_zone: None,
+            schedule_type: ScheduleType::IntervalType,
+        }),
+        status: TaskStatus::Suspended,
+        created_at: now,
+        updated_at: now,
+        ..Default::default()
+    };
+    user_mgr.add_task(tenant, task.clone(), false).await?;
+
+    let res = user_mgr.add_task(tenant, task.clone(), false).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::TASK_ALREADY_EXISTS);
+    user_mgr.add_task(tenant, task.clone(), true).await?;
+
+    let seq_task = user_mgr.get_task(tenant, task_name).await?;
+    assert_ne!(seq_task.data.task_id, 0);
+    assert_eq!(seq_task.data.query_text, task.query_text);
+
+    // update task, only the holder of the latest seq wins
+    let mut new_task = seq_task.data.clone();
+    new_task.status = TaskStatus::Started;
+    user_mgr
+        .update_task(tenant, new_task.clone(), MatchSeq::Exact(seq_task.seq))
+        .await?;
+    let res = user_mgr
+        .update_task(tenant, new_task, MatchSeq::Exact(seq_task.seq))
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_TASK);
+
+    let tasks = user_mgr.get_tasks(tenant).await?;
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].data.status, TaskStatus::Started);
+
+    // task runs
+    let mut run = TaskRun {
+        task: tasks[0].data.clone(),
+        run_id: "run_1".to_string(),
+        query_id: "query_1".to_string(),
+        attempt_number: 1,
+        state: TaskRunState::Executing,
+        scheduled_at: now,
+        root_task_id: tasks[0].data.task_id,
+        ..Default::default()
+    };
+    user_mgr.upsert_task_run(tenant, run.clone()).await?;
+    run.state = TaskRunState::Succeeded;
+    run.completed_at = Some(now);
+    user_mgr.upsert_task_run(tenant, run.clone()).await?;
+
+    let runs = user_mgr.get_task_runs(tenant, Some(task_name)).await?;
+    assert_eq!(runs, vec![run]);
+    let runs = user_mgr.get_task_runs(tenant, Some("unknown_task")).await?;
+    assert!(runs.is_empty());
+    let runs = user_mgr.get_task_runs(tenant, None).await?;
+    assert_eq!(runs.len(), 1);
+
+    // drop task
+    user_mgr.drop_task(tenant, task_name, false).await?;
+    let res = user_mgr.get_task(tenant, task_name).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_TASK);
+
+    let res = user_mgr.drop_task(tenant, task_name, false).await;
+    assert!(res.is_err());
+    user_mgr.drop_task(tenant, task_name, true).await?;
+
+    Ok(())
+}
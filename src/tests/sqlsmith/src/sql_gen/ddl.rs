@@ -240,6 +240,14 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             lit: Literal::String("1970-01-01 00:00:00".to_string()),
         },
+        TypeName::TimestampTz => Expr::Literal {
+            span: None,
+            lit: Literal::String("1970-01-01 00:00:00+00:00".to_string()),
+        },
+        TypeName::Interval => Expr::Literal {
+            span: None,
+            lit: Literal::String("00:00:00".to_string()),
+        },
         TypeName::Binary => Expr::Literal {
             span: None,
             lit: Literal::String("".to_string()),
@@ -268,6 +276,10 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             lit: Literal::String("null".to_string()),
         },
+        TypeName::Geometry => Expr::Literal {
+            span: None,
+            lit: Literal::String("POINT(0 0)".to_string()),
+        },
         TypeName::Nullable(_) => Expr::Literal {
             span: None,
             lit: Literal::Null,
@@ -298,6 +298,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
         (table, table_reference)
     }
@@ -499,6 +500,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
         Some((
             AlterTableStmt {
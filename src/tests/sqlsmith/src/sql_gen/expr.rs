@@ -682,9 +682,12 @@ fn convert_to_type_name(ty: &DataType) -> TypeName {
         },
         DataType::Date => TypeName::Date,
         DataType::Timestamp => TypeName::Timestamp,
+        DataType::Interval => TypeName::Interval,
+        DataType::TimestampTz => TypeName::TimestampTz,
         DataType::String => TypeName::String,
         DataType::Bitmap => TypeName::Bitmap,
         DataType::Variant => TypeName::Variant,
+        DataType::Geometry => TypeName::Geometry,
         DataType::Binary => TypeName::Binary,
         DataType::Nullable(box inner_ty) => {
             TypeName::Nullable(Box::new(convert_to_type_name(inner_ty)))
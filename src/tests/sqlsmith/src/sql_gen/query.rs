@@ -479,6 +479,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             pivot: None,
             // TODO
             unpivot: None,
+            sample: None,
         };
         (table_ref, schema)
     }
@@ -664,6 +665,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         let join = Join {
             op,
             condition,
+            match_condition: None,
             left: Box::new(left_table),
             right: Box::new(right_table),
         };
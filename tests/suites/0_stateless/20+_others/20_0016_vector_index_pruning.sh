@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# The read bytes of EXPLAIN depend on the compressed data, only the partitions are checked.
+cat <<EOF | $BENDSQL_CLIENT_CONNECT
+drop table if exists t_vector_prune;
+create table t_vector_prune(id int, emb array(float not null)) vector_index_columns='emb';
+insert into t_vector_prune values (1, [1.0, 0.0, 0.0]), (2, [0.9, 0.1, 0.0]);
+insert into t_vector_prune values (3, [0.0, 1.0, 0.0]), (4, [0.0, 0.9, 0.1]);
+insert into t_vector_prune values (6, [0.0, 0.0, 1.0]), (7, [0.1, 0.0, 0.9]);
+EOF
+
+# Only the blocks holding the nearest rows are scanned.
+echo "explain select id from t_vector_prune order by cosine_distance(emb, [1.0, 0.0, 0.0]) limit 2" |
+	$BENDSQL_CLIENT_CONNECT | grep -o "partitions [a-z]*: [0-9]*"
+
+echo "explain select id from t_vector_prune order by cosine_distance(emb, [0.0, 0.1, 1.0]) limit 3" |
+	$BENDSQL_CLIENT_CONNECT | grep -o "partitions [a-z]*: [0-9]*"
+
+# Blocks are not pruned by the vector index if there are filters, only by the range index.
+echo "explain select id from t_vector_prune where id > 2 order by cosine_distance(emb, [1.0, 0.0, 0.0]) limit 1" |
+	$BENDSQL_CLIENT_CONNECT | grep -o "partitions [a-z]*: [0-9]*"
+
+# Blocks indexed by refreshing and by inserting.
+cat <<EOF | $BENDSQL_CLIENT_CONNECT
+drop table t_vector_prune;
+create table t_vector_prune(id int, emb array(float not null));
+insert into t_vector_prune values (1, [1.0, 1.0]), (2, [2.0, 2.0]);
+insert into t_vector_prune values (3, [10.0, 10.0]), (4, [20.0, 20.0]);
+alter table t_vector_prune set options(vector_index_columns='emb', vector_index_distance='l2');
+refresh vector index for t_vector_prune;
+insert into t_vector_prune values (5, [11.0, 11.0]), (6, [0.0, 0.0]);
+EOF
+
+echo "explain select id from t_vector_prune order by emb <-> [10.4, 10.4] limit 2" |
+	$BENDSQL_CLIENT_CONNECT | grep -o "partitions [a-z]*: [0-9]*"
+
+echo "explain select id from t_vector_prune order by emb <-> [1.0, 1.0] limit 1" |
+	$BENDSQL_CLIENT_CONNECT | grep -o "partitions [a-z]*: [0-9]*"
+
+echo "drop table t_vector_prune" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# write into a copy of the table, keep the test data unchanged.
+SRC=$(realpath "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl/)
+ROOT=/tmp/databend_test_iceberg_insert
+rm -rf ${ROOT}
+mkdir -p ${ROOT}
+cp -r ${SRC}/* ${ROOT}/
+
+stmt "drop table if exists test_iceberg_insert;"
+
+echo ">>>> create table test_iceberg_insert engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg_insert engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_iceberg_insert values (7, 'f'), (8, 'g');"
+query "select * from test_iceberg_insert order by id, data;"
+
+stmt "insert into test_iceberg_insert select id + 10, data from test_iceberg_insert where id < 3;"
+query "select count(*), max(id) from test_iceberg_insert;"
+
+stmt "insert overwrite test_iceberg_insert values (1, 'a');"
+query "select * from test_iceberg_insert;"
+
+stmt "insert overwrite test_iceberg_insert select * from test_iceberg_insert where id > 1;"
+query "select count(*) from test_iceberg_insert;"
+
+stmt "drop table test_iceberg_insert;"
+
+rm -rf ${ROOT}
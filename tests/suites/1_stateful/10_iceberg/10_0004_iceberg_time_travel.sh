@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl/)
+
+stmt "drop table if exists test_iceberg_tt;"
+
+echo ">>>> create table test_iceberg_tt engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg_tt engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+query "select snapshot_id, parent_snapshot_id, is_current, timestamp from iceberg_snapshot('default', 'test_iceberg_tt');"
+
+query "select * from test_iceberg_tt at (snapshot => '1620235913653295893') order by id;"
+query "select count(*) from test_iceberg_tt at (snapshot => '3631613356126113181');"
+query "select * from test_iceberg_tt at (timestamp => '2023-08-08 01:35:02'::timestamp) order by id;"
+
+stmt "select * from test_iceberg_tt at (snapshot => '1');"
+
+stmt "drop table test_iceberg_tt;"
@@ -0,0 +1,92 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+REST_PORT=18181
+
+## Start a mock iceberg REST catalog serving iceberg_db.iceberg_tbl in minio.
+python3 - "$REST_PORT" <<'PYEOF' &
+import json
+import sys
+from http.server import BaseHTTPRequestHandler, HTTPServer
+
+ROUTES = {
+    "/v1/config?warehouse=demo": {"defaults": {}, "overrides": {"prefix": "demo"}},
+    "/v1/demo/namespaces": {"namespaces": [["iceberg_db"]]},
+    "/v1/demo/namespaces?parent=iceberg_db": {"namespaces": [["iceberg_db", "nested"]]},
+    "/v1/demo/namespaces?parent=iceberg_db%1Fnested": {"namespaces": []},
+    "/v1/demo/namespaces/iceberg_db": {"namespace": ["iceberg_db"], "properties": {}},
+    "/v1/demo/namespaces/iceberg_db%1Fnested": {
+        "namespace": ["iceberg_db", "nested"],
+        "properties": {},
+    },
+    "/v1/demo/namespaces/iceberg_db/tables": {
+        "identifiers": [{"namespace": ["iceberg_db"], "name": "iceberg_tbl"}]
+    },
+    "/v1/demo/namespaces/iceberg_db/tables/iceberg_tbl": {
+        "metadata-location": "s3://testbucket/iceberg_ctl/iceberg_db/iceberg_tbl/metadata/00002-06fbf608-70dc-4ad5-8cd7-9d08d6e9b556.metadata.json",
+        "metadata": {"location": "s3://testbucket/iceberg_ctl/iceberg_db/iceberg_tbl"},
+    },
+    # An old metadata file of the same table, only the first snapshot is visible.
+    "/v1/demo/namespaces/iceberg_db%1Fnested/tables/iceberg_tbl_v1": {
+        "metadata-location": "s3://testbucket/iceberg_ctl/iceberg_db/iceberg_tbl/metadata/00001-7d548a72-6363-484f-9117-35f1f5b73453.metadata.json",
+        "metadata": {"location": "s3://testbucket/iceberg_ctl/iceberg_db/iceberg_tbl"},
+    },
+}
+
+
+class Handler(BaseHTTPRequestHandler):
+    def do_GET(self):
+        if self.headers.get("Authorization") != "Bearer secret":
+            self.reply(401, {"error": {"message": "unauthorized", "code": 401}})
+        elif self.path in ROUTES:
+            self.reply(200, ROUTES[self.path])
+        else:
+            self.reply(404, {"error": {"message": "not found", "code": 404}})
+
+    def reply(self, code, body):
+        data = json.dumps(body).encode()
+        self.send_response(code)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(data)))
+        self.end_headers()
+        self.wfile.write(data)
+
+    def log_message(self, *args):
+        pass
+
+
+HTTPServer(("127.0.0.1", int(sys.argv[1])), Handler).serve_forever()
+PYEOF
+REST_PID=$!
+trap 'kill $REST_PID' EXIT
+sleep 1
+
+echo "DROP CATALOG IF EXISTS iceberg_rest_ctl" | $BENDSQL_CLIENT_CONNECT
+
+cat <<EOF | $BENDSQL_CLIENT_CONNECT
+CREATE CATALOG iceberg_rest_ctl
+TYPE=ICEBERG
+CONNECTION=(
+    URL='s3://testbucket/'
+    access_key_id ='minioadmin'
+    secret_access_key ='minioadmin'
+    ENDPOINT_URL='${STORAGE_S3_ENDPOINT_URL}'
+    CATALOG_URI='http://127.0.0.1:${REST_PORT}'
+    WAREHOUSE='demo'
+    CATALOG_TOKEN='secret'
+);
+EOF
+
+echo "SHOW DATABASES IN iceberg_rest_ctl;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT count(*) FROM iceberg_rest_ctl.iceberg_db.iceberg_tbl;" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT data FROM iceberg_rest_ctl.iceberg_db.iceberg_tbl WHERE id > 3 ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo 'SELECT count(*) FROM iceberg_rest_ctl.`iceberg_db.nested`.iceberg_tbl_v1;' | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT * FROM iceberg_rest_ctl.iceberg_db.not_exists;" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "not_exists"
+
+echo "DROP CATALOG iceberg_rest_ctl" | $BENDSQL_CLIENT_CONNECT
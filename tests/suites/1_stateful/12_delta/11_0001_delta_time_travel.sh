@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/partitioned/)
+
+stmt "drop table if exists test_delta_tt;"
+
+echo ">>>> create table test_delta_tt engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta_tt engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+query "select version, operation, read_version, is_blind_append from delta_history('default', 'test_delta_tt');"
+query "select version from delta_history('default', 'test_delta_tt') limit 2;"
+
+query "select c1 from test_delta_tt at (snapshot => '3') order by c1;"
+query "select count(*) from test_delta_tt at (snapshot => '1');"
+query "select c1, p4 from test_delta_tt at (snapshot => '4') where p4 > 20 order by c1;"
+
+stmt "select * from test_delta_tt at (snapshot => '100');"
+
+stmt "drop table test_delta_tt;"
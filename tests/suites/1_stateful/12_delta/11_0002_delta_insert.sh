@@ -0,0 +1,55 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# write into a copy of the table, keep the test data unchanged.
+SRC=$(realpath "$CURDIR"/../../../data/delta/partitioned/)
+ROOT=/tmp/databend_test_delta_insert
+rm -rf ${ROOT}
+mkdir -p ${ROOT}
+cp -r ${SRC}/. ${ROOT}/
+
+stmt "drop table if exists test_delta_insert;"
+
+echo ">>>> create table test_delta_insert engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta_insert engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_delta_insert values (30, 51, 52, 53, 54, 55), (30, 61, 62, 63, 64, 65), (40, 71, 72, 73, 74, 75);"
+query "select * from test_delta_insert order by c5;"
+query "select c1 from test_delta_insert where p0 = 30 order by c1;"
+
+stmt "insert into test_delta_insert select p0 + 100, c1, p2, c3, p4, c5 from test_delta_insert where p0 = 10;"
+query "select count(*), max(p0) from test_delta_insert;"
+
+query "select version, operation from delta_history('default', 'test_delta_insert') limit 2;"
+query "select count(*) from test_delta_insert at (snapshot => '6');"
+
+stmt "insert overwrite test_delta_insert values (1, 2, 3, 4, 5, 6);"
+
+# Add a CHECK constraint like other engines, tables with constraints are not writable.
+python3 - "${ROOT}/_delta_log" <<'PYEOF'
+import json
+import os
+import sys
+
+log = sys.argv[1]
+versions = sorted(int(f[:20]) for f in os.listdir(log) if f.endswith(".json"))
+meta = None
+for v in versions:
+    for line in open(f"{log}/{v:020}.json"):
+        action = json.loads(line)
+        if "metaData" in action:
+            meta = action
+meta["metaData"]["configuration"]["delta.constraints.c1_positive"] = "c1 > 0"
+with open(f"{log}/{versions[-1] + 1:020}.json", "w") as f:
+    f.write(json.dumps({"protocol": {"minReaderVersion": 1, "minWriterVersion": 3}}) + "\n")
+    f.write(json.dumps(meta) + "\n")
+PYEOF
+
+echo ">>>> insert into test_delta_insert values (1, 2, 3, 4, 5, 6);"
+echo "insert into test_delta_insert values (1, 2, 3, 4, 5, 6);" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "CHECK constraint"
+
+stmt "drop table test_delta_insert;"
+
+rm -rf ${ROOT}
@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/deletion_vector/)
+
+stmt "drop table if exists test_delta_dv;"
+
+echo ">>>> create table test_delta_dv engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta_dv engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+# rows deleted by inline and file deletion vectors are skipped.
+query "select * from test_delta_dv order by id;"
+query "select count(*) from test_delta_dv where id > 0;"
+query "select * from test_delta_dv at (snapshot => '0') order by id;"
+
+stmt "drop table test_delta_dv;"
@@ -0,0 +1,4 @@
+select 'desc hive.default.t_orc';
+desc hive.default.t_orc;
+select * from hive.default.t_orc order by id;
+select name from hive.default.t_orc where id > 10;
@@ -0,0 +1,11 @@
+insert into hive.default.t_w values (1, 'a'), (2, 'b');
+select * from hive.default.t_w order by id;
+insert into hive.default.customer_w values ('foo1', 'ASIA'), ('foo2', 'EUROPE'), ('foo3', 'ASIA');
+select * from hive.default.customer_w order by foo;
+alter table hive.default.customer_w add partition (c_region = 'AFRICA');
+alter table hive.default.customer_w add if not exists partition (c_region = 'AFRICA');
+insert into hive.default.customer_w values ('foo4', 'AFRICA');
+select c_region, count(*) from hive.default.customer_w group by c_region order by c_region;
+alter table hive.default.customer_w drop partition (c_region = 'EUROPE');
+alter table hive.default.customer_w drop if exists partition (c_region = 'EUROPE');
+select * from hive.default.customer_w order by foo;